@@ -1,14 +1,19 @@
 //! Info controller: connects routes to info usecases
 
-use axum::extract::{State};
+use axum::extract::{Path, State};
+use chrono::{DateTime, Utc};
 use axum::Json;
 use serde_json::Value;
 
 use crate::api::dto::ApiResponse;
 use crate::api::util::json::to_json;
 use crate::app_state::AppState;
+use crate::core::persistence::info::fixed::exchange_rate::info_exchange_rate_entity::InfoExchangeRateEntity;
 use crate::core::persistence::info::fixed::unit_price::info_unit_price_entity::InfoUnitPriceEntity;
 use crate::core::persistence::info::fixed::version::info_version_entity::InfoVersionEntity;
+use crate::domain::info::dto::info_exchange_rate_upsert_request::InfoExchangeRateUpsertRequest;
+use crate::domain::info::dto::info_price_catalog_report_dto::InfoPriceCatalogReportDto;
+use crate::domain::info::dto::info_unit_price_schedule_request::InfoUnitPriceScheduleRequest;
 use crate::domain::info::dto::info_unit_price_upsert_request::InfoUnitPriceUpsertRequest;
 use crate::errors::AppError;
 
@@ -28,6 +33,51 @@ impl InfoController {
         to_json(state.info_service.upsert_info_unit_prices(payload).await)
     }
 
+    pub async fn get_info_unit_price_history(
+        State(state): State<AppState>,
+    ) -> Result<Json<ApiResponse<Vec<InfoUnitPriceEntity>>>, AppError> {
+        to_json(state.info_service.get_info_unit_price_history().await)
+    }
+
+    pub async fn schedule_info_unit_prices(
+        State(state): State<AppState>,
+        Json(payload): Json<InfoUnitPriceScheduleRequest>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        to_json(state.info_service.schedule_info_unit_prices(payload).await)
+    }
+
+    pub async fn delete_info_unit_price_version(
+        State(state): State<AppState>,
+        Path(effective_from): Path<DateTime<Utc>>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        to_json(state.info_service.delete_info_unit_price_version(effective_from).await)
+    }
+
+    pub async fn get_price_catalog_report(
+        State(state): State<AppState>,
+    ) -> Result<Json<ApiResponse<InfoPriceCatalogReportDto>>, AppError> {
+        to_json(state.info_service.get_price_catalog_report().await)
+    }
+
+    pub async fn import_price_catalog(
+        State(state): State<AppState>,
+    ) -> Result<Json<ApiResponse<InfoPriceCatalogReportDto>>, AppError> {
+        to_json(state.info_service.import_price_catalog().await)
+    }
+
+    pub async fn get_info_exchange_rates(
+        State(state): State<AppState>,
+    ) -> Result<Json<ApiResponse<InfoExchangeRateEntity>>, AppError> {
+        to_json(state.info_service.get_info_exchange_rates().await)
+    }
+
+    pub async fn upsert_info_exchange_rates(
+        State(state): State<AppState>,
+        Json(payload): Json<InfoExchangeRateUpsertRequest>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        to_json(state.info_service.upsert_info_exchange_rates(payload).await)
+    }
+
     pub async fn get_info_versions(
         State(state): State<AppState>,
     ) -> Result<Json<ApiResponse<InfoVersionEntity>>, AppError> {
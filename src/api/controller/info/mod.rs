@@ -1,5 +1,7 @@
 pub mod setting;
 pub mod alerts;
+pub mod ownership;
+pub mod storage_class_price;
 pub mod llm;
 pub mod info_controller;
 pub mod k8s;
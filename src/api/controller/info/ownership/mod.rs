@@ -0,0 +1,36 @@
+use axum::extract::State;
+use axum::Json;
+use serde_json::Value;
+
+use crate::api::util::json::to_json;
+use crate::api::dto::ApiResponse;
+use crate::app_state::AppState;
+use crate::core::persistence::info::fixed::ownership::info_ownership_entity::InfoOwnershipEntity;
+use crate::domain::info::dto::info_ownership_preview_dto::InfoOwnershipPreviewDto;
+use crate::domain::info::dto::info_ownership_upsert_request::InfoOwnershipUpsertRequest;
+use crate::errors::AppError;
+
+pub struct InfoOwnershipController;
+
+impl InfoOwnershipController {
+    pub async fn get_info_ownership(
+        State(state): State<AppState>,
+    ) -> Result<Json<ApiResponse<InfoOwnershipEntity>>, AppError> {
+        to_json(state.info_service.get_info_ownership().await)
+    }
+
+    pub async fn upsert_info_ownership(
+        State(state): State<AppState>,
+        Json(payload): Json<InfoOwnershipUpsertRequest>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        to_json(state.info_service.upsert_info_ownership(payload).await)
+    }
+
+    /// Dry run; an empty body previews the stored rules.
+    pub async fn preview_info_ownership(
+        State(state): State<AppState>,
+        Json(payload): Json<InfoOwnershipUpsertRequest>,
+    ) -> Result<Json<ApiResponse<InfoOwnershipPreviewDto>>, AppError> {
+        to_json(state.info_service.preview_info_ownership(payload).await)
+    }
+}
@@ -0,0 +1,27 @@
+use axum::extract::State;
+use axum::Json;
+use serde_json::Value;
+
+use crate::api::util::json::to_json;
+use crate::api::dto::ApiResponse;
+use crate::app_state::AppState;
+use crate::core::persistence::info::fixed::storage_class_price::info_storage_class_price_entity::InfoStorageClassPriceEntity;
+use crate::domain::info::dto::info_storage_class_price_upsert_request::InfoStorageClassPriceUpsertRequest;
+use crate::errors::AppError;
+
+pub struct InfoStorageClassPriceController;
+
+impl InfoStorageClassPriceController {
+    pub async fn get_info_storage_class_prices(
+        State(state): State<AppState>,
+    ) -> Result<Json<ApiResponse<InfoStorageClassPriceEntity>>, AppError> {
+        to_json(state.info_service.get_info_storage_class_prices().await)
+    }
+
+    pub async fn upsert_info_storage_class_prices(
+        State(state): State<AppState>,
+        Json(payload): Json<InfoStorageClassPriceUpsertRequest>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        to_json(state.info_service.upsert_info_storage_class_prices(payload).await)
+    }
+}
@@ -84,6 +84,22 @@ impl K8sClusterMetricsController {
         )
     }
 
+    pub async fn get_metric_k8s_cluster_cost_breakdown(
+        State(state): State<AppState>,
+        Query(q): Query<RangeQuery>
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+
+        state.k8s_state.ensure_resynced().await?;
+        let node_names = state.k8s_state.get_nodes().await;
+
+        to_json(
+            state
+                .metric_service
+                .get_metric_k8s_cluster_cost_breakdown(q, node_names)
+                .await,
+        )
+    }
+
     pub async fn get_metric_k8s_cluster_raw_efficiency(
         State(state): State<AppState>,
         Query(q): Query<RangeQuery>,
@@ -0,0 +1,39 @@
+use axum::{
+    extract::{Query, State},
+    Json,
+};
+use serde_json::Value;
+
+use crate::api::util::json::to_json;
+use crate::api::dto::{metrics_dto::RangeQuery, ApiResponse};
+use crate::app_state::AppState;
+use crate::errors::AppError;
+
+/// Cost grouped by the value of a pod label (`group_by=label:<key>`).
+pub struct K8sLabelMetricsController;
+
+impl K8sLabelMetricsController {
+    pub async fn get_metric_k8s_labels_cost(
+        State(state): State<AppState>,
+        Query(q): Query<RangeQuery>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        state.k8s_state.ensure_resynced().await?;
+        to_json(state.metric_service.get_metric_k8s_labels_cost(q).await)
+    }
+
+    pub async fn get_metric_k8s_labels_cost_summary(
+        State(state): State<AppState>,
+        Query(q): Query<RangeQuery>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        state.k8s_state.ensure_resynced().await?;
+        to_json(state.metric_service.get_metric_k8s_labels_cost_summary(q).await)
+    }
+
+    pub async fn get_metric_k8s_labels_cost_trend(
+        State(state): State<AppState>,
+        Query(q): Query<RangeQuery>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        state.k8s_state.ensure_resynced().await?;
+        to_json(state.metric_service.get_metric_k8s_labels_cost_trend(q).await)
+    }
+}
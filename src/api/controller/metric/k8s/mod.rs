@@ -1,6 +1,9 @@
 pub mod cluster;
 pub mod container;
 pub mod deployment;
+pub mod label;
 pub mod namespace;
 pub mod node;
-pub mod pod;
\ No newline at end of file
+pub mod pod;
+pub mod pvc;
+pub mod workload;
\ No newline at end of file
@@ -126,6 +126,20 @@ impl K8sNodeMetricsController {
         )
     }
 
+    pub async fn get_metric_k8s_nodes_cost_breakdown(
+        State(state): State<AppState>,
+        Query(q): Query<RangeQuery>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        state.k8s_state.ensure_resynced().await?;
+        let node_names = state.k8s_state.get_nodes().await;
+        to_json(
+            state
+                .metric_service
+                .get_metric_k8s_nodes_cost_breakdown(q, node_names)
+                .await,
+        )
+    }
+
     pub async fn get_metric_k8s_node_cost(
         State(state): State<AppState>,
         Path(node_name): Path<String>,
@@ -167,4 +181,18 @@ impl K8sNodeMetricsController {
                 .await,
         )
     }
+
+    pub async fn get_metric_k8s_node_cost_breakdown(
+        State(state): State<AppState>,
+        Path(node_name): Path<String>,
+        Query(q): Query<RangeQuery>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        state.k8s_state.ensure_resynced().await?;
+        to_json(
+            state
+                .metric_service
+                .get_metric_k8s_node_cost_breakdown(node_name, q)
+                .await,
+        )
+    }
 }
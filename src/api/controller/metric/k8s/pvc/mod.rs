@@ -0,0 +1,44 @@
+use axum::{
+    extract::{Query, State},
+    Json,
+};
+use serde_json::Value;
+
+use crate::api::util::json::to_json;
+use crate::api::dto::{metrics_dto::RangeQuery, ApiResponse};
+use crate::app_state::AppState;
+use crate::errors::AppError;
+
+/// Provisioned-capacity cost of persistent volume claims.
+pub struct K8sPvcMetricsController;
+
+impl K8sPvcMetricsController {
+    pub async fn get_metric_k8s_pvcs_cost(
+        State(state): State<AppState>,
+        Query(q): Query<RangeQuery>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        to_json(state.metric_service.get_metric_k8s_pvcs_cost(q).await)
+    }
+
+    pub async fn get_metric_k8s_pvcs_cost_summary(
+        State(state): State<AppState>,
+        Query(q): Query<RangeQuery>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        to_json(state.metric_service.get_metric_k8s_pvcs_cost_summary(q).await)
+    }
+
+    pub async fn get_metric_k8s_pvcs_cost_trend(
+        State(state): State<AppState>,
+        Query(q): Query<RangeQuery>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        to_json(state.metric_service.get_metric_k8s_pvcs_cost_trend(q).await)
+    }
+
+    /// Per-claim attribution plus unbound, released and orphaned volumes.
+    pub async fn get_metric_k8s_pvcs_cost_breakdown(
+        State(state): State<AppState>,
+        Query(q): Query<RangeQuery>,
+    ) -> Result<Json<ApiResponse<Value>>, AppError> {
+        to_json(state.metric_service.get_metric_k8s_pvcs_cost_breakdown(q).await)
+    }
+}
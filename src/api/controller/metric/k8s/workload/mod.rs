@@ -0,0 +1,132 @@
+//! Controllers for the StatefulSet, DaemonSet, Job and CronJob scopes.
+//!
+//! They only differ in the workload kind they pass on, so each one is
+//! generated from the same set of handlers.
+//!
+//! A workload is addressed as `namespace/name` (`%2F`-encoded in the path) or
+//! by a bare name, which is rejected when it exists in several namespaces.
+
+use axum::{
+    extract::{Path, Query, State},
+    Json,
+};
+use serde_json::Value;
+
+use crate::api::util::json::to_json;
+use crate::api::dto::{metrics_dto::RangeQuery, ApiResponse};
+use crate::app_state::AppState;
+use crate::domain::metric::k8s::workload::owner::WorkloadKind;
+use crate::errors::AppError;
+
+macro_rules! workload_metrics_controller {
+    ($controller:ident, $kind:expr) => {
+        pub struct $controller;
+
+        impl $controller {
+            pub async fn get_metrics_raw(
+                State(state): State<AppState>,
+                Query(q): Query<RangeQuery>,
+            ) -> Result<Json<ApiResponse<Value>>, AppError> {
+                state.k8s_state.ensure_resynced().await?;
+                to_json(state.metric_service.get_metric_k8s_workloads_raw($kind, q, Vec::new()).await)
+            }
+
+            pub async fn get_metrics_raw_summary(
+                State(state): State<AppState>,
+                Query(q): Query<RangeQuery>,
+            ) -> Result<Json<ApiResponse<Value>>, AppError> {
+                state.k8s_state.ensure_resynced().await?;
+                to_json(state.metric_service.get_metric_k8s_workloads_raw_summary($kind, q, Vec::new()).await)
+            }
+
+            pub async fn get_metrics_raw_efficiency(
+                State(state): State<AppState>,
+                Query(_q): Query<RangeQuery>,
+            ) -> Result<Json<ApiResponse<Value>>, AppError> {
+                to_json(state.metric_service.get_metric_k8s_workload_raw_efficiency($kind).await)
+            }
+
+            pub async fn get_metric_raw(
+                State(state): State<AppState>,
+                Path(name): Path<String>,
+                Query(q): Query<RangeQuery>,
+            ) -> Result<Json<ApiResponse<Value>>, AppError> {
+                state.k8s_state.ensure_resynced().await?;
+                to_json(state.metric_service.get_metric_k8s_workload_raw($kind, name, q).await)
+            }
+
+            pub async fn get_metric_raw_summary(
+                State(state): State<AppState>,
+                Path(name): Path<String>,
+                Query(q): Query<RangeQuery>,
+            ) -> Result<Json<ApiResponse<Value>>, AppError> {
+                state.k8s_state.ensure_resynced().await?;
+                to_json(state.metric_service.get_metric_k8s_workload_raw_summary($kind, name, q).await)
+            }
+
+            pub async fn get_metric_raw_efficiency(
+                State(state): State<AppState>,
+                Path(_name): Path<String>,
+                Query(_q): Query<RangeQuery>,
+            ) -> Result<Json<ApiResponse<Value>>, AppError> {
+                to_json(state.metric_service.get_metric_k8s_workload_raw_efficiency($kind).await)
+            }
+
+            pub async fn get_metrics_cost(
+                State(state): State<AppState>,
+                Query(q): Query<RangeQuery>,
+            ) -> Result<Json<ApiResponse<Value>>, AppError> {
+                state.k8s_state.ensure_resynced().await?;
+                to_json(state.metric_service.get_metric_k8s_workloads_cost($kind, q, Vec::new()).await)
+            }
+
+            pub async fn get_metrics_cost_summary(
+                State(state): State<AppState>,
+                Query(q): Query<RangeQuery>,
+            ) -> Result<Json<ApiResponse<Value>>, AppError> {
+                state.k8s_state.ensure_resynced().await?;
+                to_json(state.metric_service.get_metric_k8s_workloads_cost_summary($kind, q, Vec::new()).await)
+            }
+
+            pub async fn get_metrics_cost_trend(
+                State(state): State<AppState>,
+                Query(q): Query<RangeQuery>,
+            ) -> Result<Json<ApiResponse<Value>>, AppError> {
+                state.k8s_state.ensure_resynced().await?;
+                to_json(state.metric_service.get_metric_k8s_workloads_cost_trend($kind, q, Vec::new()).await)
+            }
+
+            pub async fn get_metric_cost(
+                State(state): State<AppState>,
+                Path(name): Path<String>,
+                Query(q): Query<RangeQuery>,
+            ) -> Result<Json<ApiResponse<Value>>, AppError> {
+                state.k8s_state.ensure_resynced().await?;
+                to_json(state.metric_service.get_metric_k8s_workload_cost($kind, name, q).await)
+            }
+
+            pub async fn get_metric_cost_summary(
+                State(state): State<AppState>,
+                Path(name): Path<String>,
+                Query(q): Query<RangeQuery>,
+            ) -> Result<Json<ApiResponse<Value>>, AppError> {
+                state.k8s_state.ensure_resynced().await?;
+                to_json(state.metric_service.get_metric_k8s_workload_cost_summary($kind, name, q).await)
+            }
+
+            pub async fn get_metric_cost_trend(
+                State(state): State<AppState>,
+                Path(name): Path<String>,
+                Query(q): Query<RangeQuery>,
+            ) -> Result<Json<ApiResponse<Value>>, AppError> {
+                state.k8s_state.ensure_resynced().await?;
+                to_json(state.metric_service.get_metric_k8s_workload_cost_trend($kind, name, q).await)
+            }
+        }
+    };
+}
+
+workload_metrics_controller!(K8sStatefulSetMetricsController, WorkloadKind::StatefulSet);
+workload_metrics_controller!(K8sDaemonSetMetricsController, WorkloadKind::DaemonSet);
+workload_metrics_controller!(K8sJobMetricsController, WorkloadKind::Job);
+workload_metrics_controller!(K8sCronJobMetricsController, WorkloadKind::CronJob);
@@ -1,15 +1,26 @@
 //! System controller: connects routes to system usecases
 
+use axum::body::Body;
 use axum::extract::{Path, Query, State};
+use axum::http::header;
+use axum::response::{IntoResponse, Response};
 use axum::Json;
+use chrono::Utc;
+use futures::StreamExt;
 use serde_json::Value;
 
 
-use crate::api::dto::system_dto::{LogQuery, PaginatedLogResponse};
+use crate::api::dto::system_dto::{
+    ArchiveRestoreRequest, BackupQuery, BackupRestoreRequest, LogQuery, PaginatedLogResponse,
+    ReaggregateRequest,
+};
 use crate::api::dto::ApiResponse;
 use crate::api::util::json::to_json;
 use crate::app_state::AppState;
+use crate::core::persistence::data_backup::{BackupEntry, BackupRestoreSummary};
+use crate::core::persistence::metrics::metric_archive::{MetricArchiveEntry, MetricArchiveRestoreSummary};
 use crate::errors::AppError;
+use crate::domain::system::model::ReaggregateJob;
 
 pub struct SystemController;
 
@@ -26,10 +37,53 @@ impl SystemController {
         to_json(state.system_service.health().await)
     }
 
+    /// Writes a backup to the backup directory, or streams it as a
+    /// `.tar.gz` download with `?download=true`.
     pub async fn backup(
         State(state): State<AppState>,
-    ) -> Result<Json<ApiResponse<Value>>, AppError> {
-        to_json(state.system_service.backup().await)
+        Query(query): Query<BackupQuery>,
+    ) -> Result<Response, AppError> {
+        if !query.download.unwrap_or(false) {
+            return to_json(state.system_service.backup().await).map(IntoResponse::into_response);
+        }
+
+        let rx = state.system_service.backup_stream();
+        let stream = futures::stream::unfold(rx, |mut rx| async move {
+            rx.recv().await.map(|chunk| (chunk, rx))
+        });
+        let file_name = format!("rustcost-backup-{}.tar.gz", Utc::now().format("%Y%m%dT%H%M%SZ"));
+        Ok((
+            [
+                (header::CONTENT_TYPE, "application/gzip".to_string()),
+                (header::CONTENT_DISPOSITION, format!("attachment; filename=\"{}\"", file_name)),
+            ],
+            Body::from_stream(stream),
+        )
+            .into_response())
+    }
+
+    pub async fn list_backups(
+        State(state): State<AppState>,
+    ) -> Result<Json<ApiResponse<Vec<BackupEntry>>>, AppError> {
+        to_json(state.system_service.list_backups().await)
+    }
+
+    pub async fn restore_backup(
+        State(state): State<AppState>,
+        Json(payload): Json<BackupRestoreRequest>,
+    ) -> Result<Json<ApiResponse<BackupRestoreSummary>>, AppError> {
+        to_json(state.system_service.restore_backup(payload).await)
+    }
+
+    /// Restores a backup archive sent as the request body (`.tar.gz`).
+    pub async fn upload_backup(
+        State(state): State<AppState>,
+        body: Body,
+    ) -> Result<Json<ApiResponse<BackupRestoreSummary>>, AppError> {
+        let stream = body
+            .into_data_stream()
+            .map(|chunk| chunk.map(|bytes| bytes.to_vec()).map_err(std::io::Error::other));
+        to_json(state.system_service.restore_uploaded_backup(stream).await)
     }
 
     pub async fn resync(
@@ -38,6 +92,35 @@ impl SystemController {
         to_json(state.system_service.resync().await)
     }
 
+    pub async fn list_archives(
+        State(state): State<AppState>,
+    ) -> Result<Json<ApiResponse<Vec<MetricArchiveEntry>>>, AppError> {
+        to_json(state.system_service.list_archives().await)
+    }
+
+    pub async fn restore_archive(
+        State(state): State<AppState>,
+        Json(payload): Json<ArchiveRestoreRequest>,
+    ) -> Result<Json<ApiResponse<MetricArchiveRestoreSummary>>, AppError> {
+        to_json(state.system_service.restore_archive(payload).await)
+    }
+
+    /// Starts a forced hour/day re-aggregation of a time range in the background.
+    pub async fn reaggregate(
+        State(state): State<AppState>,
+        Json(payload): Json<ReaggregateRequest>,
+    ) -> Result<Json<ApiResponse<ReaggregateJob>>, AppError> {
+        to_json(state.system_service.reaggregate(payload).await)
+    }
+
+    /// Progress and result of a re-aggregation job.
+    pub async fn get_reaggregate_job(
+        State(state): State<AppState>,
+        Path(job_id): Path<String>,
+    ) -> Result<Json<ApiResponse<ReaggregateJob>>, AppError> {
+        to_json(state.system_service.get_reaggregate_job(&job_id).await)
+    }
+
     pub async fn get_system_log_file_list(
         State(state): State<AppState>,
     ) -> Result<Json<ApiResponse<Vec<String>>>, AppError> {
@@ -2,6 +2,7 @@
 
 use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
+use crate::core::persistence::info::fixed::unit_price::info_unit_price_entity::Currency;
 use crate::domain::metric::k8s::common::dto::MetricGranularity;
 
 /// Represents the standard query parameters for fetching metrics.
@@ -50,6 +51,18 @@ pub struct RangeQuery {
     #[serde(default)]
     pub mode: CostMode,
 
+    /// Distribute unallocated (idle + system overhead) node cost to workloads.
+    ///
+    /// Applies to namespace and deployment cost endpoints. Each workload receives
+    /// a share proportional to its allocated CPU/memory cost across the cluster.
+    pub share_idle: Option<bool>,
+
+    /// Currency for returned costs (e.g. `EUR`, `KRW`).
+    ///
+    /// Defaults to the configured unit-price currency. Conversion uses the
+    /// exchange-rate table and fails if a required rate is missing.
+    pub currency: Option<Currency>,
+
     // --- Scope Filters ---
 
     /// Filter metrics by the owning team.
@@ -65,13 +78,21 @@ pub struct RangeQuery {
     /// Filter by Kubernetes namespace.
     pub namespace: Option<String>,
 
-    /// Filter by resource labels.
-    /// Expected format (convention-based):
-    /// - `key=value`
-    /// - `key1=value1,key2=value2`
-    /// Example: `"app=api,tier=backend"`
+    /// Filter by pod labels, using the Kubernetes label selector syntax.
+    /// All requirements must match:
+    /// - `key=value` / `key==value`, `key!=value`
+    /// - `key in (v1,v2)`, `key notin (v1,v2)`
+    /// - `key` (exists), `!key` (does not exist)
+    ///
+    /// Example: `"app=api,tier!=cache,env in (prod,stage)"`
     pub labels: Option<String>,
 
+    /// Aggregation of the label cost endpoints.
+    ///
+    /// `label:<key>` returns one series per value of that pod label; pods
+    /// without the label are grouped under `__unlabeled__`.
+    pub group_by: Option<String>,
+
     // --- Resource Identification ---
 
     /// A unique identifier for a specific resource object.
@@ -86,9 +107,8 @@ pub struct RangeQuery {
 
 /// Cost calculation mode.
 ///
-/// Currently, Rustcost calculates costs using the **Showback** model (usage-based).
-/// However, the system can also support a **Chargeback** model in the future,
-/// so the cost calculation mode should be configurable.
+/// Rustcost calculates costs using the **Showback** model (usage-based) unless
+/// the query asks for **Chargeback**.
 ///
 /// ### Showback (default candidate)
 /// - Based on actual resource usage (CPU, memory, storage, network)
@@ -97,6 +117,8 @@ pub struct RangeQuery {
 ///
 /// ### Chargeback (OpenCost-style)
 /// - Based on allocated resources: `max(usage, request)`
+/// - Requests come from the container `cpu_request_millicores` / `memory_request_bytes`
+/// - Node costs are capacity-based and do not change with the mode
 /// - Enables clear cost ownership and idle cost attribution
 /// - Makes over-provisioning visible from a cost perspective
 ///
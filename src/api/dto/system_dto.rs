@@ -1,5 +1,8 @@
 //! System API DTOs
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+
+use crate::core::persistence::metrics::metric_rollup::RollupTier;
 #[derive(Deserialize)]
 pub struct LogQuery {
     pub cursor: Option<usize>,
@@ -11,4 +14,33 @@ pub struct PaginatedLogResponse {
     pub date: String,
     pub lines: Vec<String>,
     pub next_cursor: Option<usize>,
-}
\ No newline at end of file
+}
+#[derive(Deserialize)]
+pub struct BackupQuery {
+    /// Stream the archive in the response instead of writing it to the
+    /// backup directory
+    pub download: Option<bool>,
+}
+
+#[derive(Deserialize)]
+pub struct BackupRestoreRequest {
+    /// Archive name as listed by `GET /system/backups`
+    /// (e.g. `rustcost-backup-20250214T003000Z.tar.gz`)
+    pub file: String,
+}
+
+#[derive(Deserialize)]
+pub struct ArchiveRestoreRequest {
+    /// Archive path relative to the archive directory, as listed by
+    /// `GET /system/archives` (e.g. `node/worker-1/minute-2025-02.tar.gz`)
+    pub archive: String,
+}
+
+#[derive(Deserialize)]
+pub struct ReaggregateRequest {
+    /// Buckets overlapping `[start, end]` are rebuilt; open buckets are skipped
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    /// `hour` or `day`; both when omitted (hours first)
+    pub tier: Option<RollupTier>,
+}
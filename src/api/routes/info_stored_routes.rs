@@ -1,14 +1,16 @@
 //! Stored info routes (backed by persisted data)
 
 use axum::{
-    routing::{get, patch},
+    routing::{delete, get, patch, post},
     Router,
 };
 use crate::api::controller::info::alerts::InfoAlertController;
 use crate::api::controller::info::llm::InfoLlmController;
+use crate::api::controller::info::ownership::InfoOwnershipController;
 use crate::api::controller::info::info_controller::InfoController;
 use crate::api::controller::info::k8s::{container, node, pod};
 use crate::api::controller::info::setting::InfoSettingController;
+use crate::api::controller::info::storage_class_price::InfoStorageClassPriceController;
 use crate::app_state::AppState;
 
 pub fn info_stored_routes() -> Router<AppState> {
@@ -23,6 +25,15 @@ pub fn info_stored_routes() -> Router<AppState> {
             get(InfoAlertController::get_info_alerts)
                 .put(InfoAlertController::upsert_info_alerts),
         )
+        .route(
+            "/ownership-rules",
+            get(InfoOwnershipController::get_info_ownership)
+                .put(InfoOwnershipController::upsert_info_ownership),
+        )
+        .route(
+            "/ownership-rules/preview",
+            post(InfoOwnershipController::preview_info_ownership),
+        )
         .route(
             "/llm",
             get(InfoLlmController::get_info_llm)
@@ -33,6 +44,33 @@ pub fn info_stored_routes() -> Router<AppState> {
             get(InfoController::get_info_unit_prices)
                 .put(InfoController::upsert_info_unit_prices),
         )
+        .route(
+            "/unit-prices/history",
+            get(InfoController::get_info_unit_price_history)
+                .post(InfoController::schedule_info_unit_prices),
+        )
+        .route(
+            "/unit-prices/history/{effective_from}",
+            delete(InfoController::delete_info_unit_price_version),
+        )
+        .route(
+            "/unit-prices/catalog",
+            get(InfoController::get_price_catalog_report),
+        )
+        .route(
+            "/unit-prices/catalog/import",
+            post(InfoController::import_price_catalog),
+        )
+        .route(
+            "/storage-class-prices",
+            get(InfoStorageClassPriceController::get_info_storage_class_prices)
+                .put(InfoStorageClassPriceController::upsert_info_storage_class_prices),
+        )
+        .route(
+            "/exchange-rates",
+            get(InfoController::get_info_exchange_rates)
+                .put(InfoController::upsert_info_exchange_rates),
+        )
         .route("/versions", get(InfoController::get_info_versions))
         .route(
             "/k8s/store/nodes",
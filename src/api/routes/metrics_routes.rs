@@ -6,6 +6,12 @@ use crate::api::controller::metric::k8s::namespace::K8sNamespaceMetricsControlle
 use crate::api::controller::metric::k8s::node::K8sNodeMetricsController;
 use crate::api::controller::metric::k8s::container::K8sContainerMetricsController;
 use crate::api::controller::metric::k8s::deployment::K8sDeploymentMetricsController;
+use crate::api::controller::metric::k8s::label::K8sLabelMetricsController;
+use crate::api::controller::metric::k8s::pvc::K8sPvcMetricsController;
+use crate::api::controller::metric::k8s::workload::{
+    K8sCronJobMetricsController, K8sDaemonSetMetricsController, K8sJobMetricsController,
+    K8sStatefulSetMetricsController,
+};
 use crate::api::controller::metric::k8s::pod::K8sPodMetricsController;
 use crate::api::controller::metric::k8s::cluster::K8sClusterMetricsController;
 use crate::app_state::AppState;
@@ -23,9 +29,11 @@ pub fn metrics_routes() -> Router<AppState> {
         .route("/nodes/cost", get(K8sNodeMetricsController::get_metric_k8s_nodes_cost))
         .route("/nodes/cost/summary", get(K8sNodeMetricsController::get_metric_k8s_nodes_cost_summary))
         .route("/nodes/cost/trend", get(K8sNodeMetricsController::get_metric_k8s_nodes_cost_trend))
+        .route("/nodes/cost/breakdown", get(K8sNodeMetricsController::get_metric_k8s_nodes_cost_breakdown))
         .route("/nodes/{node_name}/cost", get(K8sNodeMetricsController::get_metric_k8s_node_cost))
         .route("/nodes/{node_name}/cost/summary", get(K8sNodeMetricsController::get_metric_k8s_node_cost_summary))
         .route("/nodes/{node_name}/cost/trend", get(K8sNodeMetricsController::get_metric_k8s_node_cost_trend))
+        .route("/nodes/{node_name}/cost/breakdown", get(K8sNodeMetricsController::get_metric_k8s_node_cost_breakdown))
 
         // Pods
         .route("/pods/raw", get(K8sPodMetricsController::get_metric_k8s_pods_raw))
@@ -83,6 +91,73 @@ pub fn metrics_routes() -> Router<AppState> {
         .route("/deployments/{deployment}/cost/summary", get(K8sDeploymentMetricsController::get_metric_k8s_deployment_cost_summary))
         .route("/deployments/{deployment}/cost/trend", get(K8sDeploymentMetricsController::get_metric_k8s_deployment_cost_trend))
 
+        // StatefulSets
+        .route("/statefulsets/raw", get(K8sStatefulSetMetricsController::get_metrics_raw))
+        .route("/statefulsets/raw/summary", get(K8sStatefulSetMetricsController::get_metrics_raw_summary))
+        .route("/statefulsets/raw/efficiency", get(K8sStatefulSetMetricsController::get_metrics_raw_efficiency))
+        .route("/statefulsets/{statefulset}/raw", get(K8sStatefulSetMetricsController::get_metric_raw))
+        .route("/statefulsets/{statefulset}/raw/summary", get(K8sStatefulSetMetricsController::get_metric_raw_summary))
+        .route("/statefulsets/{statefulset}/raw/efficiency", get(K8sStatefulSetMetricsController::get_metric_raw_efficiency))
+        .route("/statefulsets/cost", get(K8sStatefulSetMetricsController::get_metrics_cost))
+        .route("/statefulsets/cost/summary", get(K8sStatefulSetMetricsController::get_metrics_cost_summary))
+        .route("/statefulsets/cost/trend", get(K8sStatefulSetMetricsController::get_metrics_cost_trend))
+        .route("/statefulsets/{statefulset}/cost", get(K8sStatefulSetMetricsController::get_metric_cost))
+        .route("/statefulsets/{statefulset}/cost/summary", get(K8sStatefulSetMetricsController::get_metric_cost_summary))
+        .route("/statefulsets/{statefulset}/cost/trend", get(K8sStatefulSetMetricsController::get_metric_cost_trend))
+
+        // DaemonSets
+        .route("/daemonsets/raw", get(K8sDaemonSetMetricsController::get_metrics_raw))
+        .route("/daemonsets/raw/summary", get(K8sDaemonSetMetricsController::get_metrics_raw_summary))
+        .route("/daemonsets/raw/efficiency", get(K8sDaemonSetMetricsController::get_metrics_raw_efficiency))
+        .route("/daemonsets/{daemonset}/raw", get(K8sDaemonSetMetricsController::get_metric_raw))
+        .route("/daemonsets/{daemonset}/raw/summary", get(K8sDaemonSetMetricsController::get_metric_raw_summary))
+        .route("/daemonsets/{daemonset}/raw/efficiency", get(K8sDaemonSetMetricsController::get_metric_raw_efficiency))
+        .route("/daemonsets/cost", get(K8sDaemonSetMetricsController::get_metrics_cost))
+        .route("/daemonsets/cost/summary", get(K8sDaemonSetMetricsController::get_metrics_cost_summary))
+        .route("/daemonsets/cost/trend", get(K8sDaemonSetMetricsController::get_metrics_cost_trend))
+        .route("/daemonsets/{daemonset}/cost", get(K8sDaemonSetMetricsController::get_metric_cost))
+        .route("/daemonsets/{daemonset}/cost/summary", get(K8sDaemonSetMetricsController::get_metric_cost_summary))
+        .route("/daemonsets/{daemonset}/cost/trend", get(K8sDaemonSetMetricsController::get_metric_cost_trend))
+
+        // Jobs
+        .route("/jobs/raw", get(K8sJobMetricsController::get_metrics_raw))
+        .route("/jobs/raw/summary", get(K8sJobMetricsController::get_metrics_raw_summary))
+        .route("/jobs/raw/efficiency", get(K8sJobMetricsController::get_metrics_raw_efficiency))
+        .route("/jobs/{job}/raw", get(K8sJobMetricsController::get_metric_raw))
+        .route("/jobs/{job}/raw/summary", get(K8sJobMetricsController::get_metric_raw_summary))
+        .route("/jobs/{job}/raw/efficiency", get(K8sJobMetricsController::get_metric_raw_efficiency))
+        .route("/jobs/cost", get(K8sJobMetricsController::get_metrics_cost))
+        .route("/jobs/cost/summary", get(K8sJobMetricsController::get_metrics_cost_summary))
+        .route("/jobs/cost/trend", get(K8sJobMetricsController::get_metrics_cost_trend))
+        .route("/jobs/{job}/cost", get(K8sJobMetricsController::get_metric_cost))
+        .route("/jobs/{job}/cost/summary", get(K8sJobMetricsController::get_metric_cost_summary))
+        .route("/jobs/{job}/cost/trend", get(K8sJobMetricsController::get_metric_cost_trend))
+
+        // CronJobs
+        .route("/cronjobs/raw", get(K8sCronJobMetricsController::get_metrics_raw))
+        .route("/cronjobs/raw/summary", get(K8sCronJobMetricsController::get_metrics_raw_summary))
+        .route("/cronjobs/raw/efficiency", get(K8sCronJobMetricsController::get_metrics_raw_efficiency))
+        .route("/cronjobs/{cronjob}/raw", get(K8sCronJobMetricsController::get_metric_raw))
+        .route("/cronjobs/{cronjob}/raw/summary", get(K8sCronJobMetricsController::get_metric_raw_summary))
+        .route("/cronjobs/{cronjob}/raw/efficiency", get(K8sCronJobMetricsController::get_metric_raw_efficiency))
+        .route("/cronjobs/cost", get(K8sCronJobMetricsController::get_metrics_cost))
+        .route("/cronjobs/cost/summary", get(K8sCronJobMetricsController::get_metrics_cost_summary))
+        .route("/cronjobs/cost/trend", get(K8sCronJobMetricsController::get_metrics_cost_trend))
+        .route("/cronjobs/{cronjob}/cost", get(K8sCronJobMetricsController::get_metric_cost))
+        .route("/cronjobs/{cronjob}/cost/summary", get(K8sCronJobMetricsController::get_metric_cost_summary))
+        .route("/cronjobs/{cronjob}/cost/trend", get(K8sCronJobMetricsController::get_metric_cost_trend))
+
+        // Labels (group_by=label:<key>)
+        .route("/labels/cost", get(K8sLabelMetricsController::get_metric_k8s_labels_cost))
+        .route("/labels/cost/summary", get(K8sLabelMetricsController::get_metric_k8s_labels_cost_summary))
+        .route("/labels/cost/trend", get(K8sLabelMetricsController::get_metric_k8s_labels_cost_trend))
+
+        // Persistent volume claims
+        .route("/pvcs/cost", get(K8sPvcMetricsController::get_metric_k8s_pvcs_cost))
+        .route("/pvcs/cost/summary", get(K8sPvcMetricsController::get_metric_k8s_pvcs_cost_summary))
+        .route("/pvcs/cost/trend", get(K8sPvcMetricsController::get_metric_k8s_pvcs_cost_trend))
+        .route("/pvcs/cost/breakdown", get(K8sPvcMetricsController::get_metric_k8s_pvcs_cost_breakdown))
+
         // Cluster
         .route("/cluster/raw", get(K8sClusterMetricsController::get_metric_k8s_cluster_raw))
         .route("/cluster/raw/summary", get(K8sClusterMetricsController::get_metric_k8s_cluster_raw_summary))
@@ -90,4 +165,5 @@ pub fn metrics_routes() -> Router<AppState> {
         .route("/cluster/cost", get(K8sClusterMetricsController::get_metric_k8s_cluster_cost))
         .route("/cluster/cost/summary", get(K8sClusterMetricsController::get_metric_k8s_cluster_cost_summary))
         .route("/cluster/cost/trend", get(K8sClusterMetricsController::get_metric_k8s_cluster_cost_trend))
+        .route("/cluster/cost/breakdown", get(K8sClusterMetricsController::get_metric_k8s_cluster_cost_breakdown))
 }
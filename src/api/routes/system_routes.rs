@@ -9,7 +9,14 @@ pub fn system_routes() -> Router<AppState> {
         .route("/status", get(SystemController::status))
         .route("/health", get(SystemController::health))
         .route("/backup", post(SystemController::backup))
+        .route("/backups", get(SystemController::list_backups))
+        .route("/backup/restore", post(SystemController::restore_backup))
+        .route("/backup/upload", post(SystemController::upload_backup))
         .route("/resync", post(SystemController::resync))
+        .route("/archives", get(SystemController::list_archives))
+        .route("/archives/restore", post(SystemController::restore_archive))
+        .route("/reaggregate", post(SystemController::reaggregate))
+        .route("/reaggregate/{job_id}", get(SystemController::get_reaggregate_job))
 
         .route("/logs/{date}", get(SystemController::get_system_log_lines))
         .route("/logs", get(SystemController::get_system_log_file_list))
@@ -1,24 +1,41 @@
 use std::sync::Arc;
+use chrono::{DateTime, Utc};
 
 //
 // SHORT IMPORTS
 //
 
 // system
-use crate::domain::system::service::backup_service::backup;
+use crate::domain::system::service::archive_service::{list_archives, restore_archive};
+use crate::domain::system::model::ReaggregateJob;
+use crate::domain::system::service::reaggregate_service::{get_reaggregate_job, reaggregate};
+use crate::domain::system::service::backup_service::{
+    backup, backup_stream, list_backups, restore_backup, restore_uploaded_backup,
+};
 use crate::domain::system::service::health_service::health;
 use crate::domain::system::service::resync_service::resync;
 use crate::domain::system::service::status_service::status_internal;
 
 // info
 use crate::domain::info::service::info_alerts_service::{get_info_alerts, upsert_info_alerts};
+use crate::domain::info::service::info_ownership_service::{
+    get_info_ownership, preview_info_ownership, upsert_info_ownership,
+};
 use crate::domain::info::service::info_llm_service::{get_info_llm, upsert_info_llm};
+use crate::domain::info::service::info_storage_class_price_service::{
+    get_info_storage_class_prices, upsert_info_storage_class_prices,
+};
 use crate::domain::info::service::info_settings_service::{
     get_info_settings, upsert_info_settings,
 };
 use crate::domain::info::service::info_unit_price_service::{
-    get_info_unit_prices, upsert_info_unit_prices,
+    delete_info_unit_price_version, get_info_unit_price_history, get_info_unit_prices,
+    get_info_unit_prices_in, schedule_info_unit_prices, upsert_info_unit_prices,
 };
+use crate::domain::info::service::info_exchange_rate_service::{
+    get_info_exchange_rates, upsert_info_exchange_rates,
+};
+use crate::domain::info::service::info_price_catalog_service::import_price_catalog;
 use crate::domain::info::service::info_version_service::get_info_versions;
 use crate::domain::llm::service::llm_chat_service::chat as llm_chat;
 use crate::domain::llm::service::llm_chat_service::chat_with_context as llm_chat_with_context;
@@ -77,23 +94,35 @@ use crate::domain::info::service::info_k8s_pod_service::{
 use crate::domain::metric::k8s::cluster::service::*;
 use crate::domain::metric::k8s::container::service::*;
 use crate::domain::metric::k8s::deployment::service::*;
+use crate::domain::metric::k8s::label::service::*;
+use crate::domain::metric::k8s::pvc::service::*;
+use crate::domain::metric::k8s::workload::owner::WorkloadKind;
+use crate::domain::metric::k8s::workload::service::*;
 use crate::domain::metric::k8s::namespace::service::*;
 use crate::domain::metric::k8s::node::service::*;
 use crate::domain::metric::k8s::pod::service::*;
 
 // entities
 use crate::core::persistence::info::fixed::alerts::info_alert_entity::InfoAlertEntity;
+use crate::core::persistence::info::fixed::ownership::info_ownership_entity::InfoOwnershipEntity;
+use crate::core::persistence::info::fixed::storage_class_price::info_storage_class_price_entity::InfoStorageClassPriceEntity;
 use crate::core::persistence::info::fixed::llm::info_llm_entity::InfoLlmEntity;
 use crate::core::persistence::info::fixed::setting::info_setting_entity::InfoSettingEntity;
 use crate::core::persistence::info::fixed::unit_price::info_unit_price_entity::InfoUnitPriceEntity;
+use crate::core::persistence::info::fixed::exchange_rate::info_exchange_rate_entity::InfoExchangeRateEntity;
 use crate::core::persistence::info::fixed::version::info_version_entity::InfoVersionEntity;
 
 use crate::core::persistence::info::k8s::container::info_container_entity::InfoContainerEntity;
 use crate::core::persistence::info::k8s::node::info_node_entity::InfoNodeEntity;
 use crate::core::persistence::info::k8s::pod::info_pod_entity::InfoPodEntity;
+use crate::core::persistence::metrics::metric_archive::{MetricArchiveEntry, MetricArchiveRestoreSummary};
+use crate::core::persistence::data_backup::{BackupEntry, BackupRestoreSummary, BackupSummary};
 
 // dtos
 use crate::domain::info::dto::info_alert_upsert_request::InfoAlertUpsertRequest;
+use crate::domain::info::dto::info_ownership_preview_dto::InfoOwnershipPreviewDto;
+use crate::domain::info::dto::info_ownership_upsert_request::InfoOwnershipUpsertRequest;
+use crate::domain::info::dto::info_storage_class_price_upsert_request::InfoStorageClassPriceUpsertRequest;
 use crate::domain::info::dto::info_k8s_container_patch_request::InfoK8sContainerPatchRequest;
 use crate::domain::info::dto::info_k8s_node_patch_request::{
     InfoK8sNodePatchRequest, InfoK8sNodePricePatchRequest,
@@ -101,7 +130,10 @@ use crate::domain::info::dto::info_k8s_node_patch_request::{
 use crate::domain::info::dto::info_k8s_pod_patch_request::InfoK8sPodPatchRequest;
 use crate::domain::info::dto::info_llm_upsert_request::InfoLlmUpsertRequest;
 use crate::domain::info::dto::info_setting_upsert_request::InfoSettingUpsertRequest;
+use crate::domain::info::dto::info_price_catalog_report_dto::InfoPriceCatalogReportDto;
+use crate::domain::info::dto::info_unit_price_schedule_request::InfoUnitPriceScheduleRequest;
 use crate::domain::info::dto::info_unit_price_upsert_request::InfoUnitPriceUpsertRequest;
+use crate::domain::info::dto::info_exchange_rate_upsert_request::InfoExchangeRateUpsertRequest;
 use crate::domain::llm::dto::llm_chat_request::LlmChatRequest;
 use crate::domain::llm::dto::llm_chat_with_context_request::LlmChatWithContextRequest;
 
@@ -109,6 +141,7 @@ use crate::api::dto::info_dto::{K8sListNodeQuery, K8sListQuery};
 use crate::api::dto::k8s_pod_query_request_dto::K8sPodQueryRequestDto;
 use crate::api::dto::metrics_dto::RangeQuery;
 use crate::api::dto::paginated_response::PaginatedResponse;
+use crate::api::dto::system_dto::{ArchiveRestoreRequest, BackupRestoreRequest, ReaggregateRequest};
 
 // logs
 use crate::core::persistence::logs::log_repository::LogRepositoryImpl;
@@ -177,15 +210,48 @@ impl SystemService {
     pub async fn health(&self) -> anyhow::Result<serde_json::Value> {
         health().await
     }
-    pub async fn backup(&self) -> anyhow::Result<serde_json::Value> {
+    pub async fn backup(&self) -> anyhow::Result<BackupSummary> {
         backup().await
     }
+    pub fn backup_stream(&self) -> tokio::sync::mpsc::Receiver<std::io::Result<Vec<u8>>> {
+        backup_stream()
+    }
+    pub async fn list_backups(&self) -> anyhow::Result<Vec<BackupEntry>> {
+        list_backups().await
+    }
+    pub async fn restore_backup(
+        &self,
+        req: BackupRestoreRequest,
+    ) -> anyhow::Result<BackupRestoreSummary> {
+        restore_backup(req).await
+    }
+    pub async fn restore_uploaded_backup<S>(&self, body: S) -> anyhow::Result<BackupRestoreSummary>
+    where
+        S: futures::Stream<Item = std::io::Result<Vec<u8>>> + Unpin,
+    {
+        restore_uploaded_backup(body).await
+    }
     pub async fn status(&self) -> anyhow::Result<serde_json::Value> {
         status_internal(self.k8s_state.clone()).await
     }
     pub async fn resync(&self) -> anyhow::Result<serde_json::Value> {
         resync(self.k8s_state.clone()).await
     }
+    pub async fn list_archives(&self) -> anyhow::Result<Vec<MetricArchiveEntry>> {
+        list_archives().await
+    }
+    pub async fn restore_archive(
+        &self,
+        req: ArchiveRestoreRequest,
+    ) -> anyhow::Result<MetricArchiveRestoreSummary> {
+        restore_archive(req).await
+    }
+    pub async fn reaggregate(&self, req: ReaggregateRequest) -> anyhow::Result<ReaggregateJob> {
+        reaggregate(req).await
+    }
+    pub async fn get_reaggregate_job(&self, job_id: &str) -> anyhow::Result<ReaggregateJob> {
+        get_reaggregate_job(job_id).await
+    }
 }
 
 //
@@ -206,6 +272,39 @@ impl InfoService {
     ) -> anyhow::Result<serde_json::Value> {
         upsert_info_unit_prices(req).await
     }
+    pub async fn get_info_unit_price_history(&self) -> anyhow::Result<Vec<InfoUnitPriceEntity>> {
+        get_info_unit_price_history().await
+    }
+    pub async fn schedule_info_unit_prices(
+        &self,
+        req: InfoUnitPriceScheduleRequest,
+    ) -> anyhow::Result<serde_json::Value> {
+        schedule_info_unit_prices(req).await
+    }
+    pub async fn delete_info_unit_price_version(
+        &self,
+        effective_from: DateTime<Utc>,
+    ) -> anyhow::Result<serde_json::Value> {
+        delete_info_unit_price_version(effective_from).await
+    }
+
+    /// Dry run: match nodes against the price catalog without updating them.
+    pub async fn get_price_catalog_report(&self) -> anyhow::Result<InfoPriceCatalogReportDto> {
+        import_price_catalog(false).await
+    }
+    pub async fn import_price_catalog(&self) -> anyhow::Result<InfoPriceCatalogReportDto> {
+        import_price_catalog(true).await
+    }
+
+    pub async fn get_info_exchange_rates(&self) -> anyhow::Result<InfoExchangeRateEntity> {
+        get_info_exchange_rates().await
+    }
+    pub async fn upsert_info_exchange_rates(
+        &self,
+        req: InfoExchangeRateUpsertRequest,
+    ) -> anyhow::Result<serde_json::Value> {
+        upsert_info_exchange_rates(req).await
+    }
 
     pub async fn get_info_versions(&self) -> anyhow::Result<InfoVersionEntity> {
         get_info_versions().await
@@ -221,6 +320,32 @@ impl InfoService {
         upsert_info_alerts(req).await
     }
 
+    pub async fn get_info_ownership(&self) -> anyhow::Result<InfoOwnershipEntity> {
+        get_info_ownership().await
+    }
+    pub async fn upsert_info_ownership(
+        &self,
+        req: InfoOwnershipUpsertRequest,
+    ) -> anyhow::Result<serde_json::Value> {
+        upsert_info_ownership(req).await
+    }
+    pub async fn preview_info_ownership(
+        &self,
+        req: InfoOwnershipUpsertRequest,
+    ) -> anyhow::Result<InfoOwnershipPreviewDto> {
+        preview_info_ownership(req).await
+    }
+
+    pub async fn get_info_storage_class_prices(&self) -> anyhow::Result<InfoStorageClassPriceEntity> {
+        get_info_storage_class_prices().await
+    }
+    pub async fn upsert_info_storage_class_prices(
+        &self,
+        req: InfoStorageClassPriceUpsertRequest,
+    ) -> anyhow::Result<serde_json::Value> {
+        upsert_info_storage_class_prices(req).await
+    }
+
     pub async fn get_info_llm(&self) -> anyhow::Result<InfoLlmEntity> {
         get_info_llm().await
     }
@@ -677,6 +802,13 @@ impl MetricService {
     ) -> anyhow::Result<serde_json::Value> {
         get_metric_k8s_nodes_cost_trend(q, node_names).await
     }
+    pub async fn get_metric_k8s_nodes_cost_breakdown(
+        &self,
+        q: RangeQuery,
+        node_names: Vec<String>,
+    ) -> anyhow::Result<serde_json::Value> {
+        get_metric_k8s_nodes_cost_breakdown(q, node_names).await
+    }
 
     pub async fn get_metric_k8s_node_cost(
         &self,
@@ -699,6 +831,13 @@ impl MetricService {
     ) -> anyhow::Result<serde_json::Value> {
         get_metric_k8s_node_cost_trend(node_name, q).await
     }
+    pub async fn get_metric_k8s_node_cost_breakdown(
+        &self,
+        node_name: String,
+        q: RangeQuery,
+    ) -> anyhow::Result<serde_json::Value> {
+        get_metric_k8s_node_cost_breakdown(node_name, q).await
+    }
 
     pub async fn get_metric_k8s_namespaces_raw(
         &self,
@@ -876,6 +1015,137 @@ impl MetricService {
         get_metric_k8s_deployment_cost_trend(name, q).await
     }
 
+    pub async fn get_metric_k8s_labels_cost(
+        &self,
+        q: RangeQuery,
+    ) -> anyhow::Result<serde_json::Value> {
+        get_metric_k8s_labels_cost(q).await
+    }
+    pub async fn get_metric_k8s_labels_cost_summary(
+        &self,
+        q: RangeQuery,
+    ) -> anyhow::Result<serde_json::Value> {
+        get_metric_k8s_labels_cost_summary(q).await
+    }
+    pub async fn get_metric_k8s_labels_cost_trend(
+        &self,
+        q: RangeQuery,
+    ) -> anyhow::Result<serde_json::Value> {
+        get_metric_k8s_labels_cost_trend(q).await
+    }
+
+    pub async fn get_metric_k8s_pvcs_cost(
+        &self,
+        q: RangeQuery,
+    ) -> anyhow::Result<serde_json::Value> {
+        get_metric_k8s_pvcs_cost(q).await
+    }
+    pub async fn get_metric_k8s_pvcs_cost_summary(
+        &self,
+        q: RangeQuery,
+    ) -> anyhow::Result<serde_json::Value> {
+        get_metric_k8s_pvcs_cost_summary(q).await
+    }
+    pub async fn get_metric_k8s_pvcs_cost_trend(
+        &self,
+        q: RangeQuery,
+    ) -> anyhow::Result<serde_json::Value> {
+        get_metric_k8s_pvcs_cost_trend(q).await
+    }
+    pub async fn get_metric_k8s_pvcs_cost_breakdown(
+        &self,
+        q: RangeQuery,
+    ) -> anyhow::Result<serde_json::Value> {
+        get_metric_k8s_pvcs_cost_breakdown(q).await
+    }
+
+    pub async fn get_metric_k8s_workloads_raw(
+        &self,
+        kind: WorkloadKind,
+        q: RangeQuery,
+        names: Vec<String>,
+    ) -> anyhow::Result<serde_json::Value> {
+        get_metric_k8s_workloads_raw(kind, q, names).await
+    }
+    pub async fn get_metric_k8s_workloads_raw_summary(
+        &self,
+        kind: WorkloadKind,
+        q: RangeQuery,
+        names: Vec<String>,
+    ) -> anyhow::Result<serde_json::Value> {
+        get_metric_k8s_workloads_raw_summary(kind, q, names).await
+    }
+    pub async fn get_metric_k8s_workloads_cost(
+        &self,
+        kind: WorkloadKind,
+        q: RangeQuery,
+        names: Vec<String>,
+    ) -> anyhow::Result<serde_json::Value> {
+        get_metric_k8s_workloads_cost(kind, q, names).await
+    }
+    pub async fn get_metric_k8s_workloads_cost_summary(
+        &self,
+        kind: WorkloadKind,
+        q: RangeQuery,
+        names: Vec<String>,
+    ) -> anyhow::Result<serde_json::Value> {
+        get_metric_k8s_workloads_cost_summary(kind, q, names).await
+    }
+    pub async fn get_metric_k8s_workloads_cost_trend(
+        &self,
+        kind: WorkloadKind,
+        q: RangeQuery,
+        names: Vec<String>,
+    ) -> anyhow::Result<serde_json::Value> {
+        get_metric_k8s_workloads_cost_trend(kind, q, names).await
+    }
+    pub async fn get_metric_k8s_workload_raw_efficiency(
+        &self,
+        kind: WorkloadKind,
+    ) -> anyhow::Result<serde_json::Value> {
+        get_metric_k8s_workload_raw_efficiency(kind).await
+    }
+    pub async fn get_metric_k8s_workload_raw(
+        &self,
+        kind: WorkloadKind,
+        name: String,
+        q: RangeQuery,
+    ) -> anyhow::Result<serde_json::Value> {
+        get_metric_k8s_workload_raw(kind, name, q).await
+    }
+    pub async fn get_metric_k8s_workload_raw_summary(
+        &self,
+        kind: WorkloadKind,
+        name: String,
+        q: RangeQuery,
+    ) -> anyhow::Result<serde_json::Value> {
+        get_metric_k8s_workload_raw_summary(kind, name, q).await
+    }
+    pub async fn get_metric_k8s_workload_cost(
+        &self,
+        kind: WorkloadKind,
+        name: String,
+        q: RangeQuery,
+    ) -> anyhow::Result<serde_json::Value> {
+        get_metric_k8s_workload_cost(kind, name, q).await
+    }
+    pub async fn get_metric_k8s_workload_cost_summary(
+        &self,
+        kind: WorkloadKind,
+        name: String,
+        q: RangeQuery,
+    ) -> anyhow::Result<serde_json::Value> {
+        get_metric_k8s_workload_cost_summary(kind, name, q).await
+    }
+    pub async fn get_metric_k8s_workload_cost_trend(
+        &self,
+        kind: WorkloadKind,
+        name: String,
+        q: RangeQuery,
+    ) -> anyhow::Result<serde_json::Value> {
+        get_metric_k8s_workload_cost_trend(kind, name, q).await
+    }
+
     pub async fn get_metric_k8s_containers_raw(
         &self,
         q: RangeQuery,
@@ -1001,7 +1271,7 @@ impl MetricService {
         q: RangeQuery,
         node_names: Vec<String>,
     ) -> anyhow::Result<serde_json::Value> {
-        let costs = get_info_unit_prices().await?;
+        let costs = get_info_unit_prices_in(q.currency).await?;
         get_metric_k8s_cluster_cost(node_names, costs, q).await
     }
 
@@ -1010,7 +1280,7 @@ impl MetricService {
         q: RangeQuery,
         node_names: Vec<String>,
     ) -> anyhow::Result<serde_json::Value> {
-        let costs = get_info_unit_prices().await?;
+        let costs = get_info_unit_prices_in(q.currency).await?;
         get_metric_k8s_cluster_cost_summary(node_names, costs, q).await
     }
 
@@ -1019,7 +1289,16 @@ impl MetricService {
         q: RangeQuery,
         node_names: Vec<String>,
     ) -> anyhow::Result<serde_json::Value> {
-        let costs = get_info_unit_prices().await?;
+        let costs = get_info_unit_prices_in(q.currency).await?;
         get_metric_k8s_cluster_cost_trend(node_names, costs, q).await
     }
+
+    pub async fn get_metric_k8s_cluster_cost_breakdown(
+        &self,
+        q: RangeQuery,
+        node_names: Vec<String>,
+    ) -> anyhow::Result<serde_json::Value> {
+        let costs = get_info_unit_prices_in(q.currency).await?;
+        get_metric_k8s_cluster_cost_breakdown(node_names, costs, q).await
+    }
 }
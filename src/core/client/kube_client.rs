@@ -21,8 +21,8 @@ fn load_token() -> Option<String> {
         .map(|t| t.trim().to_string())
 }
 
-/// Load CA cert from dev override or in-cluster service account
-fn load_ca_bytes() -> Option<Vec<u8>> {
+/// Load CA cert (DER) from dev override or in-cluster service account
+pub(crate) fn load_ca_bytes() -> Option<Vec<u8>> {
     // 1. DEV override (ca.der or ca.crt)
     if let Ok(path) = env::var("RUSTCOST_CA_PATH") {
         if let Ok(bytes) = fs::read(&path) {
@@ -1,16 +1,27 @@
 /// Maps kube-rs / k8s-openapi types → internal domain models
-use crate::core::client::kube_resources::{Node, Pod, Deployment, Namespace};
-use crate::core::persistence::info::k8s::node::info_node_entity::InfoNodeEntity;
+use crate::core::client::kube_resources::{
+    Node, Pod, Deployment, Namespace, Service, Ingress, PersistentVolume, PersistentVolumeClaim,
+};
+use crate::core::persistence::info::k8s::node::info_node_entity::{InfoNodeEntity, NodeCapacityType};
 use crate::core::persistence::info::k8s::pod::info_pod_entity::InfoPodEntity;
 use crate::core::persistence::info::k8s::deployment::info_deployment_entity::InfoDeploymentEntity;
 use crate::core::persistence::info::k8s::namespace::info_namespace_entity::InfoNamespaceEntity;
+use crate::core::persistence::info::k8s::service::info_service_entity::InfoServiceEntity;
+use crate::core::persistence::info::k8s::volume::info_volume_entity::InfoVolumeEntity;
+use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 use std::collections::{BTreeMap, HashSet};
 use std::convert::TryFrom;
 
 /// Converts a k8s-openapi Node object into an InfoNodeEntity
-pub fn map_node_to_info_entity(node: &Node, now: DateTime<Utc>) -> Result<InfoNodeEntity> {
+///
+/// `spot_selector` is the `spot_node_label_selector` setting, read once by the caller.
+pub fn map_node_to_info_entity(
+    node: &Node,
+    now: DateTime<Utc>,
+    spot_selector: Option<&str>,
+) -> Result<InfoNodeEntity> {
     let metadata = &node.metadata;
     let status = node.status.as_ref();
     let spec = node.spec.as_ref();
@@ -163,6 +174,12 @@ pub fn map_node_to_info_entity(node: &Node, now: DateTime<Utc>) -> Result<InfoNo
         .as_ref()
         .map(|a| serde_json::to_string(a).unwrap_or_default());
 
+    // Spot / preemptible detection (well-known labels + settings selector)
+    let capacity_type = Some(NodeCapacityType::from_labels(
+        metadata.labels.as_ref().unwrap_or(&BTreeMap::new()),
+        spot_selector,
+    ));
+
     // Images
     let (image_count, image_names, image_total_size_bytes) = status
         .and_then(|s| s.images.as_ref())
@@ -208,6 +225,7 @@ pub fn map_node_to_info_entity(node: &Node, now: DateTime<Utc>) -> Result<InfoNo
         image_count,
         image_names,
         image_total_size_bytes,
+        capacity_type,
         last_updated_info_at,
         ..Default::default()
     })
@@ -394,6 +412,8 @@ pub fn map_pod_to_info_entity(pod: &Pod) -> Result<InfoPodEntity> {
         owner_kind,
         owner_name,
         owner_uid,
+        controller_kind: None,
+        controller_name: None,
         container_count,
         container_names,
         container_images,
@@ -415,9 +435,191 @@ pub fn map_pod_to_info_entity(pod: &Pod) -> Result<InfoPodEntity> {
         team: None,
         service: None,
         env: None,
+        team_source: None,
+        service_source: None,
+        env_source: None,
     })
 }
 
+/// Converts a k8s-openapi Service into an InfoServiceEntity seen at `now`
+pub fn map_service_to_info_entity(service: &Service, now: DateTime<Utc>) -> InfoServiceEntity {
+    let metadata = &service.metadata;
+    let load_balancer_address = service
+        .status
+        .as_ref()
+        .and_then(|s| s.load_balancer.as_ref())
+        .and_then(|lb| lb.ingress.as_ref())
+        .and_then(|ingress| ingress.first())
+        .and_then(|i| i.ip.clone().or_else(|| i.hostname.clone()));
+
+    InfoServiceEntity {
+        name: metadata.name.clone(),
+        namespace: metadata.namespace.clone(),
+        uid: metadata.uid.clone(),
+        kind: Some("Service".to_string()),
+        // The API defaults an unset type to ClusterIP
+        service_type: Some(
+            service
+                .spec
+                .as_ref()
+                .and_then(|s| s.type_.clone())
+                .unwrap_or_else(|| "ClusterIP".to_string()),
+        ),
+        service_types: Vec::new(),
+        ingress_class: None,
+        load_balancer_address,
+        creation_timestamp: metadata.creation_timestamp.as_ref().map(|t| t.0),
+        first_seen_at: Some(now),
+        deleted_at: None,
+        resource_version: metadata.resource_version.clone(),
+        last_updated_info_at: Some(now),
+        deleted: Some(false),
+        last_check_deleted_count: None,
+        label: metadata.labels.as_ref().and_then(flatten_map),
+    }
+}
+
+/// Converts a k8s-openapi Ingress into an InfoServiceEntity seen at `now`
+pub fn map_ingress_to_info_entity(ingress: &Ingress, now: DateTime<Utc>) -> InfoServiceEntity {
+    let metadata = &ingress.metadata;
+    let ingress_class = ingress
+        .spec
+        .as_ref()
+        .and_then(|s| s.ingress_class_name.clone())
+        .or_else(|| {
+            metadata
+                .annotations
+                .as_ref()
+                .and_then(|a| a.get("kubernetes.io/ingress.class").cloned())
+        });
+    let load_balancer_address = ingress
+        .status
+        .as_ref()
+        .and_then(|s| s.load_balancer.as_ref())
+        .and_then(|lb| lb.ingress.as_ref())
+        .and_then(|ingress| ingress.first())
+        .and_then(|i| i.ip.clone().or_else(|| i.hostname.clone()));
+
+    InfoServiceEntity {
+        name: metadata.name.clone(),
+        namespace: metadata.namespace.clone(),
+        uid: metadata.uid.clone(),
+        kind: Some("Ingress".to_string()),
+        service_type: None,
+        service_types: Vec::new(),
+        ingress_class,
+        load_balancer_address,
+        creation_timestamp: metadata.creation_timestamp.as_ref().map(|t| t.0),
+        first_seen_at: Some(now),
+        deleted_at: None,
+        resource_version: metadata.resource_version.clone(),
+        last_updated_info_at: Some(now),
+        deleted: Some(false),
+        last_check_deleted_count: None,
+        label: metadata.labels.as_ref().and_then(flatten_map),
+    }
+}
+
+/// Converts a k8s-openapi PersistentVolumeClaim into an InfoVolumeEntity seen
+/// at `now`. Capacity and StorageClass fall back to the bound `volume`.
+pub fn map_pvc_to_info_entity(
+    claim: &PersistentVolumeClaim,
+    volume: Option<&PersistentVolume>,
+    now: DateTime<Utc>,
+) -> InfoVolumeEntity {
+    let metadata = &claim.metadata;
+    let spec = claim.spec.as_ref();
+    let status = claim.status.as_ref();
+    let volume_spec = volume.and_then(|v| v.spec.as_ref());
+
+    InfoVolumeEntity {
+        name: metadata.name.clone(),
+        namespace: metadata.namespace.clone(),
+        uid: metadata.uid.clone(),
+        kind: Some("PersistentVolumeClaim".to_string()),
+        storage_class: spec
+            .and_then(|s| s.storage_class_name.clone())
+            .or_else(|| volume_spec.and_then(|s| s.storage_class_name.clone())),
+        capacity_bytes: storage_capacity(status.and_then(|s| s.capacity.as_ref()))
+            .or_else(|| storage_capacity(volume_spec.and_then(|s| s.capacity.as_ref())))
+            .or_else(|| storage_capacity(spec.and_then(|s| s.resources.as_ref()).and_then(|r| r.requests.as_ref()))),
+        volume_name: spec.and_then(|s| s.volume_name.clone()),
+        claim: None,
+        phase: status.and_then(|s| s.phase.clone()),
+        // Claims do not report when their phase changed
+        phase_transition_at: None,
+        phases: Vec::new(),
+        creation_timestamp: metadata.creation_timestamp.as_ref().map(|t| t.0),
+        first_seen_at: Some(now),
+        deleted_at: None,
+        resource_version: metadata.resource_version.clone(),
+        last_updated_info_at: Some(now),
+        deleted: Some(false),
+        last_check_deleted_count: None,
+        label: metadata.labels.as_ref().and_then(flatten_map),
+    }
+}
+
+/// Converts a k8s-openapi PersistentVolume into an InfoVolumeEntity seen at `now`
+pub fn map_pv_to_info_entity(volume: &PersistentVolume, now: DateTime<Utc>) -> InfoVolumeEntity {
+    let metadata = &volume.metadata;
+    let spec = volume.spec.as_ref();
+    let status = volume.status.as_ref();
+    let claim = spec
+        .and_then(|s| s.claim_ref.as_ref())
+        .and_then(|c| Some(format!("{}/{}", c.namespace.as_deref()?, c.name.as_deref()?)));
+
+    InfoVolumeEntity {
+        name: metadata.name.clone(),
+        namespace: None,
+        uid: metadata.uid.clone(),
+        kind: Some("PersistentVolume".to_string()),
+        storage_class: spec.and_then(|s| s.storage_class_name.clone()),
+        capacity_bytes: storage_capacity(spec.and_then(|s| s.capacity.as_ref())),
+        volume_name: None,
+        claim,
+        phase: status.and_then(|s| s.phase.clone()),
+        phase_transition_at: status.and_then(|s| s.last_phase_transition_time.as_ref()).map(|t| t.0),
+        phases: Vec::new(),
+        creation_timestamp: metadata.creation_timestamp.as_ref().map(|t| t.0),
+        first_seen_at: Some(now),
+        deleted_at: None,
+        resource_version: metadata.resource_version.clone(),
+        last_updated_info_at: Some(now),
+        deleted: Some(false),
+        last_check_deleted_count: None,
+        label: metadata.labels.as_ref().and_then(flatten_map),
+    }
+}
+
+/// Parses a Kubernetes storage quantity such as `10Gi` or `500M` into bytes.
+pub fn parse_storage_quantity(value: &str) -> Option<f64> {
+    const SUFFIXES: [(&str, f64); 10] = [
+        ("Ki", 1024.0),
+        ("Mi", 1024.0 * 1024.0),
+        ("Gi", 1024.0 * 1024.0 * 1024.0),
+        ("Ti", 1024.0 * 1024.0 * 1024.0 * 1024.0),
+        ("Pi", 1024.0 * 1024.0 * 1024.0 * 1024.0 * 1024.0),
+        ("k", 1e3),
+        ("M", 1e6),
+        ("G", 1e9),
+        ("T", 1e12),
+        ("P", 1e15),
+    ];
+
+    let value = value.trim();
+    for (suffix, factor) in SUFFIXES {
+        if let Some(number) = value.strip_suffix(suffix) {
+            return number.parse::<f64>().ok().map(|n| n * factor);
+        }
+    }
+    value.parse::<f64>().ok()
+}
+
+fn storage_capacity(map: Option<&BTreeMap<String, Quantity>>) -> Option<f64> {
+    map.and_then(|m| m.get("storage")).and_then(|q| parse_storage_quantity(&q.0))
+}
+
 fn flatten_map(map: &BTreeMap<String, String>) -> Option<String> {
     if map.is_empty() {
         return None;
@@ -10,6 +10,7 @@ pub mod kube_resources;
 pub mod nodes;
 pub mod pods;
 pub mod deployments;
+pub mod replicasets;
 pub mod statefulsets;
 pub mod daemonsets;
 pub mod jobs;
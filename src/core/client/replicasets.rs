@@ -0,0 +1,16 @@
+use anyhow::Result;
+use kube::{Api, Client};
+use kube::api::ListParams;
+use tracing::debug;
+
+use crate::core::client::kube_resources::ReplicaSet;
+
+/// Fetch all replicasets in the cluster
+pub async fn fetch_replicasets(client: &Client) -> Result<Vec<ReplicaSet>> {
+    let replicasets: Api<ReplicaSet> = Api::all(client.clone());
+    let rs_list = replicasets.list(&ListParams::default()).await?;
+
+    debug!("Discovered {} replicaset(s)", rs_list.items.len());
+    Ok(rs_list.items)
+}
+
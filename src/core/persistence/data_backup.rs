@@ -0,0 +1,494 @@
+//! Backups of the whole data set (info records and every metric tier) as
+//! `rustcost-backup-<UTC time>.tar.gz`.
+//!
+//! Layout inside the archive:
+//!
+//! ```text
+//! manifest.json            format version, app version, backend, creation time
+//! data/info/...            `.rci` records
+//! data/metric/k8s/...      `.rcd` partitions (plain, complete rows only)
+//! ```
+//!
+//! Objects are read through the active storage backend, so a backup taken
+//! on `fs` restores into `redb` and vice versa. The LLM API token is blanked
+//! in `info/llm.rci`; restoring such a backup keeps the current token.
+//!
+//! Restore validates the manifest, then extracts the whole archive into
+//! `<base path>/restore-staging` before touching any data. Only once staging
+//! succeeded are the partitions and records contained in the archive
+//! replaced; objects missing from it are kept. A restore interrupted after
+//! staging is finished at the next startup (see [`resume_staged_restore`]).
+
+use anyhow::{anyhow, bail, Context, Result};
+use chrono::{DateTime, Utc};
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use serde::{Deserialize, Serialize};
+use std::fs::{self, File};
+use std::io::{Read, Write};
+use std::path::{Component, Path, PathBuf};
+
+use crate::core::persistence::info::fixed::setting::info_setting_entity::InfoSettingEntity;
+use crate::core::persistence::metrics::metric_checksum::verify_row;
+use crate::core::persistence::metrics::metric_write_buffer::with_metric_writes_paused;
+use crate::core::persistence::storage_backend::storage_backend;
+use crate::core::persistence::storage_path::get_rustcost_base_path;
+
+/// Bumped whenever the archive layout changes incompatibly.
+pub const BACKUP_FORMAT_VERSION: u32 = 1;
+
+const MANIFEST_NAME: &str = "manifest.json";
+const DATA_PREFIX: &str = "data/";
+const FILE_PREFIX: &str = "rustcost-backup-";
+const FILE_SUFFIX: &str = ".tar.gz";
+const STAGING_DIR: &str = "restore-staging";
+/// Record holding the LLM API token, which backups never contain
+const LLM_RECORD: &str = "info/llm.rci";
+
+/// First entry of every backup archive.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupManifest {
+    pub format_version: u32,
+    pub app_version: String,
+    /// Backend the backup was taken from (`fs`, `redb`)
+    pub storage_backend: String,
+    pub created_at: DateTime<Utc>,
+}
+
+/// One backup archive in the backup directory.
+#[derive(Debug, Clone, Serialize)]
+pub struct BackupEntry {
+    pub file: String,
+    pub bytes: u64,
+}
+
+/// Result of writing a backup.
+#[derive(Debug, Clone, Serialize)]
+pub struct BackupSummary {
+    pub file: Option<String>,
+    pub bytes: u64,
+    pub objects: usize,
+    pub created_at: DateTime<Utc>,
+    /// Old archives deleted by `backup_retention_count`
+    pub pruned: usize,
+}
+
+/// Result of a restore.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct BackupRestoreSummary {
+    pub file: String,
+    pub format_version: u32,
+    pub records: usize,
+    pub partitions: usize,
+}
+
+/// Directory that receives backup archives.
+pub fn backup_root(settings: &InfoSettingEntity) -> PathBuf {
+    settings
+        .backup_dir
+        .as_ref()
+        .map(PathBuf::from)
+        .unwrap_or_else(|| get_rustcost_base_path().join("backups"))
+}
+
+/// Writes a full backup as tar.gz to `out`. Returns the number of objects.
+pub fn write_backup<W: Write>(out: W, created_at: DateTime<Utc>) -> Result<usize> {
+    let backend = storage_backend();
+    let manifest = BackupManifest {
+        format_version: BACKUP_FORMAT_VERSION,
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+        storage_backend: backend.name().to_string(),
+        created_at,
+    };
+
+    let mut builder = tar::Builder::new(GzEncoder::new(out, Compression::default()));
+    append_entry(&mut builder, MANIFEST_NAME, &serde_json::to_vec_pretty(&manifest)?, created_at)?;
+
+    // Buffered rows belong in the snapshot, and no new ones may land while
+    // partitions are read
+    let mut objects = 0;
+    with_metric_writes_paused(|| {
+        backend.snapshot(&mut |key, bytes| {
+            objects += 1;
+            let name = format!("{}{}", DATA_PREFIX, key);
+            if key == LLM_RECORD {
+                return append_entry(&mut builder, &name, redact_token(bytes).as_bytes(), created_at);
+            }
+            append_entry(&mut builder, &name, bytes, created_at)
+        })
+    })?;
+
+    builder.into_inner()?.finish()?.flush()?;
+    Ok(objects)
+}
+
+/// `llm.rci` with the `TOKEN` value blanked.
+fn redact_token(bytes: &[u8]) -> String {
+    String::from_utf8_lossy(bytes)
+        .lines()
+        .map(|line| match token_value(line) {
+            Some(_) => "TOKEN:\n".to_string(),
+            None => format!("{}\n", line),
+        })
+        .collect()
+}
+
+/// Value of a `TOKEN:` line.
+fn token_value(line: &str) -> Option<&str> {
+    let (key, val) = line.split_once(':')?;
+    key.trim().eq_ignore_ascii_case("TOKEN").then(|| val.trim())
+}
+
+/// Restored `llm.rci` with the current token in place of a blanked one.
+fn keep_current_token(restored: &str, current: Option<&str>) -> String {
+    let current_token = current
+        .and_then(|text| text.lines().find_map(token_value))
+        .filter(|token| !token.is_empty());
+    let Some(token) = current_token else {
+        return restored.to_string();
+    };
+
+    let mut has_token = false;
+    let mut text: String = restored
+        .lines()
+        .map(|line| match token_value(line) {
+            Some(value) => {
+                has_token = true;
+                let value = if value.is_empty() { token } else { value };
+                format!("TOKEN:{}\n", value)
+            }
+            None => format!("{}\n", line),
+        })
+        .collect();
+    if !has_token {
+        text.push_str(&format!("TOKEN:{}\n", token));
+    }
+    text
+}
+
+fn append_entry<W: Write>(
+    builder: &mut tar::Builder<W>,
+    name: &str,
+    bytes: &[u8],
+    mtime: DateTime<Utc>,
+) -> Result<()> {
+    let mut header = tar::Header::new_gnu();
+    header.set_size(bytes.len() as u64);
+    header.set_mode(0o644);
+    header.set_mtime(mtime.timestamp().max(0) as u64);
+    builder
+        .append_data(&mut header, name, bytes)
+        .with_context(|| format!("Failed to add {} to the backup", name))
+}
+
+/// Writes a backup into `root` and prunes archives beyond `keep` (0 keeps all).
+pub fn create_backup(root: &Path, keep: u32, now: DateTime<Utc>) -> Result<BackupSummary> {
+    fs::create_dir_all(root).with_context(|| format!("Failed to create {:?}", root))?;
+    let name = format!("{}{}{}", FILE_PREFIX, now.format("%Y%m%dT%H%M%SZ"), FILE_SUFFIX);
+    let path = root.join(&name);
+    let tmp_path = root.join(format!("{}.tmp", name));
+
+    let file = File::create(&tmp_path).with_context(|| format!("Failed to create {:?}", tmp_path))?;
+    let objects = write_backup(&file, now)?;
+    file.sync_all()?;
+    fs::rename(&tmp_path, &path).with_context(|| format!("Failed to rename {:?}", tmp_path))?;
+
+    Ok(BackupSummary {
+        file: Some(name),
+        bytes: fs::metadata(&path)?.len(),
+        objects,
+        created_at: now,
+        pruned: prune_backups(root, keep)?,
+    })
+}
+
+/// Backup archives in `root`, newest first.
+pub fn list_backups(root: &Path) -> Result<Vec<BackupEntry>> {
+    if !root.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut backups = Vec::new();
+    for entry in fs::read_dir(root)? {
+        let entry = entry?;
+        let file = entry.file_name().to_string_lossy().into_owned();
+        if is_backup_name(&file) {
+            backups.push(BackupEntry { file, bytes: entry.metadata()?.len() });
+        }
+    }
+    // Names embed the UTC time, so they sort chronologically
+    backups.sort_by(|a, b| b.file.cmp(&a.file));
+    Ok(backups)
+}
+
+/// Creation time of the newest backup in `root`, if any.
+pub fn latest_backup_time(root: &Path) -> Result<Option<DateTime<Utc>>> {
+    Ok(list_backups(root)?.first().and_then(|b| {
+        let stamp = b.file.strip_prefix(FILE_PREFIX)?.strip_suffix(FILE_SUFFIX)?;
+        chrono::NaiveDateTime::parse_from_str(stamp, "%Y%m%dT%H%M%SZ")
+            .ok()
+            .map(|t| t.and_utc())
+    }))
+}
+
+fn prune_backups(root: &Path, keep: u32) -> Result<usize> {
+    if keep == 0 {
+        return Ok(0);
+    }
+    let mut pruned = 0;
+    for old in list_backups(root)?.into_iter().skip(keep as usize) {
+        fs::remove_file(root.join(&old.file))
+            .with_context(|| format!("Failed to delete old backup {}", old.file))?;
+        pruned += 1;
+    }
+    Ok(pruned)
+}
+
+fn is_backup_name(name: &str) -> bool {
+    name.starts_with(FILE_PREFIX) && name.ends_with(FILE_SUFFIX)
+}
+
+/// Restores `file` (a name listed by `list_backups`) from `root`.
+pub fn restore_backup(root: &Path, file: &str) -> Result<BackupRestoreSummary> {
+    if !is_backup_name(file) || file.contains(['/', '\\']) {
+        bail!("Invalid backup file name '{}'", file);
+    }
+    let path = root.join(file);
+    if !path.exists() {
+        bail!("Backup '{}' not found", file);
+    }
+
+    let mut summary = restore_from(File::open(&path)?, &get_rustcost_base_path())?;
+    summary.file = file.to_string();
+    Ok(summary)
+}
+
+fn restore_from<R: Read>(input: R, base: &Path) -> Result<BackupRestoreSummary> {
+    stage_restore(input, base)?;
+    with_metric_writes_paused(|| apply_staged_restore(base))?
+        .ok_or_else(|| anyhow!("Staged restore disappeared before it was applied"))
+}
+
+/// Restores a backup archive read from `input` (e.g. an upload) without
+/// storing the archive itself.
+pub fn restore_backup_from<R: Read>(input: R) -> Result<BackupRestoreSummary> {
+    restore_from(input, &get_rustcost_base_path())
+}
+
+/// Finishes a restore that was staged but not fully applied before the
+/// process stopped. Called once at startup.
+pub fn resume_staged_restore() -> Result<Option<BackupRestoreSummary>> {
+    with_metric_writes_paused(|| apply_staged_restore(&get_rustcost_base_path()))
+}
+
+/// Extracts the archive into the staging directory. Nothing outside it is
+/// written, so a damaged archive leaves the data untouched.
+fn stage_restore<R: Read>(input: R, base: &Path) -> Result<BackupManifest> {
+    let mut archive = tar::Archive::new(GzDecoder::new(input));
+    let mut entries = archive.entries()?;
+
+    // Validate before anything is written
+    let mut first = entries.next().ok_or_else(|| anyhow!("Backup archive is empty"))??;
+    if first.path()?.to_string_lossy() != MANIFEST_NAME {
+        bail!("Backup archive has no {}", MANIFEST_NAME);
+    }
+    let mut text = String::new();
+    first.read_to_string(&mut text)?;
+    let manifest: BackupManifest =
+        serde_json::from_str(&text).context("Backup manifest is not valid")?;
+    if manifest.format_version != BACKUP_FORMAT_VERSION {
+        bail!(
+            "Backup format v{} is not supported by this version (expects v{})",
+            manifest.format_version,
+            BACKUP_FORMAT_VERSION
+        );
+    }
+
+    let staging = base.join(STAGING_DIR);
+    if staging.exists() {
+        bail!("An earlier restore was not finished; restart rustcost to complete it first");
+    }
+    let tmp = base.join(format!("{}.tmp", STAGING_DIR));
+    if tmp.exists() {
+        fs::remove_dir_all(&tmp).with_context(|| format!("Failed to clear {:?}", tmp))?;
+    }
+
+    let staged = (|| -> Result<()> {
+        for entry in entries {
+            let mut entry = entry?;
+            let name = entry.path()?.to_string_lossy().into_owned();
+            let Some(key) = name.strip_prefix(DATA_PREFIX) else {
+                continue;
+            };
+            let relative = Path::new(key);
+            if !relative.components().all(|c| matches!(c, Component::Normal(_))) {
+                bail!("Unsafe path '{}' in backup", name);
+            }
+            if !key.ends_with(".rci") && !key.ends_with(".rcd") {
+                continue;
+            }
+
+            let mut text = String::new();
+            entry.read_to_string(&mut text)?;
+            if key.ends_with(".rcd") {
+                text = text
+                    .lines()
+                    .filter_map(verify_row)
+                    .map(|row| format!("{}\n", row))
+                    .collect();
+            }
+
+            let target = tmp.join(DATA_PREFIX).join(relative);
+            if let Some(parent) = target.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(&target, text).with_context(|| format!("Failed to stage {}", name))?;
+        }
+        fs::write(tmp.join(MANIFEST_NAME), serde_json::to_vec_pretty(&manifest)?)?;
+        Ok(())
+    })();
+    if let Err(e) = staged {
+        let _ = fs::remove_dir_all(&tmp);
+        return Err(e);
+    }
+
+    // Commit point: from here on the restore is finished even after a crash
+    fs::rename(&tmp, &staging).with_context(|| format!("Failed to rename {:?}", tmp))?;
+    Ok(manifest)
+}
+
+/// Copies the staged archive into the active backend, then removes the
+/// staging directory. Safe to repeat: every object is replaced as a whole.
+fn apply_staged_restore(base: &Path) -> Result<Option<BackupRestoreSummary>> {
+    let staging = base.join(STAGING_DIR);
+    if !staging.exists() {
+        return Ok(None);
+    }
+    let manifest: BackupManifest = serde_json::from_slice(&fs::read(staging.join(MANIFEST_NAME))?)
+        .context("Staged backup manifest is not valid")?;
+
+    let backend = storage_backend();
+    let mut summary = BackupRestoreSummary {
+        format_version: manifest.format_version,
+        ..Default::default()
+    };
+
+    let data = staging.join(DATA_PREFIX);
+    let mut dirs = vec![data.clone()];
+    while let Some(dir) = dirs.pop() {
+        if !dir.exists() {
+            continue;
+        }
+        for entry in fs::read_dir(&dir)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                dirs.push(path);
+                continue;
+            }
+            let Ok(relative) = path.strip_prefix(&data) else {
+                continue;
+            };
+            let target = base.join(relative);
+            let text = fs::read_to_string(&path)?;
+
+            if path.extension().is_some_and(|e| e == "rci") {
+                let text = if relative == Path::new(LLM_RECORD) {
+                    keep_current_token(&text, backend.read_record(&target)?.as_deref())
+                } else {
+                    text
+                };
+                backend.write_record(&target, &text)?;
+                summary.records += 1;
+            } else if path.extension().is_some_and(|e| e == "rcd") {
+                backend.replace_segment(&target, &text)?;
+                summary.partitions += 1;
+            }
+        }
+    }
+
+    fs::remove_dir_all(&staging).with_context(|| format!("Failed to remove {:?}", staging))?;
+    Ok(Some(summary))
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn archive_with_manifest(version: u32) -> Vec<u8> {
+        let manifest = BackupManifest {
+            format_version: version,
+            app_version: "0.0.0".into(),
+            storage_backend: "fs".into(),
+            created_at: Utc::now(),
+        };
+        let mut builder = tar::Builder::new(GzEncoder::new(Vec::new(), Compression::default()));
+        let json = serde_json::to_vec(&manifest).unwrap();
+        append_entry(&mut builder, MANIFEST_NAME, &json, Utc::now()).unwrap();
+        builder.into_inner().unwrap().finish().unwrap()
+    }
+
+    #[test]
+    fn rejects_incompatible_versions_before_writing() {
+        let base = std::env::temp_dir().join(format!("rcd-backup-{}", std::process::id()));
+        let err = restore_from(archive_with_manifest(BACKUP_FORMAT_VERSION + 1).as_slice(), &base)
+            .unwrap_err();
+        assert!(err.to_string().contains("not supported"));
+        assert!(!base.exists());
+    }
+
+    #[test]
+    fn damaged_archive_leaves_data_untouched() {
+        let base = std::env::temp_dir().join(format!("rcd-backup-staging-{}", std::process::id()));
+        let mut builder = tar::Builder::new(GzEncoder::new(Vec::new(), Compression::default()));
+        let manifest = serde_json::to_vec(&BackupManifest {
+            format_version: BACKUP_FORMAT_VERSION,
+            app_version: "0.0.0".into(),
+            storage_backend: "fs".into(),
+            created_at: Utc::now(),
+        })
+        .unwrap();
+        append_entry(&mut builder, MANIFEST_NAME, &manifest, Utc::now()).unwrap();
+        append_entry(&mut builder, "data/info/settings.rci", b"LANGUAGE:ko\n", Utc::now()).unwrap();
+        let mut bytes = builder.into_inner().unwrap().finish().unwrap();
+        bytes.truncate(bytes.len() - 20);
+
+        assert!(restore_from(bytes.as_slice(), &base).is_err());
+        assert!(!base.join("info").exists());
+        assert!(!base.join(STAGING_DIR).exists());
+        let _ = fs::remove_dir_all(&base);
+    }
+
+    #[test]
+    fn llm_token_is_redacted_and_kept_on_restore() {
+        let redacted = redact_token(b"PROVIDER:openai\nTOKEN:sk-secret\nMODEL:gpt\n");
+        assert_eq!(redacted, "PROVIDER:openai\nTOKEN:\nMODEL:gpt\n");
+
+        let restored = keep_current_token(&redacted, Some("TOKEN:sk-current\n"));
+        assert_eq!(restored, "PROVIDER:openai\nTOKEN:sk-current\nMODEL:gpt\n");
+        assert_eq!(keep_current_token(&redacted, None), redacted);
+    }
+
+    #[test]
+    fn prunes_oldest_backups_beyond_retention() {
+        let root = std::env::temp_dir().join(format!("rcd-backups-{}", std::process::id()));
+        fs::create_dir_all(&root).unwrap();
+        for day in 1..=4 {
+            fs::write(root.join(format!("{}2025010{}T000000Z{}", FILE_PREFIX, day, FILE_SUFFIX)), b"x").unwrap();
+        }
+        fs::write(root.join("notes.txt"), b"keep").unwrap();
+
+        assert_eq!(prune_backups(&root, 2).unwrap(), 2);
+        let left: Vec<String> = list_backups(&root).unwrap().into_iter().map(|b| b.file).collect();
+        assert_eq!(left, ["rustcost-backup-20250104T000000Z.tar.gz", "rustcost-backup-20250103T000000Z.tar.gz"]);
+        assert_eq!(
+            latest_backup_time(&root).unwrap().unwrap().to_rfc3339(),
+            "2025-01-04T00:00:00+00:00"
+        );
+        assert!(root.join("notes.txt").exists());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -1,15 +1,11 @@
-use std::{
-    collections::HashMap,
-    fs::{self, File},
-    io::{BufRead, BufReader},
-    path::Path,
-};
+use std::{collections::HashMap, path::Path};
 
 use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
 
 use crate::core::persistence::info::fixed::info_fixed_fs_adapter_trait::InfoFixedFsAdapterTrait;
 use crate::core::persistence::storage_path::{info_alert_path, info_setting_path};
+use crate::core::persistence::storage_backend::storage_backend;
 
 use super::alert_rule_entity::{AlertMetricType, AlertOperator, AlertRuleEntity, AlertSeverity};
 use super::info_alert_entity::InfoAlertEntity;
@@ -28,12 +24,12 @@ impl InfoFixedFsAdapterTrait<InfoAlertEntity> for InfoAlertFsAdapter {
 
     fn read(&self) -> Result<InfoAlertEntity> {
         let path = info_alert_path();
-        if path.exists() {
+        if storage_backend().record_exists(&path)? {
             return Self::read_from_path(&path);
         }
 
         let legacy = info_setting_path();
-        if legacy.exists() {
+        if storage_backend().record_exists(&legacy)? {
             if let Ok(entity) = Self::read_from_path(&legacy) {
                 return Ok(entity);
             }
@@ -52,22 +48,20 @@ impl InfoFixedFsAdapterTrait<InfoAlertEntity> for InfoAlertFsAdapter {
 
     fn delete(&self) -> Result<()> {
         let path = info_alert_path();
-        if path.exists() {
-            fs::remove_file(&path).context("Failed to delete alerts file")?;
-        }
+        storage_backend().delete_record(&path).context("Failed to delete alerts file")?;
         Ok(())
     }
 }
 
 impl InfoAlertFsAdapter {
     fn read_from_path(path: &Path) -> Result<InfoAlertEntity> {
-        let file = File::open(path).context("Failed to open alerts file")?;
-        let reader = BufReader::new(file);
+        let text = storage_backend()
+            .read_record(path)?
+            .context("Failed to open alerts file")?;
         let mut s = InfoAlertEntity::default();
         let mut raw_rules: HashMap<String, String> = HashMap::new();
 
-        for line in reader.lines() {
-            let line = line?;
+        for line in text.lines() {
             if let Some((key, val)) = line.split_once(':') {
                 let key = key.trim().to_uppercase();
                 let val = val.trim();
@@ -140,16 +134,11 @@ impl InfoAlertFsAdapter {
     }
 
     fn write(&self, data: &InfoAlertEntity) -> Result<()> {
-        use std::io::Write;
+        use std::fmt::Write;
 
         let path = info_alert_path();
 
-        if let Some(dir) = path.parent() {
-            fs::create_dir_all(dir).context("Failed to create alerts directory")?;
-        }
-
-        let tmp_path = path.with_extension("rci.tmp");
-        let mut f = File::create(&tmp_path).context("Failed to create temp alerts file")?;
+        let mut f = String::new();
 
         writeln!(f, "ALERT_RULE_COUNT:{}", data.rules.len())?;
         for (idx, rule) in data.rules.iter().enumerate() {
@@ -204,18 +193,7 @@ impl InfoAlertFsAdapter {
         writeln!(f, "UPDATED_AT:{}", data.updated_at.to_rfc3339())?;
         writeln!(f, "VERSION:{}", data.version)?;
 
-        f.flush()?;
-        f.sync_all().context("Failed to sync temp alerts file")?;
-
-        fs::rename(&tmp_path, &path).context("Failed to finalize alerts file")?;
-
-        #[cfg(unix)]
-        if let Some(dir) = path.parent() {
-            let dir_file = File::open(dir).context("Failed to open alerts directory")?;
-            dir_file
-                .sync_all()
-                .context("Failed to sync alerts directory")?;
-        }
+        storage_backend().write_record(&path, &f)?;
 
         Ok(())
     }
@@ -0,0 +1,17 @@
+use super::info_exchange_rate_entity::InfoExchangeRateEntity;
+use crate::core::persistence::info::fixed::info_fixed_fs_adapter_trait::InfoFixedFsAdapterTrait;
+use anyhow::Result;
+
+/// API repository trait for exchange rates.
+/// API can read and update, but usually not create/delete.
+pub trait InfoExchangeRateApiRepository: Send + Sync {
+    fn fs_adapter(&self) -> &dyn InfoFixedFsAdapterTrait<InfoExchangeRateEntity>;
+
+    fn read(&self) -> Result<InfoExchangeRateEntity> {
+        self.fs_adapter().read()
+    }
+
+    fn update(&self, data: &InfoExchangeRateEntity) -> Result<()> {
+        self.fs_adapter().update(data)
+    }
+}
@@ -0,0 +1,62 @@
+use std::collections::BTreeMap;
+
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::core::persistence::info::fixed::unit_price::info_unit_price_entity::Currency;
+use crate::domain::info::dto::info_exchange_rate_upsert_request::InfoExchangeRateUpsertRequest;
+
+/// User-maintained exchange-rate table used to convert costs between currencies.
+///
+/// Each rate is the amount of that currency worth 1 USD (e.g. `EUR: 0.92`,
+/// `KRW: 1380`). USD is implicitly `1.0` and never stored.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InfoExchangeRateEntity {
+    /// Units of each currency per 1 USD
+    pub rates: BTreeMap<Currency, f64>,
+
+    /// Last update timestamp (UTC).
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Default for InfoExchangeRateEntity {
+    fn default() -> Self {
+        Self {
+            rates: BTreeMap::new(),
+            updated_at: Utc::now(),
+        }
+    }
+}
+
+impl InfoExchangeRateEntity {
+    /// Units of `currency` per 1 USD, if known.
+    pub fn rate(&self, currency: Currency) -> Option<f64> {
+        match currency {
+            Currency::USD => Some(1.0),
+            other => self.rates.get(&other).copied(),
+        }
+    }
+
+    /// Multiplier converting an amount in `from` into `to`.
+    pub fn factor(&self, from: Currency, to: Currency) -> Result<f64> {
+        if from == to {
+            return Ok(1.0);
+        }
+
+        let missing = |c: Currency| anyhow!("No exchange rate configured for {}", c.code());
+        let from_rate = self.rate(from).ok_or_else(|| missing(from))?;
+        let to_rate = self.rate(to).ok_or_else(|| missing(to))?;
+
+        Ok(to_rate / from_rate)
+    }
+
+    pub fn apply_update(&mut self, req: InfoExchangeRateUpsertRequest) {
+        for (currency, rate) in req.rates {
+            if currency != Currency::USD {
+                self.rates.insert(currency, rate);
+            }
+        }
+        self.updated_at = Utc::now();
+    }
+}
@@ -0,0 +1,86 @@
+use super::info_exchange_rate_entity::InfoExchangeRateEntity;
+use crate::core::persistence::info::fixed::info_fixed_fs_adapter_trait::InfoFixedFsAdapterTrait;
+use crate::core::persistence::info::fixed::unit_price::info_unit_price_entity::Currency;
+use crate::core::persistence::storage_path::info_exchange_rate_path;
+use crate::core::persistence::storage_backend::storage_backend;
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+
+/// File-based adapter for reading and writing [`InfoExchangeRateEntity`] data.
+///
+/// One `CURRENCY:rate` line per currency (e.g. `EUR:0.92`), plus `updated_at`.
+pub struct InfoExchangeRateFsAdapter;
+
+impl InfoFixedFsAdapterTrait<InfoExchangeRateEntity> for InfoExchangeRateFsAdapter {
+    fn new() -> Self {
+        Self
+    }
+
+    /// Reads the exchange-rate table from disk.
+    /// Returns an empty table if the file does not exist.
+    fn read(&self) -> Result<InfoExchangeRateEntity> {
+        let path = info_exchange_rate_path();
+
+        let Some(text) = storage_backend().read_record(&path)? else {
+            return Ok(InfoExchangeRateEntity::default());
+        };
+        let mut entity = InfoExchangeRateEntity::default();
+
+        for line in text.lines() {
+            if let Some((key, val)) = line.split_once(':') {
+                let key = key.trim();
+                let val = val.trim();
+
+                if key.eq_ignore_ascii_case("updated_at") {
+                    if let Ok(parsed) = DateTime::parse_from_rfc3339(val) {
+                        entity.updated_at = parsed.with_timezone(&Utc);
+                    }
+                    continue;
+                }
+
+                if let (Some(currency), Ok(rate)) = (Currency::parse(key), val.parse::<f64>()) {
+                    if currency != Currency::USD {
+                        entity.rates.insert(currency, rate);
+                    }
+                }
+            }
+        }
+
+        Ok(entity)
+    }
+
+    fn insert(&self, data: &InfoExchangeRateEntity) -> Result<()> {
+        self.write(data)
+    }
+
+    fn update(&self, data: &InfoExchangeRateEntity) -> Result<()> {
+        self.write(data)
+    }
+
+    fn delete(&self) -> Result<()> {
+        let path = info_exchange_rate_path();
+
+        storage_backend().delete_record(&path).context("Failed to delete exchange rate file")?;
+
+        Ok(())
+    }
+}
+
+impl InfoExchangeRateFsAdapter {
+    /// Writes the exchange-rate table to disk atomically.
+    fn write(&self, data: &InfoExchangeRateEntity) -> Result<()> {
+        use std::fmt::Write;
+        let path = info_exchange_rate_path();
+
+        let mut f = String::new();
+
+        for (currency, rate) in &data.rates {
+            writeln!(f, "{}:{}", currency.code(), rate)?;
+        }
+        writeln!(f, "updated_at:{}", data.updated_at.to_rfc3339())?;
+
+        storage_backend().write_record(&path, &f)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,29 @@
+use crate::core::persistence::info::fixed::exchange_rate::info_exchange_rate_api_repository_trait::InfoExchangeRateApiRepository;
+use crate::core::persistence::info::fixed::exchange_rate::info_exchange_rate_entity::InfoExchangeRateEntity;
+use crate::core::persistence::info::fixed::exchange_rate::info_exchange_rate_fs_adapter::InfoExchangeRateFsAdapter;
+use crate::core::persistence::info::fixed::info_fixed_fs_adapter_trait::InfoFixedFsAdapterTrait;
+
+/// Repository for the exchange-rate table backed by the filesystem adapter.
+pub struct InfoExchangeRateRepository {
+    adapter: InfoExchangeRateFsAdapter,
+}
+
+impl InfoExchangeRateRepository {
+    pub fn new() -> Self {
+        Self {
+            adapter: InfoExchangeRateFsAdapter,
+        }
+    }
+}
+
+impl Default for InfoExchangeRateRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InfoExchangeRateApiRepository for InfoExchangeRateRepository {
+    fn fs_adapter(&self) -> &dyn InfoFixedFsAdapterTrait<InfoExchangeRateEntity> {
+        &self.adapter
+    }
+}
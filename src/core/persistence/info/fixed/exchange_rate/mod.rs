@@ -0,0 +1,4 @@
+pub mod info_exchange_rate_entity;
+pub mod info_exchange_rate_fs_adapter;
+pub mod info_exchange_rate_api_repository_trait;
+pub mod info_exchange_rate_repository;
@@ -1,13 +1,10 @@
-use std::{
-    fs::{self, File},
-    io::{BufRead, BufReader},
-};
 
 use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
 
 use crate::core::persistence::info::fixed::info_fixed_fs_adapter_trait::InfoFixedFsAdapterTrait;
 use crate::core::persistence::storage_path::info_llm_path;
+use crate::core::persistence::storage_backend::storage_backend;
 
 use super::info_llm_entity::InfoLlmEntity;
 use super::llm_provider::LlmProvider;
@@ -27,16 +24,12 @@ impl InfoFixedFsAdapterTrait<InfoLlmEntity> for InfoLlmFsAdapter {
 
     fn read(&self) -> Result<InfoLlmEntity> {
         let path = info_llm_path();
-        if !path.exists() {
+        let Some(text) = storage_backend().read_record(&path)? else {
             return Ok(InfoLlmEntity::default());
-        }
-
-        let file = File::open(&path).context("Failed to open llm file")?;
-        let reader = BufReader::new(file);
+        };
         let mut s = InfoLlmEntity::default();
 
-        for line in reader.lines() {
-            let line = line?;
+        for line in text.lines() {
             if let Some((key, val)) = line.split_once(':') {
                 let key = key.trim().to_uppercase();
                 let val = val.trim();
@@ -129,24 +122,17 @@ impl InfoFixedFsAdapterTrait<InfoLlmEntity> for InfoLlmFsAdapter {
 
     fn delete(&self) -> Result<()> {
         let path = info_llm_path();
-        if path.exists() {
-            fs::remove_file(&path).context("Failed to delete llm file")?;
-        }
+        storage_backend().delete_record(&path).context("Failed to delete llm file")?;
         Ok(())
     }
 }
 
 impl InfoLlmFsAdapter {
     fn write(&self, data: &InfoLlmEntity) -> Result<()> {
-        use std::io::Write;
+        use std::fmt::Write;
 
         let path = info_llm_path();
-        if let Some(dir) = path.parent() {
-            fs::create_dir_all(dir).context("Failed to create llm directory")?;
-        }
-
-        let tmp_path = path.with_extension("rci.tmp");
-        let mut f = File::create(&tmp_path).context("Failed to create temp llm file")?;
+        let mut f = String::new();
 
         writeln!(f, "PROVIDER:{}", data.provider.as_code())?;
         writeln!(f, "BASE_URL:{}", data.base_url.clone().unwrap_or_default())?;
@@ -190,17 +176,7 @@ impl InfoLlmFsAdapter {
         writeln!(f, "UPDATED_AT:{}", data.updated_at.to_rfc3339())?;
         writeln!(f, "VERSION:{}", data.version)?;
 
-        f.flush()?;
-        f.sync_all().context("Failed to sync temp llm file")?;
-        fs::rename(&tmp_path, &path).context("Failed to finalize llm file")?;
-
-        #[cfg(unix)]
-        if let Some(dir) = path.parent() {
-            let dir_file = File::open(dir).context("Failed to open llm directory")?;
-            dir_file
-                .sync_all()
-                .context("Failed to sync llm directory")?;
-        }
+        storage_backend().write_record(&path, &f)?;
 
         Ok(())
     }
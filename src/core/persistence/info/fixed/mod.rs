@@ -2,5 +2,8 @@ pub mod version;
 pub mod setting;
 pub mod info_fixed_fs_adapter_trait;
 pub mod unit_price;
+pub mod exchange_rate;
 pub mod alerts;
 pub mod llm;
+pub mod ownership;
+pub mod storage_class_price;
@@ -0,0 +1,15 @@
+use crate::core::persistence::info::fixed::info_fixed_fs_adapter_trait::InfoFixedFsAdapterTrait;
+use super::info_ownership_entity::InfoOwnershipEntity;
+
+/// API-facing repository abstraction for ownership rules.
+pub trait InfoOwnershipApiRepository {
+    fn fs_adapter(&self) -> &dyn InfoFixedFsAdapterTrait<InfoOwnershipEntity>;
+
+    fn read(&self) -> anyhow::Result<InfoOwnershipEntity> {
+        self.fs_adapter().read()
+    }
+
+    fn update(&self, settings: &InfoOwnershipEntity) -> anyhow::Result<()> {
+        self.fs_adapter().update(settings)
+    }
+}
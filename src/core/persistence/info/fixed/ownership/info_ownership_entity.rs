@@ -0,0 +1,82 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::info::dto::info_ownership_upsert_request::InfoOwnershipUpsertRequest;
+
+use super::ownership_rule_entity::{OwnershipRuleEntity, OwnershipTarget};
+
+/// Rules that derive team/service/env for pods and containers from
+/// Kubernetes labels and annotations.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InfoOwnershipEntity {
+    /// Ownership rules, evaluated by descending priority.
+    pub rules: Vec<OwnershipRuleEntity>,
+    /// Team applied when no rule matches.
+    pub default_team: Option<String>,
+    /// Service applied when no rule matches.
+    pub default_service: Option<String>,
+    /// Env applied when no rule matches.
+    pub default_env: Option<String>,
+    /// Configuration creation timestamp (UTC).
+    pub created_at: DateTime<Utc>,
+    /// Last update timestamp (UTC).
+    pub updated_at: DateTime<Utc>,
+    /// Version identifier for the configuration format.
+    pub version: String,
+}
+
+impl Default for InfoOwnershipEntity {
+    fn default() -> Self {
+        let now = Utc::now();
+        Self {
+            rules: Vec::new(),
+            default_team: None,
+            default_service: None,
+            default_env: None,
+            created_at: now,
+            updated_at: now,
+            version: "1.0.0".into(),
+        }
+    }
+}
+
+impl InfoOwnershipEntity {
+    pub fn apply_update(&mut self, req: InfoOwnershipUpsertRequest) {
+        if let Some(v) = req.rules {
+            self.rules = v.into_iter().map(OwnershipRuleEntity::from).collect();
+        }
+        if let Some(v) = normalize_string_opt(req.default_team) {
+            self.default_team = v;
+        }
+        if let Some(v) = normalize_string_opt(req.default_service) {
+            self.default_service = v;
+        }
+        if let Some(v) = normalize_string_opt(req.default_env) {
+            self.default_env = v;
+        }
+
+        self.updated_at = Utc::now();
+    }
+
+    /// Whether any rule or default could tag an object.
+    pub fn is_active(&self) -> bool {
+        self.rules.iter().any(|r| r.enabled)
+            || OwnershipTarget::ALL.iter().any(|t| self.default_for(*t).is_some())
+    }
+
+    pub fn default_for(&self, target: OwnershipTarget) -> Option<&str> {
+        match target {
+            OwnershipTarget::Team => self.default_team.as_deref(),
+            OwnershipTarget::Service => self.default_service.as_deref(),
+            OwnershipTarget::Env => self.default_env.as_deref(),
+        }
+    }
+}
+
+fn normalize_string_opt(v: Option<String>) -> Option<Option<String>> {
+    match v {
+        Some(s) if s.trim().is_empty() => Some(None),
+        Some(s) => Some(Some(s.trim().to_string())),
+        None => None,
+    }
+}
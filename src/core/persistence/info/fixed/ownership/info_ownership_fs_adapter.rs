@@ -0,0 +1,158 @@
+use std::collections::HashMap;
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+
+use crate::core::persistence::info::fixed::info_fixed_fs_adapter_trait::InfoFixedFsAdapterTrait;
+use crate::core::persistence::storage_backend::storage_backend;
+use crate::core::persistence::storage_path::info_ownership_path;
+
+use super::info_ownership_entity::InfoOwnershipEntity;
+use super::ownership_rule_entity::{OwnershipRuleEntity, OwnershipSource, OwnershipTarget};
+
+/// FS adapter for persisted ownership rules.
+///
+/// Reads and writes a simple key-value file located at `ownership_rules.rci`.
+pub struct InfoOwnershipFsAdapter;
+
+impl InfoFixedFsAdapterTrait<InfoOwnershipEntity> for InfoOwnershipFsAdapter {
+    fn new() -> Self {
+        Self {}
+    }
+
+    fn read(&self) -> Result<InfoOwnershipEntity> {
+        let path = info_ownership_path();
+        let Some(text) = storage_backend().read_record(&path)? else {
+            return Ok(InfoOwnershipEntity::default());
+        };
+
+        let mut s = InfoOwnershipEntity::default();
+        let mut raw_rules: HashMap<String, String> = HashMap::new();
+
+        for line in text.lines() {
+            if let Some((key, val)) = line.split_once(':') {
+                let key = key.trim().to_uppercase();
+                let val = val.trim();
+
+                if key.starts_with("OWNERSHIP_RULE_") {
+                    raw_rules.insert(key.clone(), val.to_string());
+                }
+
+                match key.as_str() {
+                    "DEFAULT_TEAM" => s.default_team = non_empty(val),
+                    "DEFAULT_SERVICE" => s.default_service = non_empty(val),
+                    "DEFAULT_ENV" => s.default_env = non_empty(val),
+                    "CREATED_AT" => {
+                        if let Ok(dt) = val.parse::<DateTime<Utc>>() {
+                            s.created_at = dt;
+                        }
+                    }
+                    "UPDATED_AT" => {
+                        if let Ok(dt) = val.parse::<DateTime<Utc>>() {
+                            s.updated_at = dt;
+                        }
+                    }
+                    "VERSION" => s.version = val.to_string(),
+                    _ => {}
+                }
+            }
+        }
+
+        s.rules = Self::parse_rules(&raw_rules);
+        Ok(s)
+    }
+
+    fn insert(&self, data: &InfoOwnershipEntity) -> Result<()> {
+        self.write(data)
+    }
+
+    fn update(&self, data: &InfoOwnershipEntity) -> Result<()> {
+        self.write(data)
+    }
+
+    fn delete(&self) -> Result<()> {
+        let path = info_ownership_path();
+        storage_backend()
+            .delete_record(&path)
+            .context("Failed to delete ownership rules file")?;
+        Ok(())
+    }
+}
+
+impl InfoOwnershipFsAdapter {
+    fn write(&self, data: &InfoOwnershipEntity) -> Result<()> {
+        use std::fmt::Write;
+
+        let path = info_ownership_path();
+        let mut f = String::new();
+
+        writeln!(f, "OWNERSHIP_RULE_COUNT:{}", data.rules.len())?;
+        for (idx, rule) in data.rules.iter().enumerate() {
+            writeln!(f, "OWNERSHIP_RULE_{}_ID:{}", idx, rule.id)?;
+            writeln!(f, "OWNERSHIP_RULE_{}_SOURCE:{}", idx, rule.source.as_code())?;
+            writeln!(f, "OWNERSHIP_RULE_{}_KEY:{}", idx, rule.key)?;
+            writeln!(f, "OWNERSHIP_RULE_{}_TARGET:{}", idx, rule.target.as_code())?;
+            writeln!(f, "OWNERSHIP_RULE_{}_PRIORITY:{}", idx, rule.priority)?;
+            writeln!(f, "OWNERSHIP_RULE_{}_ENABLED:{}", idx, rule.enabled)?;
+        }
+
+        writeln!(f, "DEFAULT_TEAM:{}", data.default_team.clone().unwrap_or_default())?;
+        writeln!(f, "DEFAULT_SERVICE:{}", data.default_service.clone().unwrap_or_default())?;
+        writeln!(f, "DEFAULT_ENV:{}", data.default_env.clone().unwrap_or_default())?;
+        writeln!(f, "CREATED_AT:{}", data.created_at.to_rfc3339())?;
+        writeln!(f, "UPDATED_AT:{}", data.updated_at.to_rfc3339())?;
+        writeln!(f, "VERSION:{}", data.version)?;
+
+        storage_backend().write_record(&path, &f)?;
+
+        Ok(())
+    }
+
+    fn parse_rules(raw: &HashMap<String, String>) -> Vec<OwnershipRuleEntity> {
+        let count = raw
+            .get("OWNERSHIP_RULE_COUNT")
+            .and_then(|v| v.parse::<usize>().ok())
+            .unwrap_or(0);
+
+        let mut rules = Vec::with_capacity(count);
+
+        for idx in 0..count {
+            let prefix = format!("OWNERSHIP_RULE_{}_", idx);
+            let get = |suffix: &str| -> Option<String> {
+                raw.get(&(prefix.clone() + suffix)).map(|v| v.to_string())
+            };
+
+            // A rule without a key or a known source/target cannot match anything
+            let (Some(key), Some(source), Some(target)) = (
+                get("KEY").filter(|k| !k.is_empty()),
+                get("SOURCE").and_then(OwnershipSource::from_code),
+                get("TARGET").and_then(OwnershipTarget::from_code),
+            ) else {
+                continue;
+            };
+
+            rules.push(OwnershipRuleEntity {
+                id: get("ID").unwrap_or_else(|| format!("rule-{}", idx)),
+                source,
+                key,
+                target,
+                priority: get("PRIORITY")
+                    .and_then(|v| v.parse::<i32>().ok())
+                    .unwrap_or(0),
+                enabled: get("ENABLED")
+                    .map(|v| v.eq_ignore_ascii_case("true"))
+                    .unwrap_or(true),
+            });
+        }
+
+        rules
+    }
+}
+
+fn non_empty(val: &str) -> Option<String> {
+    if val.is_empty() {
+        None
+    } else {
+        Some(val.to_string())
+    }
+}
@@ -0,0 +1,29 @@
+use crate::core::persistence::info::fixed::info_fixed_fs_adapter_trait::InfoFixedFsAdapterTrait;
+
+use super::info_ownership_api_repository_trait::InfoOwnershipApiRepository;
+use super::info_ownership_entity::InfoOwnershipEntity;
+use super::info_ownership_fs_adapter::InfoOwnershipFsAdapter;
+
+pub struct InfoOwnershipRepository {
+    adapter: InfoOwnershipFsAdapter,
+}
+
+impl InfoOwnershipRepository {
+    pub fn new() -> Self {
+        Self {
+            adapter: InfoOwnershipFsAdapter::new(),
+        }
+    }
+}
+
+impl Default for InfoOwnershipRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InfoOwnershipApiRepository for InfoOwnershipRepository {
+    fn fs_adapter(&self) -> &dyn InfoFixedFsAdapterTrait<InfoOwnershipEntity> {
+        &self.adapter
+    }
+}
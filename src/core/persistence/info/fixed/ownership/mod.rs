@@ -0,0 +1,5 @@
+pub mod info_ownership_entity;
+pub mod info_ownership_fs_adapter;
+pub mod info_ownership_api_repository_trait;
+pub mod info_ownership_repository;
+pub mod ownership_rule_entity;
@@ -0,0 +1,116 @@
+use serde::{Deserialize, Serialize};
+
+/// Kubernetes metadata an ownership rule reads its value from.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum OwnershipSource {
+    PodLabel,
+    PodAnnotation,
+    NamespaceLabel,
+    NamespaceAnnotation,
+}
+
+impl OwnershipSource {
+    pub fn from_code<S: AsRef<str>>(code: S) -> Option<Self> {
+        match code.as_ref().to_uppercase().as_str() {
+            "LABEL" => Some(Self::PodLabel),
+            "ANNOTATION" => Some(Self::PodAnnotation),
+            "NS_LABEL" => Some(Self::NamespaceLabel),
+            "NS_ANNOTATION" => Some(Self::NamespaceAnnotation),
+            _ => None,
+        }
+    }
+
+    pub fn as_code(&self) -> &'static str {
+        match self {
+            Self::PodLabel => "LABEL",
+            Self::PodAnnotation => "ANNOTATION",
+            Self::NamespaceLabel => "NS_LABEL",
+            Self::NamespaceAnnotation => "NS_ANNOTATION",
+        }
+    }
+}
+
+/// Ownership field a rule fills in.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum OwnershipTarget {
+    Team,
+    Service,
+    Env,
+}
+
+impl OwnershipTarget {
+    pub const ALL: [OwnershipTarget; 3] = [Self::Team, Self::Service, Self::Env];
+
+    pub fn from_code<S: AsRef<str>>(code: S) -> Option<Self> {
+        match code.as_ref().to_uppercase().as_str() {
+            "TEAM" => Some(Self::Team),
+            "SERVICE" => Some(Self::Service),
+            "ENV" => Some(Self::Env),
+            _ => None,
+        }
+    }
+
+    pub fn as_code(&self) -> &'static str {
+        match self {
+            Self::Team => "TEAM",
+            Self::Service => "SERVICE",
+            Self::Env => "ENV",
+        }
+    }
+}
+
+/// Maps one label or annotation key onto team, service or env.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct OwnershipRuleEntity {
+    pub id: String,
+    pub source: OwnershipSource,
+    /// Label or annotation key, e.g. `app.kubernetes.io/part-of`
+    pub key: String,
+    pub target: OwnershipTarget,
+    /// Higher values win when several rules set the same target.
+    pub priority: i32,
+    pub enabled: bool,
+}
+
+/// Where an object's team/service/env tag came from. Tags derived from a
+/// rule or a default are recomputed whenever the object is re-tagged; manual
+/// ones are kept.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum OwnershipTagSource {
+    /// Set through a PATCH
+    Manual,
+    /// The configured default for the target
+    Default,
+    /// The rule with this id
+    Rule(String),
+}
+
+impl OwnershipTagSource {
+    pub fn from_code<S: AsRef<str>>(code: S) -> Option<Self> {
+        let code = code.as_ref().trim();
+        match code.to_uppercase().as_str() {
+            "MANUAL" => Some(Self::Manual),
+            "DEFAULT" => Some(Self::Default),
+            _ => code
+                .split_once(':')
+                .filter(|(kind, id)| kind.eq_ignore_ascii_case("RULE") && !id.is_empty())
+                .map(|(_, id)| Self::Rule(id.to_string())),
+        }
+    }
+
+    /// True for tags the rules produced, which may be recomputed.
+    pub fn is_derived(&self) -> bool {
+        !matches!(self, Self::Manual)
+    }
+}
+
+impl std::fmt::Display for OwnershipTagSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Manual => f.write_str("MANUAL"),
+            Self::Default => f.write_str("DEFAULT"),
+            Self::Rule(id) => write!(f, "RULE:{}", id),
+        }
+    }
+}
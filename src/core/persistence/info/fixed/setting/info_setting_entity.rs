@@ -30,22 +30,38 @@ pub struct InfoSettingEntity {
     /// Day data (files named YYYY)
     pub day_retention_years: u32,
 
-    /// Retention behavior: `"delete"` or `"archive"`.
+    /// Retention behavior: `"delete"` or `"archive"`. Archiving needs the
+    /// `fs` backend; on `redb` expired data is kept instead.
     pub retention_policy: String,
 
+    /// Where expired partitions are bundled when `retention_policy` is
+    /// `"archive"` (defaults to `<base path>/archive`).
+    pub archive_dir: Option<String>,
+
     // ===== File-based Persistence Options =====
     /// Whether to include line numbers when writing records.
     pub enable_line_num_tracking: bool,
 
-    /// Whether to create `.idx` index sidecar files for faster reads.
+    /// Whether the day task writes `.idx` sidecars for closed partitions.
+    /// Existing sidecars are used by readers either way.
     pub enable_index_file: bool,
 
-    /// Maximum local storage capacity in gigabytes before cleanup triggers.
+    /// Maximum metric + info data in gigabytes before old minute/hour data is evicted (0 disables the limit).
     pub max_storage_gb: u32,
 
-    /// Enables on-disk compression (gzip or zstd).
+    /// Gzip-compresses closed metric partitions during the day task.
     pub compression_enabled: bool,
 
+    // ===== Backup =====
+    /// Where backup archives are written (defaults to `<base path>/backups`).
+    pub backup_dir: Option<String>,
+
+    /// Hours between scheduled backups (0 disables scheduled backups).
+    pub backup_interval_hours: u32,
+
+    /// Number of backup archives kept; older ones are deleted (0 keeps all).
+    pub backup_retention_count: u32,
+
     // ===== Metrics Collection =====
     /// Scrape interval in seconds (e.g. 60 = every minute).
     pub scrape_interval_sec: u32,
@@ -53,6 +69,16 @@ pub struct InfoSettingEntity {
     /// Number of metrics batched together when written to disk.
     pub metrics_batch_size: u32,
 
+    // ===== Cost =====
+    /// Extra node label selector marking spot/preemptible nodes, on top of the
+    /// well-known cloud labels (e.g. `"lifecycle=spot,pool=preemptible"`).
+    pub spot_node_label_selector: Option<String>,
+
+    /// Provider price catalog file mounted into the container (CSV keyed by
+    /// instance type and region, or AWS/GCP/Azure pricing JSON). When set, node
+    /// fixed prices are imported from it.
+    pub price_catalog_path: Option<String>,
+
     // ===== LLM Integration =====
     /// Endpoint for an external LLM API (e.g., OpenAI, Anthropic).
     pub llm_url: Option<String>,
@@ -83,7 +109,21 @@ pub struct InfoSettingEntity {
 
     pub gpu_exporter_urls: Vec<String>,
     pub container_exporter_urls: Vec<String>,
+
+    /// Skip TLS certificate verification for `container_exporter_urls`.
+    /// Off by default: endpoints must present a certificate signed by the
+    /// cluster CA or a system root.
+    pub container_exporter_insecure_tls: bool,
+
     pub k8s_api_url: Option<String>,
+
+    /// Where pod/container metrics come from: the kubelet `/stats/summary`
+    /// proxy, or the cAdvisor endpoints in `container_exporter_urls`.
+    pub container_metrics_source: ContainerMetricsSource,
+
+    /// Flow exporter endpoints exposing per source/destination IP byte
+    /// counters, used to split network cost by traffic locality.
+    pub network_flow_exporter_urls: Vec<String>,
 }
 
 impl Default for InfoSettingEntity {
@@ -98,17 +138,33 @@ impl Default for InfoSettingEntity {
             hour_retention_months: 12,
             day_retention_years: 30,
             retention_policy: "delete".into(),
+            archive_dir: env::var("RUSTCOST_ARCHIVE_DIR")
+                .ok()
+                .filter(|v| !v.trim().is_empty()),
 
             // --- Persistence ---
             enable_line_num_tracking: true,
             enable_index_file: true,
-            max_storage_gb: 5,
+            max_storage_gb: 0,
             compression_enabled: true,
 
+            // --- Backup ---
+            backup_dir: env::var("RUSTCOST_BACKUP_DIR")
+                .ok()
+                .filter(|v| !v.trim().is_empty()),
+            backup_interval_hours: 0,
+            backup_retention_count: 7,
+
             // --- Metrics ---
             scrape_interval_sec: 60,
             metrics_batch_size: 500,
 
+            // --- Cost ---
+            spot_node_label_selector: None,
+            price_catalog_path: env::var("RUSTCOST_PRICE_CATALOG_PATH")
+                .ok()
+                .filter(|v| !v.trim().is_empty()),
+
             // --- LLM ---
             llm_url: None,
             llm_token: None,
@@ -146,7 +202,19 @@ impl Default for InfoSettingEntity {
                 .map(|v| v.split(',').map(|s| s.trim().to_string()).collect())
                 .unwrap_or_else(Vec::new),
 
+            container_exporter_insecure_tls: env::var("RUSTCOST_CONTAINER_EXPORTER_INSECURE_TLS")
+                .map(|v| v == "true")
+                .unwrap_or(false),
+
             k8s_api_url: env::var("RUSTCOST_K8S_API_URL").ok(),
+
+            container_metrics_source: ContainerMetricsSource::default(),
+
+            network_flow_exporter_urls: env::var("RUSTCOST_NETWORK_FLOW_EXPORTER_URLS")
+                .ok()
+                .filter(|v| !v.trim().is_empty())
+                .map(|v| v.split(',').map(|s| s.trim().to_string()).collect())
+                .unwrap_or_default(),
         }
     }
 }
@@ -185,6 +253,12 @@ impl InfoSettingEntity {
         if let Some(v) = req.compression_enabled {
             self.compression_enabled = v;
         }
+        if let Some(v) = req.backup_interval_hours {
+            self.backup_interval_hours = v;
+        }
+        if let Some(v) = req.backup_retention_count {
+            self.backup_retention_count = v;
+        }
 
         // === Metrics ===
         if let Some(v) = req.scrape_interval_sec {
@@ -208,6 +282,18 @@ impl InfoSettingEntity {
         if let Some(v) = normalize_string_opt(req.k8s_api_url) {
             self.k8s_api_url = v;
         }
+        if let Some(v) = normalize_string_opt(req.spot_node_label_selector) {
+            self.spot_node_label_selector = v;
+        }
+        if let Some(v) = normalize_string_opt(req.price_catalog_path) {
+            self.price_catalog_path = v;
+        }
+        if let Some(v) = normalize_string_opt(req.archive_dir) {
+            self.archive_dir = v;
+        }
+        if let Some(v) = normalize_string_opt(req.backup_dir) {
+            self.backup_dir = v;
+        }
 
         // === Runtime ===
         if let Some(v) = req.runtime_type {
@@ -233,6 +319,15 @@ impl InfoSettingEntity {
         if let Some(v) = req.container_exporter_urls {
             self.container_exporter_urls = v;
         }
+        if let Some(v) = req.container_exporter_insecure_tls {
+            self.container_exporter_insecure_tls = v;
+        }
+        if let Some(v) = req.container_metrics_source {
+            self.container_metrics_source = ContainerMetricsSource::parse(&v);
+        }
+        if let Some(v) = req.network_flow_exporter_urls {
+            self.network_flow_exporter_urls = v;
+        }
 
         // === Update timestamp ===
         self.updated_at = Utc::now();
@@ -273,3 +368,33 @@ impl Default for RuntimeType {
         }
     }
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ContainerMetricsSource {
+    #[serde(rename = "kubelet")]
+    Kubelet,
+    #[serde(rename = "cadvisor")]
+    Cadvisor,
+}
+
+impl ContainerMetricsSource {
+    pub fn parse(v: &str) -> Self {
+        match v.trim().to_lowercase().as_str() {
+            "cadvisor" => ContainerMetricsSource::Cadvisor,
+            _ => ContainerMetricsSource::Kubelet,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ContainerMetricsSource::Kubelet => "kubelet",
+            ContainerMetricsSource::Cadvisor => "cadvisor",
+        }
+    }
+}
+
+impl Default for ContainerMetricsSource {
+    fn default() -> Self {
+        Self::parse(&env::var("RUSTCOST_CONTAINER_METRICS_SOURCE").unwrap_or_default())
+    }
+}
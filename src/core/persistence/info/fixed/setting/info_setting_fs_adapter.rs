@@ -1,12 +1,9 @@
-use super::info_setting_entity::{InfoSettingEntity, RuntimeType};
+use super::info_setting_entity::{ContainerMetricsSource, InfoSettingEntity, RuntimeType};
 use crate::core::persistence::info::fixed::info_fixed_fs_adapter_trait::InfoFixedFsAdapterTrait;
 use crate::core::persistence::storage_path::info_setting_path;
+use crate::core::persistence::storage_backend::storage_backend;
 use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
-use std::{
-    fs::{self, File},
-    io::{BufRead, BufReader},
-};
 
 /// File-based FS_ADAPTER implementation for the `Settings` entity.
 ///
@@ -28,16 +25,12 @@ impl InfoFixedFsAdapterTrait<InfoSettingEntity> for InfoSettingFsAdapter {
     fn read(&self) -> Result<InfoSettingEntity> {
         let path = info_setting_path();
 
-        if !path.exists() {
+        let Some(text) = storage_backend().read_record(&path)? else {
             return Ok(InfoSettingEntity::default());
-        }
-
-        let file = File::open(&path).context("Failed to open settings file")?;
-        let reader = BufReader::new(file);
+        };
         let mut s = InfoSettingEntity::default();
 
-        for line in reader.lines() {
-            let line = line?;
+        for line in text.lines() {
             if let Some((key, val)) = line.split_once(':') {
                 let key = key.trim().to_uppercase();
                 let val = val.trim();
@@ -57,6 +50,13 @@ impl InfoFixedFsAdapterTrait<InfoSettingEntity> for InfoSettingFsAdapter {
                         s.day_retention_years = val.parse().unwrap_or(s.day_retention_years)
                     }
                     "RETENTION_POLICY" => s.retention_policy = val.to_string(),
+                    "ARCHIVE_DIR" => {
+                        s.archive_dir = if val.is_empty() {
+                            None
+                        } else {
+                            Some(val.to_string())
+                        }
+                    }
 
                     // === TSDB Options ===
                     "ENABLE_LINE_NUM_TRACKING" => {
@@ -68,6 +68,21 @@ impl InfoFixedFsAdapterTrait<InfoSettingEntity> for InfoSettingFsAdapter {
                         s.compression_enabled = val.eq_ignore_ascii_case("true")
                     }
 
+                    // === Backup ===
+                    "BACKUP_DIR" => {
+                        s.backup_dir = if val.is_empty() {
+                            None
+                        } else {
+                            Some(val.to_string())
+                        }
+                    }
+                    "BACKUP_INTERVAL_HOURS" => {
+                        s.backup_interval_hours = val.parse().unwrap_or(s.backup_interval_hours)
+                    }
+                    "BACKUP_RETENTION_COUNT" => {
+                        s.backup_retention_count = val.parse().unwrap_or(s.backup_retention_count)
+                    }
+
                     // === Metrics ===
                     "SCRAPE_INTERVAL_SEC" => {
                         s.scrape_interval_sec = val.parse().unwrap_or(s.scrape_interval_sec)
@@ -76,6 +91,22 @@ impl InfoFixedFsAdapterTrait<InfoSettingEntity> for InfoSettingFsAdapter {
                         s.metrics_batch_size = val.parse().unwrap_or(s.metrics_batch_size)
                     }
 
+                    // === Cost ===
+                    "SPOT_NODE_LABEL_SELECTOR" => {
+                        s.spot_node_label_selector = if val.is_empty() {
+                            None
+                        } else {
+                            Some(val.to_string())
+                        }
+                    }
+                    "PRICE_CATALOG_PATH" => {
+                        s.price_catalog_path = if val.is_empty() {
+                            None
+                        } else {
+                            Some(val.to_string())
+                        }
+                    }
+
                     // === LLM ===
                     "LLM_URL" => {
                         s.llm_url = if val.is_empty() {
@@ -138,6 +169,19 @@ impl InfoFixedFsAdapterTrait<InfoSettingEntity> for InfoSettingFsAdapter {
                             .filter(|v| !v.is_empty())
                             .collect();
                     }
+                    "NETWORK_FLOW_EXPORTER_URLS" => {
+                        s.network_flow_exporter_urls = val
+                            .split(',')
+                            .map(|v| v.trim().to_string())
+                            .filter(|v| !v.is_empty())
+                            .collect();
+                    }
+                    "CONTAINER_EXPORTER_INSECURE_TLS" => {
+                        s.container_exporter_insecure_tls = val == "true";
+                    }
+                    "CONTAINER_METRICS_SOURCE" => {
+                        s.container_metrics_source = ContainerMetricsSource::parse(val);
+                    }
                     "K8S_API_URL" => {
                         s.k8s_api_url = if val.trim().is_empty() {
                             None
@@ -164,9 +208,7 @@ impl InfoFixedFsAdapterTrait<InfoSettingEntity> for InfoSettingFsAdapter {
     fn delete(&self) -> Result<()> {
         let path = info_setting_path();
 
-        if path.exists() {
-            fs::remove_file(&path).context("Failed to delete settings file")?;
-        }
+        storage_backend().delete_record(&path).context("Failed to delete settings file")?;
         Ok(())
     }
 }
@@ -174,20 +216,11 @@ impl InfoFixedFsAdapterTrait<InfoSettingEntity> for InfoSettingFsAdapter {
 impl InfoSettingFsAdapter {
     /// Internal helper to atomically write the settings file.
     fn write(&self, data: &InfoSettingEntity) -> Result<()> {
-        use std::fs::File;
-        use std::io::Write;
+        use std::fmt::Write;
 
         let path = info_setting_path();
 
-        // Ensure parent directory exists
-        if let Some(dir) = path.parent() {
-            fs::create_dir_all(dir).context("Failed to create settings directory")?;
-        }
-
-        // We write to a temporary file first, then atomically rename.
-        // e.g. settings.rci.tmp
-        let tmp_path = path.with_extension("rci.tmp");
-        let mut f = File::create(&tmp_path).context("Failed to create temp settings file")?;
+        let mut f = String::new();
 
         // Write all fields
         writeln!(f, "IS_DARK_MODE:{}", data.is_dark_mode)?;
@@ -196,6 +229,7 @@ impl InfoSettingFsAdapter {
         writeln!(f, "HOUR_RETENTION_MONTH:{}", data.hour_retention_months)?;
         writeln!(f, "DAY_RETENTION_YEAR:{}", data.day_retention_years)?;
         writeln!(f, "RETENTION_POLICY:{}", data.retention_policy)?;
+        writeln!(f, "ARCHIVE_DIR:{}", data.archive_dir.clone().unwrap_or_default())?;
         writeln!(
             f,
             "ENABLE_LINE_NUM_TRACKING:{}",
@@ -204,8 +238,21 @@ impl InfoSettingFsAdapter {
         writeln!(f, "ENABLE_INDEX_FILE:{}", data.enable_index_file)?;
         writeln!(f, "MAX_STORAGE_GB:{}", data.max_storage_gb)?;
         writeln!(f, "COMPRESSION_ENABLED:{}", data.compression_enabled)?;
+        writeln!(f, "BACKUP_DIR:{}", data.backup_dir.clone().unwrap_or_default())?;
+        writeln!(f, "BACKUP_INTERVAL_HOURS:{}", data.backup_interval_hours)?;
+        writeln!(f, "BACKUP_RETENTION_COUNT:{}", data.backup_retention_count)?;
         writeln!(f, "SCRAPE_INTERVAL_SEC:{}", data.scrape_interval_sec)?;
         writeln!(f, "METRICS_BATCH_SIZE:{}", data.metrics_batch_size)?;
+        writeln!(
+            f,
+            "SPOT_NODE_LABEL_SELECTOR:{}",
+            data.spot_node_label_selector.clone().unwrap_or_default()
+        )?;
+        writeln!(
+            f,
+            "PRICE_CATALOG_PATH:{}",
+            data.price_catalog_path.clone().unwrap_or_default()
+        )?;
         writeln!(f, "LLM_URL:{}", data.llm_url.clone().unwrap_or_default())?;
         writeln!(
             f,
@@ -234,27 +281,28 @@ impl InfoSettingFsAdapter {
             "CONTAINER_EXPORTER_URLS:{}",
             data.container_exporter_urls.join(", ")
         )?;
+        writeln!(
+            f,
+            "CONTAINER_EXPORTER_INSECURE_TLS:{}",
+            data.container_exporter_insecure_tls
+        )?;
+        writeln!(
+            f,
+            "CONTAINER_METRICS_SOURCE:{}",
+            data.container_metrics_source.as_str()
+        )?;
+        writeln!(
+            f,
+            "NETWORK_FLOW_EXPORTER_URLS:{}",
+            data.network_flow_exporter_urls.join(", ")
+        )?;
         writeln!(
             f,
             "K8S_API_URL:{}",
             data.k8s_api_url.clone().unwrap_or_default()
         )?;
 
-        // Make sure all data hits the disk
-        f.flush()?;
-        f.sync_all().context("Failed to sync temp settings file")?;
-
-        // Atomically replace the old file with the new one
-        fs::rename(&tmp_path, &path).context("Failed to finalize settings file")?;
-
-        // On Unix, also fsync the directory so the rename is durable
-        #[cfg(unix)]
-        if let Some(dir) = path.parent() {
-            let dir_file = File::open(dir).context("Failed to open settings directory")?;
-            dir_file
-                .sync_all()
-                .context("Failed to sync settings directory")?;
-        }
+        storage_backend().write_record(&path, &f)?;
 
         Ok(())
     }
@@ -0,0 +1,15 @@
+use crate::core::persistence::info::fixed::info_fixed_fs_adapter_trait::InfoFixedFsAdapterTrait;
+use super::info_storage_class_price_entity::InfoStorageClassPriceEntity;
+
+/// API-facing repository abstraction for StorageClass prices.
+pub trait InfoStorageClassPriceApiRepository {
+    fn fs_adapter(&self) -> &dyn InfoFixedFsAdapterTrait<InfoStorageClassPriceEntity>;
+
+    fn read(&self) -> anyhow::Result<InfoStorageClassPriceEntity> {
+        self.fs_adapter().read()
+    }
+
+    fn update(&self, settings: &InfoStorageClassPriceEntity) -> anyhow::Result<()> {
+        self.fs_adapter().update(settings)
+    }
+}
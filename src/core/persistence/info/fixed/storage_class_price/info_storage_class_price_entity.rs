@@ -0,0 +1,102 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::core::persistence::info::fixed::unit_price::info_unit_price_entity::Currency;
+use crate::domain::info::dto::info_storage_class_price_upsert_request::InfoStorageClassPriceUpsertRequest;
+
+/// Price of one StorageClass, overriding the flat `storage_gb_hour` unit price
+/// for persistent volumes provisioned from it.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct StorageClassPriceEntity {
+    /// StorageClass name, e.g. `gp3`.
+    pub storage_class: String,
+    /// Price per GB-hour of provisioned capacity, in the configured currency.
+    #[serde(alias = "gb_hour_usd")]
+    pub gb_hour: f64,
+}
+
+/// A replaced set of StorageClass prices and the time it stopped applying.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct StorageClassPriceVersion {
+    pub prices: Vec<StorageClassPriceEntity>,
+    pub currency: Currency,
+    /// First instant this version no longer applies.
+    pub effective_until: DateTime<Utc>,
+}
+
+/// Per-StorageClass prices for persistent volume capacity.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InfoStorageClassPriceEntity {
+    pub prices: Vec<StorageClassPriceEntity>,
+    /// Currency `prices` are expressed in.
+    #[serde(default)]
+    pub currency: Currency,
+    /// Earlier price sets, oldest first, so past windows keep their prices.
+    #[serde(default)]
+    pub history: Vec<StorageClassPriceVersion>,
+    /// Configuration creation timestamp (UTC).
+    pub created_at: DateTime<Utc>,
+    /// Last update timestamp (UTC).
+    pub updated_at: DateTime<Utc>,
+    /// Version identifier for the configuration format.
+    pub version: String,
+}
+
+impl Default for InfoStorageClassPriceEntity {
+    fn default() -> Self {
+        let now = Utc::now();
+        Self {
+            prices: Vec::new(),
+            currency: Currency::USD,
+            history: Vec::new(),
+            created_at: now,
+            updated_at: now,
+            version: "1.0.0".into(),
+        }
+    }
+}
+
+impl InfoStorageClassPriceEntity {
+    /// Replaces the price list from now on. The previous list is kept in the
+    /// history unless nothing changed.
+    pub fn apply_update(&mut self, req: InfoStorageClassPriceUpsertRequest) {
+        let now = Utc::now();
+        let prices: Vec<StorageClassPriceEntity> = req
+            .prices
+            .into_iter()
+            .map(|p| StorageClassPriceEntity {
+                storage_class: p.storage_class.trim().to_string(),
+                gb_hour: p.gb_hour,
+            })
+            .collect();
+        let currency = req.currency.unwrap_or(self.currency);
+
+        if prices != self.prices || currency != self.currency {
+            self.history.push(StorageClassPriceVersion {
+                prices: std::mem::replace(&mut self.prices, prices),
+                currency: std::mem::replace(&mut self.currency, currency),
+                effective_until: now,
+            });
+        }
+        self.updated_at = now;
+    }
+
+    /// Times at which the price list changed, oldest first.
+    pub fn price_changes(&self) -> Vec<DateTime<Utc>> {
+        self.history.iter().map(|v| v.effective_until).collect()
+    }
+
+    /// Price per GB-hour configured for `storage_class` at `time`, with its
+    /// currency.
+    pub fn gb_hour_at(&self, time: DateTime<Utc>, storage_class: Option<&str>) -> Option<(f64, Currency)> {
+        let storage_class = storage_class?;
+        let (prices, currency) = match self.history.iter().find(|v| time < v.effective_until) {
+            Some(version) => (&version.prices, version.currency),
+            None => (&self.prices, self.currency),
+        };
+        prices
+            .iter()
+            .find(|p| p.storage_class == storage_class)
+            .map(|p| (p.gb_hour, currency))
+    }
+}
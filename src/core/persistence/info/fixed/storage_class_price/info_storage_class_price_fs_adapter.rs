@@ -0,0 +1,169 @@
+use std::collections::HashMap;
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+
+use crate::core::persistence::info::fixed::info_fixed_fs_adapter_trait::InfoFixedFsAdapterTrait;
+use crate::core::persistence::storage_backend::storage_backend;
+use crate::core::persistence::storage_path::info_storage_class_price_path;
+
+use crate::core::persistence::info::fixed::unit_price::info_unit_price_entity::Currency;
+
+use super::info_storage_class_price_entity::{
+    InfoStorageClassPriceEntity, StorageClassPriceEntity, StorageClassPriceVersion,
+};
+
+/// FS adapter for persisted StorageClass prices.
+///
+/// Reads and writes a simple key-value file located at `storage_class_prices.rci`.
+/// Earlier price sets are stored as `HISTORY_{i}_*` keys, oldest first.
+pub struct InfoStorageClassPriceFsAdapter;
+
+impl InfoFixedFsAdapterTrait<InfoStorageClassPriceEntity> for InfoStorageClassPriceFsAdapter {
+    fn new() -> Self {
+        Self {}
+    }
+
+    fn read(&self) -> Result<InfoStorageClassPriceEntity> {
+        let path = info_storage_class_price_path();
+        let Some(text) = storage_backend().read_record(&path)? else {
+            return Ok(InfoStorageClassPriceEntity::default());
+        };
+
+        let mut s = InfoStorageClassPriceEntity::default();
+        let mut raw_prices: HashMap<String, String> = HashMap::new();
+
+        for line in text.lines() {
+            if let Some((key, val)) = line.split_once(':') {
+                let key = key.trim().to_uppercase();
+                let val = val.trim();
+
+                if key.starts_with("STORAGE_CLASS_") || key.starts_with("HISTORY_") {
+                    raw_prices.insert(key.clone(), val.to_string());
+                }
+
+                match key.as_str() {
+                    "CREATED_AT" => {
+                        if let Ok(dt) = val.parse::<DateTime<Utc>>() {
+                            s.created_at = dt;
+                        }
+                    }
+                    "UPDATED_AT" => {
+                        if let Ok(dt) = val.parse::<DateTime<Utc>>() {
+                            s.updated_at = dt;
+                        }
+                    }
+                    "VERSION" => s.version = val.to_string(),
+                    "CURRENCY" => s.currency = Currency::parse(val).unwrap_or_default(),
+                    _ => {}
+                }
+            }
+        }
+
+        s.prices = Self::parse_prices(&raw_prices, "");
+        s.history = Self::parse_history(&raw_prices);
+        Ok(s)
+    }
+
+    fn insert(&self, data: &InfoStorageClassPriceEntity) -> Result<()> {
+        self.write(data)
+    }
+
+    fn update(&self, data: &InfoStorageClassPriceEntity) -> Result<()> {
+        self.write(data)
+    }
+
+    fn delete(&self) -> Result<()> {
+        let path = info_storage_class_price_path();
+        storage_backend()
+            .delete_record(&path)
+            .context("Failed to delete storage class prices file")?;
+        Ok(())
+    }
+}
+
+impl InfoStorageClassPriceFsAdapter {
+    fn write(&self, data: &InfoStorageClassPriceEntity) -> Result<()> {
+        use std::fmt::Write;
+
+        let path = info_storage_class_price_path();
+        let mut f = String::new();
+
+        writeln!(f, "CURRENCY:{}", data.currency.code())?;
+        Self::write_prices(&mut f, "", &data.prices)?;
+
+        writeln!(f, "HISTORY_COUNT:{}", data.history.len())?;
+        for (idx, version) in data.history.iter().enumerate() {
+            writeln!(f, "HISTORY_{}_CURRENCY:{}", idx, version.currency.code())?;
+            writeln!(f, "HISTORY_{}_EFFECTIVE_UNTIL:{}", idx, version.effective_until.to_rfc3339())?;
+            Self::write_prices(&mut f, &format!("HISTORY_{}_", idx), &version.prices)?;
+        }
+
+        writeln!(f, "CREATED_AT:{}", data.created_at.to_rfc3339())?;
+        writeln!(f, "UPDATED_AT:{}", data.updated_at.to_rfc3339())?;
+        writeln!(f, "VERSION:{}", data.version)?;
+
+        storage_backend().write_record(&path, &f)?;
+
+        Ok(())
+    }
+
+    fn write_prices(f: &mut String, prefix: &str, prices: &[StorageClassPriceEntity]) -> Result<()> {
+        use std::fmt::Write;
+
+        writeln!(f, "{}STORAGE_CLASS_COUNT:{}", prefix, prices.len())?;
+        for (idx, price) in prices.iter().enumerate() {
+            writeln!(f, "{}STORAGE_CLASS_{}_NAME:{}", prefix, idx, price.storage_class)?;
+            writeln!(f, "{}STORAGE_CLASS_{}_GB_HOUR:{}", prefix, idx, price.gb_hour)?;
+        }
+        Ok(())
+    }
+
+    fn parse_prices(raw: &HashMap<String, String>, prefix: &str) -> Vec<StorageClassPriceEntity> {
+        let count = raw
+            .get(&format!("{}STORAGE_CLASS_COUNT", prefix))
+            .and_then(|v| v.parse::<usize>().ok())
+            .unwrap_or(0);
+
+        (0..count)
+            .filter_map(|idx| {
+                let storage_class = raw
+                    .get(&format!("{}STORAGE_CLASS_{}_NAME", prefix, idx))
+                    .filter(|v| !v.is_empty())?;
+                // Files written before prices had a currency use `_GB_HOUR_USD`
+                let gb_hour = raw
+                    .get(&format!("{}STORAGE_CLASS_{}_GB_HOUR", prefix, idx))
+                    .or_else(|| raw.get(&format!("{}STORAGE_CLASS_{}_GB_HOUR_USD", prefix, idx)))?
+                    .parse::<f64>()
+                    .ok()?;
+                Some(StorageClassPriceEntity { storage_class: storage_class.clone(), gb_hour })
+            })
+            .collect()
+    }
+
+    fn parse_history(raw: &HashMap<String, String>) -> Vec<StorageClassPriceVersion> {
+        let count = raw
+            .get("HISTORY_COUNT")
+            .and_then(|v| v.parse::<usize>().ok())
+            .unwrap_or(0);
+
+        (0..count)
+            .filter_map(|idx| {
+                let prefix = format!("HISTORY_{}_", idx);
+                let effective_until = raw
+                    .get(&format!("{}EFFECTIVE_UNTIL", prefix))?
+                    .parse::<DateTime<Utc>>()
+                    .ok()?;
+                let currency = raw
+                    .get(&format!("{}CURRENCY", prefix))
+                    .and_then(|v| Currency::parse(v))
+                    .unwrap_or_default();
+                Some(StorageClassPriceVersion {
+                    prices: Self::parse_prices(raw, &prefix),
+                    currency,
+                    effective_until,
+                })
+            })
+            .collect()
+    }
+}
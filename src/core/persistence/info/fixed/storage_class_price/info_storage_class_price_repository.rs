@@ -0,0 +1,29 @@
+use crate::core::persistence::info::fixed::info_fixed_fs_adapter_trait::InfoFixedFsAdapterTrait;
+
+use super::info_storage_class_price_api_repository_trait::InfoStorageClassPriceApiRepository;
+use super::info_storage_class_price_entity::InfoStorageClassPriceEntity;
+use super::info_storage_class_price_fs_adapter::InfoStorageClassPriceFsAdapter;
+
+pub struct InfoStorageClassPriceRepository {
+    adapter: InfoStorageClassPriceFsAdapter,
+}
+
+impl InfoStorageClassPriceRepository {
+    pub fn new() -> Self {
+        Self {
+            adapter: InfoStorageClassPriceFsAdapter::new(),
+        }
+    }
+}
+
+impl Default for InfoStorageClassPriceRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InfoStorageClassPriceApiRepository for InfoStorageClassPriceRepository {
+    fn fs_adapter(&self) -> &dyn InfoFixedFsAdapterTrait<InfoStorageClassPriceEntity> {
+        &self.adapter
+    }
+}
@@ -0,0 +1,4 @@
+pub mod info_storage_class_price_entity;
+pub mod info_storage_class_price_fs_adapter;
+pub mod info_storage_class_price_api_repository_trait;
+pub mod info_storage_class_price_repository;
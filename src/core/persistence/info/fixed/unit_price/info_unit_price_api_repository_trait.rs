@@ -1,6 +1,8 @@
 use super::info_unit_price_entity::InfoUnitPriceEntity;
+use super::info_unit_price_history_fs_adapter::InfoUnitPriceHistoryFsAdapter;
 use crate::core::persistence::info::fixed::info_fixed_fs_adapter_trait::InfoFixedFsAdapterTrait;
 use anyhow::Result;
+use chrono::{DateTime, Utc};
 
 /// API repository trait for unitPrices.
 /// API can read and update, but usually not create/delete.
@@ -14,4 +16,19 @@ pub trait InfoUnitPriceApiRepository: Send + Sync {
     fn update(&self, data: &InfoUnitPriceEntity) -> Result<()> {
         self.fs_adapter().update(data)
     }
+
+    fn history_adapter(&self) -> &InfoUnitPriceHistoryFsAdapter;
+
+    /// Effective-dated price versions, oldest first.
+    fn list_history(&self) -> Result<Vec<InfoUnitPriceEntity>> {
+        self.history_adapter().list()
+    }
+
+    fn insert_history(&self, data: &InfoUnitPriceEntity) -> Result<()> {
+        self.history_adapter().insert(data)
+    }
+
+    fn delete_history(&self, effective_from: DateTime<Utc>) -> Result<()> {
+        self.history_adapter().delete(effective_from)
+    }
 }
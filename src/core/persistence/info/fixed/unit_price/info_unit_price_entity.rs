@@ -1,5 +1,8 @@
+use anyhow::Result;
 use chrono::{DateTime, Utc};
 use serde::{Serialize, Deserialize};
+use crate::core::persistence::info::fixed::exchange_rate::info_exchange_rate_entity::InfoExchangeRateEntity;
+use crate::core::persistence::info::k8s::node::info_node_entity::NodeCapacityType;
 use crate::domain::info::dto::info_unit_price_upsert_request::InfoUnitPriceUpsertRequest;
 
 /// Represents per-unit pricing configuration for system resource usage.
@@ -12,9 +15,39 @@ use crate::domain::info::dto::info_unit_price_upsert_request::InfoUnitPriceUpser
 /// Combine this configuration with resource metrics (e.g., [`MetricNodeEntity`])
 /// to estimate the operational cost of a node, pod, or container.
 ///
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum Currency {
+    #[default]
     USD,
+    EUR,
+    KRW,
+    JPY,
+    GBP,
+}
+
+impl Currency {
+    /// ISO 4217 code.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Currency::USD => "USD",
+            Currency::EUR => "EUR",
+            Currency::KRW => "KRW",
+            Currency::JPY => "JPY",
+            Currency::GBP => "GBP",
+        }
+    }
+
+    /// Parses an ISO 4217 code (case-insensitive).
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_uppercase().as_str() {
+            "USD" => Some(Currency::USD),
+            "EUR" => Some(Currency::EUR),
+            "KRW" => Some(Currency::KRW),
+            "JPY" => Some(Currency::JPY),
+            "GBP" => Some(Currency::GBP),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -49,9 +82,34 @@ pub struct InfoUnitPriceEntity {
     /// Price per GB transferred to external networks (internet egress)
     pub network_external_gb: f64,
 
-    // = always USD
+    // --- Service endpoints ---
+    /// Price per hour of a `type: LoadBalancer` service
+    pub load_balancer_hour: f64,
+    /// Price per hour of a `type: NodePort` service
+    pub node_port_hour: f64,
+    /// Price per hour of an Ingress. Leave at zero when ingresses share a
+    /// controller that is already billed as a LoadBalancer service.
+    pub ingress_hour: f64,
+
+    /// Currency all prices above are expressed in.
     pub currency: Currency,
 
+    /// Units of `currency` per 1 USD, resolved from the exchange-rate table when
+    /// prices are loaded. `None` when no rate is configured for `currency`.
+    /// See [`Self::from_usd`].
+    #[serde(skip)]
+    pub usd_exchange_rate: Option<f64>,
+
+    /// Time this price version takes effect. Only set on price history entries.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub effective_from: Option<DateTime<Utc>>,
+
+    /// Effective-dated price versions, oldest first, resolved when prices are
+    /// loaded. Empty when no history exists, in which case these prices apply
+    /// at every point in time. See [`Self::at`].
+    #[serde(skip)]
+    pub history: Vec<InfoUnitPriceEntity>,
+
     /// Last update timestamp (UTC).
     pub updated_at: DateTime<Utc>,
 }
@@ -68,8 +126,159 @@ impl InfoUnitPriceEntity {
         if let Some(v) = req.network_local_gb { self.network_local_gb = v; }
         if let Some(v) = req.network_regional_gb { self.network_regional_gb = v; }
         if let Some(v) = req.network_external_gb { self.network_external_gb = v; }
+        if let Some(v) = req.load_balancer_hour { self.load_balancer_hour = v; }
+        if let Some(v) = req.node_port_hour { self.node_port_hour = v; }
+        if let Some(v) = req.ingress_hour { self.ingress_hour = v; }
+        if let Some(v) = req.currency { self.currency = v; }
         self.updated_at = Utc::now();
     }
+
+    /// Converts a USD amount (fixed node prices, StorageClass prices) into
+    /// `currency`. `None` when `currency` is not USD and no rate is configured.
+    pub fn from_usd(&self, usd: f64) -> Option<f64> {
+        match self.currency {
+            Currency::USD => Some(usd),
+            _ => self.usd_exchange_rate.map(|rate| usd * rate),
+        }
+    }
+
+    /// Copy of these prices expressed in `target`, using the exchange-rate table.
+    pub fn converted(&self, target: Currency, rates: &InfoExchangeRateEntity) -> Result<Self> {
+        let factor = rates.factor(self.currency, target)?;
+        let history = self
+            .history
+            .iter()
+            .map(|version| version.converted(target, rates))
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            cpu_core_hour: self.cpu_core_hour * factor,
+            cpu_spot_core_hour: self.cpu_spot_core_hour * factor,
+            memory_gb_hour: self.memory_gb_hour * factor,
+            memory_spot_gb_hour: self.memory_spot_gb_hour * factor,
+            gpu_hour: self.gpu_hour * factor,
+            gpu_spot_hour: self.gpu_spot_hour * factor,
+            storage_gb_hour: self.storage_gb_hour * factor,
+            network_local_gb: self.network_local_gb * factor,
+            network_regional_gb: self.network_regional_gb * factor,
+            network_external_gb: self.network_external_gb * factor,
+            load_balancer_hour: self.load_balancer_hour * factor,
+            node_port_hour: self.node_port_hour * factor,
+            ingress_hour: self.ingress_hour * factor,
+            currency: target,
+            usd_exchange_rate: rates.rate(target).or(self.usd_exchange_rate.map(|r| r * factor)),
+            effective_from: self.effective_from,
+            history,
+            updated_at: self.updated_at,
+        })
+    }
+
+    /// Copy of these prices with the spot rates in place of the on-demand ones.
+    pub fn spot_prices(&self) -> Self {
+        Self {
+            cpu_core_hour: self.cpu_spot_core_hour,
+            memory_gb_hour: self.memory_spot_gb_hour,
+            gpu_hour: self.gpu_spot_hour,
+            ..self.clone()
+        }
+    }
+
+    /// Prices that apply to a node of the given capacity type.
+    pub fn for_capacity_type(&self, capacity_type: Option<NodeCapacityType>) -> Self {
+        self.map_versions(|prices| match capacity_type {
+            Some(NodeCapacityType::Spot) => prices.spot_prices(),
+            _ => prices.clone(),
+        })
+    }
+
+    /// Applies `f` to these prices and to every version in the history, so
+    /// derived rates (spot, fixed node price, ...) stay effective-dated.
+    pub fn map_versions(&self, f: impl Fn(&Self) -> Self) -> Self {
+        let mut out = f(self);
+        out.history = self.history.iter().map(&f).collect();
+        out
+    }
+
+    /// Like [`Self::map_versions`], but also starts a new version at every
+    /// time in `changes`, for derived rates that change on their own schedule
+    /// (e.g. fixed node prices). `f` receives the prices and the time the
+    /// version starts: `MIN_UTC` for the oldest version, `MAX_UTC` for the
+    /// current one.
+    pub fn map_versions_at(
+        &self,
+        changes: &[DateTime<Utc>],
+        f: impl Fn(&Self, DateTime<Utc>) -> Self,
+    ) -> Self {
+        let mut out = f(self, DateTime::<Utc>::MAX_UTC);
+        if changes.is_empty() {
+            out.history = self
+                .history
+                .iter()
+                .map(|v| f(v, v.effective_from.unwrap_or(DateTime::<Utc>::MIN_UTC)))
+                .collect();
+            return out;
+        }
+
+        let mut starts: Vec<DateTime<Utc>> = self
+            .history
+            .iter()
+            .filter_map(|v| v.effective_from)
+            .chain(changes.iter().copied())
+            .collect();
+        starts.sort();
+        starts.dedup();
+
+        let oldest = self.history.first().unwrap_or(self);
+        out.history = std::iter::once(Self {
+            effective_from: None,
+            ..f(oldest, DateTime::<Utc>::MIN_UTC)
+        })
+        .chain(starts.into_iter().map(|t| Self {
+            effective_from: Some(t),
+            ..f(self.at(t), t)
+        }))
+        .collect();
+        out
+    }
+
+    /// Prices in force at `time`.
+    ///
+    /// Points older than the first recorded version use that version.
+    pub fn at(&self, time: DateTime<Utc>) -> &Self {
+        self.history
+            .iter()
+            .rev()
+            .find(|version| version.effective_from.is_none_or(|from| from <= time))
+            .or_else(|| self.history.first())
+            .unwrap_or(self)
+    }
+
+    /// Price versions in force during `[start, end)`, each with the fraction of
+    /// the window it covers. Used where costs are computed over a whole window.
+    pub fn periods(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> Vec<(&Self, f64)> {
+        let total_seconds = (end - start).num_seconds();
+        if self.history.is_empty() || total_seconds <= 0 {
+            return vec![(self.at(start), 1.0)];
+        }
+
+        let mut periods = Vec::new();
+        for (idx, version) in self.history.iter().enumerate() {
+            let from = match (idx, version.effective_from) {
+                (0, _) | (_, None) => start,
+                (_, Some(from)) => from.max(start),
+            };
+            let to = self
+                .history
+                .get(idx + 1)
+                .and_then(|next| next.effective_from)
+                .map_or(end, |next| next.min(end));
+
+            if to > from {
+                periods.push((version, (to - from).num_seconds() as f64 / total_seconds as f64));
+            }
+        }
+        periods
+    }
 }
 
 impl Default for InfoUnitPriceEntity {
@@ -86,8 +295,15 @@ impl Default for InfoUnitPriceEntity {
             network_local_gb: 0.01,
             network_regional_gb: 0.01,
             network_external_gb: 0.12,
+            load_balancer_hour: 0.025,
+            node_port_hour: 0.0,
+            ingress_hour: 0.0,
             currency: Currency::USD,
+            usd_exchange_rate: None,
+            effective_from: None,
+            history: Vec::new(),
             updated_at: now,
         }
     }
-}
\ No newline at end of file
+}
+
@@ -1,12 +1,10 @@
 use super::info_unit_price_entity::{Currency, InfoUnitPriceEntity};
 use crate::core::persistence::info::fixed::info_fixed_fs_adapter_trait::InfoFixedFsAdapterTrait;
 use crate::core::persistence::storage_path::info_unit_price_path;
+use crate::core::persistence::storage_backend::storage_backend;
 use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
-use std::{
-    fs::{self, File},
-    io::{BufRead, BufReader},
-};
+use std::path::Path;
 
 /// File-based adapter for reading and writing [`InfoUnitPriceEntity`] data.
 ///
@@ -25,16 +23,39 @@ impl InfoFixedFsAdapterTrait<InfoUnitPriceEntity> for InfoUnitPriceFsAdapter {
     fn read(&self) -> Result<InfoUnitPriceEntity> {
         let path = info_unit_price_path();
 
-        if !path.exists() {
+        if !storage_backend().record_exists(&path)? {
             return Ok(InfoUnitPriceEntity::default());
         }
 
-        let file = File::open(&path).context("Failed to open unit price file")?;
-        let reader = BufReader::new(file);
+        Self::read_from(&path)
+    }
+
+    fn insert(&self, data: &InfoUnitPriceEntity) -> Result<()> {
+        Self::write_to(&info_unit_price_path(), data)
+    }
+
+    fn update(&self, data: &InfoUnitPriceEntity) -> Result<()> {
+        Self::write_to(&info_unit_price_path(), data)
+    }
+
+    fn delete(&self) -> Result<()> {
+        let path = info_unit_price_path();
+
+        storage_backend().delete_record(&path).context("Failed to delete unit price file")?;
+
+        Ok(())
+    }
+}
+
+impl InfoUnitPriceFsAdapter {
+    /// Parses a unit price file. Shared with the price history adapter.
+    pub(super) fn read_from(path: &Path) -> Result<InfoUnitPriceEntity> {
+        let text = storage_backend()
+            .read_record(path)?
+            .context("Failed to open unit price file")?;
         let mut entity = InfoUnitPriceEntity::default();
 
-        for line in reader.lines() {
-            let line = line?;
+        for line in text.lines() {
             if let Some((key, val)) = line.split_once(':') {
                 let key = key.trim().to_lowercase(); // normalize key
                 let val = val.trim();
@@ -60,10 +81,20 @@ impl InfoFixedFsAdapterTrait<InfoUnitPriceEntity> for InfoUnitPriceFsAdapter {
                     "network_regional_gb" => entity.network_regional_gb = val.parse().unwrap_or_default(),
                     "network_external_gb" => entity.network_external_gb = val.parse().unwrap_or_default(),
 
+                    // Service endpoints
+                    "load_balancer_hour" => entity.load_balancer_hour = val.parse().unwrap_or_default(),
+                    "node_port_hour" => entity.node_port_hour = val.parse().unwrap_or_default(),
+                    "ingress_hour" => entity.ingress_hour = val.parse().unwrap_or_default(),
+
                     "currency" => {
-                        match val.to_uppercase().as_str() {
-                            "USD" => entity.currency = Currency::USD,
-                            _ => {} // ignore or fallback
+                        if let Some(currency) = Currency::parse(val) {
+                            entity.currency = currency;
+                        }
+                    }
+
+                    "effective_from" => {
+                        if let Ok(parsed) = DateTime::parse_from_rfc3339(val) {
+                            entity.effective_from = Some(parsed.with_timezone(&Utc));
                         }
                     }
 
@@ -82,44 +113,12 @@ impl InfoFixedFsAdapterTrait<InfoUnitPriceEntity> for InfoUnitPriceFsAdapter {
         Ok(entity)
     }
 
-    fn insert(&self, data: &InfoUnitPriceEntity) -> Result<()> {
-        self.write(data)
-    }
-
-    fn update(&self, data: &InfoUnitPriceEntity) -> Result<()> {
-        self.write(data)
-    }
-
-    fn delete(&self) -> Result<()> {
-        let path = info_unit_price_path();
-
-        if path.exists() {
-            fs::remove_file(&path).context("Failed to delete unit price file")?;
-        }
-
-        Ok(())
-    }
-}
-
-impl InfoUnitPriceFsAdapter {
-    /// Writes the unit price configuration to disk atomically.
+    /// Writes a unit price file atomically. Shared with the price history adapter.
     /// All keys are written in snake_case for consistency.
-    fn write(&self, data: &InfoUnitPriceEntity) -> Result<()> {
-        use std::io::Write;
-        let path = info_unit_price_path();
+    pub(super) fn write_to(path: &Path, data: &InfoUnitPriceEntity) -> Result<()> {
+        use std::fmt::Write;
 
-        // Ensure directory exists
-        if let Some(dir) = path.parent() {
-            fs::create_dir_all(dir)
-                .context("Failed to create unit price directory")?;
-        }
-
-        // Temporary file path, e.g. "unit_price.tmp"
-        let tmp_path = path.with_extension("tmp");
-
-        // Open temp file
-        let mut f = File::create(&tmp_path)
-            .context("Failed to create temporary unit price file")?;
+        let mut f = String::new();
 
         // --- Write all fields ---
         writeln!(f, "cpu_core_hour:{}", data.cpu_core_hour)?;
@@ -136,26 +135,17 @@ impl InfoUnitPriceFsAdapter {
         writeln!(f, "network_local_gb:{}", data.network_local_gb)?;
         writeln!(f, "network_regional_gb:{}", data.network_regional_gb)?;
         writeln!(f, "network_external_gb:{}", data.network_external_gb)?;
-        writeln!(f, "currency:{:?}", data.currency)?;
-        writeln!(f, "updated_at:{}", data.updated_at.to_rfc3339())?;
 
-        // --- Flush + sync to ensure data is fully written to disk ---
-        f.flush()?;
-        f.sync_all()
-            .context("Failed to sync temporary unit price file")?;
-
-        // --- Atomically replace old file ---
-        fs::rename(&tmp_path, &path)
-            .context("Failed to finalize unit price file atomically")?;
-
-        // --- On Unix: also fsync the directory to make the rename durable ---
-        #[cfg(unix)]
-        if let Some(dir) = path.parent() {
-            let dir_file = File::open(dir)
-                .context("Failed to open directory for fsync")?;
-            dir_file.sync_all()
-                .context("Failed to fsync unit price directory")?;
+        writeln!(f, "load_balancer_hour:{}", data.load_balancer_hour)?;
+        writeln!(f, "node_port_hour:{}", data.node_port_hour)?;
+        writeln!(f, "ingress_hour:{}", data.ingress_hour)?;
+        writeln!(f, "currency:{}", data.currency.code())?;
+        if let Some(effective_from) = data.effective_from {
+            writeln!(f, "effective_from:{}", effective_from.to_rfc3339())?;
         }
+        writeln!(f, "updated_at:{}", data.updated_at.to_rfc3339())?;
+
+        storage_backend().write_record(path, &f)?;
 
         Ok(())
     }
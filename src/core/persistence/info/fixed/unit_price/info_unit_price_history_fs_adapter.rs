@@ -0,0 +1,61 @@
+use super::info_unit_price_entity::InfoUnitPriceEntity;
+use super::info_unit_price_fs_adapter::InfoUnitPriceFsAdapter;
+use crate::core::persistence::storage_path::{
+    info_unit_price_history_dir_path, info_unit_price_history_file_path,
+};
+use crate::core::persistence::storage_backend::storage_backend;
+use anyhow::{anyhow, Context, Result};
+use chrono::{DateTime, Utc};
+
+/// File-based adapter for the effective-dated unit price history.
+///
+/// Each version is stored as its own `unit_price_history/<unix_ts>.rci` file,
+/// named after its `effective_from`, in the same format as `unit_price.rci`.
+pub struct InfoUnitPriceHistoryFsAdapter;
+
+impl InfoUnitPriceHistoryFsAdapter {
+    /// Lists every stored version, oldest first.
+    pub fn list(&self) -> Result<Vec<InfoUnitPriceEntity>> {
+        let dir = info_unit_price_history_dir_path();
+        let paths = storage_backend()
+            .list_records(&dir)
+            .context("Failed to read unit price history directory")?;
+
+        let mut versions = Vec::new();
+        for path in paths {
+            if path.extension().and_then(|e| e.to_str()) != Some("rci") {
+                continue;
+            }
+
+            let version = InfoUnitPriceFsAdapter::read_from(&path)?;
+            if version.effective_from.is_some() {
+                versions.push(version);
+            }
+        }
+
+        versions.sort_by_key(|v| v.effective_from);
+        Ok(versions)
+    }
+
+    /// Stores a version, replacing any version with the same `effective_from`.
+    pub fn insert(&self, data: &InfoUnitPriceEntity) -> Result<()> {
+        let effective_from = data
+            .effective_from
+            .ok_or_else(|| anyhow!("Price history entries require effective_from"))?;
+
+        InfoUnitPriceFsAdapter::write_to(
+            &info_unit_price_history_file_path(effective_from.timestamp()),
+            data,
+        )
+    }
+
+    pub fn delete(&self, effective_from: DateTime<Utc>) -> Result<()> {
+        let path = info_unit_price_history_file_path(effective_from.timestamp());
+
+        storage_backend()
+            .delete_record(&path)
+            .context("Failed to delete unit price history file")?;
+
+        Ok(())
+    }
+}
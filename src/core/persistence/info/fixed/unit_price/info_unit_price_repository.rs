@@ -3,6 +3,7 @@ use crate::core::persistence::info::fixed::unit_price::info_unit_price_api_repos
 use crate::core::persistence::info::fixed::unit_price::info_unit_price_collector_repository_trait::InfoUnitPriceCollectorRepository;
 use crate::core::persistence::info::fixed::unit_price::info_unit_price_entity::InfoUnitPriceEntity;
 use crate::core::persistence::info::fixed::unit_price::info_unit_price_fs_adapter::InfoUnitPriceFsAdapter;
+use crate::core::persistence::info::fixed::unit_price::info_unit_price_history_fs_adapter::InfoUnitPriceHistoryFsAdapter;
 use anyhow::Result;
 use tracing::error;
 
@@ -10,12 +11,14 @@ use tracing::error;
 /// Implements both API and collector traits to keep wiring consistent.
 pub struct InfoUnitPriceRepository {
     adapter: InfoUnitPriceFsAdapter,
+    history_adapter: InfoUnitPriceHistoryFsAdapter,
 }
 
 impl InfoUnitPriceRepository {
     pub fn new() -> Self {
         Self {
             adapter: InfoUnitPriceFsAdapter,
+            history_adapter: InfoUnitPriceHistoryFsAdapter,
         }
     }
 }
@@ -44,6 +47,10 @@ impl InfoUnitPriceApiRepository for InfoUnitPriceRepository {
             err
         })
     }
+
+    fn history_adapter(&self) -> &InfoUnitPriceHistoryFsAdapter {
+        &self.history_adapter
+    }
 }
 
 impl InfoUnitPriceCollectorRepository for InfoUnitPriceRepository {
@@ -1,10 +1,7 @@
 use super::info_version_entity::InfoVersionEntity;
 use crate::core::persistence::info::fixed::info_fixed_fs_adapter_trait::InfoFixedFsAdapterTrait;
+use crate::core::persistence::storage_backend::storage_backend;
 use anyhow::{Context, Result};
-use std::{
-    fs::{self, File},
-    io::{BufRead, BufReader},
-};
 use crate::core::persistence::storage_path::{info_version_path};
 
 /// File-based FS_ADAPTER implementation for the `VersionInfo` entity.
@@ -27,16 +24,12 @@ impl InfoFixedFsAdapterTrait<InfoVersionEntity> for InfoVersionFsAdapter {
     fn read(&self) -> Result<InfoVersionEntity> {
         let path = info_version_path();
 
-        if !path.exists() {
+        let Some(text) = storage_backend().read_record(&path)? else {
             return Ok(InfoVersionEntity::default());
-        }
-
-        let file = File::open(&path).context("Failed to open version file")?;
-        let reader = BufReader::new(file);
+        };
         let mut v = InfoVersionEntity::default();
 
-        for line in reader.lines() {
-            let line = line?;
+        for line in text.lines() {
             if let Some((key, val)) = line.split_once(':') {
                 let key = key.trim().to_uppercase();
                 let val = val.trim();
@@ -70,9 +63,7 @@ impl InfoFixedFsAdapterTrait<InfoVersionEntity> for InfoVersionFsAdapter {
     fn delete(&self) -> Result<()> {
         let path = info_version_path();
 
-        if path.exists() {
-            fs::remove_file(&path).context("Failed to delete version file")?;
-        }
+        storage_backend().delete_record(&path).context("Failed to delete version file")?;
         Ok(())
     }
 }
@@ -80,22 +71,11 @@ impl InfoFixedFsAdapterTrait<InfoVersionEntity> for InfoVersionFsAdapter {
 impl InfoVersionFsAdapter {
     /// Internal helper to atomically write the version file.
     fn write(&self, data: &InfoVersionEntity) -> Result<()> {
-        use std::io::Write;
+        use std::fmt::Write;
 
         let path = info_version_path();
 
-        // Ensure parent directory exists
-        if let Some(dir) = path.parent() {
-            fs::create_dir_all(dir)
-                .context("Failed to create version directory")?;
-        }
-
-        // Temporary file: version.tmp
-        let tmp_path = path.with_extension("tmp");
-
-        // Open temporary file
-        let mut f = File::create(&tmp_path)
-            .context("Failed to create temporary version file")?;
+        let mut f = String::new();
 
         // ----- Write all fields -----
         writeln!(f, "DATE:{}", data.date)?;
@@ -108,23 +88,7 @@ impl InfoVersionFsAdapter {
         writeln!(f, "COMPILER:{}", data.compiler)?;
         writeln!(f, "PLATFORM:{}", data.platform)?;
 
-        // ----- Flush + sync -----
-        f.flush()?;
-        f.sync_all()
-            .context("Failed to fsync temporary version file")?;
-
-        // ----- Atomic rename -----
-        fs::rename(&tmp_path, &path)
-            .context("Failed to finalize version file atomically")?;
-
-        // ----- fsync directory (required for true durability) -----
-        #[cfg(unix)]
-        if let Some(dir) = path.parent() {
-            let dir_file = File::open(dir)
-                .context("Failed to open version directory for fsync")?;
-            dir_file.sync_all()
-                .context("Failed to fsync version directory")?;
-        }
+        storage_backend().write_record(&path, &f)?;
 
         Ok(())
     }
@@ -1,6 +1,8 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use crate::core::persistence::info::fixed::ownership::ownership_rule_entity::OwnershipTagSource;
+
 /// Represents static and runtime information for a Kubernetes **Container**.
 ///
 /// Derived from Pod/Container metadata and Kubelet `/stats/summary`.
@@ -76,6 +78,11 @@ pub struct InfoContainerEntity {
     pub team: Option<String>,
     pub service: Option<String>,
     pub env: Option<String>, // "dev", "stage", "prod"
+
+    /// Where `team` / `service` / `env` came from (see `InfoPodEntity`)
+    pub team_source: Option<OwnershipTagSource>,
+    pub service_source: Option<OwnershipTagSource>,
+    pub env_source: Option<OwnershipTagSource>,
 }
 
 impl InfoContainerEntity {
@@ -125,8 +132,17 @@ impl InfoContainerEntity {
             newer.last_check_deleted_count.or(self.last_check_deleted_count.take());
 
         // Preserve team/service/env
-        if newer.team.is_some()     { self.team = newer.team; }
-        if newer.service.is_some()  { self.service = newer.service; }
-        if newer.env.is_some()      { self.env = newer.env; }
+        if newer.team.is_some()     { self.team = newer.team; self.team_source = newer.team_source; }
+        if newer.service.is_some()  { self.service = newer.service; self.service_source = newer.service_source; }
+        if newer.env.is_some()      { self.env = newer.env; self.env_source = newer.env_source; }
+    }
+
+    /// Team, service and env with their sources, in `OwnershipTarget::ALL` order.
+    pub fn ownership_fields(&mut self) -> [(&mut Option<String>, &mut Option<OwnershipTagSource>); 3] {
+        [
+            (&mut self.team, &mut self.team_source),
+            (&mut self.service, &mut self.service_source),
+            (&mut self.env, &mut self.env_source),
+        ]
     }
 }
\ No newline at end of file
@@ -1,12 +1,9 @@
 use crate::core::persistence::info::k8s::info_dynamic_fs_adapter_trait::InfoDynamicFsAdapterTrait;
 use crate::core::persistence::info::k8s::container::info_container_entity::InfoContainerEntity;
 use anyhow::{anyhow, Context, Result};
-use std::{
-    fs::{self, File},
-    io::{BufRead, BufReader},
-    path::Path,
-};
-use crate::core::persistence::info::path::{info_k8s_container_key_dir_path, info_k8s_container_file_path};
+use crate::core::persistence::info::path::info_k8s_container_file_path;
+use crate::core::persistence::storage_backend::storage_backend;
+use crate::core::persistence::info::fixed::ownership::ownership_rule_entity::OwnershipTagSource;
 
 /// File-based FS adapter for `InfoContainerEntity`.
 ///
@@ -18,16 +15,12 @@ impl InfoDynamicFsAdapterTrait<InfoContainerEntity> for InfoContainerFsAdapter {
     /// Reads the container info file into memory.
     fn read(&self, container_key: &str) -> Result<InfoContainerEntity> {
         let path = info_k8s_container_file_path(container_key);
-        if !Path::new(&path).exists() {
+        let Some(text) = storage_backend().read_record(&path)? else {
             return Err(anyhow!("Missing container info file '{}'", path.display()));
-        }
-
-        let file = File::open(&path).context("Failed to open container info file")?;
-        let reader = BufReader::new(file);
+        };
         let mut v = InfoContainerEntity::default();
 
-        for line in reader.lines() {
-            let line = line?;
+        for line in text.lines() {
             if let Some((key, val)) = line.split_once(':') {
                 let key = key.trim().to_uppercase();
                 let val = val.trim().to_string();
@@ -77,6 +70,9 @@ impl InfoDynamicFsAdapterTrait<InfoContainerEntity> for InfoContainerFsAdapter {
                     "TEAM" => v.team = Some(val),
                     "SERVICE" => v.service = Some(val),
                     "ENV" => v.env = Some(val),
+                    "TEAM_SOURCE" => v.team_source = OwnershipTagSource::from_code(&val),
+                    "SERVICE_SOURCE" => v.service_source = OwnershipTagSource::from_code(&val),
+                    "ENV_SOURCE" => v.env_source = OwnershipTagSource::from_code(&val),
 
                     // Bookkeeping
                     "LAST_UPDATED_INFO_AT" => v.last_updated_info_at = val.parse().ok(),
@@ -94,29 +90,25 @@ impl InfoDynamicFsAdapterTrait<InfoContainerEntity> for InfoContainerFsAdapter {
     /// Inserts (creates) a container info file.
     fn insert(&self, data: &InfoContainerEntity) -> Result<()> {
         let key = Self::container_key(data)?;
-        Self::create_container_dir_if_missing(&key)?;
         self.write(&key, data)
     }
 
     /// Updates a container info file.
     fn update(&self, data: &InfoContainerEntity) -> Result<()> {
         let container_key = Self::container_key(data)?;
-        Self::create_container_dir_if_missing(&container_key)?;
         self.write(&container_key, data)
     }
 
     /// Deletes the container info file.
     fn delete(&self, key: &str) -> Result<()> {
         let path = info_k8s_container_file_path(key);
-        if Path::new(&path).exists() {
-            fs::remove_file(&path).context("Failed to delete container info file")?;
-        }
+        storage_backend().delete_record(&path).context("Failed to delete container info file")?;
         Ok(())
     }
 
     fn exists(&self, container_key: &str) -> Result<bool> {
         let path = info_k8s_container_file_path(container_key);
-        Ok(Path::new(&path).exists())
+        storage_backend().record_exists(&path)
     }
 }
 
@@ -134,27 +126,12 @@ impl InfoContainerFsAdapter {
         Ok(format!("{}-{}", pod_uid, container_name))
     }
 
-    /// Ensures the container directory exists.
-    pub fn create_container_dir_if_missing(container_key: &str) -> Result<()> {
-        let path = info_k8s_container_key_dir_path(container_key);
-        fs::create_dir_all(&path).context("Failed to create container info directory")?;
-        Ok(())
-    }
-
     /// Writes the info.rci file atomically.
     fn write(&self, container_key: &str, data: &InfoContainerEntity) -> Result<()> {
-        use std::io::Write;
+        use std::fmt::Write;
 
-        let dir = info_k8s_container_key_dir_path(container_key);
-        fs::create_dir_all(&dir)
-            .context("Failed to create container info directory")?;
-
-        let tmp_path = dir.join("info.rci.tmp");
-        let final_path = dir.join("info.rci");
-
-        // ---- Create temporary file ----
-        let mut f = File::create(&tmp_path)
-            .context("Failed to create temp info.rci file")?;
+        let path = info_k8s_container_file_path(container_key);
+        let mut f = String::new();
 
         // Generic Option<T> writer
         macro_rules! write_field {
@@ -210,25 +187,16 @@ impl InfoContainerFsAdapter {
         write_field!("TEAM", data.team.clone());
         write_field!("SERVICE", data.service.clone());
         write_field!("ENV", data.env.clone());
+        write_field!("TEAM_SOURCE", data.team_source.clone());
+        write_field!("SERVICE_SOURCE", data.service_source.clone());
+        write_field!("ENV_SOURCE", data.env_source.clone());
 
         // ---- Bookkeeping ----
         write_field!("LAST_UPDATED_INFO_AT", data.last_updated_info_at.map(|t| t.to_string()));
         write_field!("DELETED", data.deleted.map(|v| v.to_string()));
         write_field!("LAST_CHECK_DELETED_COUNT", data.last_check_deleted_count.map(|v| v.to_string()));
 
-        // ---- Flush buffer only (NO fsync) ----
-        f.flush()?;
-
-        // ---- Windows: pre-delete needed ----
-        #[cfg(windows)]
-        if final_path.exists() {
-            fs::remove_file(&final_path)
-                .context("Failed to remove old info.rci before rename")?;
-        }
-
-        // ---- Atomic rename ----
-        fs::rename(&tmp_path, &final_path)
-            .context("Failed to atomically rename info.rci.tmp → info.rci")?;
+        storage_backend().write_record(&path, &f)?;
 
         Ok(())
     }
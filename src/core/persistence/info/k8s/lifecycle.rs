@@ -0,0 +1,95 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A value an object held from `since` until the next segment starts (or the
+/// object is gone), e.g. a volume phase or a service type.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LifecycleSegment {
+    pub value: String,
+    pub since: DateTime<Utc>,
+}
+
+/// Starts a segment for `value` unless it is already the current one.
+/// `since` is clamped to the last segment so the list stays ordered.
+pub fn push_segment(segments: &mut Vec<LifecycleSegment>, value: &str, since: DateTime<Utc>) -> bool {
+    let since = match segments.last() {
+        Some(last) if last.value == value => return false,
+        Some(last) => since.max(last.since),
+        None => since,
+    };
+    segments.push(LifecycleSegment { value: value.to_string(), since });
+    true
+}
+
+/// Parts of `[start, end)` covered by each segment, as `(value, from, to)`.
+/// Time before the first segment is not covered.
+pub fn segment_ranges(
+    segments: &[LifecycleSegment],
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> Vec<(&str, DateTime<Utc>, DateTime<Utc>)> {
+    segments
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, segment)| {
+            let from = segment.since.max(start);
+            let to = segments.get(idx + 1).map_or(end, |next| next.since.min(end));
+            (to > from).then_some((segment.value.as_str(), from, to))
+        })
+        .collect()
+}
+
+/// `value@rfc3339,...`, the form segments take in `.rci` records.
+pub fn format_segments(segments: &[LifecycleSegment]) -> String {
+    segments
+        .iter()
+        .map(|s| format!("{}@{}", s.value, s.since.to_rfc3339()))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Parses [`format_segments`] output, skipping malformed entries.
+pub fn parse_segments(value: &str) -> Vec<LifecycleSegment> {
+    value
+        .split(',')
+        .filter_map(|entry| {
+            let (value, since) = entry.trim().split_once('@')?;
+            Some(LifecycleSegment {
+                value: value.to_string(),
+                since: since.parse().ok()?,
+            })
+        })
+        .collect()
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn segments_cover_the_window_in_order() {
+        let t0 = DateTime::parse_from_rfc3339("2026-01-01T00:00:00Z").unwrap().with_timezone(&Utc);
+        let mut segments = Vec::new();
+        assert!(push_segment(&mut segments, "Bound", t0));
+        assert!(!push_segment(&mut segments, "Bound", t0 + Duration::hours(1)));
+        // Out-of-order times are clamped
+        assert!(push_segment(&mut segments, "Released", t0 + Duration::hours(4)));
+        assert!(push_segment(&mut segments, "Available", t0 + Duration::hours(2)));
+        assert_eq!(segments[2].since, t0 + Duration::hours(4));
+
+        let ranges = segment_ranges(&segments, t0 + Duration::hours(1), t0 + Duration::hours(6));
+        assert_eq!(
+            ranges,
+            vec![
+                ("Bound", t0 + Duration::hours(1), t0 + Duration::hours(4)),
+                ("Available", t0 + Duration::hours(4), t0 + Duration::hours(6)),
+            ]
+        );
+
+        assert_eq!(parse_segments(&format_segments(&segments)), segments);
+        assert!(parse_segments("").is_empty());
+    }
+}
@@ -1,6 +1,9 @@
 pub mod container;
 pub mod node;
 pub mod pod;
+pub mod service;
+pub mod volume;
+pub mod lifecycle;
 pub mod deployment;
 pub mod namespace;
 pub mod info_dynamic_fs_adapter_trait;
@@ -1,5 +1,6 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 /// Represents static and runtime information for a Kubernetes node.
 ///
@@ -51,20 +52,104 @@ pub struct InfoNodeEntity {
     pub image_names: Option<Vec<String>>,
     pub image_total_size_bytes: Option<u64>,
 
+    // --- System overhead ---
+    /// Latest CPU usage of the kubelet `system_containers` (kubelet, runtime, pods slice)
+    pub system_cpu_usage_nano_cores: Option<u64>,
+    /// Latest memory working set of the kubelet `system_containers`
+    pub system_memory_working_set_bytes: Option<u64>,
+
     // --- Cost (node-specific) ---
+    /// Purchase option detected from node labels (on-demand vs spot/preemptible)
+    pub capacity_type: Option<NodeCapacityType>,
+
     /// Fixed price for this node in USD (instance / VM / bare metal)
     pub fixed_instance_usd: Option<f64>,
 
     /// Billing period for `fixed_instance`
     pub price_period: Option<NodePricePeriod>,
 
+    /// Where `fixed_instance_usd` came from. Catalog imports never overwrite
+    /// manually set prices.
+    pub price_source: Option<NodePriceSource>,
+
+    /// Earlier fixed prices, oldest first. Empty when the price never changed,
+    /// in which case `fixed_instance_usd` applies at every point in time.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub price_history: Vec<NodePriceVersion>,
+
     pub team: Option<String>,
     pub service: Option<String>,
     pub env: Option<String>, // "dev", "stage", "prod"
 
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NodeCapacityType {
+    /// Regular on-demand / reserved capacity
+    OnDemand,
+
+    /// Spot, preemptible or otherwise interruptible capacity
+    Spot,
+}
+
+impl NodeCapacityType {
+    /// Well-known `key=value` node labels that mark interruptible capacity.
+    const SPOT_LABELS: &'static [(&'static str, &'static str)] = &[
+        ("karpenter.sh/capacity-type", "spot"),
+        ("eks.amazonaws.com/capacityType", "spot"),
+        ("cloud.google.com/gke-spot", "true"),
+        ("cloud.google.com/gke-preemptible", "true"),
+        ("kubernetes.azure.com/scalesetpriority", "spot"),
+        ("node.kubernetes.io/lifecycle", "spot"),
+    ];
+
+    /// Detect capacity type from node labels.
+    ///
+    /// `custom_selector` is a comma-separated list of `key=value` pairs from
+    /// settings; a node matching any of them (or a well-known label) is spot.
+    pub fn from_labels(labels: &BTreeMap<String, String>, custom_selector: Option<&str>) -> Self {
+        let matches = |key: &str, value: &str| {
+            labels
+                .get(key)
+                .map(|v| v.eq_ignore_ascii_case(value))
+                .unwrap_or(false)
+        };
+
+        let well_known = Self::SPOT_LABELS.iter().any(|(k, v)| matches(k, v));
+        let custom = custom_selector
+            .map(|selector| {
+                selector
+                    .split(',')
+                    .filter_map(|pair| pair.split_once('='))
+                    .any(|(k, v)| matches(k.trim(), v.trim()))
+            })
+            .unwrap_or(false);
+
+        if well_known || custom {
+            NodeCapacityType::Spot
+        } else {
+            NodeCapacityType::OnDemand
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NodeCapacityType::OnDemand => "on_demand",
+            NodeCapacityType::Spot => "spot",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "on_demand" | "ondemand" => Some(NodeCapacityType::OnDemand),
+            "spot" => Some(NodeCapacityType::Spot),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum NodePricePeriod {
     /// Unit-based pricing (CPU-hour, GB-hour, etc.)
     Unit,
@@ -79,7 +164,128 @@ pub enum NodePricePeriod {
     Month,
 }
 
+impl NodePricePeriod {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "unit" => Some(NodePricePeriod::Unit),
+            "hour" => Some(NodePricePeriod::Hour),
+            "day" => Some(NodePricePeriod::Day),
+            "month" => Some(NodePricePeriod::Month),
+            _ => None,
+        }
+    }
+
+    /// `price` per this period normalized to USD per hour. Months are treated
+    /// as 30 days, like the default unit prices.
+    fn hourly(&self, price: f64) -> Option<f64> {
+        match self {
+            NodePricePeriod::Unit => None,
+            NodePricePeriod::Hour => Some(price),
+            NodePricePeriod::Day => Some(price / 24.0),
+            NodePricePeriod::Month => Some(price / (30.0 * 24.0)),
+        }
+    }
+}
+
+/// A fixed node price that was replaced, kept so that changing the price
+/// does not reprice earlier metric points.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NodePriceVersion {
+    pub fixed_instance_usd: Option<f64>,
+    pub price_period: Option<NodePricePeriod>,
+
+    /// Time the next price took over.
+    pub effective_until: DateTime<Utc>,
+}
+
+impl NodePriceVersion {
+    fn hourly_usd(&self) -> Option<f64> {
+        self.price_period.as_ref()?.hourly(self.fixed_instance_usd?)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NodePriceSource {
+    /// Set through the node price API
+    Manual,
+
+    /// Imported from a cloud provider price catalog
+    Catalog,
+}
+
+impl NodePriceSource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NodePriceSource::Manual => "manual",
+            NodePriceSource::Catalog => "catalog",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "manual" => Some(NodePriceSource::Manual),
+            "catalog" => Some(NodePriceSource::Catalog),
+            _ => None,
+        }
+    }
+}
+
 impl InfoNodeEntity {
+    /// Fixed node price in force now, normalized to USD per hour.
+    ///
+    /// `None` when the node has no fixed price or is billed by unit prices.
+    pub fn fixed_hourly_usd(&self) -> Option<f64> {
+        self.price_period.as_ref()?.hourly(self.fixed_instance_usd?)
+    }
+
+    /// Fixed node price in force at `time`, normalized to USD per hour.
+    pub fn fixed_hourly_usd_at(&self, time: DateTime<Utc>) -> Option<f64> {
+        match self.price_history.iter().find(|v| time < v.effective_until) {
+            Some(version) => version.hourly_usd(),
+            None => self.fixed_hourly_usd(),
+        }
+    }
+
+    /// Times at which the fixed node price changed, oldest first.
+    pub fn fixed_price_changes(&self) -> Vec<DateTime<Utc>> {
+        self.price_history.iter().map(|v| v.effective_until).collect()
+    }
+
+    /// Sets the fixed node price from `effective_from` on.
+    ///
+    /// The price in force at `effective_from` is kept in the history; versions
+    /// that started later are superseded by the new price. Setting the current
+    /// price again leaves the history untouched.
+    pub fn set_fixed_price(
+        &mut self,
+        fixed_instance_usd: Option<f64>,
+        price_period: Option<NodePricePeriod>,
+        effective_from: DateTime<Utc>,
+    ) {
+        let unchanged = self.fixed_instance_usd == fixed_instance_usd
+            && self.price_period == price_period
+            && self.price_history.last().is_none_or(|v| v.effective_until <= effective_from);
+        if unchanged {
+            return;
+        }
+
+        match self.price_history.iter().position(|v| effective_from < v.effective_until) {
+            Some(idx) => {
+                self.price_history.truncate(idx + 1);
+                self.price_history[idx].effective_until = effective_from;
+            }
+            None => self.price_history.push(NodePriceVersion {
+                fixed_instance_usd: self.fixed_instance_usd,
+                price_period: self.price_period.clone(),
+                effective_until: effective_from,
+            }),
+        }
+
+        self.fixed_instance_usd = fixed_instance_usd;
+        self.price_period = price_period;
+    }
+
     /// Merge data from API (`newer`), preserving user-managed fields.
     pub fn merge_from(&mut self, newer: InfoNodeEntity) {
         self.node_name = newer.node_name.or(self.node_name.take());
@@ -129,6 +335,15 @@ impl InfoNodeEntity {
         self.image_total_size_bytes =
             newer.image_total_size_bytes.or(self.image_total_size_bytes.take());
 
+        self.system_cpu_usage_nano_cores = newer
+            .system_cpu_usage_nano_cores
+            .or(self.system_cpu_usage_nano_cores.take());
+        self.system_memory_working_set_bytes = newer
+            .system_memory_working_set_bytes
+            .or(self.system_memory_working_set_bytes.take());
+
+        self.capacity_type = newer.capacity_type.or(self.capacity_type.take());
+
         // Preserve user-provided metadata (local annotations)
         if newer.team.is_some() { self.team = newer.team; }
         if newer.service.is_some() { self.service = newer.service; }
@@ -141,5 +356,8 @@ impl InfoNodeEntity {
         if newer.price_period.is_some() {
             self.price_period = newer.price_period;
         }
+        if newer.price_source.is_some() {
+            self.price_source = newer.price_source;
+        }
     }
 }
\ No newline at end of file
@@ -1,8 +1,9 @@
-use super::info_node_entity::{InfoNodeEntity, NodePricePeriod};
+use super::info_node_entity::{InfoNodeEntity, NodeCapacityType, NodePricePeriod, NodePriceSource, NodePriceVersion};
 use crate::core::persistence::info::k8s::info_dynamic_fs_adapter_trait::InfoDynamicFsAdapterTrait;
 use anyhow::{anyhow, Context, Result};
-use std::{fs::{self, File}, io::{BufRead, BufReader}, path::Path};
-use crate::core::persistence::info::path::{info_k8s_node_key_dir_path, info_k8s_node_file_path};
+use chrono::{DateTime, Utc};
+use crate::core::persistence::info::path::info_k8s_node_file_path;
+use crate::core::persistence::storage_backend::storage_backend;
 
 /// File-based FS adapter for the `InfoNodeEntity`.
 ///
@@ -18,16 +19,12 @@ impl InfoDynamicFsAdapterTrait<InfoNodeEntity> for InfoNodeFsAdapter {
     fn read(&self, node_name: &str) -> Result<InfoNodeEntity> {
 
         let path = info_k8s_node_file_path(node_name);
-        if !Path::new(&path).exists() {
+        let Some(text) = storage_backend().read_record(&path)? else {
             return Err(anyhow!("Missing Node info file '{}'", path.display()));
-        }
-
-        let file = File::open(&path).context("Failed to open node info file")?;
-        let reader = BufReader::new(file);
+        };
         let mut v = InfoNodeEntity::default();
 
-        for line in reader.lines() {
-            let line = line?;
+        for line in text.lines() {
             if let Some((key, val)) = line.split_once(':') {
                 let key = key.trim().to_uppercase();
                 let val = val.trim().to_string();
@@ -64,14 +61,14 @@ impl InfoDynamicFsAdapterTrait<InfoNodeEntity> for InfoNodeFsAdapter {
                     "IMAGE_NAMES" => v.image_names = Some(val.split(',').map(|s| s.trim().to_string()).collect()),
                     "IMAGE_TOTAL_SIZE_BYTES" => v.image_total_size_bytes = val.parse().ok(),
 
+                    "SYSTEM_CPU_USAGE_NANO_CORES" => v.system_cpu_usage_nano_cores = val.parse().ok(),
+                    "SYSTEM_MEMORY_WORKING_SET_BYTES" => v.system_memory_working_set_bytes = val.parse().ok(),
+
+                    "CAPACITY_TYPE" => v.capacity_type = NodeCapacityType::parse(&val),
                     "FIXED_INSTANCE_USD" => v.fixed_instance_usd = val.parse().ok(),
-                    "PRICE_PERIOD" => v.price_period = match val.to_lowercase().as_str() {
-                        "unit" => Some(NodePricePeriod::Unit),
-                        "hour" => Some(NodePricePeriod::Hour),
-                        "day" => Some(NodePricePeriod::Day),
-                        "month" => Some(NodePricePeriod::Month),
-                        _ => None,
-                    },
+                    "PRICE_PERIOD" => v.price_period = NodePricePeriod::parse(&val),
+                    "PRICE_SOURCE" => v.price_source = NodePriceSource::parse(&val),
+                    "PRICE_HISTORY" => v.price_history = parse_price_history(&val),
 
                     "TEAM" => v.team = Some(val),
                     "SERVICE" => v.service = Some(val),
@@ -92,7 +89,6 @@ impl InfoDynamicFsAdapterTrait<InfoNodeEntity> for InfoNodeFsAdapter {
             .as_ref()
             .ok_or_else(|| anyhow::anyhow!("Missing node_name in InfoNodeEntity"))?;
 
-        Self::create_node_dir_if_missing(node_name)?;
         self.write(node_name, data)
     }
 
@@ -104,9 +100,6 @@ impl InfoDynamicFsAdapterTrait<InfoNodeEntity> for InfoNodeFsAdapter {
             .as_ref()
             .ok_or_else(|| anyhow::anyhow!("Missing node_name in InfoNodeEntity"))?;
 
-        // Create directory if missing
-        Self::create_node_dir_if_missing(node_name)?;
-
         // Write node info
         self.write(node_name, data)
     }
@@ -114,39 +107,23 @@ impl InfoDynamicFsAdapterTrait<InfoNodeEntity> for InfoNodeFsAdapter {
     /// Deletes the node info file if present.
     fn delete(&self, node_name: &str) -> Result<()> {
         let path = info_k8s_node_file_path(node_name);
-        if Path::new(&path).exists() {
-            fs::remove_file(&path).context("Failed to delete node info file")?;
-        }
+        storage_backend().delete_record(&path).context("Failed to delete node info file")?;
         Ok(())
     }
 
     fn exists(&self, node_name: &str) -> Result<bool> {
         let path = info_k8s_node_file_path(node_name);
-        Ok(Path::new(&path).exists())
+        storage_backend().record_exists(&path)
     }
 
 }
 
 impl InfoNodeFsAdapter {
-    pub fn create_node_dir_if_missing(node_name: &str) -> Result<()> {
-        let path = info_k8s_node_key_dir_path(node_name);
-        fs::create_dir_all(&path).context("Failed to create node info directory")?;
-        Ok(())
-    }
-
     fn write(&self, node_name: &str, data: &InfoNodeEntity) -> Result<()> {
-        use std::io::Write;
-
-        let dir = info_k8s_node_key_dir_path(node_name);
-        fs::create_dir_all(&dir)
-            .context("Failed to create node info directory")?;
-
-        let tmp_path = dir.join("info.rci.tmp");
-        let final_path = dir.join("info.rci");
+        use std::fmt::Write;
 
-        // write to temporary file
-        let mut f = File::create(&tmp_path)
-            .context("Failed to create temporary node info file")?;
+        let path = info_k8s_node_file_path(node_name);
+        let mut f = String::new();
 
         // Helper macro that handles Option<T>
         macro_rules! write_field {
@@ -202,7 +179,12 @@ impl InfoNodeFsAdapter {
         write_field!("IMAGE_NAMES", data.image_names.clone().map(|v| v.join(",")));
         write_field!("IMAGE_TOTAL_SIZE_BYTES", data.image_total_size_bytes.map(|v| v.to_string()));
 
+        // ---- System overhead ----
+        write_field!("SYSTEM_CPU_USAGE_NANO_CORES", data.system_cpu_usage_nano_cores.map(|v| v.to_string()));
+        write_field!("SYSTEM_MEMORY_WORKING_SET_BYTES", data.system_memory_working_set_bytes.map(|v| v.to_string()));
+
         // ---- Cost (node-specific) ----
+        write_field!("CAPACITY_TYPE", data.capacity_type.map(|v| v.as_str().to_string()));
         write_field!("FIXED_INSTANCE_USD", data.fixed_instance_usd.map(|v| v.to_string()));
         write_field!(
             "PRICE_PERIOD",
@@ -210,27 +192,51 @@ impl InfoNodeFsAdapter {
                 .as_ref()
                 .map(|v| format!("{:?}", v))
         );
+        write_field!("PRICE_SOURCE", data.price_source.map(|v| v.as_str().to_string()));
+        write_field!(
+            "PRICE_HISTORY",
+            (!data.price_history.is_empty()).then(|| format_price_history(&data.price_history))
+        );
 
         // ---- Custom fields ----
         write_field!("TEAM", data.team);
         write_field!("SERVICE", data.service);
         write_field!("ENV", data.env);
 
-        // ---- Flush only (no fsync) ----
-        f.flush()?;
-
-        // ---- Windows-safe: remove existing file before rename ----
-        #[cfg(windows)]
-        if final_path.exists() {
-            fs::remove_file(&final_path)
-                .context("Failed to remove old info.rci before rename")?;
-        }
-
-        // ---- Atomic rename ----
-        fs::rename(&tmp_path, &final_path)
-            .context("Failed to atomically replace node info file")?;
+        storage_backend().write_record(&path, &f)?;
 
         Ok(())
     }
 
 }
+
+/// Price history is stored on one line as `until=usd/period` entries
+/// separated by `;`, with empty values for unset fields.
+fn format_price_history(history: &[NodePriceVersion]) -> String {
+    history
+        .iter()
+        .map(|v| {
+            format!(
+                "{}={}/{}",
+                v.effective_until.to_rfc3339(),
+                v.fixed_instance_usd.map(|p| p.to_string()).unwrap_or_default(),
+                v.price_period.as_ref().map(|p| format!("{:?}", p)).unwrap_or_default(),
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(";")
+}
+
+fn parse_price_history(val: &str) -> Vec<NodePriceVersion> {
+    val.split(';')
+        .filter_map(|entry| {
+            let (until, price) = entry.split_once('=')?;
+            let (usd, period) = price.split_once('/')?;
+            Some(NodePriceVersion {
+                fixed_instance_usd: usd.parse().ok(),
+                price_period: NodePricePeriod::parse(period),
+                effective_until: DateTime::parse_from_rfc3339(until.trim()).ok()?.with_timezone(&Utc),
+            })
+        })
+        .collect()
+}
@@ -1,6 +1,8 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use crate::core::persistence::info::fixed::ownership::ownership_rule_entity::OwnershipTagSource;
+
 /// Represents static and runtime information for a Kubernetes Pod.
 ///
 /// Derived from Pod metadata (`.metadata`, `.spec`, `.status`) and runtime summary.
@@ -37,6 +39,10 @@ pub struct InfoPodEntity {
     pub owner_kind: Option<String>,
     pub owner_name: Option<String>,
     pub owner_uid: Option<String>,
+    /// Top-level controller resolved at collection time, e.g. the Deployment
+    /// behind the owning ReplicaSet. Kept after the ReplicaSet is deleted.
+    pub controller_kind: Option<String>,
+    pub controller_name: Option<String>,
 
     // --- Containers ---
     pub container_count: Option<u32>,
@@ -65,6 +71,12 @@ pub struct InfoPodEntity {
     pub team: Option<String>,
     pub service: Option<String>,
     pub env: Option<String>, // "dev", "stage", "prod"
+
+    /// Where `team` / `service` / `env` came from (`None` on records tagged
+    /// before sources were kept, which are treated as manual)
+    pub team_source: Option<OwnershipTagSource>,
+    pub service_source: Option<OwnershipTagSource>,
+    pub env_source: Option<OwnershipTagSource>,
 }
 
 impl InfoPodEntity {
@@ -94,6 +106,8 @@ impl InfoPodEntity {
         self.owner_kind = newer.owner_kind.or(self.owner_kind.take());
         self.owner_name = newer.owner_name.or(self.owner_name.take());
         self.owner_uid = newer.owner_uid.or(self.owner_uid.take());
+        self.controller_kind = newer.controller_kind.or(self.controller_kind.take());
+        self.controller_name = newer.controller_name.or(self.controller_name.take());
 
         self.container_count = newer.container_count.or(self.container_count.take());
         self.container_names = newer.container_names.or(self.container_names.take());
@@ -113,8 +127,17 @@ impl InfoPodEntity {
             newer.termination_grace_period_seconds.or(self.termination_grace_period_seconds.take());
         self.tolerations = newer.tolerations.or(self.tolerations.take());
         // DO NOT overwrite team/service/env – these are local annotations
-        if newer.team.is_some() { self.team = newer.team; }
-        if newer.service.is_some() { self.service = newer.service; }
-        if newer.env.is_some() { self.env = newer.env; }
+        if newer.team.is_some() { self.team = newer.team; self.team_source = newer.team_source; }
+        if newer.service.is_some() { self.service = newer.service; self.service_source = newer.service_source; }
+        if newer.env.is_some() { self.env = newer.env; self.env_source = newer.env_source; }
+    }
+
+    /// Team, service and env with their sources, in `OwnershipTarget::ALL` order.
+    pub fn ownership_fields(&mut self) -> [(&mut Option<String>, &mut Option<OwnershipTagSource>); 3] {
+        [
+            (&mut self.team, &mut self.team_source),
+            (&mut self.service, &mut self.service_source),
+            (&mut self.env, &mut self.env_source),
+        ]
     }
 }
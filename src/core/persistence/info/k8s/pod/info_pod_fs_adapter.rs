@@ -1,13 +1,10 @@
 use crate::core::persistence::info::k8s::info_dynamic_fs_adapter_trait::InfoDynamicFsAdapterTrait;
 use crate::core::persistence::info::k8s::pod::info_pod_entity::InfoPodEntity;
 use anyhow::{anyhow, Context, Result};
-use std::{
-    fs::{self, File},
-    io::{BufRead, BufReader},
-    path::Path,
-};
 use tracing::log::debug;
-use crate::core::persistence::info::path::{info_k8s_pod_key_dir_path, info_k8s_pod_file_path};
+use crate::core::persistence::info::path::info_k8s_pod_file_path;
+use crate::core::persistence::storage_backend::storage_backend;
+use crate::core::persistence::info::fixed::ownership::ownership_rule_entity::OwnershipTagSource;
 
 /// File-based FS adapter for `InfoPodEntity`.
 ///
@@ -21,16 +18,12 @@ impl InfoDynamicFsAdapterTrait<InfoPodEntity> for InfoPodFsAdapter {
     fn read(&self, pod_uid: &str) -> Result<InfoPodEntity> {
 
         let path = info_k8s_pod_file_path(pod_uid);
-        if !Path::new(&path).exists() {
+        let Some(text) = storage_backend().read_record(&path)? else {
             return Err(anyhow!("Missing pod info file '{}'", path.display()));
-        }
-
-        let file = File::open(&path).context("Failed to open pod info file")?;
-        let reader = BufReader::new(file);
+        };
         let mut v = InfoPodEntity::default();
 
-        for line in reader.lines() {
-            let line = line?;
+        for line in text.lines() {
             if let Some((key, val)) = line.split_once(':') {
                 let key = key.trim().to_uppercase();
                 let val = val.trim().to_string();
@@ -64,6 +57,8 @@ impl InfoDynamicFsAdapterTrait<InfoPodEntity> for InfoPodFsAdapter {
                     "OWNER_KIND" => v.owner_kind = Some(val),
                     "OWNER_NAME" => v.owner_name = Some(val),
                     "OWNER_UID" => v.owner_uid = Some(val),
+                    "CONTROLLER_KIND" => v.controller_kind = Some(val),
+                    "CONTROLLER_NAME" => v.controller_name = Some(val),
 
                     // Containers
                     "CONTAINER_COUNT" => v.container_count = val.parse().ok(),
@@ -98,6 +93,9 @@ impl InfoDynamicFsAdapterTrait<InfoPodEntity> for InfoPodFsAdapter {
                     "TEAM" => v.team = Some(val),
                     "SERVICE" => v.service = Some(val),
                     "ENV" => v.env = Some(val),
+                    "TEAM_SOURCE" => v.team_source = OwnershipTagSource::from_code(&val),
+                    "SERVICE_SOURCE" => v.service_source = OwnershipTagSource::from_code(&val),
+                    "ENV_SOURCE" => v.env_source = OwnershipTagSource::from_code(&val),
                     _ => {}
                 }
             }
@@ -113,7 +111,6 @@ impl InfoDynamicFsAdapterTrait<InfoPodEntity> for InfoPodFsAdapter {
             .as_ref()
             .ok_or_else(|| anyhow::anyhow!("Missing pod_uid in InfoPodEntity"))?;
 
-        Self::create_pod_dir_if_missing(pod_uid)?;
         self.write(pod_uid, data)
     }
 
@@ -125,22 +122,18 @@ impl InfoDynamicFsAdapterTrait<InfoPodEntity> for InfoPodFsAdapter {
             .as_ref()
             .ok_or_else(|| anyhow::anyhow!("Missing pod_uid in InfoPodEntity"))?;
 
-        // 2️⃣ Ensure the directory exists
-        Self::create_pod_dir_if_missing(pod_uid)
-            .with_context(|| format!("Failed to prepare pod directory for '{}'", pod_uid))?;
-
-        // 3️⃣ Log what we’re about to persist (optional but strongly recommended)
+        // 2️⃣ Log what we’re about to persist (optional but strongly recommended)
         debug!(
             "📝 Updating InfoPodEntity '{}': {}",
             pod_uid,
             serde_json::to_string_pretty(data).unwrap_or_default()
         );
 
-        // 4️⃣ Perform atomic write
+        // 3️⃣ Perform atomic write
         self.write(pod_uid, data)
             .with_context(|| format!("Failed to write pod info for '{}'", pod_uid))?;
 
-        // 5️⃣ Confirm
+        // 4️⃣ Confirm
         debug!("✅ Successfully updated InfoPodEntity '{}'", pod_uid);
         Ok(())
     }
@@ -148,38 +141,23 @@ impl InfoDynamicFsAdapterTrait<InfoPodEntity> for InfoPodFsAdapter {
     /// Deletes the pod info file if present.
     fn delete(&self, pod_uid: &str) -> Result<()> {
         let path = info_k8s_pod_file_path(pod_uid);
-        if Path::new(&path).exists() {
-            fs::remove_file(&path).context("Failed to delete pod info file")?;
-        }
+        storage_backend().delete_record(&path).context("Failed to delete pod info file")?;
         Ok(())
     }
 
     fn exists(&self, pod_uid: &str) -> Result<bool> {
         let path = info_k8s_pod_file_path(pod_uid);
-        Ok(Path::new(&path).exists())
+        storage_backend().record_exists(&path)
     }
 }
 
 impl InfoPodFsAdapter {
-    pub fn create_pod_dir_if_missing(pod_uid: &str) -> Result<()> {
-        let path = info_k8s_pod_key_dir_path(pod_uid);
-        fs::create_dir_all(&path).context("Failed to create pod info directory")?;
-        Ok(())
-    }
-
-    /// Ensures the pod info directory exists.
+    /// Writes the pod info record atomically.
     pub fn write(&self, pod_uid: &str, data: &InfoPodEntity) -> Result<()> {
-        use std::io::Write;
+        use std::fmt::Write;
 
-        let dir = info_k8s_pod_key_dir_path(pod_uid);
-        fs::create_dir_all(&dir)
-            .context("Failed to create pod info directory")?;
-
-        let tmp_path = dir.join("info.rci.tmp");
-        let final_path = dir.join("info.rci");
-
-        let mut f = File::create(&tmp_path)
-            .context("Failed to create temporary pod info file")?;
+        let path = info_k8s_pod_file_path(pod_uid);
+        let mut f = String::new();
 
         // --- helper macros --------------------------------------------------
         macro_rules! write_field {
@@ -237,6 +215,8 @@ impl InfoPodFsAdapter {
         write_field!("OWNER_KIND", data.owner_kind);
         write_field!("OWNER_NAME", data.owner_name);
         write_field!("OWNER_UID", data.owner_uid);
+        write_field!("CONTROLLER_KIND", data.controller_kind);
+        write_field!("CONTROLLER_NAME", data.controller_name);
 
         // --- Containers ---
         write_field!("CONTAINER_COUNT", data.container_count.map(|v| v.to_string()));
@@ -275,19 +255,11 @@ impl InfoPodFsAdapter {
         write_field!("TEAM", data.team);
         write_field!("SERVICE", data.service);
         write_field!("ENV", data.env);
+        write_field!("TEAM_SOURCE", data.team_source);
+        write_field!("SERVICE_SOURCE", data.service_source);
+        write_field!("ENV_SOURCE", data.env_source);
 
-        // --- finalize atomic write (NO fsync) ------------------------
-
-        f.flush()?;  // write buffer → temp file (still OK if crash happens)
-
-        #[cfg(windows)]
-        if final_path.exists() {
-            fs::remove_file(&final_path)
-                .context("Failed to remove old pod info file before rename")?;
-        }
-
-        fs::rename(&tmp_path, &final_path)
-            .context("Failed to atomically replace pod info file")?;
+        storage_backend().write_record(&path, &f)?;
 
         debug!("💾 Successfully wrote info.rci for '{}'", pod_uid);
         Ok(())
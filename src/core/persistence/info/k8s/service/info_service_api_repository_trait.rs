@@ -0,0 +1,17 @@
+use super::info_service_entity::InfoServiceEntity;
+use crate::core::persistence::info::k8s::info_dynamic_fs_adapter_trait::InfoDynamicFsAdapterTrait;
+use anyhow::Result;
+
+/// API repository trait for Services and Ingresses.
+///
+/// The API only reads the records written by the collector.
+pub trait InfoServiceApiRepository: Send + Sync {
+    fn fs_adapter(&self) -> &dyn InfoDynamicFsAdapterTrait<InfoServiceEntity>;
+
+    fn read(&self, uid: &str) -> Result<InfoServiceEntity> {
+        self.fs_adapter().read(uid)
+    }
+
+    /// Every stored record, deleted ones included.
+    fn list(&self) -> Result<Vec<InfoServiceEntity>>;
+}
@@ -0,0 +1,88 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::core::persistence::info::k8s::lifecycle::{push_segment, LifecycleSegment};
+
+/// Lifecycle information for a Kubernetes Service or Ingress.
+///
+/// Records are kept after the object is deleted (`deleted = true`) so
+/// LoadBalancer / NodePort / Ingress costs stay attributable for past windows.
+/// Stored at: `data/info/k8s/service/{uid}/info.rci`
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct InfoServiceEntity {
+    // --- Identity ---
+    pub name: Option<String>,
+    pub namespace: Option<String>,
+    pub uid: Option<String>,
+    /// `Service` or `Ingress`
+    pub kind: Option<String>,
+
+    // --- Spec ---
+    /// Service type (`ClusterIP`, `NodePort`, `LoadBalancer`, `ExternalName`)
+    pub service_type: Option<String>,
+    /// Service types over the lifetime, oldest first
+    pub service_types: Vec<LifecycleSegment>,
+    pub ingress_class: Option<String>,
+    /// First IP or hostname assigned by the load balancer
+    pub load_balancer_address: Option<String>,
+
+    // --- Lifecycle ---
+    pub creation_timestamp: Option<DateTime<Utc>>,
+    pub first_seen_at: Option<DateTime<Utc>>,
+    pub deleted_at: Option<DateTime<Utc>>,
+    pub resource_version: Option<String>,
+
+    pub last_updated_info_at: Option<DateTime<Utc>>,
+    pub deleted: Option<bool>,
+    pub last_check_deleted_count: Option<u64>,
+
+    // --- Metadata ---
+    pub label: Option<String>,
+}
+
+impl InfoServiceEntity {
+    pub fn is_ingress(&self) -> bool {
+        self.kind.as_deref() == Some("Ingress")
+    }
+
+    /// Time range the object existed, with live objects running until `now`.
+    pub fn lifetime(&self, now: DateTime<Utc>) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+        let start = self.creation_timestamp.or(self.first_seen_at)?;
+        let end = if self.deleted == Some(true) {
+            self.deleted_at.or(self.last_updated_info_at)?
+        } else {
+            now
+        };
+        (end > start).then_some((start, end))
+    }
+
+    /// Adds the current `service_type` to the history when it changed. The
+    /// first type is dated from creation, later changes from `now`.
+    pub fn record_service_type(&mut self, now: DateTime<Utc>) {
+        let Some(service_type) = self.service_type.clone() else {
+            return;
+        };
+        let since = match self.service_types.is_empty() {
+            true => self.creation_timestamp.unwrap_or(now),
+            false => now,
+        };
+        push_segment(&mut self.service_types, &service_type, since);
+    }
+
+    /// Copies the live fields of `newer`, keeping the lifecycle recorded so far.
+    /// Call [`Self::record_service_type`] afterwards to extend the type history.
+    pub fn merge_from(&mut self, newer: InfoServiceEntity) {
+        self.name = newer.name.or(self.name.take());
+        self.namespace = newer.namespace.or(self.namespace.take());
+        self.kind = newer.kind.or(self.kind.take());
+        self.service_type = newer.service_type.or(self.service_type.take());
+        self.ingress_class = newer.ingress_class.or(self.ingress_class.take());
+        self.load_balancer_address = newer.load_balancer_address.or(self.load_balancer_address.take());
+        self.creation_timestamp = self.creation_timestamp.or(newer.creation_timestamp);
+        self.first_seen_at = self.first_seen_at.or(newer.first_seen_at);
+        self.resource_version = newer.resource_version.or(self.resource_version.take());
+        self.last_updated_info_at = newer.last_updated_info_at.or(self.last_updated_info_at);
+        self.label = newer.label.or(self.label.take());
+    }
+}
@@ -0,0 +1,149 @@
+use crate::core::persistence::info::k8s::info_dynamic_fs_adapter_trait::InfoDynamicFsAdapterTrait;
+use crate::core::persistence::info::k8s::lifecycle::{format_segments, parse_segments};
+use crate::core::persistence::info::k8s::service::info_service_entity::InfoServiceEntity;
+use crate::core::persistence::info::path::info_k8s_service_file_path;
+use crate::core::persistence::storage_backend::storage_backend;
+use anyhow::{anyhow, Context, Result};
+use tracing::debug;
+
+/// File-based FS adapter for `InfoServiceEntity`.
+///
+/// Each Service / Ingress has its own file at `data/info/k8s/service/{uid}/info.rci`.
+/// Uses the same key–value text format as the pod info files.
+pub struct InfoServiceFsAdapter;
+
+impl InfoDynamicFsAdapterTrait<InfoServiceEntity> for InfoServiceFsAdapter {
+    fn read(&self, uid: &str) -> Result<InfoServiceEntity> {
+        let path = info_k8s_service_file_path(uid);
+        let Some(text) = storage_backend().read_record(&path)? else {
+            return Err(anyhow!("Missing service info file '{}'", path.display()));
+        };
+        let mut v = InfoServiceEntity::default();
+
+        for line in text.lines() {
+            if let Some((key, val)) = line.split_once(':') {
+                let key = key.trim().to_uppercase();
+                let val = val.trim().to_string();
+                if val.is_empty() {
+                    continue;
+                }
+
+                match key.as_str() {
+                    // Identity
+                    "NAME" => v.name = Some(val),
+                    "NAMESPACE" => v.namespace = Some(val),
+                    "UID" => v.uid = Some(val),
+                    "KIND" => v.kind = Some(val),
+
+                    // Spec
+                    "SERVICE_TYPE" => v.service_type = Some(val),
+                    "SERVICE_TYPES" => v.service_types = parse_segments(&val),
+                    "INGRESS_CLASS" => v.ingress_class = Some(val),
+                    "LOAD_BALANCER_ADDRESS" => v.load_balancer_address = Some(val),
+
+                    // Lifecycle
+                    "CREATION_TIMESTAMP" => v.creation_timestamp = val.parse().ok(),
+                    "FIRST_SEEN_AT" => v.first_seen_at = val.parse().ok(),
+                    "DELETED_AT" => v.deleted_at = val.parse().ok(),
+                    "RESOURCE_VERSION" => v.resource_version = Some(val),
+                    "LAST_UPDATED_INFO_AT" => v.last_updated_info_at = val.parse().ok(),
+                    "DELETED" => v.deleted = Some(val == "true"),
+                    "LAST_CHECK_DELETED_COUNT" => v.last_check_deleted_count = val.parse().ok(),
+
+                    // Metadata
+                    "LABEL" => v.label = Some(val),
+                    _ => {}
+                }
+            }
+        }
+
+        Ok(v)
+    }
+
+    fn insert(&self, data: &InfoServiceEntity) -> Result<()> {
+        let uid = data
+            .uid
+            .as_ref()
+            .ok_or_else(|| anyhow!("Missing uid in InfoServiceEntity"))?;
+
+        self.write(uid, data)
+    }
+
+    fn update(&self, data: &InfoServiceEntity) -> Result<()> {
+        let uid = data
+            .uid
+            .as_ref()
+            .ok_or_else(|| anyhow!("Missing uid in InfoServiceEntity"))?;
+
+        self.write(uid, data)
+            .with_context(|| format!("Failed to write service info for '{}'", uid))
+    }
+
+    fn delete(&self, uid: &str) -> Result<()> {
+        let path = info_k8s_service_file_path(uid);
+        storage_backend().delete_record(&path).context("Failed to delete service info file")?;
+        Ok(())
+    }
+
+    fn exists(&self, uid: &str) -> Result<bool> {
+        let path = info_k8s_service_file_path(uid);
+        storage_backend().record_exists(&path)
+    }
+}
+
+impl InfoServiceFsAdapter {
+    /// Writes the service info record atomically.
+    pub fn write(&self, uid: &str, data: &InfoServiceEntity) -> Result<()> {
+        use std::fmt::Write;
+
+        let path = info_k8s_service_file_path(uid);
+        let mut f = String::new();
+
+        macro_rules! write_field {
+            ($key:expr, $val:expr) => {{
+                match &$val {
+                    Some(v) => writeln!(f, "{}:{}", $key, v)?,
+                    None => writeln!(f, "{}:", $key)?,
+                }
+            }};
+        }
+
+        macro_rules! write_datetime {
+            ($key:expr, $val:expr) => {{
+                match &$val {
+                    Some(dt) => writeln!(f, "{}:{}", $key, dt.to_rfc3339())?,
+                    None => writeln!(f, "{}:", $key)?,
+                }
+            }};
+        }
+
+        // --- Identity ---
+        write_field!("NAME", data.name);
+        write_field!("NAMESPACE", data.namespace);
+        write_field!("UID", data.uid);
+        write_field!("KIND", data.kind);
+
+        // --- Spec ---
+        write_field!("SERVICE_TYPE", data.service_type);
+        writeln!(f, "SERVICE_TYPES:{}", format_segments(&data.service_types))?;
+        write_field!("INGRESS_CLASS", data.ingress_class);
+        write_field!("LOAD_BALANCER_ADDRESS", data.load_balancer_address);
+
+        // --- Lifecycle ---
+        write_datetime!("CREATION_TIMESTAMP", data.creation_timestamp);
+        write_datetime!("FIRST_SEEN_AT", data.first_seen_at);
+        write_datetime!("DELETED_AT", data.deleted_at);
+        write_field!("RESOURCE_VERSION", data.resource_version);
+        write_datetime!("LAST_UPDATED_INFO_AT", data.last_updated_info_at);
+        write_field!("DELETED", data.deleted.map(|v| v.to_string()));
+        write_field!("LAST_CHECK_DELETED_COUNT", data.last_check_deleted_count.map(|v| v.to_string()));
+
+        // --- Metadata ---
+        write_field!("LABEL", data.label);
+
+        storage_backend().write_record(&path, &f)?;
+
+        debug!("💾 Successfully wrote service info.rci for '{}'", uid);
+        Ok(())
+    }
+}
@@ -0,0 +1,55 @@
+use crate::core::persistence::info::k8s::info_dynamic_fs_adapter_trait::InfoDynamicFsAdapterTrait;
+use crate::core::persistence::info::k8s::service::info_service_api_repository_trait::InfoServiceApiRepository;
+use crate::core::persistence::info::k8s::service::info_service_collector_repository_trait::InfoServiceCollectorRepository;
+use crate::core::persistence::info::k8s::service::info_service_entity::InfoServiceEntity;
+use crate::core::persistence::info::k8s::service::info_service_fs_adapter::InfoServiceFsAdapter;
+use crate::core::persistence::info::path::info_k8s_service_dir_path;
+use crate::core::persistence::storage_backend::storage_backend;
+use anyhow::Result;
+use tracing::warn;
+
+/// Repository for Service / Ingress info bridging traits to the filesystem adapter.
+pub struct InfoServiceRepository {
+    adapter: InfoServiceFsAdapter,
+}
+
+impl InfoServiceRepository {
+    pub fn new() -> Self {
+        Self {
+            adapter: InfoServiceFsAdapter,
+        }
+    }
+}
+
+impl Default for InfoServiceRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InfoServiceApiRepository for InfoServiceRepository {
+    fn fs_adapter(&self) -> &dyn InfoDynamicFsAdapterTrait<InfoServiceEntity> {
+        &self.adapter
+    }
+
+    fn list(&self) -> Result<Vec<InfoServiceEntity>> {
+        let mut services = Vec::new();
+        for uid in storage_backend().list_keys(&info_k8s_service_dir_path())? {
+            match self.adapter.read(&uid) {
+                Ok(info) => services.push(info),
+                Err(err) => warn!(error = %err, uid, "Skipping unreadable service info"),
+            }
+        }
+        Ok(services)
+    }
+}
+
+impl InfoServiceCollectorRepository for InfoServiceRepository {
+    fn fs_adapter(&self) -> &dyn InfoDynamicFsAdapterTrait<InfoServiceEntity> {
+        &self.adapter
+    }
+
+    fn list_uids(&self) -> Result<Vec<String>> {
+        storage_backend().list_keys(&info_k8s_service_dir_path())
+    }
+}
@@ -0,0 +1,5 @@
+pub mod info_service_entity;
+pub mod info_service_fs_adapter;
+pub mod info_service_collector_repository_trait;
+pub mod info_service_api_repository_trait;
+pub mod info_service_repository;
@@ -0,0 +1,17 @@
+use super::info_volume_entity::InfoVolumeEntity;
+use crate::core::persistence::info::k8s::info_dynamic_fs_adapter_trait::InfoDynamicFsAdapterTrait;
+use anyhow::Result;
+
+/// API repository trait for persistent volume claims and volumes.
+///
+/// The API only reads the records written by the collector.
+pub trait InfoVolumeApiRepository: Send + Sync {
+    fn fs_adapter(&self) -> &dyn InfoDynamicFsAdapterTrait<InfoVolumeEntity>;
+
+    fn read(&self, uid: &str) -> Result<InfoVolumeEntity> {
+        self.fs_adapter().read(uid)
+    }
+
+    /// Every stored record, deleted ones included.
+    fn list(&self) -> Result<Vec<InfoVolumeEntity>>;
+}
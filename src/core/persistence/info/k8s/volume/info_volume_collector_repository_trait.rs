@@ -0,0 +1,30 @@
+use super::info_volume_entity::InfoVolumeEntity;
+use crate::core::persistence::info::k8s::info_dynamic_fs_adapter_trait::InfoDynamicFsAdapterTrait;
+use anyhow::Result;
+
+/// Collector repository trait for persistent volume claims and volumes.
+///
+/// Collectors create records for new objects and keep existing ones up to
+/// date, including marking them deleted.
+pub trait InfoVolumeCollectorRepository: Send + Sync {
+    fn fs_adapter(&self) -> &dyn InfoDynamicFsAdapterTrait<InfoVolumeEntity>;
+
+    fn read(&self, uid: &str) -> Result<InfoVolumeEntity> {
+        self.fs_adapter().read(uid)
+    }
+
+    fn create(&self, data: &InfoVolumeEntity) -> Result<()> {
+        self.fs_adapter().insert(data)
+    }
+
+    fn update(&self, data: &InfoVolumeEntity) -> Result<()> {
+        self.fs_adapter().update(data)
+    }
+
+    fn exists(&self, uid: &str) -> Result<bool> {
+        self.fs_adapter().exists(uid)
+    }
+
+    /// Uids of every stored record, deleted ones included.
+    fn list_uids(&self) -> Result<Vec<String>>;
+}
@@ -0,0 +1,107 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::core::persistence::info::k8s::lifecycle::{push_segment, LifecycleSegment};
+
+/// Lifecycle information for a PersistentVolumeClaim or PersistentVolume.
+///
+/// Records are kept after the object is deleted (`deleted = true`) so volume
+/// capacity stays billable for past windows. The phase history decides which
+/// parts of the lifetime are billed to the claim and which are waste.
+/// Stored at: `data/info/k8s/volume/{uid}/info.rci`
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct InfoVolumeEntity {
+    // --- Identity ---
+    pub name: Option<String>,
+    /// Claims only; volumes are cluster-scoped
+    pub namespace: Option<String>,
+    pub uid: Option<String>,
+    /// `PersistentVolumeClaim` or `PersistentVolume`
+    pub kind: Option<String>,
+
+    // --- Spec ---
+    pub storage_class: Option<String>,
+    /// Provisioned capacity
+    pub capacity_bytes: Option<f64>,
+    /// Claims: the volume they are bound to
+    pub volume_name: Option<String>,
+    /// Volumes: `<namespace>/<claim>` they are or were bound to
+    pub claim: Option<String>,
+
+    // --- Status ---
+    /// Phase reported by the API on the last update
+    pub phase: Option<String>,
+    /// Volumes: `status.lastPhaseTransitionTime`, when the API reports it
+    pub phase_transition_at: Option<DateTime<Utc>>,
+    /// Phases over the lifetime, oldest first
+    pub phases: Vec<LifecycleSegment>,
+
+    // --- Lifecycle ---
+    pub creation_timestamp: Option<DateTime<Utc>>,
+    pub first_seen_at: Option<DateTime<Utc>>,
+    pub deleted_at: Option<DateTime<Utc>>,
+    pub resource_version: Option<String>,
+
+    pub last_updated_info_at: Option<DateTime<Utc>>,
+    pub deleted: Option<bool>,
+    pub last_check_deleted_count: Option<u64>,
+
+    // --- Metadata ---
+    pub label: Option<String>,
+}
+
+impl InfoVolumeEntity {
+    pub fn is_claim(&self) -> bool {
+        self.kind.as_deref() == Some("PersistentVolumeClaim")
+    }
+
+    /// Time range the object existed, with live objects running until `now`.
+    pub fn lifetime(&self, now: DateTime<Utc>) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+        let start = self.creation_timestamp.or(self.first_seen_at)?;
+        let end = if self.deleted == Some(true) {
+            self.deleted_at.or(self.last_updated_info_at)?
+        } else {
+            now
+        };
+        (end > start).then_some((start, end))
+    }
+
+    /// Adds the current `phase` to the history when it changed.
+    ///
+    /// The transition time comes from the API when it reports one. Otherwise
+    /// the phase an object starts in (`Pending` claims, `Available` volumes,
+    /// or `Bound` when binding happened before the first observation) is
+    /// dated from creation, and any later phase from `now`.
+    pub fn record_phase(&mut self, now: DateTime<Utc>) {
+        let Some(phase) = self.phase.clone() else {
+            return;
+        };
+        let initial = self.phases.is_empty() && matches!(phase.as_str(), "Pending" | "Available" | "Bound");
+        let since = match self.phase_transition_at {
+            Some(at) => at,
+            None if initial => self.creation_timestamp.unwrap_or(now),
+            None => now,
+        };
+        push_segment(&mut self.phases, &phase, since);
+    }
+
+    /// Copies the live fields of `newer`, keeping the lifecycle recorded so far.
+    /// Call [`Self::record_phase`] afterwards to extend the phase history.
+    pub fn merge_from(&mut self, newer: InfoVolumeEntity) {
+        self.name = newer.name.or(self.name.take());
+        self.namespace = newer.namespace.or(self.namespace.take());
+        self.kind = newer.kind.or(self.kind.take());
+        self.storage_class = newer.storage_class.or(self.storage_class.take());
+        self.capacity_bytes = newer.capacity_bytes.or(self.capacity_bytes.take());
+        self.volume_name = newer.volume_name.or(self.volume_name.take());
+        self.claim = newer.claim.or(self.claim.take());
+        self.phase = newer.phase.or(self.phase.take());
+        self.phase_transition_at = newer.phase_transition_at;
+        self.creation_timestamp = self.creation_timestamp.or(newer.creation_timestamp);
+        self.first_seen_at = self.first_seen_at.or(newer.first_seen_at);
+        self.resource_version = newer.resource_version.or(self.resource_version.take());
+        self.last_updated_info_at = newer.last_updated_info_at.or(self.last_updated_info_at);
+        self.label = newer.label.or(self.label.take());
+    }
+}
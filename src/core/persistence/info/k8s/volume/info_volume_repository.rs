@@ -0,0 +1,55 @@
+use crate::core::persistence::info::k8s::info_dynamic_fs_adapter_trait::InfoDynamicFsAdapterTrait;
+use crate::core::persistence::info::k8s::volume::info_volume_api_repository_trait::InfoVolumeApiRepository;
+use crate::core::persistence::info::k8s::volume::info_volume_collector_repository_trait::InfoVolumeCollectorRepository;
+use crate::core::persistence::info::k8s::volume::info_volume_entity::InfoVolumeEntity;
+use crate::core::persistence::info::k8s::volume::info_volume_fs_adapter::InfoVolumeFsAdapter;
+use crate::core::persistence::info::path::info_k8s_volume_dir_path;
+use crate::core::persistence::storage_backend::storage_backend;
+use anyhow::Result;
+use tracing::warn;
+
+/// Repository for claim / volume info bridging traits to the filesystem adapter.
+pub struct InfoVolumeRepository {
+    adapter: InfoVolumeFsAdapter,
+}
+
+impl InfoVolumeRepository {
+    pub fn new() -> Self {
+        Self {
+            adapter: InfoVolumeFsAdapter,
+        }
+    }
+}
+
+impl Default for InfoVolumeRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InfoVolumeApiRepository for InfoVolumeRepository {
+    fn fs_adapter(&self) -> &dyn InfoDynamicFsAdapterTrait<InfoVolumeEntity> {
+        &self.adapter
+    }
+
+    fn list(&self) -> Result<Vec<InfoVolumeEntity>> {
+        let mut volumes = Vec::new();
+        for uid in storage_backend().list_keys(&info_k8s_volume_dir_path())? {
+            match self.adapter.read(&uid) {
+                Ok(info) => volumes.push(info),
+                Err(err) => warn!(error = %err, uid, "Skipping unreadable volume info"),
+            }
+        }
+        Ok(volumes)
+    }
+}
+
+impl InfoVolumeCollectorRepository for InfoVolumeRepository {
+    fn fs_adapter(&self) -> &dyn InfoDynamicFsAdapterTrait<InfoVolumeEntity> {
+        &self.adapter
+    }
+
+    fn list_uids(&self) -> Result<Vec<String>> {
+        storage_backend().list_keys(&info_k8s_volume_dir_path())
+    }
+}
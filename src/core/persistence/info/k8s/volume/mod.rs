@@ -0,0 +1,5 @@
+pub mod info_volume_entity;
+pub mod info_volume_fs_adapter;
+pub mod info_volume_collector_repository_trait;
+pub mod info_volume_api_repository_trait;
+pub mod info_volume_repository;
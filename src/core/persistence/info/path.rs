@@ -19,10 +19,31 @@ pub fn info_unit_price_path() -> PathBuf {
     info_path("unit_price.rci")
 }
 
+/// Directory holding one file per effective-dated unit price version.
+pub fn info_unit_price_history_dir_path() -> PathBuf {
+    info_path("unit_price_history")
+}
+
+pub fn info_unit_price_history_file_path(effective_from_ts: i64) -> PathBuf {
+    info_path(format!("unit_price_history/{}.rci", effective_from_ts))
+}
+
+pub fn info_exchange_rate_path() -> PathBuf {
+    info_path("exchange_rate.rci")
+}
+
 pub fn info_alert_path() -> PathBuf {
     info_path("alerts.rci")
 }
 
+pub fn info_ownership_path() -> PathBuf {
+    info_path("ownership_rules.rci")
+}
+
+pub fn info_storage_class_price_path() -> PathBuf {
+    info_path("storage_class_prices.rci")
+}
+
 pub fn info_llm_path() -> PathBuf {
     info_path("llm.rci")
 }
@@ -56,6 +77,24 @@ pub fn info_k8s_pod_file_path(pod_key: &str) -> PathBuf {
     info_k8s_path(format!("pod/{}/info.rci", pod_key))
 }
 
+// Dynamic info: service (Services and Ingresses)
+pub fn info_k8s_service_dir_path() -> PathBuf {
+    info_k8s_path("service")
+}
+
+pub fn info_k8s_service_file_path(service_key: &str) -> PathBuf {
+    info_k8s_path(format!("service/{}/info.rci", service_key))
+}
+
+// Dynamic info: volume (PersistentVolumeClaims and PersistentVolumes)
+pub fn info_k8s_volume_dir_path() -> PathBuf {
+    info_k8s_path("volume")
+}
+
+pub fn info_k8s_volume_file_path(volume_key: &str) -> PathBuf {
+    info_k8s_path(format!("volume/{}/info.rci", volume_key))
+}
+
 // Dynamic info: node
 pub fn info_k8s_node_dir_path() -> PathBuf {
     info_k8s_path("node".to_string())
@@ -1,13 +1,11 @@
 use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::metric_segment::segment_stem;
+use crate::core::persistence::storage_backend::storage_backend;
 use crate::core::persistence::metrics::k8s::container::metric_container_entity::MetricContainerEntity;
 use anyhow::{anyhow, Result};
 use chrono::{DateTime, NaiveDate, Utc, Datelike};
-use std::io::BufWriter;
 use std::{
-    fs::File,
-    fs::{self, OpenOptions},
-    io::Write,
-    io::{BufRead, BufReader},
+    io::BufRead,
     path::Path,
 };
 use std::path::PathBuf;
@@ -22,7 +20,7 @@ pub struct MetricContainerDayFsAdapter;
 impl MetricContainerDayFsAdapter {
     fn delete_batch(batch: &[PathBuf]) -> Result<()> {
         for path in batch {
-            match fs::remove_file(path) {
+            match storage_backend().remove_segment_file(path) {
                 Ok(_) => tracing::info!("Deleted old metric file {:?}", path),
                 Err(e) => tracing::error!("Failed to delete {:?}: {}", path, e),
             }
@@ -37,7 +35,8 @@ impl MetricContainerDayFsAdapter {
 
     fn parse_line(header: &[&str], line: &str) -> Option<MetricContainerEntity> {
         let parts: Vec<&str> = line.split('|').collect();
-        if parts.len() != header.len() {
+        // Rows written before the GPU / peak columns were added are shorter
+        if parts.len() > header.len() || parts.len() < 11 {
             return None;
         }
 
@@ -55,6 +54,15 @@ impl MetricContainerDayFsAdapter {
             fs_capacity_bytes: parts[8].parse().ok(),
             fs_inodes_used: parts[9].parse().ok(),
             fs_inodes: parts[10].parse().ok(),
+            gpu_count: parts.get(11).and_then(|v| v.parse().ok()),
+            gpu_utilization_percent: parts.get(12).and_then(|v| v.parse().ok()),
+            gpu_memory_used_bytes: parts.get(13).and_then(|v| v.parse().ok()),
+            cpu_usage_nano_cores_max: parts.get(14).and_then(|v| v.parse().ok()),
+            cpu_usage_nano_cores_p95: parts.get(15).and_then(|v| v.parse().ok()),
+            cpu_usage_nano_cores_p99: parts.get(16).and_then(|v| v.parse().ok()),
+            memory_working_set_bytes_max: parts.get(17).and_then(|v| v.parse().ok()),
+            memory_working_set_bytes_p95: parts.get(18).and_then(|v| v.parse().ok()),
+            memory_working_set_bytes_p99: parts.get(19).and_then(|v| v.parse().ok()),
         })
     }
 
@@ -78,19 +86,6 @@ impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerDayFsAdapter
         let path_str = self.build_path_for(container, now_date);
         let path = Path::new(&path_str);
 
-        if let Some(parent) = path.parent() {
-            fs::create_dir_all(parent)?;
-        }
-
-        // let new = !path.exists();
-
-        // ✅ open file and wrap in BufWriter
-        let file = OpenOptions::new()
-            .create(true)
-            .append(true)
-            .open(&path)?;
-        let mut writer = BufWriter::new(file);
-
         // Write header if file newly created
         // if new {
         //     self.ensure_header(path, &mut writer)?;
@@ -98,7 +93,7 @@ impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerDayFsAdapter
 
         // Format the row
         let row = format!(
-            "{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}\n",
+            "{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}\n",
             dto.time.to_rfc3339_opts(chrono::SecondsFormat::Secs, false),
             Self::opt(dto.cpu_usage_nano_cores),
             Self::opt(dto.cpu_usage_core_nano_seconds),
@@ -111,14 +106,19 @@ impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerDayFsAdapter
             Self::opt(dto.fs_capacity_bytes),
             Self::opt(dto.fs_inodes_used),
             Self::opt(dto.fs_inodes),
+            Self::opt(dto.gpu_count),
+            Self::opt(dto.gpu_utilization_percent),
+            Self::opt(dto.gpu_memory_used_bytes),
+            Self::opt(dto.cpu_usage_nano_cores_max),
+            Self::opt(dto.cpu_usage_nano_cores_p95),
+            Self::opt(dto.cpu_usage_nano_cores_p99),
+            Self::opt(dto.memory_working_set_bytes_max),
+            Self::opt(dto.memory_working_set_bytes_p95),
+            Self::opt(dto.memory_working_set_bytes_p99),
         );
 
 
-        // ✅ write to buffer
-        writer.write_all(row.as_bytes())?;
-
-        // ✅ ensure everything flushed to disk
-        writer.flush()?;
+        storage_backend().append_rows(path, &row)?;
         Ok(())
     }
 
@@ -167,6 +167,40 @@ impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerDayFsAdapter
             if count > 0 { Some(sum / count) } else { None }
         };
 
+        // GPU gauges are only present while a GPU is attached, so they are
+        // time-weighted over the window like the pod day rows
+        let twa_u64 = |f: fn(&MetricContainerEntity) -> Option<u64>| -> Option<u64> {
+            let mut pts: Vec<(DateTime<Utc>, u64)> =
+                rows.iter().filter_map(|r| f(r).map(|v| (r.time, v))).collect();
+
+            if pts.is_empty() {
+                return None;
+            }
+            pts.sort_by_key(|(t, _)| *t);
+
+            let window_ns = (end - start).num_nanoseconds()? as f64;
+            if window_ns <= 0.0 {
+                return Some(pts.last().unwrap().1);
+            }
+
+            let mut area: f64 = 0.0;
+
+            for i in 0..pts.len() {
+                let (t_i, v_i) = pts[i];
+                let seg_end = if i + 1 < pts.len() { pts[i + 1].0 } else { end };
+
+                let seg_start = std::cmp::max(t_i, start);
+                let seg_end = std::cmp::min(seg_end, end);
+
+                if seg_end > seg_start {
+                    let seg_ns = (seg_end - seg_start).num_nanoseconds()? as f64;
+                    area += (v_i as f64) * seg_ns;
+                }
+            }
+
+            Some((area / window_ns).round() as u64)
+        };
+
         // ---- 2️⃣ deltas (with counter reset detection)
         let delta = |f: fn(&MetricContainerEntity) -> Option<u64>| -> Option<u64> {
             match (f(first), f(last)) {
@@ -178,6 +212,11 @@ impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerDayFsAdapter
             }
         };
 
+        // --- Peaks: highest hourly value (see `PeakStats`)
+        let peak = |f: fn(&MetricContainerEntity) -> Option<u64>| -> Option<u64> {
+            rows.iter().filter_map(f).max()
+        };
+
         // ---- 3️⃣ final aggregated entity
         let aggregated = MetricContainerEntity {
             time: end,
@@ -194,6 +233,24 @@ impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerDayFsAdapter
             fs_capacity_bytes:               last.fs_capacity_bytes,
             fs_inodes_used:                  avg_or_none(fs_inodes_used_sum),
             fs_inodes:                       last.fs_inodes,
+
+            gpu_count:                       twa_u64(|r| r.gpu_count),
+            gpu_utilization_percent:         twa_u64(|r| r.gpu_utilization_percent),
+            gpu_memory_used_bytes:           twa_u64(|r| r.gpu_memory_used_bytes),
+
+            // Peaks (hourly peaks)
+            cpu_usage_nano_cores_max: peak(|r| r.cpu_usage_nano_cores_max.or(r.cpu_usage_nano_cores)),
+            cpu_usage_nano_cores_p95: peak(|r| r.cpu_usage_nano_cores_p95.or(r.cpu_usage_nano_cores)),
+            cpu_usage_nano_cores_p99: peak(|r| r.cpu_usage_nano_cores_p99.or(r.cpu_usage_nano_cores)),
+            memory_working_set_bytes_max: peak(|r| {
+                r.memory_working_set_bytes_max.or(r.memory_working_set_bytes)
+            }),
+            memory_working_set_bytes_p95: peak(|r| {
+                r.memory_working_set_bytes_p95.or(r.memory_working_set_bytes)
+            }),
+            memory_working_set_bytes_p99: peak(|r| {
+                r.memory_working_set_bytes_p99.or(r.memory_working_set_bytes)
+            }),
         };
 
         // ---- 4️⃣ append row into correct day file
@@ -207,24 +264,18 @@ impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerDayFsAdapter
         const BATCH_SIZE: usize = 200;
 
         let dir = metric_k8s_container_key_day_dir_path(container_key);
-        if !dir.exists() {
-            return Ok(());
-        }
 
         let cutoff_year = before.year();
         let mut batch = Vec::with_capacity(BATCH_SIZE);
 
-        for entry in fs::read_dir(&dir)? {
-            let entry = entry?;
-            let path = entry.path();
-
-            // Only delete *.rcd
-            if path.extension().and_then(|e| e.to_str()) != Some("rcd") {
+        for path in storage_backend().list_segment_files(&dir)? {
+            // Only delete *.rcd / *.rcd.gz
+            if segment_stem(&path).is_none() {
                 continue;
             }
 
             // Extract filename stem safely
-            let stem = match path.file_stem().and_then(|s| s.to_str()) {
+            let stem = match segment_stem(&path) {
                 Some(s) => s.trim(),
                 None => {
                     tracing::warn!("Skipping invalid UTF-8 filename: {:?}", path);
@@ -423,7 +474,7 @@ impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerDayFsAdapter
         limit: Option<usize>,
         offset: Option<usize>,
     ) -> Result<Vec<MetricContainerEntity>> {
-        const HEADER: [&str; 11] = [
+        const HEADER: [&str; 20] = [
             "TIME",
             "CPU_USAGE_NANO_CORES",
             "CPU_USAGE_CORE_NANO_SECONDS",
@@ -435,6 +486,15 @@ impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerDayFsAdapter
             "FS_CAPACITY_BYTES",
             "FS_INODES_USED",
             "FS_INODES",
+            "GPU_COUNT",
+            "GPU_UTILIZATION_PERCENT",
+            "GPU_MEMORY_USED_BYTES",
+            "CPU_USAGE_NANO_CORES_MAX",
+            "CPU_USAGE_NANO_CORES_P95",
+            "CPU_USAGE_NANO_CORES_P99",
+            "MEMORY_WORKING_SET_BYTES_MAX",
+            "MEMORY_WORKING_SET_BYTES_P95",
+            "MEMORY_WORKING_SET_BYTES_P99",
         ];
 
         let mut data = Vec::new();
@@ -446,14 +506,13 @@ impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerDayFsAdapter
             let path = self.build_path_for(object_name, current_date);
             let path_obj = Path::new(&path);
 
-            if !path_obj.exists() {
+            if !storage_backend().segment_exists(path_obj) {
                 current_date = NaiveDate::from_ymd_opt(current_date.year() + 1, 1, 1)
                     .unwrap_or(current_date);
                 continue;
             }
 
-            if let Ok(file) = File::open(&path_obj) {
-                let reader = BufReader::new(file);
+            if let Ok(reader) = storage_backend().open_segment_from(path_obj, start) {
                 for line_result in reader.lines() {
                     let line = match line_result {
                         Ok(ref l) if !l.trim().is_empty() => l,
@@ -464,7 +523,7 @@ impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerDayFsAdapter
                             continue;
                         }
                         if row.time > end {
-                            break;
+                            continue;
                         }
                         data.push(row);
                     }
@@ -1,13 +1,12 @@
 use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::metric_segment::segment_stem;
+use crate::core::persistence::storage_backend::storage_backend;
 use crate::core::persistence::metrics::k8s::container::metric_container_entity::MetricContainerEntity;
+use crate::core::util::peak_stats::PeakStats;
 use anyhow::{anyhow, Result};
 use chrono::{DateTime, NaiveDate, Datelike, Utc};
-use std::io::BufWriter;
 use std::{
-    fs::File,
-    fs::{self, OpenOptions},
-    io::Write,
-    io::{BufRead, BufReader},
+    io::BufRead,
     path::Path,
 };
 use std::path::PathBuf;
@@ -38,7 +37,7 @@ impl MetricContainerHourFsAdapter {
 
     fn delete_batch(batch: &[PathBuf]) -> Result<()> {
         for path in batch {
-            match fs::remove_file(path) {
+            match storage_backend().remove_segment_file(path) {
                 Ok(_) => tracing::info!("Deleted old metric file {:?}", path),
                 Err(e) => tracing::error!("Failed to delete {:?}: {}", path, e),
             }
@@ -53,7 +52,8 @@ impl MetricContainerHourFsAdapter {
 
     fn parse_line(header: &[&str], line: &str) -> Option<MetricContainerEntity> {
         let parts: Vec<&str> = line.split('|').collect();
-        if parts.len() != header.len() {
+        // Rows written before the GPU / peak columns were added are shorter
+        if parts.len() > header.len() || parts.len() < 11 {
             return None;
         }
 
@@ -71,6 +71,15 @@ impl MetricContainerHourFsAdapter {
             fs_capacity_bytes: parts[8].parse().ok(),
             fs_inodes_used: parts[9].parse().ok(),
             fs_inodes: parts[10].parse().ok(),
+            gpu_count: parts.get(11).and_then(|v| v.parse().ok()),
+            gpu_utilization_percent: parts.get(12).and_then(|v| v.parse().ok()),
+            gpu_memory_used_bytes: parts.get(13).and_then(|v| v.parse().ok()),
+            cpu_usage_nano_cores_max: parts.get(14).and_then(|v| v.parse().ok()),
+            cpu_usage_nano_cores_p95: parts.get(15).and_then(|v| v.parse().ok()),
+            cpu_usage_nano_cores_p99: parts.get(16).and_then(|v| v.parse().ok()),
+            memory_working_set_bytes_max: parts.get(17).and_then(|v| v.parse().ok()),
+            memory_working_set_bytes_p95: parts.get(18).and_then(|v| v.parse().ok()),
+            memory_working_set_bytes_p99: parts.get(19).and_then(|v| v.parse().ok()),
         })
     }
 
@@ -94,19 +103,6 @@ impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerHourFsAdapter
         let path_str = self.build_path_for(container, now_date);
         let path = Path::new(&path_str);
 
-        if let Some(parent) = path.parent() {
-            fs::create_dir_all(parent)?;
-        }
-
-        // let new = !path.exists();
-
-        // ✅ open file and wrap in BufWriter
-        let file = OpenOptions::new()
-            .create(true)
-            .append(true)
-            .open(&path)?;
-        let mut writer = BufWriter::new(file);
-
         // Write header if file newly created
         // if new {
         //     self.ensure_header(path, &mut writer)?;
@@ -114,7 +110,7 @@ impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerHourFsAdapter
 
         // Format the row
         let row = format!(
-            "{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}\n",
+            "{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}\n",
             dto.time.to_rfc3339_opts(chrono::SecondsFormat::Secs, false),
             Self::opt(dto.cpu_usage_nano_cores),
             Self::opt(dto.cpu_usage_core_nano_seconds),
@@ -127,14 +123,19 @@ impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerHourFsAdapter
             Self::opt(dto.fs_capacity_bytes),
             Self::opt(dto.fs_inodes_used),
             Self::opt(dto.fs_inodes),
+            Self::opt(dto.gpu_count),
+            Self::opt(dto.gpu_utilization_percent),
+            Self::opt(dto.gpu_memory_used_bytes),
+            Self::opt(dto.cpu_usage_nano_cores_max),
+            Self::opt(dto.cpu_usage_nano_cores_p95),
+            Self::opt(dto.cpu_usage_nano_cores_p99),
+            Self::opt(dto.memory_working_set_bytes_max),
+            Self::opt(dto.memory_working_set_bytes_p95),
+            Self::opt(dto.memory_working_set_bytes_p99),
         );
 
 
-        // ✅ write to buffer
-        writer.write_all(row.as_bytes())?;
-
-        // ✅ ensure everything flushed to disk
-        writer.flush()?;
+        storage_backend().append_rows(path, &row)?;
         Ok(())
     }
 
@@ -175,6 +176,11 @@ impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerHourFsAdapter
             }
         };
 
+        // --- Peaks over the minute samples (the only record once minute data expires).
+        let cpu_peaks = PeakStats::from_samples(rows.iter().filter_map(|r| r.cpu_usage_nano_cores));
+        let memory_peaks =
+            PeakStats::from_samples(rows.iter().filter_map(|r| r.memory_working_set_bytes));
+
         let aggregated = MetricContainerEntity {
             time: end, // time marker = end of the aggregation window
 
@@ -193,6 +199,19 @@ impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerHourFsAdapter
             fs_capacity_bytes: last.fs_capacity_bytes,
             fs_inodes_used: avg(|r| r.fs_inodes_used),
             fs_inodes: last.fs_inodes,
+
+            // GPU
+            gpu_count: avg(|r| r.gpu_count),
+            gpu_utilization_percent: avg(|r| r.gpu_utilization_percent),
+            gpu_memory_used_bytes: avg(|r| r.gpu_memory_used_bytes),
+
+            // Peaks (minute samples)
+            cpu_usage_nano_cores_max: cpu_peaks.max,
+            cpu_usage_nano_cores_p95: cpu_peaks.p95,
+            cpu_usage_nano_cores_p99: cpu_peaks.p99,
+            memory_working_set_bytes_max: memory_peaks.max,
+            memory_working_set_bytes_p95: memory_peaks.p95,
+            memory_working_set_bytes_p99: memory_peaks.p99,
         };
 
         // --- 3️⃣ Append the aggregated row into the hour-level file
@@ -205,9 +224,6 @@ impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerHourFsAdapter
         const BATCH_SIZE: usize = 200;
 
         let dir = metric_k8s_container_key_hour_dir_path(container_uid);
-        if !dir.exists() {
-            return Ok(());
-        }
 
         // Normalize cutoff to YYYY-MM-01
         let before_month = NaiveDate::from_ymd_opt(before.year(), before.month(), 1)
@@ -215,17 +231,14 @@ impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerHourFsAdapter
 
         let mut batch = Vec::with_capacity(BATCH_SIZE);
 
-        for entry in fs::read_dir(&dir)? {
-            let entry = entry?;
-            let path = entry.path();
-
-            // Only process `.rcd` files
-            if path.extension().and_then(|e| e.to_str()) != Some("rcd") {
+        for path in storage_backend().list_segment_files(&dir)? {
+            // Only process `.rcd` / `.rcd.gz` files
+            if segment_stem(&path).is_none() {
                 continue;
             }
 
             // Extract stem safely
-            let stem = match path.file_stem().and_then(|s| s.to_str()) {
+            let stem = match segment_stem(&path) {
                 Some(s) => s.trim(),
                 None => {
                     tracing::warn!("Skipping file with invalid UTF-8 filename: {:?}", path);
@@ -436,13 +449,13 @@ impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerHourFsAdapter
             let path = self.build_path_for(object_name, current_date);
             let path_obj = Path::new(&path);
 
-            if !path_obj.exists() {
+            if !storage_backend().segment_exists(path_obj) {
                 tracing::debug!("Hour metrics file missing for {} on {}", object_name, current_date);
                 current_date = current_date.checked_add_months(Months::new(1)).unwrap_or(current_date);
                 continue;
             }
 
-            let file = match File::open(&path_obj) {
+            let reader = match storage_backend().open_segment_from(path_obj, start) {
                 Ok(f) => f,
                 Err(e) => {
                     tracing::warn!("Cannot open {:?}: {}", path_obj, e);
@@ -451,7 +464,6 @@ impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerHourFsAdapter
                 }
             };
 
-            let reader = BufReader::new(file);
             let mut lines = reader.lines();
 
             // Handle empty files
@@ -475,6 +487,9 @@ impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerHourFsAdapter
                     "MEMORY_USAGE_BYTES", "MEMORY_WORKING_SET_BYTES", "MEMORY_RSS_BYTES",
                     "MEMORY_PAGE_FAULTS", "FS_USED_BYTES", "FS_CAPACITY_BYTES",
                     "FS_INODES_USED", "FS_INODES",
+                    "GPU_COUNT", "GPU_UTILIZATION_PERCENT", "GPU_MEMORY_USED_BYTES",
+                    "CPU_USAGE_NANO_CORES_MAX", "CPU_USAGE_NANO_CORES_P95", "CPU_USAGE_NANO_CORES_P99",
+                    "MEMORY_WORKING_SET_BYTES_MAX", "MEMORY_WORKING_SET_BYTES_P95", "MEMORY_WORKING_SET_BYTES_P99",
                 ];
 
                 if let Some(row) = Self::parse_line(&header, &first_line) {
@@ -498,7 +513,7 @@ impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerHourFsAdapter
                         continue;
                     }
                     if row.time > end {
-                        break;
+                        continue;
                     }
                     rows.push(row);
                 } else {
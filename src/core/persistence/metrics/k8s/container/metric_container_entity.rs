@@ -21,6 +21,19 @@ pub struct MetricContainerEntity {
     pub fs_inodes_used: Option<u64>,
     pub fs_inodes: Option<u64>,
 
+    // GPU (DCGM exporter)
+    pub gpu_count: Option<u64>,
+    pub gpu_utilization_percent: Option<u64>,
+    pub gpu_memory_used_bytes: Option<u64>,
+
+    // Peaks within the aggregation window (hour/day tiers only)
+    pub cpu_usage_nano_cores_max: Option<u64>,
+    pub cpu_usage_nano_cores_p95: Option<u64>,
+    pub cpu_usage_nano_cores_p99: Option<u64>,
+    pub memory_working_set_bytes_max: Option<u64>,
+    pub memory_working_set_bytes_p95: Option<u64>,
+    pub memory_working_set_bytes_p99: Option<u64>,
+
     // Swap (optional)
     // pub swap_used_bytes: Option<u64>,
     // pub swap_available_bytes: Option<u64>,
@@ -1,5 +1,6 @@
 use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
 use crate::core::persistence::metrics::k8s::container::metric_container_entity::MetricContainerEntity;
+use crate::core::persistence::metrics::metric_write_buffer::buffer_metric_row;
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 
@@ -7,9 +8,10 @@ use chrono::{DateTime, Utc};
 pub trait MetricContainerMinuteCollectorRepository: Send + Sync {
     fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricContainerEntity>;
 
-    /// Inserts one metric sample for a given container.
+    /// Buffers one metric sample for a given container; written on the next flush.
     fn append_row(&self, container_key: &str, data: &MetricContainerEntity, now:DateTime<Utc>) -> Result<()> {
-        self.fs_adapter().append_row(container_key, data, now)
+        let (path, row) = self.fs_adapter().encode_row(container_key, data, now)?;
+        buffer_metric_row(path, row)
     }
 
 }
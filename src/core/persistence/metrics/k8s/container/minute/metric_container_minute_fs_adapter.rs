@@ -1,13 +1,11 @@
 use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::metric_segment::segment_stem;
+use crate::core::persistence::storage_backend::storage_backend;
 use crate::core::persistence::metrics::k8s::container::metric_container_entity::MetricContainerEntity;
 use anyhow::{Result};
 use chrono::{DateTime, NaiveDate, Utc};
-use std::io::BufWriter;
 use std::{
-    fs::File,
-    fs::{self, OpenOptions},
-    io::Write,
-    io::{BufRead, BufReader},
+    io::BufRead,
     path::Path,
 };
 use std::path::PathBuf;
@@ -24,7 +22,7 @@ pub struct MetricContainerMinuteFsAdapter;
 impl MetricContainerMinuteFsAdapter {
     fn delete_batch(batch: &[PathBuf]) -> Result<()> {
         for path in batch {
-            match fs::remove_file(path) {
+            match storage_backend().remove_segment_file(path) {
                 Ok(_) => tracing::info!("Deleted old container metric {:?}", path),
                 Err(e) => tracing::error!("Failed to delete {:?}: {}", path, e),
             }
@@ -39,7 +37,8 @@ impl MetricContainerMinuteFsAdapter {
 
     fn parse_line(header: &[&str], line: &str) -> Option<MetricContainerEntity> {
         let parts: Vec<&str> = line.split('|').collect();
-        if parts.len() != header.len() {
+        // Rows written before the GPU columns were added are three fields shorter
+        if parts.len() != header.len() && parts.len() + 3 != header.len() {
             return None;
         }
 
@@ -57,6 +56,10 @@ impl MetricContainerMinuteFsAdapter {
             fs_capacity_bytes: parts[8].parse().ok(),
             fs_inodes_used: parts[9].parse().ok(),
             fs_inodes: parts[10].parse().ok(),
+            gpu_count: parts.get(11).and_then(|v| v.parse().ok()),
+            gpu_utilization_percent: parts.get(12).and_then(|v| v.parse().ok()),
+            gpu_memory_used_bytes: parts.get(13).and_then(|v| v.parse().ok()),
+            ..Default::default()
         })
     }
 
@@ -74,23 +77,9 @@ impl MetricContainerMinuteFsAdapter {
 }
 
 impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerMinuteFsAdapter {
-    fn append_row(&self, container: &str, dto: &MetricContainerEntity, now: DateTime<Utc>) -> Result<()> {
+    fn encode_row(&self, container: &str, dto: &MetricContainerEntity, now: DateTime<Utc>) -> Result<(PathBuf, String)> {
         let now_date = now.date_naive();
-        let path_str = self.build_path_for(container, now_date);
-        let path = Path::new(&path_str);
-
-        if let Some(parent) = path.parent() {
-            fs::create_dir_all(parent)?;
-        }
-
-        // let new = !path.exists();
-
-        // ✅ open file and wrap in BufWriter
-        let file = OpenOptions::new()
-            .create(true)
-            .append(true)
-            .open(&path)?;
-        let mut writer = BufWriter::new(file);
+        let path = self.build_path_for(container, now_date);
 
         // Write header if file newly created
         // if new {
@@ -99,7 +88,7 @@ impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerMinuteFsAdapt
 
         // Format the row
         let row = format!(
-            "{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}\n",
+            "{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}\n",
             dto.time.to_rfc3339_opts(chrono::SecondsFormat::Secs, false),
             Self::opt(dto.cpu_usage_nano_cores),
             Self::opt(dto.cpu_usage_core_nano_seconds),
@@ -112,38 +101,35 @@ impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerMinuteFsAdapt
             Self::opt(dto.fs_capacity_bytes),
             Self::opt(dto.fs_inodes_used),
             Self::opt(dto.fs_inodes),
+            Self::opt(dto.gpu_count),
+            Self::opt(dto.gpu_utilization_percent),
+            Self::opt(dto.gpu_memory_used_bytes),
         );
 
-        // ✅ write to buffer
-        writer.write_all(row.as_bytes())?;
+        Ok((path, row))
+    }
 
-        // ✅ ensure everything flushed to disk
-        writer.flush()?;
-        Ok(())
+    fn append_row(&self, container: &str, dto: &MetricContainerEntity, now: DateTime<Utc>) -> Result<()> {
+        let (path, row) = self.encode_row(container, dto, now)?;
+        storage_backend().append_rows(&path, &row)
     }
 
     fn cleanup_old(&self, container_key: &str, before: DateTime<Utc>) -> Result<()> {
         const BATCH_SIZE: usize = 200;
 
         let dir = metric_k8s_container_key_minute_dir_path(container_key);
-        if !dir.exists() {
-            return Ok(());
-        }
 
         let cutoff = before.date_naive();
         let mut batch: Vec<PathBuf> = Vec::with_capacity(BATCH_SIZE);
 
-        for entry in fs::read_dir(&dir)? {
-            let entry = entry?;
-            let path = entry.path();
-
-            // Must be .rcd
-            if path.extension().and_then(|e| e.to_str()) != Some("rcd") {
+        for path in storage_backend().list_segment_files(&dir)? {
+            // Must be .rcd or .rcd.gz
+            if segment_stem(&path).is_none() {
                 continue;
             }
 
             // Extract valid UTF-8 filename stem
-            let stem = match path.file_stem().and_then(|s| s.to_str()) {
+            let stem = match segment_stem(&path) {
                 Some(s) => s.trim(),
                 None => {
                     tracing::warn!("Skipping invalid UTF-8 file: {:?}", path);
@@ -202,14 +188,14 @@ impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerMinuteFsAdapt
             let path = self.build_path_for(object_name, current_date);
             let path_obj = Path::new(&path);
 
-            if !path_obj.exists() {
+            if !storage_backend().segment_exists(path_obj) {
                 tracing::debug!("Minute metrics file missing for {} on {}", object_name, current_date);
                 current_date = current_date.succ_opt().unwrap_or(current_date);
                 continue;
             }
 
             // Safely open file
-            let file = match File::open(&path_obj) {
+            let reader = match storage_backend().open_segment_from(path_obj, start) {
                 Ok(f) => f,
                 Err(e) => {
                     tracing::warn!("Cannot open {:?}: {}", path_obj, e);
@@ -218,7 +204,6 @@ impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerMinuteFsAdapt
                 }
             };
 
-            let reader = BufReader::new(file);
             let mut lines = reader.lines();
 
             // Skip empty files
@@ -241,7 +226,8 @@ impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerMinuteFsAdapt
                     "TIME", "CPU_USAGE_NANO_CORES", "CPU_USAGE_CORE_NANO_SECONDS",
                     "MEMORY_USAGE_BYTES", "MEMORY_WORKING_SET_BYTES", "MEMORY_RSS_BYTES",
                     "MEMORY_PAGE_FAULTS", "FS_USED_BYTES", "FS_CAPACITY_BYTES",
-                    "FS_INODES_USED", "FS_INODES"
+                    "FS_INODES_USED", "FS_INODES",
+                    "GPU_COUNT", "GPU_UTILIZATION_PERCENT", "GPU_MEMORY_USED_BYTES",
                 ];
 
                 if let Some(row) = Self::parse_line(&header, &first_line) {
@@ -265,7 +251,7 @@ impl MetricFsAdapterBase<MetricContainerEntity> for MetricContainerMinuteFsAdapt
                         continue;
                     }
                     if row.time > end {
-                        break;
+                        continue;
                     }
                     rows.push(row);
                 } else {
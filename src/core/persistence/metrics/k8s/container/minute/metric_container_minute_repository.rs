@@ -5,6 +5,7 @@ use crate::core::persistence::metrics::k8s::container::minute::metric_container_
 use crate::core::persistence::metrics::k8s::container::minute::metric_container_minute_processor_repository_trait::MetricContainerMinuteProcessorRepository;
 use crate::core::persistence::metrics::k8s::container::minute::metric_container_minute_retention_repository_traits::MetricContainerMinuteRetentionRepository;
 use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::metric_write_buffer::buffer_metric_row;
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 use tracing::error;
@@ -56,10 +57,13 @@ impl MetricContainerMinuteCollectorRepository for MetricContainerMinuteRepositor
     }
 
     fn append_row(&self, container_key: &str, data: &MetricContainerEntity, now: DateTime<Utc>) -> Result<()> {
-        self.adapter.append_row(container_key, data, now).map_err(|err| {
-            error!(error = %err, container_key, "Failed to append container minute row");
-            err
-        })
+        self.adapter
+            .encode_row(container_key, data, now)
+            .and_then(|(path, row)| buffer_metric_row(path, row))
+            .map_err(|err| {
+                error!(error = %err, container_key, "Failed to append container minute row");
+                err
+            })
     }
 }
 
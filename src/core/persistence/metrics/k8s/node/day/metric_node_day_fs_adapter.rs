@@ -1,13 +1,11 @@
 use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::metric_segment::segment_stem;
+use crate::core::persistence::storage_backend::storage_backend;
 use crate::core::persistence::metrics::k8s::node::metric_node_entity::MetricNodeEntity;
 use anyhow::{anyhow, Result};
 use chrono::{DateTime, Datelike, NaiveDate, Utc};
-use std::io::BufWriter;
 use std::{
-    fs::File,
-    fs::{self, OpenOptions},
-    io::Write,
-    io::{BufRead, BufReader},
+    io::BufRead,
     path::Path,
 };
 use std::path::PathBuf;
@@ -25,7 +23,7 @@ pub struct MetricNodeDayFsAdapter;
 impl MetricNodeDayFsAdapter {
     fn delete_batch(batch: &[PathBuf]) -> Result<()> {
         for path in batch {
-            match fs::remove_file(path) {
+            match storage_backend().remove_segment_file(path) {
                 Ok(_) => tracing::info!("Deleted old metric file {:?}", path),
                 Err(e) => tracing::error!("Failed to delete {:?}: {}", path, e),
             }
@@ -66,6 +64,17 @@ impl MetricNodeDayFsAdapter {
             fs_capacity_bytes: parts.get(12).and_then(|s| s.parse::<u64>().ok()),
             fs_inodes_used: parts.get(13).and_then(|s| s.parse::<u64>().ok()),
             fs_inodes: parts.get(14).and_then(|s| s.parse::<u64>().ok()),
+            cpu_usage_nano_cores_max: parts.get(15).and_then(|s| s.parse::<u64>().ok()),
+            cpu_usage_nano_cores_p95: parts.get(16).and_then(|s| s.parse::<u64>().ok()),
+            cpu_usage_nano_cores_p99: parts.get(17).and_then(|s| s.parse::<u64>().ok()),
+            memory_working_set_bytes_max: parts.get(18).and_then(|s| s.parse::<u64>().ok()),
+            memory_working_set_bytes_p95: parts.get(19).and_then(|s| s.parse::<u64>().ok()),
+            memory_working_set_bytes_p99: parts.get(20).and_then(|s| s.parse::<u64>().ok()),
+            system_cpu_usage_nano_cores: parts.get(21).and_then(|s| s.parse::<u64>().ok()),
+            system_memory_working_set_bytes: parts.get(22).and_then(|s| s.parse::<u64>().ok()),
+            gpu_count: parts.get(23).and_then(|s| s.parse::<u64>().ok()),
+            gpu_utilization_percent: parts.get(24).and_then(|s| s.parse::<u64>().ok()),
+            gpu_memory_used_bytes: parts.get(25).and_then(|s| s.parse::<u64>().ok()),
         })
     }
 
@@ -89,19 +98,6 @@ impl MetricFsAdapterBase<MetricNodeEntity> for MetricNodeDayFsAdapter {
         let path_str = self.build_path_for(node, now_date);
         let path = Path::new(&path_str);
 
-        if let Some(parent) = path.parent() {
-            fs::create_dir_all(parent)?;
-        }
-
-        // let new = !path.exists();
-
-        // ✅ open file and wrap in BufWriter
-        let file = OpenOptions::new()
-            .create(true)
-            .append(true)
-            .open(&path)?;
-        let mut writer = BufWriter::new(file);
-
         // Write header if file newly created
         // if new {
         //     self.ensure_header(path, &mut writer)?;
@@ -109,7 +105,7 @@ impl MetricFsAdapterBase<MetricNodeEntity> for MetricNodeDayFsAdapter {
 
         // Format the row
         let row = format!(
-            "{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}\n",
+            "{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}\n",
             dto.time.to_rfc3339_opts(chrono::SecondsFormat::Secs, false),
             Self::opt(dto.cpu_usage_nano_cores),
             Self::opt(dto.cpu_usage_core_nano_seconds),
@@ -125,14 +121,21 @@ impl MetricFsAdapterBase<MetricNodeEntity> for MetricNodeDayFsAdapter {
             Self::opt(dto.fs_capacity_bytes),
             Self::opt(dto.fs_inodes_used),
             Self::opt(dto.fs_inodes),
+            Self::opt(dto.cpu_usage_nano_cores_max),
+            Self::opt(dto.cpu_usage_nano_cores_p95),
+            Self::opt(dto.cpu_usage_nano_cores_p99),
+            Self::opt(dto.memory_working_set_bytes_max),
+            Self::opt(dto.memory_working_set_bytes_p95),
+            Self::opt(dto.memory_working_set_bytes_p99),
+            Self::opt(dto.system_cpu_usage_nano_cores),
+            Self::opt(dto.system_memory_working_set_bytes),
+            Self::opt(dto.gpu_count),
+            Self::opt(dto.gpu_utilization_percent),
+            Self::opt(dto.gpu_memory_used_bytes),
         );
 
 
-        // ✅ write to buffer
-        writer.write_all(row.as_bytes())?;
-
-        // ✅ ensure everything flushed to disk
-        writer.flush()?;
+        storage_backend().append_rows(path, &row)?;
         Ok(())
     }
 
@@ -173,6 +176,11 @@ impl MetricFsAdapterBase<MetricNodeEntity> for MetricNodeDayFsAdapter {
             }
         };
 
+        // --- Peaks: highest hourly value (see `PeakStats`)
+        let peak = |f: fn(&MetricNodeEntity) -> Option<u64>| -> Option<u64> {
+            rows.iter().filter_map(f).max()
+        };
+
         let aggregated = MetricNodeEntity {
             time: end, // time marker = end of the aggregation window
 
@@ -197,6 +205,29 @@ impl MetricFsAdapterBase<MetricNodeEntity> for MetricNodeDayFsAdapter {
             fs_capacity_bytes: last.fs_capacity_bytes,
             fs_inodes_used: avg(|r| r.fs_inodes_used),
             fs_inodes: last.fs_inodes,
+
+            // Peaks (hourly peaks)
+            cpu_usage_nano_cores_max: peak(|r| r.cpu_usage_nano_cores_max.or(r.cpu_usage_nano_cores)),
+            cpu_usage_nano_cores_p95: peak(|r| r.cpu_usage_nano_cores_p95.or(r.cpu_usage_nano_cores)),
+            cpu_usage_nano_cores_p99: peak(|r| r.cpu_usage_nano_cores_p99.or(r.cpu_usage_nano_cores)),
+            memory_working_set_bytes_max: peak(|r| {
+                r.memory_working_set_bytes_max.or(r.memory_working_set_bytes)
+            }),
+            memory_working_set_bytes_p95: peak(|r| {
+                r.memory_working_set_bytes_p95.or(r.memory_working_set_bytes)
+            }),
+            memory_working_set_bytes_p99: peak(|r| {
+                r.memory_working_set_bytes_p99.or(r.memory_working_set_bytes)
+            }),
+
+            // System overhead
+            system_cpu_usage_nano_cores: avg(|r| r.system_cpu_usage_nano_cores),
+            system_memory_working_set_bytes: avg(|r| r.system_memory_working_set_bytes),
+
+            // GPU
+            gpu_count: avg(|r| r.gpu_count),
+            gpu_utilization_percent: avg(|r| r.gpu_utilization_percent),
+            gpu_memory_used_bytes: avg(|r| r.gpu_memory_used_bytes),
         };
 
         // --- 3️⃣ Append the aggregated row into the day-level file
@@ -210,24 +241,18 @@ impl MetricFsAdapterBase<MetricNodeEntity> for MetricNodeDayFsAdapter {
         const BATCH_SIZE: usize = 200;
 
         let dir = metric_k8s_node_key_day_dir_path(node_uid);
-        if !dir.exists() {
-            return Ok(());
-        }
 
         let cutoff_year = before.year();
         let mut batch = Vec::with_capacity(BATCH_SIZE);
 
-        for entry in fs::read_dir(&dir)? {
-            let entry = entry?;
-            let path = entry.path();
-
-            // Must be .rcd
-            if path.extension().and_then(|e| e.to_str()) != Some("rcd") {
+        for path in storage_backend().list_segment_files(&dir)? {
+            // Must be .rcd or .rcd.gz
+            if segment_stem(&path).is_none() {
                 continue;
             }
 
             // Extract filename stem as UTF-8
-            let stem = match path.file_stem().and_then(|s| s.to_str()) {
+            let stem = match segment_stem(&path) {
                 Some(s) => s.trim(),
                 None => {
                     tracing::warn!("Skipping file with invalid UTF-8 name: {:?}", path);
@@ -560,7 +585,7 @@ impl MetricFsAdapterBase<MetricNodeEntity> for MetricNodeDayFsAdapter {
             let path_obj = Path::new(&path);
 
             // Skip years with no data file
-            if !path_obj.exists() {
+            if !storage_backend().segment_exists(path_obj) {
                 tracing::debug!(
                 "Metric year file not found for {} in {}",
                 object_name,
@@ -570,7 +595,7 @@ impl MetricFsAdapterBase<MetricNodeEntity> for MetricNodeDayFsAdapter {
             }
 
             // Open the yearly metric file
-            let file = match File::open(&path_obj) {
+            let reader = match storage_backend().open_segment_from(path_obj, start) {
                 Ok(f) => f,
                 Err(e) => {
                     tracing::warn!("Could not open {:?}: {}", path_obj, e);
@@ -578,10 +603,9 @@ impl MetricFsAdapterBase<MetricNodeEntity> for MetricNodeDayFsAdapter {
                 }
             };
 
-            let reader = BufReader::new(file);
 
             // 4️⃣ Read file line-by-line
-            // Assumption: rows are written in chronological order
+            // Late rows may be out of order, so the whole file is scanned
             for line in reader.lines().flatten() {
                 // Parse a single metric row
                 let Some(row) = Self::parse_line(&[], &line) else {
@@ -593,13 +617,15 @@ impl MetricFsAdapterBase<MetricNodeEntity> for MetricNodeDayFsAdapter {
                     continue;
                 }
 
-                // Stop reading this file once we exceed the end time
-                // This is critical for performance
+                // Skip rows after the requested end time
                 if row.time > end {
-                    break;
+                    continue;
                 }
 
                 // Row is within [start, end] → collect it
+                if data.last().is_some_and(|r| r.time == row.time) {
+                    continue;
+                }
                 data.push(row);
             }
         }
@@ -4,15 +4,14 @@ use crate::core::persistence::metrics::k8s::path::{
     metric_k8s_node_key_hour_dir_path, metric_k8s_node_key_hour_file_path,
 };
 use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::metric_segment::segment_stem;
+use crate::core::persistence::storage_backend::storage_backend;
+use crate::core::util::peak_stats::PeakStats;
 use anyhow::{anyhow, Result};
 use chrono::{DateTime, Datelike, NaiveDate, Utc};
-use std::io::BufWriter;
 use std::path::PathBuf;
 use std::{
-    fs::File,
-    fs::{self, OpenOptions},
-    io::Write,
-    io::{BufRead, BufReader},
+    io::BufRead,
     path::Path,
 };
 
@@ -24,7 +23,7 @@ pub struct MetricNodeHourFsAdapter;
 impl MetricNodeHourFsAdapter {
     fn delete_batch(batch: &[PathBuf]) -> Result<()> {
         for path in batch {
-            match fs::remove_file(path) {
+            match storage_backend().remove_segment_file(path) {
                 Ok(_) => tracing::info!("Deleted old metric file {:?}", path),
                 Err(e) => tracing::error!("Failed to delete {:?}: {}", path, e),
             }
@@ -52,7 +51,8 @@ impl MetricNodeHourFsAdapter {
 
     fn parse_line(header: &[&str], line: &str) -> Option<MetricNodeEntity> {
         let parts: Vec<&str> = line.split('|').collect();
-        if parts.len() != header.len() {
+        // Rows written before the peak columns were added are shorter
+        if parts.len() > header.len() || parts.len() < 15 {
             return None;
         }
 
@@ -74,6 +74,17 @@ impl MetricNodeHourFsAdapter {
             fs_capacity_bytes: parts[12].parse().ok(),
             fs_inodes_used: parts[13].parse().ok(),
             fs_inodes: parts[14].parse().ok(),
+            cpu_usage_nano_cores_max: parts.get(15).and_then(|v| v.parse().ok()),
+            cpu_usage_nano_cores_p95: parts.get(16).and_then(|v| v.parse().ok()),
+            cpu_usage_nano_cores_p99: parts.get(17).and_then(|v| v.parse().ok()),
+            memory_working_set_bytes_max: parts.get(18).and_then(|v| v.parse().ok()),
+            memory_working_set_bytes_p95: parts.get(19).and_then(|v| v.parse().ok()),
+            memory_working_set_bytes_p99: parts.get(20).and_then(|v| v.parse().ok()),
+            system_cpu_usage_nano_cores: parts.get(21).and_then(|v| v.parse().ok()),
+            system_memory_working_set_bytes: parts.get(22).and_then(|v| v.parse().ok()),
+            gpu_count: parts.get(23).and_then(|v| v.parse().ok()),
+            gpu_utilization_percent: parts.get(24).and_then(|v| v.parse().ok()),
+            gpu_memory_used_bytes: parts.get(25).and_then(|v| v.parse().ok()),
         })
     }
 
@@ -142,16 +153,6 @@ impl MetricFsAdapterBase<MetricNodeEntity> for MetricNodeHourFsAdapter {
         let path_str = self.build_path(node, now_date);
         let path = Path::new(&path_str);
 
-        if let Some(parent) = path.parent() {
-            fs::create_dir_all(parent)?;
-        }
-
-        // let new = !path.exists();
-
-        // ✅ open file and wrap in BufWriter
-        let file = OpenOptions::new().create(true).append(true).open(&path)?;
-        let mut writer = BufWriter::new(file);
-
         // Write header if file newly created
         // if new {
         //     self.ensure_header(path, &mut writer)?;
@@ -159,7 +160,7 @@ impl MetricFsAdapterBase<MetricNodeEntity> for MetricNodeHourFsAdapter {
 
         // Format the row
         let row = format!(
-            "{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}\n",
+            "{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}\n",
             dto.time.to_rfc3339_opts(chrono::SecondsFormat::Secs, false),
             Self::opt(dto.cpu_usage_nano_cores),
             Self::opt(dto.cpu_usage_core_nano_seconds),
@@ -175,13 +176,20 @@ impl MetricFsAdapterBase<MetricNodeEntity> for MetricNodeHourFsAdapter {
             Self::opt(dto.fs_capacity_bytes),
             Self::opt(dto.fs_inodes_used),
             Self::opt(dto.fs_inodes),
+            Self::opt(dto.cpu_usage_nano_cores_max),
+            Self::opt(dto.cpu_usage_nano_cores_p95),
+            Self::opt(dto.cpu_usage_nano_cores_p99),
+            Self::opt(dto.memory_working_set_bytes_max),
+            Self::opt(dto.memory_working_set_bytes_p95),
+            Self::opt(dto.memory_working_set_bytes_p99),
+            Self::opt(dto.system_cpu_usage_nano_cores),
+            Self::opt(dto.system_memory_working_set_bytes),
+            Self::opt(dto.gpu_count),
+            Self::opt(dto.gpu_utilization_percent),
+            Self::opt(dto.gpu_memory_used_bytes),
         );
 
-        // ✅ write to buffer
-        writer.write_all(row.as_bytes())?;
-
-        // ✅ ensure everything flushed to disk
-        writer.flush()?;
+        storage_backend().append_rows(path, &row)?;
         Ok(())
     }
 
@@ -224,6 +232,11 @@ impl MetricFsAdapterBase<MetricNodeEntity> for MetricNodeHourFsAdapter {
             }
         };
 
+        // --- Peaks over the minute samples (the only record once minute data expires).
+        let cpu_peaks = PeakStats::from_samples(rows.iter().filter_map(|r| r.cpu_usage_nano_cores));
+        let memory_peaks =
+            PeakStats::from_samples(rows.iter().filter_map(|r| r.memory_working_set_bytes));
+
         let aggregated = MetricNodeEntity {
             time: end, // time marker = end of the aggregation window
 
@@ -248,6 +261,23 @@ impl MetricFsAdapterBase<MetricNodeEntity> for MetricNodeHourFsAdapter {
             fs_capacity_bytes: last.fs_capacity_bytes,
             fs_inodes_used: avg(|r| r.fs_inodes_used),
             fs_inodes: last.fs_inodes,
+
+            // Peaks (minute samples)
+            cpu_usage_nano_cores_max: cpu_peaks.max,
+            cpu_usage_nano_cores_p95: cpu_peaks.p95,
+            cpu_usage_nano_cores_p99: cpu_peaks.p99,
+            memory_working_set_bytes_max: memory_peaks.max,
+            memory_working_set_bytes_p95: memory_peaks.p95,
+            memory_working_set_bytes_p99: memory_peaks.p99,
+
+            // System overhead
+            system_cpu_usage_nano_cores: avg(|r| r.system_cpu_usage_nano_cores),
+            system_memory_working_set_bytes: avg(|r| r.system_memory_working_set_bytes),
+
+            // GPU
+            gpu_count: avg(|r| r.gpu_count),
+            gpu_utilization_percent: avg(|r| r.gpu_utilization_percent),
+            gpu_memory_used_bytes: avg(|r| r.gpu_memory_used_bytes),
         };
 
         // --- 3️⃣ Append the aggregated row into the hour-level file
@@ -260,9 +290,6 @@ impl MetricFsAdapterBase<MetricNodeEntity> for MetricNodeHourFsAdapter {
         const BATCH_SIZE: usize = 200;
 
         let dir = metric_k8s_node_key_hour_dir_path(node_name);
-        if !dir.exists() {
-            return Ok(());
-        }
 
         // Normalize cutoff month (YYYY-MM-01)
         let before_month =
@@ -276,17 +303,14 @@ impl MetricFsAdapterBase<MetricNodeEntity> for MetricNodeHourFsAdapter {
 
         let mut batch = Vec::with_capacity(BATCH_SIZE);
 
-        for entry in fs::read_dir(&dir)? {
-            let entry = entry?;
-            let path = entry.path();
-
-            // Only *.rcd files
-            if path.extension().and_then(|e| e.to_str()) != Some("rcd") {
+        for path in storage_backend().list_segment_files(&dir)? {
+            // Only *.rcd / *.rcd.gz files
+            if segment_stem(&path).is_none() {
                 continue;
             }
 
             // Filename -> UTF-8 -> trimmed
-            let stem = match path.file_stem().and_then(|s| s.to_str()) {
+            let stem = match segment_stem(&path) {
                 Some(s) => s.trim(),
                 None => {
                     tracing::warn!("Skipping invalid UTF-8 filename: {:?}", path);
@@ -350,6 +374,17 @@ impl MetricFsAdapterBase<MetricNodeEntity> for MetricNodeHourFsAdapter {
             "FS_CAPACITY_BYTES",
             "FS_INODES_USED",
             "FS_INODES",
+            "CPU_USAGE_NANO_CORES_MAX",
+            "CPU_USAGE_NANO_CORES_P95",
+            "CPU_USAGE_NANO_CORES_P99",
+            "MEMORY_WORKING_SET_BYTES_MAX",
+            "MEMORY_WORKING_SET_BYTES_P95",
+            "MEMORY_WORKING_SET_BYTES_P99",
+            "SYSTEM_CPU_USAGE_NANO_CORES",
+            "SYSTEM_MEMORY_WORKING_SET_BYTES",
+            "GPU_COUNT",
+            "GPU_UTILIZATION_PERCENT",
+            "GPU_MEMORY_USED_BYTES",
         ];
 
         let file_names =
@@ -359,9 +394,8 @@ impl MetricFsAdapterBase<MetricNodeEntity> for MetricNodeHourFsAdapter {
             let path = metric_k8s_node_key_hour_dir_path(object_name).join(file_name);
             let path_obj = Path::new(&path);
 
-            if path_obj.exists() {
-                let file = File::open(&path_obj)?;
-                let reader = BufReader::new(file);
+            if storage_backend().segment_exists(path_obj) {
+                let reader = storage_backend().open_segment_from(path_obj, start)?;
                 let mut lines = reader.lines();
 
                 if let Some(first_line_res) = lines.next() {
@@ -379,7 +413,7 @@ impl MetricFsAdapterBase<MetricNodeEntity> for MetricNodeHourFsAdapter {
                                 continue;
                             }
                             if row.time > end {
-                                break;
+                                continue;
                             }
                             data.push(row);
                         }
@@ -1,5 +1,6 @@
 use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
 use crate::core::persistence::metrics::k8s::node::metric_node_entity::MetricNodeEntity;
+use crate::core::persistence::metrics::metric_write_buffer::buffer_metric_row;
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 
@@ -7,9 +8,10 @@ use chrono::{DateTime, Utc};
 pub trait MetricNodeMinuteCollectorRepository: Send + Sync {
     fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricNodeEntity>;
 
-    /// Inserts one metric sample for a given node.
+    /// Buffers one metric sample for a given node; written on the next flush.
     fn append_row(&self, node_name: &str, data: &MetricNodeEntity, now: DateTime<Utc>) -> Result<()> {
-        self.fs_adapter().append_row(node_name, data, now)
+        let (path, row) = self.fs_adapter().encode_row(node_name, data, now)?;
+        buffer_metric_row(path, row)
     }
 
 }
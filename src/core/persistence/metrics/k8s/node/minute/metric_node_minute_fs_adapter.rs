@@ -1,12 +1,11 @@
 use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::metric_segment::segment_stem;
+use crate::core::persistence::storage_backend::storage_backend;
 use crate::core::persistence::metrics::k8s::node::metric_node_entity::MetricNodeEntity;
 use anyhow::{anyhow, Result};
 use chrono::{DateTime, NaiveDate, Utc};
 use std::{
-    fs::File,
-    fs::{self, OpenOptions},
-    io::Write,
-    io::{BufRead, BufReader},
+    io::BufRead,
     path::Path,
 };
 use std::path::PathBuf;
@@ -23,7 +22,7 @@ pub struct MetricNodeMinuteFsAdapter;
 impl MetricNodeMinuteFsAdapter {
     fn delete_batch(batch: &[PathBuf]) -> Result<()> {
         for path in batch {
-            match fs::remove_file(path) {
+            match storage_backend().remove_segment_file(path) {
                 Ok(_) => tracing::debug!("Deleted old metric file {:?}", path),
                 Err(e) => tracing::error!("Failed to delete {:?}: {}", path, e),
             }
@@ -37,7 +36,8 @@ impl MetricNodeMinuteFsAdapter {
 
     fn parse_line(header: &[&str], line: &str) -> Option<MetricNodeEntity> {
         let parts: Vec<&str> = line.split('|').collect();
-        if parts.len() != header.len() {
+        // Rows written before the system columns were added are shorter
+        if parts.len() > header.len() || parts.len() < 15 {
             return None;
         }
 
@@ -59,6 +59,12 @@ impl MetricNodeMinuteFsAdapter {
             fs_capacity_bytes: parts[12].parse().ok(),
             fs_inodes_used: parts[13].parse().ok(),
             fs_inodes: parts[14].parse().ok(),
+            system_cpu_usage_nano_cores: parts.get(15).and_then(|v| v.parse().ok()),
+            system_memory_working_set_bytes: parts.get(16).and_then(|v| v.parse().ok()),
+            gpu_count: parts.get(17).and_then(|v| v.parse().ok()),
+            gpu_utilization_percent: parts.get(18).and_then(|v| v.parse().ok()),
+            gpu_memory_used_bytes: parts.get(19).and_then(|v| v.parse().ok()),
+            ..Default::default()
         })
     }
 
@@ -68,8 +74,7 @@ impl MetricNodeMinuteFsAdapter {
         start: DateTime<Utc>,
         end: DateTime<Utc>,
     ) -> Result<Vec<MetricNodeEntity>> {
-        let file = File::open(path)?;
-        let reader = BufReader::new(file);
+        let reader = storage_backend().open_segment_from(path, start)?;
         let mut lines = reader.lines();
 
         // Try to read the first line
@@ -85,7 +90,9 @@ impl MetricNodeMinuteFsAdapter {
                 "MEMORY_USAGE_BYTES", "MEMORY_WORKING_SET_BYTES", "MEMORY_RSS_BYTES",
                 "MEMORY_PAGE_FAULTS", "NETWORK_PHYSICAL_RX_BYTES", "NETWORK_PHYSICAL_TX_BYTES",
                 "NETWORK_PHYSICAL_RX_ERRORS", "NETWORK_PHYSICAL_TX_ERRORS",
-                "FS_USED_BYTES", "FS_CAPACITY_BYTES", "FS_INODES_USED", "FS_INODES"
+                "FS_USED_BYTES", "FS_CAPACITY_BYTES", "FS_INODES_USED", "FS_INODES",
+                "SYSTEM_CPU_USAGE_NANO_CORES", "SYSTEM_MEMORY_WORKING_SET_BYTES",
+                "GPU_COUNT", "GPU_UTILIZATION_PERCENT", "GPU_MEMORY_USED_BYTES"
             ];
 
             if let Some(row) = Self::parse_line(&header, &first_line) {
@@ -104,7 +111,7 @@ impl MetricNodeMinuteFsAdapter {
                     continue;
                 }
                 if row.time > end {
-                    break;
+                    continue;
                 }
                 data.push(row);
             }
@@ -126,24 +133,17 @@ impl MetricNodeMinuteFsAdapter {
 }
 
 impl MetricFsAdapterBase<MetricNodeEntity> for MetricNodeMinuteFsAdapter {
-    fn append_row(&self, node: &str, dto: &MetricNodeEntity, now: DateTime<Utc>) -> Result<()> {
+    fn encode_row(&self, node: &str, dto: &MetricNodeEntity, now: DateTime<Utc>) -> Result<(PathBuf, String)> {
 
         let now_date = now.date_naive();
-        let path_str = self.build_path_for(node, now_date);
-        let path = Path::new(&path_str);
+        let path = self.build_path_for(node, now_date);
 
-        if let Some(parent) = path.parent() {
-            fs::create_dir_all(parent)?;
-        }
-
-        // let new = !path.exists();
-        let mut file = OpenOptions::new().create(true).append(true).open(&path)?;
         // if new {
         //     self.ensure_header(path, &mut file)?;
         // }
 
         let row = format!(
-            "{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}\n",
+            "{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}\n",
             dto.time.to_rfc3339_opts(chrono::SecondsFormat::Secs, false),
             Self::opt(dto.cpu_usage_nano_cores),
             Self::opt(dto.cpu_usage_core_nano_seconds),
@@ -159,34 +159,37 @@ impl MetricFsAdapterBase<MetricNodeEntity> for MetricNodeMinuteFsAdapter {
             Self::opt(dto.fs_capacity_bytes),
             Self::opt(dto.fs_inodes_used),
             Self::opt(dto.fs_inodes),
+            Self::opt(dto.system_cpu_usage_nano_cores),
+            Self::opt(dto.system_memory_working_set_bytes),
+            Self::opt(dto.gpu_count),
+            Self::opt(dto.gpu_utilization_percent),
+            Self::opt(dto.gpu_memory_used_bytes),
         );
 
-        file.write_all(row.as_bytes())?;
-        Ok(())
+        Ok((path, row))
+    }
+
+    fn append_row(&self, node: &str, dto: &MetricNodeEntity, now: DateTime<Utc>) -> Result<()> {
+        let (path, row) = self.encode_row(node, dto, now)?;
+        storage_backend().append_rows(&path, &row)
     }
 
     fn cleanup_old(&self, node: &str, before: DateTime<Utc>) -> Result<()> {
         const BATCH_SIZE: usize = 200;
 
         let dir = metric_k8s_node_key_minute_dir_path(node);
-        if !dir.exists() {
-            return Ok(());
-        }
 
         let cutoff = before.date_naive();
         let mut batch: Vec<PathBuf> = Vec::with_capacity(BATCH_SIZE);
 
-        for entry in fs::read_dir(&dir)? {
-            let entry = entry?;
-            let path = entry.path();
-
-            // Only process *.rcd files
-            if path.extension().and_then(|e| e.to_str()) != Some("rcd") {
+        for path in storage_backend().list_segment_files(&dir)? {
+            // Only process *.rcd / *.rcd.gz files
+            if segment_stem(&path).is_none() {
                 continue;
             }
 
             // Extract filename stem as UTF-8
-            let stem = match path.file_stem().and_then(|s| s.to_str()) {
+            let stem = match segment_stem(&path) {
                 Some(s) => s.trim(),
                 None => {
                     tracing::warn!("Skipping file with invalid UTF-8 name: {:?}", path);
@@ -251,7 +254,7 @@ impl MetricFsAdapterBase<MetricNodeEntity> for MetricNodeMinuteFsAdapter {
             let path = self.build_path_for(object_name, current_date);
             let path_obj = Path::new(&path);
 
-            if path_obj.exists() {
+            if storage_backend().segment_exists(path_obj) {
                 // read file and collect relevant rows
                 if let Ok(mut rows) = self.read_file_between(&path_obj, start, end) {
                     data.append(&mut rows);
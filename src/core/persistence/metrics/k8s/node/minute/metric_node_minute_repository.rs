@@ -5,6 +5,7 @@ use crate::core::persistence::metrics::k8s::node::minute::metric_node_minute_fs_
 use crate::core::persistence::metrics::k8s::node::minute::metric_node_minute_processor_repository_trait::MetricNodeMinuteProcessorRepository;
 use crate::core::persistence::metrics::k8s::node::minute::metric_node_minute_retention_repository_traits::MetricNodeMinuteRetentionRepository;
 use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::metric_write_buffer::buffer_metric_row;
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 use tracing::error;
@@ -46,10 +47,13 @@ impl MetricNodeMinuteCollectorRepository for MetricNodeMinuteRepository {
     }
 
     fn append_row(&self, node_name: &str, data: &MetricNodeEntity, now: DateTime<Utc>) -> Result<()> {
-        self.adapter.append_row(node_name, data, now).map_err(|err| {
-            error!(error = %err, node_name, "Failed to append node minute row");
-            err
-        })
+        self.adapter
+            .encode_row(node_name, data, now)
+            .and_then(|(path, row)| buffer_metric_row(path, row))
+            .map_err(|err| {
+                error!(error = %err, node_name, "Failed to append node minute row");
+                err
+            })
     }
 }
 
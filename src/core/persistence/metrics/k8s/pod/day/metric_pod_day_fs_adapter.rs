@@ -1,13 +1,11 @@
 use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::metric_segment::segment_stem;
+use crate::core::persistence::storage_backend::storage_backend;
 use crate::core::persistence::metrics::k8s::pod::metric_pod_entity::MetricPodEntity;
 use anyhow::{anyhow, Context, Result};
 use chrono::{DateTime, Datelike, Utc};
-use std::io::BufWriter;
 use std::{
-    fs::File,
-    fs::{self, OpenOptions},
-    io::Write,
-    io::{BufRead, BufReader},
+    io::BufRead,
     path::Path,
 };
 use std::path::PathBuf;
@@ -27,7 +25,7 @@ impl MetricPodDayFsAdapter {
 
     fn delete_batch(batch: &[PathBuf]) -> Result<()> {
         for path in batch {
-            fs::remove_file(path)
+            storage_backend().remove_segment_file(path)
                 .with_context(|| format!("Failed to delete old metric file {:?}", path))?;
         }
         Ok(())
@@ -40,7 +38,8 @@ impl MetricPodDayFsAdapter {
 
     fn parse_line(header: &[&str], line: &str) -> Option<MetricPodEntity> {
         let parts: Vec<&str> = line.split('|').collect();
-        if parts.len() != header.len() {
+        // Rows written before the GPU / locality / peak columns were added are shorter
+        if parts.len() > header.len() || parts.len() < 19 {
             return None;
         }
 
@@ -66,6 +65,18 @@ impl MetricPodDayFsAdapter {
             pv_capacity_bytes: parts[16].parse().ok(),
             pv_inodes_used: parts[17].parse().ok(),
             pv_inodes: parts[18].parse().ok(),
+            gpu_count: parts.get(19).and_then(|v| v.parse().ok()),
+            gpu_utilization_percent: parts.get(20).and_then(|v| v.parse().ok()),
+            gpu_memory_used_bytes: parts.get(21).and_then(|v| v.parse().ok()),
+            network_local_bytes: parts.get(22).and_then(|v| v.parse().ok()),
+            network_regional_bytes: parts.get(23).and_then(|v| v.parse().ok()),
+            network_external_bytes: parts.get(24).and_then(|v| v.parse().ok()),
+            cpu_usage_nano_cores_max: parts.get(25).and_then(|v| v.parse().ok()),
+            cpu_usage_nano_cores_p95: parts.get(26).and_then(|v| v.parse().ok()),
+            cpu_usage_nano_cores_p99: parts.get(27).and_then(|v| v.parse().ok()),
+            memory_working_set_bytes_max: parts.get(28).and_then(|v| v.parse().ok()),
+            memory_working_set_bytes_p95: parts.get(29).and_then(|v| v.parse().ok()),
+            memory_working_set_bytes_p99: parts.get(30).and_then(|v| v.parse().ok()),
         })
     }
 
@@ -91,22 +102,9 @@ impl MetricFsAdapterBase<MetricPodEntity> for MetricPodDayFsAdapter {
         let path_str = self.build_path_for(pod, dto_date);
         let path = Path::new(&path_str);
 
-        if let Some(parent) = path.parent() {
-            fs::create_dir_all(parent)?;
-        }
-
-        // let new = !path.exists();
-
-        // ✅ open file and wrap in BufWriter
-        let file = OpenOptions::new()
-            .create(true)
-            .append(true)
-            .open(&path)?;
-        let mut writer = BufWriter::new(file);
-
         // Format the row
         let row = format!(
-            "{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}\n",
+            "{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}\n",
             dto.time.to_rfc3339_opts(chrono::SecondsFormat::Secs, false),
             Self::opt(dto.cpu_usage_nano_cores),
             Self::opt(dto.cpu_usage_core_nano_seconds),
@@ -126,14 +124,22 @@ impl MetricFsAdapterBase<MetricPodEntity> for MetricPodDayFsAdapter {
             Self::opt(dto.pv_capacity_bytes),
             Self::opt(dto.pv_inodes_used),
             Self::opt(dto.pv_inodes),
+            Self::opt(dto.gpu_count),
+            Self::opt(dto.gpu_utilization_percent),
+            Self::opt(dto.gpu_memory_used_bytes),
+            Self::opt(dto.network_local_bytes),
+            Self::opt(dto.network_regional_bytes),
+            Self::opt(dto.network_external_bytes),
+            Self::opt(dto.cpu_usage_nano_cores_max),
+            Self::opt(dto.cpu_usage_nano_cores_p95),
+            Self::opt(dto.cpu_usage_nano_cores_p99),
+            Self::opt(dto.memory_working_set_bytes_max),
+            Self::opt(dto.memory_working_set_bytes_p95),
+            Self::opt(dto.memory_working_set_bytes_p99),
         );
 
 
-        // ✅ write to buffer
-        writer.write_all(row.as_bytes())?;
-
-        // ✅ ensure everything flushed to disk
-        writer.flush()?;
+        storage_backend().append_rows(path, &row)?;
         Ok(())
     }
 
@@ -209,6 +215,11 @@ impl MetricFsAdapterBase<MetricPodEntity> for MetricPodDayFsAdapter {
             rows.iter().filter_map(f).max()
         };
 
+        // --- Peaks: highest hourly value (see `PeakStats`)
+        let peak = |f: fn(&MetricPodEntity) -> Option<u64>| -> Option<u64> {
+            rows.iter().filter_map(f).max()
+        };
+
         // 2) Build day-level row.
         let aggregated = MetricPodEntity {
             time: end,
@@ -243,6 +254,30 @@ impl MetricFsAdapterBase<MetricPodEntity> for MetricPodDayFsAdapter {
             pv_capacity_bytes: max_u64(|r| r.pv_capacity_bytes).or(last.pv_capacity_bytes),
             pv_inodes_used: twa_u64(|r| r.pv_inodes_used),
             pv_inodes: max_u64(|r| r.pv_inodes).or(last.pv_inodes),
+
+            // GPU (gauges)
+            gpu_count: twa_u64(|r| r.gpu_count),
+            gpu_utilization_percent: twa_u64(|r| r.gpu_utilization_percent),
+            gpu_memory_used_bytes: twa_u64(|r| r.gpu_memory_used_bytes),
+
+            // Network by locality (already interval deltas)
+            network_local_bytes: sum_u64(|r| r.network_local_bytes),
+            network_regional_bytes: sum_u64(|r| r.network_regional_bytes),
+            network_external_bytes: sum_u64(|r| r.network_external_bytes),
+
+            // Peaks (hourly peaks)
+            cpu_usage_nano_cores_max: peak(|r| r.cpu_usage_nano_cores_max.or(r.cpu_usage_nano_cores)),
+            cpu_usage_nano_cores_p95: peak(|r| r.cpu_usage_nano_cores_p95.or(r.cpu_usage_nano_cores)),
+            cpu_usage_nano_cores_p99: peak(|r| r.cpu_usage_nano_cores_p99.or(r.cpu_usage_nano_cores)),
+            memory_working_set_bytes_max: peak(|r| {
+                r.memory_working_set_bytes_max.or(r.memory_working_set_bytes)
+            }),
+            memory_working_set_bytes_p95: peak(|r| {
+                r.memory_working_set_bytes_p95.or(r.memory_working_set_bytes)
+            }),
+            memory_working_set_bytes_p99: peak(|r| {
+                r.memory_working_set_bytes_p99.or(r.memory_working_set_bytes)
+            }),
         };
 
         // --- 3️⃣ Append the aggregated row into the day-level file
@@ -253,20 +288,16 @@ impl MetricFsAdapterBase<MetricPodEntity> for MetricPodDayFsAdapter {
 
     fn cleanup_old(&self, pod_uid: &str, before: DateTime<Utc>) -> Result<()> {
         let dir = metric_k8s_pod_key_day_dir_path(pod_uid);
-        if !dir.exists() { return Ok(()); }
 
         let cutoff_year = before.year();
         let mut batch = Vec::with_capacity(Self::BATCH_SIZE);
 
-        for entry in fs::read_dir(&dir)? {
-            let entry = entry?;
-            let path = entry.path();
-
-            if path.extension().and_then(|e| e.to_str()) != Some("rcd") {
+        for path in storage_backend().list_segment_files(&dir)? {
+            if segment_stem(&path).is_none() {
                 continue;
             }
 
-            let stem = match path.file_stem().and_then(|s| s.to_str()).map(|s| s.trim()) {
+            let stem = match segment_stem(&path).map(|s| s.trim()) {
                 Some(s) => s,
                 None => continue,
             };
@@ -713,7 +744,7 @@ impl MetricFsAdapterBase<MetricPodEntity> for MetricPodDayFsAdapter {
             let path = self.build_path_for(object_name, date);
             let path_obj = Path::new(&path);
 
-            if !path_obj.exists() {
+            if !storage_backend().segment_exists(path_obj) {
                 tracing::debug!(
                 "Day metrics file missing for pod {} in year {}",
                 object_name,
@@ -723,7 +754,7 @@ impl MetricFsAdapterBase<MetricPodEntity> for MetricPodDayFsAdapter {
                 continue;
             }
 
-            let file = match File::open(&path_obj) {
+            let reader = match storage_backend().open_segment_from(path_obj, start) {
                 Ok(f) => f,
                 Err(e) => {
                     tracing::warn!("Could not open {:?}: {}", path_obj, e);
@@ -732,7 +763,6 @@ impl MetricFsAdapterBase<MetricPodEntity> for MetricPodDayFsAdapter {
                 }
             };
 
-            let reader = BufReader::new(file);
             let mut lines = reader.lines();
 
             // 2️⃣ Try to read the first line (header or data)
@@ -753,7 +783,11 @@ impl MetricFsAdapterBase<MetricPodEntity> for MetricPodDayFsAdapter {
                     "MEMORY_PAGE_FAULTS", "NETWORK_PHYSICAL_RX_BYTES", "NETWORK_PHYSICAL_TX_BYTES",
                     "NETWORK_PHYSICAL_RX_ERRORS", "NETWORK_PHYSICAL_TX_ERRORS",
                     "ES_USED_BYTES", "ES_CAPACITY_BYTES", "ES_INODES_USED", "ES_INODES",
-                    "PV_USED_BYTES", "PV_CAPACITY_BYTES", "PV_INODES_USED", "PV_INODES"
+                    "PV_USED_BYTES", "PV_CAPACITY_BYTES", "PV_INODES_USED", "PV_INODES",
+                    "GPU_COUNT", "GPU_UTILIZATION_PERCENT", "GPU_MEMORY_USED_BYTES",
+                    "NETWORK_LOCAL_BYTES", "NETWORK_REGIONAL_BYTES", "NETWORK_EXTERNAL_BYTES",
+                    "CPU_USAGE_NANO_CORES_MAX", "CPU_USAGE_NANO_CORES_P95", "CPU_USAGE_NANO_CORES_P99",
+                    "MEMORY_WORKING_SET_BYTES_MAX", "MEMORY_WORKING_SET_BYTES_P95", "MEMORY_WORKING_SET_BYTES_P99",
                 ];
 
                 if let Some(row) = Self::parse_line(&header, &first_line) {
@@ -772,7 +806,7 @@ impl MetricFsAdapterBase<MetricPodEntity> for MetricPodDayFsAdapter {
                         continue;
                     }
                     if row.time > end {
-                        break;
+                        continue;
                     }
                     rows.push(row);
                 }
@@ -4,15 +4,14 @@ use crate::core::persistence::metrics::k8s::path::{
 use crate::core::persistence::metrics::k8s::pod::metric_pod_entity::MetricPodEntity;
 use crate::core::persistence::metrics::k8s::pod::minute::metric_pod_minute_fs_adapter::MetricPodMinuteFsAdapter;
 use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::metric_segment::segment_stem;
+use crate::core::persistence::storage_backend::storage_backend;
+use crate::core::util::peak_stats::PeakStats;
 use anyhow::{anyhow, Result};
 use chrono::{DateTime, Datelike, NaiveDate, Utc};
-use std::io::BufWriter;
 use std::path::PathBuf;
 use std::{
-    fs::File,
-    fs::{self, OpenOptions},
-    io::Write,
-    io::{BufRead, BufReader},
+    io::BufRead,
     path::Path,
 };
 
@@ -25,7 +24,7 @@ impl MetricPodHourFsAdapter {
     /// Delete a batch of files safely
     fn delete_batch(batch: &[PathBuf]) -> Result<()> {
         for path in batch {
-            if let Err(e) = fs::remove_file(path) {
+            if let Err(e) = storage_backend().remove_segment_file(path) {
                 // Continue deleting others — best-effort cleanup
                 tracing::error!("Failed to delete {:?}: {}", path, e);
             } else {
@@ -41,7 +40,8 @@ impl MetricPodHourFsAdapter {
 
     fn parse_line(header: &[&str], line: &str) -> Option<MetricPodEntity> {
         let parts: Vec<&str> = line.split('|').collect();
-        if parts.len() != header.len() {
+        // Rows written before the GPU / locality / peak columns were added are shorter
+        if parts.len() > header.len() || parts.len() < 19 {
             return None;
         }
 
@@ -67,6 +67,18 @@ impl MetricPodHourFsAdapter {
             pv_capacity_bytes: parts[16].parse().ok(),
             pv_inodes_used: parts[17].parse().ok(),
             pv_inodes: parts[18].parse().ok(),
+            gpu_count: parts.get(19).and_then(|v| v.parse().ok()),
+            gpu_utilization_percent: parts.get(20).and_then(|v| v.parse().ok()),
+            gpu_memory_used_bytes: parts.get(21).and_then(|v| v.parse().ok()),
+            network_local_bytes: parts.get(22).and_then(|v| v.parse().ok()),
+            network_regional_bytes: parts.get(23).and_then(|v| v.parse().ok()),
+            network_external_bytes: parts.get(24).and_then(|v| v.parse().ok()),
+            cpu_usage_nano_cores_max: parts.get(25).and_then(|v| v.parse().ok()),
+            cpu_usage_nano_cores_p95: parts.get(26).and_then(|v| v.parse().ok()),
+            cpu_usage_nano_cores_p99: parts.get(27).and_then(|v| v.parse().ok()),
+            memory_working_set_bytes_max: parts.get(28).and_then(|v| v.parse().ok()),
+            memory_working_set_bytes_p95: parts.get(29).and_then(|v| v.parse().ok()),
+            memory_working_set_bytes_p99: parts.get(30).and_then(|v| v.parse().ok()),
         })
     }
 
@@ -91,16 +103,6 @@ impl MetricFsAdapterBase<MetricPodEntity> for MetricPodHourFsAdapter {
         let path_str = self.build_path_for(pod, dto_date);
         let path = Path::new(&path_str);
 
-        if let Some(parent) = path.parent() {
-            fs::create_dir_all(parent)?;
-        }
-
-        // let new = !path.exists();
-
-        // ✅ open file and wrap in BufWriter
-        let file = OpenOptions::new().create(true).append(true).open(&path)?;
-        let mut writer = BufWriter::new(file);
-
         // Write header if file newly created
         // if new {
         //     self.ensure_header(path, &mut writer)?;
@@ -108,7 +110,7 @@ impl MetricFsAdapterBase<MetricPodEntity> for MetricPodHourFsAdapter {
 
         // Format the row
         let row = format!(
-            "{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}\n",
+            "{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}\n",
             dto.time.to_rfc3339_opts(chrono::SecondsFormat::Secs, false),
             Self::opt(dto.cpu_usage_nano_cores),
             Self::opt(dto.cpu_usage_core_nano_seconds),
@@ -128,13 +130,21 @@ impl MetricFsAdapterBase<MetricPodEntity> for MetricPodHourFsAdapter {
             Self::opt(dto.pv_capacity_bytes),
             Self::opt(dto.pv_inodes_used),
             Self::opt(dto.pv_inodes),
+            Self::opt(dto.gpu_count),
+            Self::opt(dto.gpu_utilization_percent),
+            Self::opt(dto.gpu_memory_used_bytes),
+            Self::opt(dto.network_local_bytes),
+            Self::opt(dto.network_regional_bytes),
+            Self::opt(dto.network_external_bytes),
+            Self::opt(dto.cpu_usage_nano_cores_max),
+            Self::opt(dto.cpu_usage_nano_cores_p95),
+            Self::opt(dto.cpu_usage_nano_cores_p99),
+            Self::opt(dto.memory_working_set_bytes_max),
+            Self::opt(dto.memory_working_set_bytes_p95),
+            Self::opt(dto.memory_working_set_bytes_p99),
         );
 
-        // ✅ write to buffer
-        writer.write_all(row.as_bytes())?;
-
-        // ✅ ensure everything flushed to disk
-        writer.flush()?;
+        storage_backend().append_rows(path, &row)?;
         Ok(())
     }
 
@@ -231,11 +241,21 @@ impl MetricFsAdapterBase<MetricPodEntity> for MetricPodHourFsAdapter {
             }
         };
 
+        // --- Plain sum for fields that are already per-interval deltas.
+        let sum_u64 = |f: fn(&MetricPodEntity) -> Option<u64>| -> Option<u64> {
+            rows.iter().filter_map(f).reduce(|a, b| a.saturating_add(b))
+        };
+
         // --- Supply/capacity snapshots: prefer max (conservative), fallback to last.
         let max_u64 = |f: fn(&MetricPodEntity) -> Option<u64>| -> Option<u64> {
             rows.iter().filter_map(f).max()
         };
 
+        // --- Peaks over the minute samples (the only record once minute data expires).
+        let cpu_peaks = PeakStats::from_samples(rows.iter().filter_map(|r| r.cpu_usage_nano_cores));
+        let memory_peaks =
+            PeakStats::from_samples(rows.iter().filter_map(|r| r.memory_working_set_bytes));
+
         // 2) Build hour-level aggregated row (timestamp = end of window).
         let aggregated = MetricPodEntity {
             time: end,
@@ -269,6 +289,24 @@ impl MetricFsAdapterBase<MetricPodEntity> for MetricPodHourFsAdapter {
             pv_capacity_bytes: max_u64(|r| r.pv_capacity_bytes).or(last.pv_capacity_bytes),
             pv_inodes_used: twa_u64(|r| r.pv_inodes_used),
             pv_inodes: max_u64(|r| r.pv_inodes).or(last.pv_inodes),
+
+            // GPU (gauges)
+            gpu_count: twa_u64(|r| r.gpu_count),
+            gpu_utilization_percent: twa_u64(|r| r.gpu_utilization_percent),
+            gpu_memory_used_bytes: twa_u64(|r| r.gpu_memory_used_bytes),
+
+            // Network by locality (already interval deltas)
+            network_local_bytes: sum_u64(|r| r.network_local_bytes),
+            network_regional_bytes: sum_u64(|r| r.network_regional_bytes),
+            network_external_bytes: sum_u64(|r| r.network_external_bytes),
+
+            // Peaks (minute samples)
+            cpu_usage_nano_cores_max: cpu_peaks.max,
+            cpu_usage_nano_cores_p95: cpu_peaks.p95,
+            cpu_usage_nano_cores_p99: cpu_peaks.p99,
+            memory_working_set_bytes_max: memory_peaks.max,
+            memory_working_set_bytes_p95: memory_peaks.p95,
+            memory_working_set_bytes_p99: memory_peaks.p99,
         };
 
         // 3) Append the aggregated sample (storage partitioning uses aggregated.time internally).
@@ -280,9 +318,6 @@ impl MetricFsAdapterBase<MetricPodEntity> for MetricPodHourFsAdapter {
     fn cleanup_old(&self, pod_uid: &str, before: DateTime<Utc>) -> Result<()> {
         const BATCH_SIZE: usize = 200;
         let dir = metric_k8s_pod_key_hour_dir_path(pod_uid);
-        if !dir.exists() {
-            return Ok(());
-        }
 
         // Normalize cutoff to YYYY-MM-01
         let before_month =
@@ -296,16 +331,13 @@ impl MetricFsAdapterBase<MetricPodEntity> for MetricPodHourFsAdapter {
 
         let mut batch: Vec<PathBuf> = Vec::with_capacity(BATCH_SIZE);
 
-        for entry in fs::read_dir(&dir)? {
-            let entry = entry?;
-            let path = entry.path();
-
-            // Only process *.rcd
-            if path.extension().and_then(|e| e.to_str()) != Some("rcd") {
+        for path in storage_backend().list_segment_files(&dir)? {
+            // Only process *.rcd / *.rcd.gz
+            if segment_stem(&path).is_none() {
                 continue;
             }
 
-            let stem = match path.file_stem().and_then(|s| s.to_str()) {
+            let stem = match segment_stem(&path) {
                 Some(s) => s.trim(),
                 None => {
                     tracing::warn!("Skipping file with non-UTF8 name in {:?}", path);
@@ -384,7 +416,7 @@ impl MetricFsAdapterBase<MetricPodEntity> for MetricPodHourFsAdapter {
             let path = self.build_path_for(object_name, current_date);
             let path_obj = Path::new(&path);
 
-            if !path_obj.exists() {
+            if !storage_backend().segment_exists(path_obj) {
                 tracing::debug!(
                     "Hour metrics file missing for {} at month {}",
                     object_name,
@@ -400,7 +432,7 @@ impl MetricFsAdapterBase<MetricPodEntity> for MetricPodHourFsAdapter {
                 continue;
             }
 
-            let file = match File::open(&path_obj) {
+            let reader = match storage_backend().open_segment_from(path_obj, start) {
                 Ok(f) => f,
                 Err(e) => {
                     tracing::warn!("Could not open {:?}: {}", path_obj, e);
@@ -414,7 +446,6 @@ impl MetricFsAdapterBase<MetricPodEntity> for MetricPodHourFsAdapter {
                 }
             };
 
-            let reader = BufReader::new(file);
             let mut lines = reader.lines();
 
             // 2️⃣ Try to read the first line (header or data)
@@ -455,6 +486,10 @@ impl MetricFsAdapterBase<MetricPodEntity> for MetricPodHourFsAdapter {
                     "PV_CAPACITY_BYTES",
                     "PV_INODES_USED",
                     "PV_INODES",
+                    "GPU_COUNT", "GPU_UTILIZATION_PERCENT", "GPU_MEMORY_USED_BYTES",
+                    "NETWORK_LOCAL_BYTES", "NETWORK_REGIONAL_BYTES", "NETWORK_EXTERNAL_BYTES",
+                    "CPU_USAGE_NANO_CORES_MAX", "CPU_USAGE_NANO_CORES_P95", "CPU_USAGE_NANO_CORES_P99",
+                    "MEMORY_WORKING_SET_BYTES_MAX", "MEMORY_WORKING_SET_BYTES_P95", "MEMORY_WORKING_SET_BYTES_P99",
                 ];
 
                 if let Some(row) = Self::parse_line(&header, &first_line) {
@@ -473,7 +508,7 @@ impl MetricFsAdapterBase<MetricPodEntity> for MetricPodHourFsAdapter {
                         continue;
                     }
                     if row.time > end {
-                        break;
+                        continue;
                     }
                     rows.push(row);
                 }
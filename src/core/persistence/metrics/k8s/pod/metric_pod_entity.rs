@@ -32,4 +32,22 @@ pub struct MetricPodEntity {
     pub pv_capacity_bytes: Option<u64>,
     pub pv_inodes_used: Option<u64>,
     pub pv_inodes: Option<u64>,
+
+    // GPU (DCGM exporter)
+    pub gpu_count: Option<u64>,
+    pub gpu_utilization_percent: Option<u64>,
+    pub gpu_memory_used_bytes: Option<u64>,
+
+    // Network by traffic locality (flow exporter; bytes within the interval)
+    pub network_local_bytes: Option<u64>,
+    pub network_regional_bytes: Option<u64>,
+    pub network_external_bytes: Option<u64>,
+
+    // Peaks within the aggregation window (hour/day tiers only)
+    pub cpu_usage_nano_cores_max: Option<u64>,
+    pub cpu_usage_nano_cores_p95: Option<u64>,
+    pub cpu_usage_nano_cores_p99: Option<u64>,
+    pub memory_working_set_bytes_max: Option<u64>,
+    pub memory_working_set_bytes_p95: Option<u64>,
+    pub memory_working_set_bytes_p99: Option<u64>,
 }
\ No newline at end of file
@@ -1,5 +1,6 @@
 use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
 use crate::core::persistence::metrics::k8s::pod::metric_pod_entity::MetricPodEntity;
+use crate::core::persistence::metrics::metric_write_buffer::buffer_metric_row;
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 
@@ -7,9 +8,10 @@ use chrono::{DateTime, Utc};
 pub trait MetricPodMinuteCollectorRepository: Send + Sync {
     fn fs_adapter(&self) -> &dyn MetricFsAdapterBase<MetricPodEntity>;
 
-    /// Inserts one metric sample for a given pod.
+    /// Buffers one metric sample for a given pod; written on the next flush.
     fn append_row(&self, pod_uid: &str, data: &MetricPodEntity, now: DateTime<Utc>) -> Result<()> {
-        self.fs_adapter().append_row(pod_uid, data, now)
+        let (path, row) = self.fs_adapter().encode_row(pod_uid, data, now)?;
+        buffer_metric_row(path, row)
     }
 
 }
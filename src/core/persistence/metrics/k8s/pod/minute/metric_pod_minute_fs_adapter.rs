@@ -1,13 +1,11 @@
 use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::metric_segment::segment_stem;
+use crate::core::persistence::storage_backend::storage_backend;
 use crate::core::persistence::metrics::k8s::pod::metric_pod_entity::MetricPodEntity;
 use anyhow::{Result};
 use chrono::{DateTime, NaiveDate, Utc};
-use std::io::BufWriter;
 use std::{
-    fs::File,
-    fs::{self, OpenOptions},
-    io::Write,
-    io::{BufRead, BufReader},
+    io::BufRead,
     path::Path,
 };
 use std::path::PathBuf;
@@ -24,7 +22,7 @@ pub struct MetricPodMinuteFsAdapter;
 impl MetricPodMinuteFsAdapter {
     fn delete_batch(batch: &[PathBuf]) -> Result<()> {
         for path in batch {
-            match fs::remove_file(path) {
+            match storage_backend().remove_segment_file(path) {
                 Ok(_) => tracing::debug!("Deleted old metric file {:?}", path),
                 Err(e) => tracing::error!("Failed to delete {:?}: {}", path, e),
             }
@@ -38,7 +36,8 @@ impl MetricPodMinuteFsAdapter {
 
     fn parse_line(header: &[&str], line: &str) -> Option<MetricPodEntity> {
         let parts: Vec<&str> = line.split('|').collect();
-        if parts.len() != header.len() {
+        // Rows written before the GPU / locality columns were added are shorter
+        if parts.len() > header.len() || parts.len() < 19 {
             return None;
         }
 
@@ -64,6 +63,13 @@ impl MetricPodMinuteFsAdapter {
             pv_capacity_bytes: parts[16].parse().ok(),
             pv_inodes_used: parts[17].parse().ok(),
             pv_inodes: parts[18].parse().ok(),
+            gpu_count: parts.get(19).and_then(|v| v.parse().ok()),
+            gpu_utilization_percent: parts.get(20).and_then(|v| v.parse().ok()),
+            gpu_memory_used_bytes: parts.get(21).and_then(|v| v.parse().ok()),
+            network_local_bytes: parts.get(22).and_then(|v| v.parse().ok()),
+            network_regional_bytes: parts.get(23).and_then(|v| v.parse().ok()),
+            network_external_bytes: parts.get(24).and_then(|v| v.parse().ok()),
+            ..Default::default()
         })
     }
 
@@ -82,28 +88,17 @@ impl MetricPodMinuteFsAdapter {
 }
 
 impl MetricFsAdapterBase<MetricPodEntity> for MetricPodMinuteFsAdapter {
-    fn append_row(&self, pod: &str, dto: &MetricPodEntity, _now: DateTime<Utc>) -> Result<()> {
+    fn encode_row(&self, pod: &str, dto: &MetricPodEntity, _now: DateTime<Utc>) -> Result<(PathBuf, String)> {
         // IMPORTANT: partition by the metric timestamp (dto.time), not by "now".
         // This prevents late-arriving samples/backfills from being written into the wrong file.
         let dto_date = dto.time.date_naive();
-        let path_str = self.build_path_for(pod, dto_date);
-        let path = Path::new(&path_str);
-
-        if let Some(parent) = path.parent() {
-            fs::create_dir_all(parent)?;
-        }
-
-        let file = OpenOptions::new()
-            .create(true)
-            .append(true)
-            .open(&path)?;
-        let mut writer = BufWriter::new(file);
+        let path = self.build_path_for(pod, dto_date);
 
         // Note: empty fields are serialized as empty string ("") to preserve current schema.
         // If you want "missing network metrics" to behave as 0 in later aggregations,
         // consider writing "0" instead of empty for counter fields at the ingestion stage.
         let row = format!(
-            "{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}\n",
+            "{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}\n",
             dto.time.to_rfc3339_opts(chrono::SecondsFormat::Secs, false),
             Self::opt(dto.cpu_usage_nano_cores),
             Self::opt(dto.cpu_usage_core_nano_seconds),
@@ -123,39 +118,38 @@ impl MetricFsAdapterBase<MetricPodEntity> for MetricPodMinuteFsAdapter {
             Self::opt(dto.pv_capacity_bytes),
             Self::opt(dto.pv_inodes_used),
             Self::opt(dto.pv_inodes),
+            Self::opt(dto.gpu_count),
+            Self::opt(dto.gpu_utilization_percent),
+            Self::opt(dto.gpu_memory_used_bytes),
+            Self::opt(dto.network_local_bytes),
+            Self::opt(dto.network_regional_bytes),
+            Self::opt(dto.network_external_bytes),
         );
 
+        Ok((path, row))
+    }
 
-        // ✅ write to buffer
-        writer.write_all(row.as_bytes())?;
-
-        // ✅ ensure everything flushed to disk
-        writer.flush()?;
-        Ok(())
+    fn append_row(&self, pod: &str, dto: &MetricPodEntity, now: DateTime<Utc>) -> Result<()> {
+        let (path, row) = self.encode_row(pod, dto, now)?;
+        storage_backend().append_rows(&path, &row)
     }
 
     fn cleanup_old(&self, pod_uid: &str, before: DateTime<Utc>) -> Result<()> {
         const BATCH_SIZE: usize = 200;
 
         let dir = metric_k8s_pod_key_minute_dir_path(pod_uid);
-        if !dir.exists() {
-            return Ok(());
-        }
 
         let cutoff = before.date_naive();
         let mut batch: Vec<PathBuf> = Vec::with_capacity(BATCH_SIZE);
 
-        for entry in fs::read_dir(&dir)? {
-            let entry = entry?;
-            let path = entry.path();
-
-            // Only process *.rcd
-            if path.extension().and_then(|e| e.to_str()) != Some("rcd") {
+        for path in storage_backend().list_segment_files(&dir)? {
+            // Only process *.rcd / *.rcd.gz
+            if segment_stem(&path).is_none() {
                 continue;
             }
 
             // Extract filename stem
-            let stem = match path.file_stem().and_then(|s| s.to_str()) {
+            let stem = match segment_stem(&path) {
                 Some(s) => s.trim(),
                 None => {
                     tracing::warn!("Skipping file with invalid UTF-8 filename: {:?}", path);
@@ -214,7 +208,7 @@ impl MetricFsAdapterBase<MetricPodEntity> for MetricPodMinuteFsAdapter {
             let path = self.build_path_for(object_name, current_date);
             let path_obj = Path::new(&path);
 
-            if !path_obj.exists() {
+            if !storage_backend().segment_exists(path_obj) {
                 tracing::debug!(
                 "Minute metrics file missing for pod {} on {}",
                 object_name,
@@ -224,7 +218,7 @@ impl MetricFsAdapterBase<MetricPodEntity> for MetricPodMinuteFsAdapter {
                 continue;
             }
 
-            let file = match File::open(&path_obj) {
+            let reader = match storage_backend().open_segment_from(path_obj, start) {
                 Ok(f) => f,
                 Err(e) => {
                     tracing::warn!("Could not open {:?}: {}", path_obj, e);
@@ -233,7 +227,6 @@ impl MetricFsAdapterBase<MetricPodEntity> for MetricPodMinuteFsAdapter {
                 }
             };
 
-            let reader = BufReader::new(file);
             let mut lines = reader.lines();
 
             // Try to read the first line (header or data)
@@ -254,7 +247,9 @@ impl MetricFsAdapterBase<MetricPodEntity> for MetricPodMinuteFsAdapter {
                     "MEMORY_PAGE_FAULTS", "NETWORK_PHYSICAL_RX_BYTES", "NETWORK_PHYSICAL_TX_BYTES",
                     "NETWORK_PHYSICAL_RX_ERRORS", "NETWORK_PHYSICAL_TX_ERRORS",
                     "ES_USED_BYTES", "ES_CAPACITY_BYTES", "ES_INODES_USED", "ES_INODES",
-                    "PV_USED_BYTES", "PV_CAPACITY_BYTES", "PV_INODES_USED", "PV_INODES"
+                    "PV_USED_BYTES", "PV_CAPACITY_BYTES", "PV_INODES_USED", "PV_INODES",
+                    "GPU_COUNT", "GPU_UTILIZATION_PERCENT", "GPU_MEMORY_USED_BYTES",
+                    "NETWORK_LOCAL_BYTES", "NETWORK_REGIONAL_BYTES", "NETWORK_EXTERNAL_BYTES",
                 ];
 
                 if let Some(row) = Self::parse_line(&header, &first_line) {
@@ -273,7 +268,7 @@ impl MetricFsAdapterBase<MetricPodEntity> for MetricPodMinuteFsAdapter {
                         continue;
                     }
                     if row.time > end {
-                        break;
+                        continue;
                     }
                     rows.push(row);
                 }
@@ -5,6 +5,7 @@ use crate::core::persistence::metrics::k8s::pod::minute::metric_pod_minute_fs_ad
 use crate::core::persistence::metrics::k8s::pod::minute::metric_pod_minute_processor_repository_trait::MetricPodMinuteProcessorRepository;
 use crate::core::persistence::metrics::k8s::pod::minute::metric_pod_minute_retention_repository_traits::MetricPodMinuteRetentionRepository;
 use crate::core::persistence::metrics::metric_fs_adapter_base_trait::MetricFsAdapterBase;
+use crate::core::persistence::metrics::metric_write_buffer::buffer_metric_row;
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 use tracing::error;
@@ -67,10 +68,13 @@ impl MetricPodMinuteCollectorRepository for MetricPodMinuteRepository {
     }
 
     fn append_row(&self, pod_uid: &str, data: &MetricPodEntity, now: DateTime<Utc>) -> Result<()> {
-        self.adapter.append_row(pod_uid, data, now).map_err(|err| {
-            error!(error = %err, pod_uid, "Failed to append pod minute row");
-            err
-        })
+        self.adapter
+            .encode_row(pod_uid, data, now)
+            .and_then(|(path, row)| buffer_metric_row(path, row))
+            .map_err(|err| {
+                error!(error = %err, pod_uid, "Failed to append pod minute row");
+                err
+            })
     }
 }
 
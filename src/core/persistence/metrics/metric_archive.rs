@@ -0,0 +1,353 @@
+//! Archive retention: expired metric partitions bundled into `.tar.gz` files.
+//!
+//! Layout under the archive directory:
+//!
+//! ```text
+//! <archive dir>/<kind>/<key>/<tier>-<period>.tar.gz
+//! e.g. archive/node/worker-1/minute-2025-02.tar.gz   (minute days of Feb 2025)
+//!      archive/pod/<uid>/hour-2024.tar.gz            (hour months of 2024)
+//! ```
+//!
+//! A period is archived once all of its partitions have expired, and each
+//! archive is written once and never rewritten. Partitions of an archived
+//! period that show up later (late rows) go into `<tier>-<period>.<n>.tar.gz`.
+//!
+//! Each archive holds the segment files as they were on disk (`.rcd` or
+//! `.rcd.gz`); `.idx` sidecars are dropped.
+//!
+//! Restores extract into a separate `restored/` tree mirroring the live
+//! `metric/` layout. Readers fall back to it for partitions missing from the
+//! live tree, while retention, compression and writers never touch it.
+
+use anyhow::{anyhow, Context, Result};
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::{
+    fs::{self, File},
+    path::{Component, Path, PathBuf},
+};
+
+use crate::core::persistence::info::fixed::setting::info_setting_entity::InfoSettingEntity;
+use crate::core::persistence::metrics::k8s::path::{
+    metric_k8s_container_dir_path, metric_k8s_node_dir_path, metric_k8s_pod_dir_path,
+};
+use crate::core::persistence::metrics::metric_segment::segment_stem;
+use crate::core::persistence::storage_path::get_rustcost_base_path;
+
+const ARCHIVE_EXTENSION: &str = ".tar.gz";
+
+/// Metric object kinds with their own directory tree.
+pub const ARCHIVE_KINDS: [&str; 3] = ["node", "pod", "container"];
+
+/// Tier name used in archive file names → tier directory in the live tree.
+pub const ARCHIVE_TIERS: [(&str, &str); 3] = [("minute", "m"), ("hour", "h"), ("day", "d")];
+
+/// One archive file, as listed by the API.
+#[derive(Debug, Clone, Serialize)]
+pub struct MetricArchiveEntry {
+    /// Path relative to the archive directory (used to restore it)
+    pub archive: String,
+    pub kind: String,
+    pub key: String,
+    pub tier: String,
+    /// `YYYY-MM` for minute archives, `YYYY` for hour and day archives
+    pub period: String,
+    pub size_bytes: u64,
+}
+
+/// Files written back by a restore.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct MetricArchiveRestoreSummary {
+    pub archive: String,
+    pub restored: Vec<String>,
+    /// Partitions already present in the live or restored tree (left untouched)
+    pub skipped: Vec<String>,
+}
+
+/// Root of the read-only tree archives are restored into.
+pub fn restored_root() -> PathBuf {
+    get_rustcost_base_path().join("restored")
+}
+
+/// Location of a live path (under the base path) in the restored tree.
+pub fn restored_path(path: &Path) -> Option<PathBuf> {
+    let relative = path.strip_prefix(get_rustcost_base_path()).ok()?;
+    Some(restored_root().join(relative))
+}
+
+pub fn archive_root(settings: &InfoSettingEntity) -> PathBuf {
+    settings
+        .archive_dir
+        .as_ref()
+        .map(PathBuf::from)
+        .unwrap_or_else(|| get_rustcost_base_path().join("archive"))
+}
+
+/// Live metric directory of a kind (`metric/k8s/<kind>`).
+pub fn kind_dir_path(kind: &str) -> Option<PathBuf> {
+    match kind {
+        "node" => Some(metric_k8s_node_dir_path()),
+        "pod" => Some(metric_k8s_pod_dir_path()),
+        "container" => Some(metric_k8s_container_dir_path()),
+        _ => None,
+    }
+}
+
+fn tier_dir_name(tier: &str) -> Option<&'static str> {
+    ARCHIVE_TIERS.iter().find(|(name, _)| *name == tier).map(|(_, dir)| *dir)
+}
+
+/// Archive period of a partition: its name minus the last date component
+/// (`2025-02-14` → `2025-02`, `2025-02` → `2025`, `2025` → `2025`).
+fn period_of(stem: &str) -> &str {
+    stem.rsplit_once('-').map(|(period, _)| period).unwrap_or(stem)
+}
+
+/// Moves the partitions of every period in `tier_dir` that lies entirely
+/// before `cutoff_stem` into a new `<root>/<kind>/<key>/<tier>-<period>.tar.gz`
+/// (or `.<n>.tar.gz` when that period was archived before). Partitions of the
+/// period containing the cutoff stay until it has fully expired. Returns the
+/// number of partitions archived.
+pub fn archive_expired_segments(
+    root: &Path,
+    kind: &str,
+    key: &str,
+    tier: &str,
+    tier_dir: &Path,
+    cutoff_stem: &str,
+) -> Result<usize> {
+    if !tier_dir.exists() {
+        return Ok(0);
+    }
+
+    // period → files of that period, for periods that have fully expired
+    let cutoff_period = period_of(cutoff_stem);
+    let mut expired: BTreeMap<String, Vec<PathBuf>> = BTreeMap::new();
+    for entry in fs::read_dir(tier_dir)? {
+        let path = entry?.path();
+        let Some(stem) = segment_stem(&path) else {
+            continue;
+        };
+        let period = period_of(stem);
+        if stem < cutoff_stem && period < cutoff_period {
+            expired.entry(period.to_string()).or_default().push(path);
+        }
+    }
+
+    let mut archived = 0;
+    for (period, files) in expired {
+        let (segments, sidecars): (Vec<_>, Vec<_>) =
+            files.into_iter().partition(|p| !p.to_string_lossy().ends_with(".idx"));
+
+        let archive = next_archive_path(&root.join(kind).join(key), tier, &period);
+        write_archive(&archive, &segments)?;
+
+        for path in segments.iter().chain(&sidecars) {
+            fs::remove_file(path).with_context(|| format!("Failed to remove {:?}", path))?;
+        }
+        archived += segments.len();
+    }
+    Ok(archived)
+}
+
+/// First free `<tier>-<period>[.<n>].tar.gz` in `dir`.
+fn next_archive_path(dir: &Path, tier: &str, period: &str) -> PathBuf {
+    let first = dir.join(format!("{tier}-{period}{ARCHIVE_EXTENSION}"));
+    if !first.exists() {
+        return first;
+    }
+    (1..)
+        .map(|n| dir.join(format!("{tier}-{period}.{n}{ARCHIVE_EXTENSION}")))
+        .find(|path| !path.exists())
+        .unwrap_or(first)
+}
+
+/// Writes `files` into a new `archive`. Written to a temporary file and
+/// renamed, so an interrupted run never leaves a partial archive.
+fn write_archive(archive: &Path, files: &[PathBuf]) -> Result<()> {
+    if files.is_empty() {
+        return Ok(());
+    }
+    if let Some(parent) = archive.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let tmp_path = archive.with_extension("gz.tmp");
+    let tmp = File::create(&tmp_path).with_context(|| format!("Failed to create {:?}", tmp_path))?;
+    let mut builder = tar::Builder::new(GzEncoder::new(tmp, Compression::default()));
+
+    for path in files {
+        let name = path.file_name().ok_or_else(|| anyhow!("Invalid segment path {:?}", path))?;
+        builder
+            .append_path_with_name(path, name)
+            .with_context(|| format!("Failed to archive {:?}", path))?;
+    }
+
+    builder.into_inner()?.finish()?.sync_all()?;
+    fs::rename(&tmp_path, archive).with_context(|| format!("Failed to rename {:?}", tmp_path))?;
+    Ok(())
+}
+
+/// Lists all archives under `root`, sorted by path.
+pub fn list_archives(root: &Path) -> Result<Vec<MetricArchiveEntry>> {
+    let mut archives = Vec::new();
+    for kind in ARCHIVE_KINDS {
+        let kind_dir = root.join(kind);
+        if !kind_dir.exists() {
+            continue;
+        }
+        for key_entry in fs::read_dir(&kind_dir)? {
+            let key_dir = key_entry?.path();
+            if !key_dir.is_dir() {
+                continue;
+            }
+            let key = key_dir.file_name().unwrap_or_default().to_string_lossy().into_owned();
+
+            for file in fs::read_dir(&key_dir)? {
+                let file = file?;
+                let name = file.file_name().to_string_lossy().into_owned();
+                let Some((tier, period)) = parse_archive_name(&name) else {
+                    continue;
+                };
+                archives.push(MetricArchiveEntry {
+                    archive: format!("{kind}/{key}/{name}"),
+                    kind: kind.to_string(),
+                    key: key.clone(),
+                    tier: tier.to_string(),
+                    period: period.to_string(),
+                    size_bytes: file.metadata().map(|m| m.len()).unwrap_or(0),
+                });
+            }
+        }
+    }
+
+    archives.sort_by(|a, b| a.archive.cmp(&b.archive));
+    Ok(archives)
+}
+
+/// `minute-2025-02.tar.gz` / `minute-2025-02.1.tar.gz` → `("minute", "2025-02")`
+fn parse_archive_name(name: &str) -> Option<(&str, &str)> {
+    let (tier, rest) = name.strip_suffix(ARCHIVE_EXTENSION)?.split_once('-')?;
+    tier_dir_name(tier)?;
+    let period = rest.split_once('.').map_or(rest, |(period, _)| period);
+    Some((tier, period))
+}
+
+/// Extracts an archive (path relative to `root`, as returned by
+/// [`list_archives`]) into the restored tree (see [`restored_root`]).
+/// Partitions that already exist in the live or restored tree are skipped,
+/// never overwritten.
+pub fn restore_archive(root: &Path, archive: &str) -> Result<MetricArchiveRestoreSummary> {
+    let relative = Path::new(archive);
+    let parts: Vec<&str> = relative
+        .components()
+        .map(|c| match c {
+            Component::Normal(part) => part.to_str(),
+            _ => None,
+        })
+        .collect::<Option<_>>()
+        .ok_or_else(|| anyhow!("Invalid archive path '{}'", archive))?;
+
+    let [kind, key, name] = parts[..] else {
+        return Err(anyhow!("Archive path must be '<kind>/<key>/<file>', got '{}'", archive));
+    };
+    let kind_dir = kind_dir_path(kind).ok_or_else(|| anyhow!("Unknown metric kind '{}'", kind))?;
+    let (tier, _) = parse_archive_name(name).ok_or_else(|| anyhow!("Not a metric archive: '{}'", name))?;
+    let live_dir = kind_dir.join(key).join(tier_dir_name(tier).unwrap_or_default());
+    let tier_dir = restored_path(&live_dir)
+        .ok_or_else(|| anyhow!("Metric directory {:?} is outside the data directory", live_dir))?;
+
+    let path = root.join(relative);
+    let file = File::open(&path).with_context(|| format!("Archive {:?} not found", path))?;
+    fs::create_dir_all(&tier_dir)?;
+
+    let mut summary = MetricArchiveRestoreSummary {
+        archive: archive.to_string(),
+        ..Default::default()
+    };
+    let mut tar = tar::Archive::new(GzDecoder::new(file));
+    for entry in tar.entries()? {
+        let mut entry = entry?;
+        // Only the file name is trusted; archives are flat
+        let entry_path = entry.path()?.into_owned();
+        let Some(file_name) = entry_path.file_name().map(|n| n.to_string_lossy().into_owned()) else {
+            continue;
+        };
+        if segment_stem(Path::new(&file_name)).is_none() {
+            continue;
+        }
+
+        let target = tier_dir.join(&file_name);
+        if target.exists() || live_dir.join(&file_name).exists() {
+            summary.skipped.push(file_name);
+            continue;
+        }
+        entry.unpack(&target).with_context(|| format!("Failed to restore {:?}", target))?;
+        summary.restored.push(file_name);
+    }
+
+    Ok(summary)
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn archives_each_expired_period_once() {
+        let base = std::env::temp_dir().join(format!("rcd-archive-{}", std::process::id()));
+        let live = base.join("live");
+        let root = base.join("archive");
+        fs::create_dir_all(&live).unwrap();
+        for name in ["2025-01-31.rcd", "2025-02-01.rcd.gz", "2025-02-01.rcd.idx", "2025-02-02.rcd", "2025-03-01.rcd"] {
+            fs::write(live.join(name), name).unwrap();
+        }
+
+        // February has not fully expired yet: only January is archived
+        let n = archive_expired_segments(&root, "node", "n1", "minute", &live, "2025-02-02").unwrap();
+        assert_eq!(n, 1);
+        assert!(live.join("2025-02-01.rcd.gz").exists());
+
+        let n = archive_expired_segments(&root, "node", "n1", "minute", &live, "2025-03-01").unwrap();
+        assert_eq!(n, 2);
+        assert!(!live.join("2025-02-01.rcd.idx").exists());
+        assert!(live.join("2025-03-01.rcd").exists());
+
+        // A late partition of an archived period gets its own archive
+        fs::write(live.join("2025-02-03.rcd"), "late").unwrap();
+        let n = archive_expired_segments(&root, "node", "n1", "minute", &live, "2025-03-01").unwrap();
+        assert_eq!(n, 1);
+
+        let listed = list_archives(&root).unwrap();
+        let archives: Vec<_> = listed.iter().map(|a| (a.archive.as_str(), a.period.as_str())).collect();
+        assert_eq!(
+            archives,
+            [
+                ("node/n1/minute-2025-01.tar.gz", "2025-01"),
+                ("node/n1/minute-2025-02.1.tar.gz", "2025-02"),
+                ("node/n1/minute-2025-02.tar.gz", "2025-02"),
+            ]
+        );
+
+        // Entries unpack by file name
+        let mut names: Vec<String> = tar::Archive::new(GzDecoder::new(
+            File::open(root.join("node/n1/minute-2025-02.tar.gz")).unwrap(),
+        ))
+        .entries()
+        .unwrap()
+        .map(|e| e.unwrap().path().unwrap().to_string_lossy().into_owned())
+        .collect();
+        names.sort();
+        assert_eq!(names, ["2025-02-01.rcd.gz", "2025-02-02.rcd"]);
+
+        assert!(restore_archive(&root, "../etc/passwd").is_err());
+        assert!(restore_archive(&root, "node/n1/../../x.tar.gz").is_err());
+
+        fs::remove_dir_all(&base).unwrap();
+    }
+}
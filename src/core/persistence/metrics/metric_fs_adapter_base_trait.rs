@@ -1,5 +1,6 @@
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use chrono::{DateTime, Utc};
+use std::path::PathBuf;
 
 /// Unified FS adapter trait for metrics (collector, processor, and API).
 /// Each implementation may only use a subset of these methods.
@@ -11,6 +12,14 @@ pub trait MetricFsAdapterBase<T>: Send + Sync {
         unimplemented!("append_row not used in this adapter")
     }
 
+    /// Partition path and serialized line for one raw row, without writing it
+    /// (used by the collector write buffer). Only minute adapters buffer rows;
+    /// the others return an error.
+    #[allow(unused_variables)]
+    fn encode_row(&self, name: &str, data: &T, now: DateTime<Utc>) -> Result<(PathBuf, String)> {
+        Err(anyhow!("encode_row is not supported by this adapter"))
+    }
+
     // === Processor-like ===
     /// Append aggregated metrics (e.g. hour, day)
     #[allow(unused_variables)]
@@ -0,0 +1,303 @@
+//! `.idx` sidecar index for metric partitions.
+//!
+//! For `2025-02-14.rcd` the index lives in `2025-02-14.rcd.idx` and records the
+//! byte offset of every `INDEX_STRIDE`-th row together with the latest row
+//! time seen before it, so a range read can seek past rows that all precede
+//! its start. Using the running maximum rather than the row's own time keeps
+//! the seek correct when late samples were appended out of order. Offsets are
+//! positions in the logical row stream (compressed rows first, then plain
+//! rows; see `metric_segment`).
+//!
+//! Layout (pipe-delimited like the segments themselves):
+//!
+//! ```text
+//! <gz bytes>|<plain bytes>
+//! <latest row time before offset (unix secs)>|<byte offset>
+//! ...
+//! ```
+//!
+//! Indexes are written only for closed partitions, by the day task
+//! ([`index_closed_segments`]); readers never write them. Rows appended to a
+//! closed partition afterwards lie past every indexed offset and are always
+//! read. Any other change to the segment files makes the index stale, and it
+//! is ignored until the next rebuild.
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use std::{
+    collections::BTreeSet,
+    fs::{self, File},
+    io::{self, BufRead, BufReader, Read, Seek, SeekFrom, Write},
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use crate::core::persistence::metrics::metric_segment::{
+    compressed_path, open_segment, segment_exists, segment_stem, with_segment_lock,
+};
+
+/// One index entry every this many rows (one per hour for minute files).
+const INDEX_STRIDE: u64 = 60;
+
+/// Distinguishes temporary index files written concurrently by this process.
+static TMP_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// `<path>.idx` for a plain `.rcd` path.
+pub fn index_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(".idx");
+    PathBuf::from(name)
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+struct SegmentIndex {
+    gz_bytes: u64,
+    plain_bytes: u64,
+    /// `(latest row time before the offset in unix seconds, logical byte offset)`,
+    /// both ascending
+    entries: Vec<(i64, u64)>,
+}
+
+impl SegmentIndex {
+    fn load(path: &Path) -> Option<Self> {
+        let text = fs::read_to_string(path).ok()?;
+        let mut lines = text.lines();
+
+        let head: Vec<u64> = lines
+            .next()?
+            .split('|')
+            .map(|v| v.parse().ok())
+            .collect::<Option<_>>()?;
+        let [gz_bytes, plain_bytes] = head[..] else {
+            return None;
+        };
+
+        let entries = lines
+            .map(|line| {
+                let (time, offset) = line.split_once('|')?;
+                Some((time.parse().ok()?, offset.parse().ok()?))
+            })
+            .collect::<Option<_>>()?;
+
+        Some(Self { gz_bytes, plain_bytes, entries })
+    }
+
+    /// Scans the whole segment. A trailing row without `\n` (torn write) is
+    /// not indexed; it is still read, since it lies past every offset.
+    fn build(path: &Path) -> Result<Self> {
+        let (gz_bytes, plain_bytes) = fingerprint(path);
+        let mut index = Self { gz_bytes, plain_bytes, entries: Vec::new() };
+
+        let mut reader = open_segment(path)?;
+        let (mut offset, mut rows, mut latest) = (0u64, 0u64, i64::MIN);
+        let mut line = String::new();
+        loop {
+            line.clear();
+            let read = reader.read_line(&mut line)?;
+            if read == 0 || !line.ends_with('\n') {
+                return Ok(index);
+            }
+
+            let time = line
+                .split('|')
+                .next()
+                .and_then(|v| v.trim().parse::<DateTime<Utc>>().ok());
+            match time {
+                Some(time) => {
+                    if rows.is_multiple_of(INDEX_STRIDE) {
+                        index.entries.push((latest, offset));
+                    }
+                    rows += 1;
+                    latest = latest.max(time.timestamp());
+                }
+                // Legacy header line: rows can't be seeked without it
+                None if offset == 0 => return Ok(index),
+                None => {}
+            }
+            offset += read as u64;
+        }
+    }
+
+    /// Writes through a uniquely named temporary file so readers never see a
+    /// partial index and concurrent writers don't clobber each other.
+    fn save(&self, path: &Path) -> Result<()> {
+        let mut tmp_name = path.as_os_str().to_owned();
+        tmp_name.push(format!(
+            ".{}.{}.tmp",
+            std::process::id(),
+            TMP_COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        let tmp_path = PathBuf::from(tmp_name);
+
+        let mut out = format!("{}|{}\n", self.gz_bytes, self.plain_bytes);
+        for (time, offset) in &self.entries {
+            out.push_str(&format!("{}|{}\n", time, offset));
+        }
+
+        let mut file = File::create(&tmp_path)
+            .with_context(|| format!("Failed to create {:?}", tmp_path))?;
+        file.write_all(out.as_bytes())?;
+        fs::rename(&tmp_path, path).with_context(|| format!("Failed to rename {:?}", tmp_path))?;
+        Ok(())
+    }
+
+    /// True when the index describes the segment, possibly followed by
+    /// appended plain rows.
+    fn usable_for(&self, path: &Path) -> bool {
+        let (gz_bytes, plain_bytes) = fingerprint(path);
+        gz_bytes == self.gz_bytes && plain_bytes >= self.plain_bytes
+    }
+
+    /// Offset of the last entry whose preceding rows all lie before `start`.
+    fn seek_offset(&self, start: DateTime<Utc>) -> u64 {
+        let start = start.timestamp();
+        let idx = self.entries.partition_point(|(latest, _)| *latest < start);
+        idx.checked_sub(1).map(|i| self.entries[i].1).unwrap_or(0)
+    }
+}
+
+/// Sizes of the compressed and plain parts of a segment (0 when absent).
+fn fingerprint(path: &Path) -> (u64, u64) {
+    let len = |p: &Path| fs::metadata(p).map(|m| m.len()).unwrap_or(0);
+    (len(&compressed_path(path)), len(path))
+}
+
+/// (Re)builds the index of a segment.
+pub fn write_index(path: &Path) -> Result<()> {
+    with_segment_lock(path, || SegmentIndex::build(path)?.save(&index_path(path)))
+}
+
+/// Indexes every partition in `dir` whose name sorts before `open_stem` and
+/// whose index is missing or stale. Returns the number of indexes written.
+pub fn index_closed_segments(dir: &Path, open_stem: &str) -> Result<usize> {
+    if !dir.exists() {
+        return Ok(0);
+    }
+
+    let mut stems = BTreeSet::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if let Some(stem) = segment_stem(&path).filter(|stem| *stem < open_stem) {
+            stems.insert(stem.to_string());
+        }
+    }
+
+    let mut written = 0;
+    for stem in stems {
+        let path = dir.join(format!("{}.rcd", stem));
+        let current = SegmentIndex::load(&index_path(&path))
+            .is_some_and(|index| (index.gz_bytes, index.plain_bytes) == fingerprint(&path));
+        if segment_exists(&path) && !current {
+            write_index(&path)?;
+            written += 1;
+        }
+    }
+    Ok(written)
+}
+
+/// Opens the logical row stream of a segment positioned at `offset`.
+/// Plain-only segments seek directly; compressed ones decompress and discard.
+fn open_at(path: &Path, offset: u64) -> io::Result<Box<dyn BufRead>> {
+    if !compressed_path(path).exists() {
+        let mut file = File::open(path)?;
+        file.seek(SeekFrom::Start(offset))?;
+        return Ok(Box::new(BufReader::new(file)));
+    }
+
+    let mut reader = open_segment(path)?;
+    io::copy(&mut (&mut reader).take(offset), &mut io::sink())?;
+    Ok(reader)
+}
+
+/// Opens a segment positioned at or shortly before the first row with
+/// `time >= start`, using the `.idx` sidecar when one is usable, and reading
+/// from the beginning otherwise.
+pub fn open_segment_from(path: &Path, start: DateTime<Utc>) -> io::Result<Box<dyn BufRead>> {
+    match SegmentIndex::load(&index_path(path)) {
+        Some(index) if index.usable_for(path) => open_at(path, index.seek_offset(start)),
+        _ => open_segment(path),
+    }
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::persistence::metrics::metric_segment::compress_segment;
+    use chrono::{Duration, TimeZone};
+    use std::fs::OpenOptions;
+
+    fn append_rows(path: &Path, minutes: impl IntoIterator<Item = i64>) {
+        let base = Utc.with_ymd_and_hms(2025, 2, 14, 0, 0, 0).unwrap();
+        let mut file = OpenOptions::new().create(true).append(true).open(path).unwrap();
+        for i in minutes {
+            let time = (base + Duration::minutes(i)).to_rfc3339();
+            writeln!(file, "{}|{}", time, i).unwrap();
+        }
+    }
+
+    fn first_value(path: &Path, start: DateTime<Utc>) -> String {
+        let mut line = String::new();
+        open_segment_from(path, start).unwrap().read_line(&mut line).unwrap();
+        line.trim_end().rsplit('|').next().unwrap().to_string()
+    }
+
+    #[test]
+    fn seeks_near_start_and_survives_growth_and_compression() {
+        let dir = std::env::temp_dir().join(format!("rcd-index-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("2025-02-14.rcd");
+        let at = |minute| Utc.with_ymd_and_hms(2025, 2, 14, 0, 0, 0).unwrap() + Duration::minutes(minute);
+
+        // Reads never write an index
+        append_rows(&path, 0..150);
+        assert_eq!(first_value(&path, at(130)), "0");
+        assert!(!index_path(&path).exists());
+
+        // Closed partition: indexed once, then seeked
+        assert_eq!(index_closed_segments(&dir, "2025-02-15").unwrap(), 1);
+        assert_eq!(index_closed_segments(&dir, "2025-02-15").unwrap(), 0);
+        assert_eq!(first_value(&path, at(130)), "120");
+        assert_eq!(first_value(&path, at(0)), "0");
+
+        // Late rows appended after indexing are still read
+        append_rows(&path, [125]);
+        let late: Vec<String> = open_segment_from(&path, at(125))
+            .unwrap()
+            .lines()
+            .map_while(Result::ok)
+            .filter(|l| l.ends_with("|125"))
+            .collect();
+        assert_eq!(late.len(), 2);
+
+        // Compression makes the index stale until it is rebuilt
+        compress_segment(&path).unwrap();
+        assert_eq!(first_value(&path, at(130)), "0");
+        index_closed_segments(&dir, "2025-02-15").unwrap();
+        assert_eq!(first_value(&path, at(130)), "120");
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn out_of_order_rows_are_not_skipped() {
+        let dir = std::env::temp_dir().join(format!("rcd-index-late-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("2025-02-14.rcd");
+        let at = |minute| Utc.with_ymd_and_hms(2025, 2, 14, 0, 0, 0).unwrap() + Duration::minutes(minute);
+
+        // A late sample for minute 200 lands among rows for minutes 0..120
+        append_rows(&path, (0..60).chain([200]).chain(60..120));
+        write_index(&path).unwrap();
+
+        let values: Vec<String> = open_segment_from(&path, at(150))
+            .unwrap()
+            .lines()
+            .map_while(Result::ok)
+            .collect();
+        assert!(values.iter().any(|l| l.ends_with("|200")));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
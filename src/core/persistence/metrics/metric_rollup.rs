@@ -0,0 +1,411 @@
+//! Materialization ledger for the hour and day tiers.
+//!
+//! Every rolled-up tier directory (`<key>/h`, `<key>/d`) holds `rollup.rci`,
+//! which records, for each bucket it produced, a fingerprint of the source
+//! rows the bucket was built from:
+//!
+//! ```text
+//! <bucket start (unix secs)>:<source rows>|<crc32 of source rows>
+//! ```
+//!
+//! A bucket is (re)aggregated when its source has rows but no ledger entry
+//! (a missed tick, a restart) or a different fingerprint (late samples, or a
+//! re-aggregated hour under a day). Source partitions that received rows out
+//! of time order are re-sorted first so range readers see the late rows, and
+//! target partitions are rewritten with one row per bucket time (the newest),
+//! so aggregating a bucket twice is harmless.
+
+use anyhow::Result;
+use chrono::{DateTime, Duration, Months, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::{self, BufRead};
+use std::path::Path;
+use std::sync::Mutex;
+use tracing::{debug, warn};
+
+use crate::core::persistence::metrics::metric_segment::segment_stem;
+use crate::core::persistence::metrics::metric_write_buffer::with_metric_writes_paused;
+use crate::core::persistence::storage_backend::storage_backend;
+
+const LEDGER_NAME: &str = "rollup.rci";
+
+/// Serializes the hour loop, the day loop and forced re-aggregation.
+static ROLLUP_LOCK: Mutex<()> = Mutex::new(());
+
+/// Tier produced by a rollup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RollupTier {
+    /// Minute rows → hour buckets
+    Hour,
+    /// Hour rows → day buckets
+    Day,
+}
+
+impl RollupTier {
+    fn source_dir(self) -> &'static str {
+        match self {
+            Self::Hour => "m",
+            Self::Day => "h",
+        }
+    }
+
+    fn target_dir(self) -> &'static str {
+        match self {
+            Self::Hour => "h",
+            Self::Day => "d",
+        }
+    }
+
+    pub fn bucket_len(self) -> Duration {
+        match self {
+            Self::Hour => Duration::hours(1),
+            Self::Day => Duration::days(1),
+        }
+    }
+
+    /// Start of the bucket containing `t`.
+    pub fn bucket_start(self, t: DateTime<Utc>) -> DateTime<Utc> {
+        let len = self.bucket_len().num_seconds();
+        DateTime::from_timestamp(t.timestamp().div_euclid(len) * len, 0).unwrap_or(t)
+    }
+}
+
+/// Buckets looked at by one rollup pass.
+#[derive(Debug, Clone, Copy)]
+pub struct RollupWindow {
+    /// Earliest bucket start
+    pub from: DateTime<Utc>,
+    /// Latest bucket end
+    pub to: DateTime<Utc>,
+    /// Re-aggregate every bucket with source rows, even if up to date
+    pub force: bool,
+}
+
+/// Outcome of a rollup pass.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct RollupReport {
+    pub objects: usize,
+    /// Buckets aggregated for the first time or again
+    pub materialized: usize,
+    pub failed: usize,
+}
+
+impl RollupReport {
+    pub fn merge(&mut self, other: RollupReport) {
+        self.objects += other.objects;
+        self.materialized += other.materialized;
+        self.failed += other.failed;
+    }
+}
+
+/// Source rows a bucket was built from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Fingerprint {
+    rows: usize,
+    crc: u32,
+}
+
+/// Source side of one object's tier.
+struct SourceScan {
+    /// Bucket start (unix secs) → fingerprint, for buckets with source rows
+    buckets: BTreeMap<i64, Fingerprint>,
+    /// Start of the oldest source partition
+    oldest: Option<DateTime<Utc>>,
+}
+
+/// Row time (leading RFC3339 column) and line; `None` for header lines.
+type Row = (Option<DateTime<Utc>>, String);
+
+/// Brings the `tier` buckets of one object up to date within `window`.
+///
+/// `key_dir` is the object's metric directory (e.g. `metric/k8s/pod/<uid>`);
+/// `aggregate(start, end)` appends the row of one bucket to the target tier.
+pub fn rollup_object(
+    key_dir: &Path,
+    tier: RollupTier,
+    window: &RollupWindow,
+    mut aggregate: impl FnMut(DateTime<Utc>, DateTime<Utc>) -> Result<()>,
+) -> Result<RollupReport> {
+    let _guard = ROLLUP_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let target_dir = key_dir.join(tier.target_dir());
+    let ledger_path = target_dir.join(LEDGER_NAME);
+
+    let scan = scan_sources(&key_dir.join(tier.source_dir()), tier, window)?;
+    let mut ledger = read_ledger(&ledger_path)?;
+    let mut report = RollupReport { objects: 1, ..Default::default() };
+    let mut touched: Option<(DateTime<Utc>, DateTime<Utc>)> = None;
+
+    for (start, fingerprint) in scan.buckets {
+        if !window.force && ledger.get(&start) == Some(&fingerprint) {
+            continue;
+        }
+        let Some(start_time) = DateTime::from_timestamp(start, 0) else {
+            continue;
+        };
+        let end = start_time + tier.bucket_len();
+
+        match aggregate(start_time, end) {
+            Ok(()) => {
+                ledger.insert(start, fingerprint);
+                report.materialized += 1;
+                touched = Some(touched.map_or((end, end), |(first, _)| (first, end)));
+            }
+            Err(e) => {
+                warn!("Failed to aggregate {:?} bucket {}: {}", target_dir, start_time, e);
+                report.failed += 1;
+            }
+        }
+    }
+
+    if let Some((first, last)) = touched {
+        normalize_targets(&target_dir, first, last)?;
+    }
+
+    // Buckets whose source partitions expired can't be checked again
+    let entries = ledger.len();
+    if let Some(oldest) = scan.oldest {
+        ledger.retain(|start, _| *start >= oldest.timestamp());
+    }
+    if report.materialized > 0 || ledger.len() != entries {
+        write_ledger(&ledger_path, &ledger)?;
+    }
+    Ok(report)
+}
+
+/// Fingerprints the buckets in `window` from the source partitions in `dir`.
+fn scan_sources(dir: &Path, tier: RollupTier, window: &RollupWindow) -> Result<SourceScan> {
+    let len = tier.bucket_len();
+    let mut buckets: BTreeMap<i64, (usize, crc32fast::Hasher)> = BTreeMap::new();
+    let mut oldest: Option<DateTime<Utc>> = None;
+
+    for stem in partition_stems(dir)? {
+        let Some((span_start, span_end)) = stem_span(&stem) else {
+            continue;
+        };
+        oldest = Some(oldest.map_or(span_start, |o| o.min(span_start)));
+        if span_end < window.from || span_start > window.to {
+            continue;
+        }
+
+        let path = dir.join(format!("{}.rcd", stem));
+        let mut rows = read_rows(&path, window.from)?;
+        if !rows.windows(2).all(|w| w[0].0 <= w[1].0) {
+            // Late rows were appended after newer ones; range readers stop at
+            // the first row past their end and would never see them
+            rows = with_metric_writes_paused(|| sort_segment(&path))?;
+        }
+
+        for (time, line) in &rows {
+            let Some(time) = *time else {
+                continue;
+            };
+            let start = tier.bucket_start(time);
+            // Bucket ranges include their end, so a row on a boundary also
+            // belongs to the previous bucket
+            let owners = if time == start { vec![start - len, start] } else { vec![start] };
+            for owner in owners {
+                if owner < window.from || owner + len > window.to {
+                    continue;
+                }
+                let (count, hasher) = buckets.entry(owner.timestamp()).or_default();
+                *count += 1;
+                hasher.update(line.as_bytes());
+                hasher.update(b"\n");
+            }
+        }
+    }
+
+    let buckets = buckets
+        .into_iter()
+        .map(|(start, (rows, hasher))| (start, Fingerprint { rows, crc: hasher.finalize() }))
+        .collect();
+    Ok(SourceScan { buckets, oldest })
+}
+
+/// Rewrites target partitions holding bucket times in `[first, last]` in time
+/// order, keeping the newest row of each time.
+fn normalize_targets(dir: &Path, first: DateTime<Utc>, last: DateTime<Utc>) -> Result<()> {
+    for stem in partition_stems(dir)? {
+        let Some((span_start, span_end)) = stem_span(&stem) else {
+            continue;
+        };
+        if span_end < first || span_start > last {
+            continue;
+        }
+
+        let path = dir.join(format!("{}.rcd", stem));
+        let rows = read_rows(&path, DateTime::<Utc>::MIN_UTC)?;
+        let mut sorted = rows.clone();
+        sorted.sort_by_key(|(time, _)| *time);
+
+        let mut latest: Vec<Row> = Vec::with_capacity(sorted.len());
+        for row in sorted {
+            match latest.last_mut() {
+                Some(prev) if prev.0.is_some() && prev.0 == row.0 => *prev = row,
+                _ => latest.push(row),
+            }
+        }
+
+        if latest != rows {
+            storage_backend().replace_segment(&path, &join_rows(&latest))?;
+            debug!("Rewrote {:?} with {} rows after re-aggregation", path, latest.len());
+        }
+    }
+    Ok(())
+}
+
+/// Rewrites a partition in time order; rows with equal times keep their order.
+fn sort_segment(path: &Path) -> Result<Vec<Row>> {
+    let mut rows = read_rows(path, DateTime::<Utc>::MIN_UTC)?;
+    rows.sort_by_key(|(time, _)| *time);
+    storage_backend().replace_segment(path, &join_rows(&rows))?;
+    debug!("Re-sorted {:?} after late rows", path);
+    Ok(rows)
+}
+
+fn read_rows(path: &Path, from: DateTime<Utc>) -> Result<Vec<Row>> {
+    let reader = match storage_backend().open_segment_from(path, from) {
+        Ok(reader) => reader,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e.into()),
+    };
+
+    let mut rows = Vec::new();
+    for line in reader.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let time = line.split('|').next().and_then(|v| v.trim().parse().ok());
+        rows.push((time, line));
+    }
+    Ok(rows)
+}
+
+fn join_rows(rows: &[Row]) -> String {
+    rows.iter().map(|(_, line)| format!("{}\n", line)).collect()
+}
+
+/// Partition names in a tier directory (plain and compressed forms merged).
+fn partition_stems(dir: &Path) -> Result<BTreeSet<String>> {
+    Ok(storage_backend()
+        .list_segment_files(dir)?
+        .iter()
+        .filter_map(|path| segment_stem(path).map(str::to_string))
+        .collect())
+}
+
+/// Time span of a partition named `YYYY`, `YYYY-MM` or `YYYY-MM-DD`.
+fn stem_span(stem: &str) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+    let (first, next) = match stem.len() {
+        4 => {
+            let year: i32 = stem.parse().ok()?;
+            (NaiveDate::from_ymd_opt(year, 1, 1)?, NaiveDate::from_ymd_opt(year + 1, 1, 1)?)
+        }
+        7 => {
+            let first = NaiveDate::parse_from_str(&format!("{}-01", stem), "%Y-%m-%d").ok()?;
+            (first, first.checked_add_months(Months::new(1))?)
+        }
+        10 => {
+            let day = NaiveDate::parse_from_str(stem, "%Y-%m-%d").ok()?;
+            (day, day.succ_opt()?)
+        }
+        _ => return None,
+    };
+    Some((
+        first.and_hms_opt(0, 0, 0)?.and_utc(),
+        next.and_hms_opt(0, 0, 0)?.and_utc(),
+    ))
+}
+
+fn read_ledger(path: &Path) -> Result<BTreeMap<i64, Fingerprint>> {
+    let Some(text) = storage_backend().read_record(path)? else {
+        return Ok(BTreeMap::new());
+    };
+
+    Ok(text
+        .lines()
+        .filter_map(|line| {
+            let (start, value) = line.split_once(':')?;
+            let (rows, crc) = value.split_once('|')?;
+            let fingerprint = Fingerprint {
+                rows: rows.trim().parse().ok()?,
+                crc: u32::from_str_radix(crc.trim(), 16).ok()?,
+            };
+            Some((start.trim().parse().ok()?, fingerprint))
+        })
+        .collect())
+}
+
+fn write_ledger(path: &Path, ledger: &BTreeMap<i64, Fingerprint>) -> Result<()> {
+    let text: String = ledger
+        .iter()
+        .map(|(start, fp)| format!("{}:{}|{:08x}\n", start, fp.rows, fp.crc))
+        .collect();
+    storage_backend().write_record(path, &text)
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use std::fs;
+
+    #[test]
+    fn aggregates_gaps_and_late_samples_once() {
+        let key_dir = std::env::temp_dir().join(format!("rcd-rollup-{}", std::process::id()));
+        let minute = key_dir.join("m/2025-02-14.rcd");
+        let hour = key_dir.join("h/2025-02.rcd");
+        let backend = storage_backend();
+
+        // Two closed hours; 01:30 arrives late, after 02:00 was written
+        backend
+            .append_rows(&minute, "2025-02-14T00:00:00+00:00|1\n2025-02-14T00:30:00+00:00|2\n")
+            .unwrap();
+        backend
+            .append_rows(&minute, "2025-02-14T02:00:00+00:00|3\n2025-02-14T01:30:00+00:00|4\n")
+            .unwrap();
+
+        let calls = Cell::new(0);
+        let aggregate = |_start: DateTime<Utc>, end: DateTime<Utc>| {
+            calls.set(calls.get() + 1);
+            let row = format!("{}|{}\n", end.to_rfc3339(), calls.get());
+            storage_backend().append_rows(&hour, &row)
+        };
+        let window = RollupWindow {
+            from: "2025-02-14T00:00:00Z".parse().unwrap(),
+            to: "2025-02-14T02:00:00Z".parse().unwrap(),
+            force: false,
+        };
+
+        let report = rollup_object(&key_dir, RollupTier::Hour, &window, aggregate).unwrap();
+        assert_eq!(report.materialized, 2);
+        let sorted: Vec<String> = read_rows(&minute, DateTime::<Utc>::MIN_UTC)
+            .unwrap()
+            .into_iter()
+            .map(|(_, line)| line)
+            .collect();
+        assert_eq!(sorted[2], "2025-02-14T01:30:00+00:00|4");
+
+        // Nothing changed: no work
+        let report = rollup_object(&key_dir, RollupTier::Hour, &window, aggregate).unwrap();
+        assert_eq!(report.materialized, 0);
+
+        // A late sample re-aggregates only its hour, replacing the old row
+        backend.append_rows(&minute, "2025-02-14T01:45:00+00:00|5\n").unwrap();
+        let report = rollup_object(&key_dir, RollupTier::Hour, &window, aggregate).unwrap();
+        assert_eq!(report.materialized, 1);
+        let hour_rows: Vec<String> = read_rows(&hour, DateTime::<Utc>::MIN_UTC)
+            .unwrap()
+            .into_iter()
+            .map(|(_, line)| line)
+            .collect();
+        assert_eq!(hour_rows, ["2025-02-14T01:00:00+00:00|1", "2025-02-14T02:00:00+00:00|3"]);
+
+        fs::remove_dir_all(&key_dir).unwrap();
+    }
+}
@@ -0,0 +1,180 @@
+//! Metric partition files (`*.rcd`) and their gzip-compressed form (`*.rcd.gz`).
+//!
+//! Writers always append to the plain `.rcd` file. Once a partition is closed
+//! it may be compressed; a late append after that lands in a fresh plain file
+//! next to the `.gz`, and readers return both (compressed rows first).
+
+use anyhow::{Context, Result};
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use std::{
+    collections::hash_map::DefaultHasher,
+    fs::{self, File},
+    hash::{Hash, Hasher},
+    io::{self, BufRead, BufReader, Read},
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+
+const PLAIN_EXTENSION: &str = ".rcd";
+const COMPRESSED_EXTENSION: &str = ".rcd.gz";
+const INDEX_EXTENSION: &str = ".rcd.idx";
+
+/// Striped partition locks: appends and compression of the same partition
+/// never overlap, while unrelated partitions rarely contend.
+static SEGMENT_LOCKS: [Mutex<()>; 64] = [const { Mutex::new(()) }; 64];
+
+/// Runs `f` while holding the lock for the partition at `path` (plain path).
+pub fn with_segment_lock<T>(path: &Path, f: impl FnOnce() -> T) -> T {
+    let mut hasher = DefaultHasher::new();
+    path.hash(&mut hasher);
+    let lock = &SEGMENT_LOCKS[hasher.finish() as usize % SEGMENT_LOCKS.len()];
+    let _guard = lock.lock().unwrap_or_else(|e| e.into_inner());
+    f()
+}
+
+/// `<path>.gz` for a plain `.rcd` path.
+pub fn compressed_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(".gz");
+    PathBuf::from(name)
+}
+
+/// True if the partition exists in plain or compressed form.
+pub fn segment_exists(path: &Path) -> bool {
+    path.exists() || compressed_path(path).exists()
+}
+
+/// Partition name without its `.rcd` / `.rcd.gz` / `.rcd.idx` extension
+/// (e.g. `2025-02-14`), or `None` if `path` is not a metric segment file.
+pub fn segment_stem(path: &Path) -> Option<&str> {
+    let name = path.file_name()?.to_str()?;
+    name.strip_suffix(COMPRESSED_EXTENSION)
+        .or_else(|| name.strip_suffix(INDEX_EXTENSION))
+        .or_else(|| name.strip_suffix(PLAIN_EXTENSION))
+}
+
+/// Opens a partition for line reading, decompressing transparently.
+///
+/// Fails with `NotFound` when neither form exists.
+pub fn open_segment(path: &Path) -> io::Result<Box<dyn BufRead>> {
+    let gz_path = compressed_path(path);
+    let compressed = if gz_path.exists() {
+        Some(GzDecoder::new(File::open(&gz_path)?))
+    } else {
+        None
+    };
+    let plain = if path.exists() { Some(File::open(path)?) } else { None };
+
+    let reader: Box<dyn BufRead> = match (compressed, plain) {
+        (Some(gz), Some(file)) => Box::new(BufReader::new(gz.chain(file))),
+        (Some(gz), None) => Box::new(BufReader::new(gz)),
+        (None, Some(file)) => Box::new(BufReader::new(file)),
+        (None, None) => return Err(io::ErrorKind::NotFound.into()),
+    };
+    Ok(reader)
+}
+
+/// Compresses a plain partition into `<path>.gz` and removes the plain file.
+///
+/// Rows already in an existing `.gz` are kept. The archive is written to a
+/// temporary file and renamed, so a crash never leaves a partial segment.
+/// Appends to the partition wait until the plain file is gone, so none are
+/// lost. Returns `false` when there is no plain file to compress.
+pub fn compress_segment(path: &Path) -> Result<bool> {
+    with_segment_lock(path, || compress_segment_locked(path))
+}
+
+fn compress_segment_locked(path: &Path) -> Result<bool> {
+    if !path.exists() {
+        return Ok(false);
+    }
+
+    let gz_path = compressed_path(path);
+    let tmp_path = gz_path.with_extension("gz.tmp");
+
+    let mut reader = open_segment(path)?;
+    let tmp = File::create(&tmp_path)
+        .with_context(|| format!("Failed to create {:?}", tmp_path))?;
+    let mut encoder = GzEncoder::new(tmp, Compression::default());
+    io::copy(&mut reader, &mut encoder)?;
+    encoder.finish()?.sync_all()?;
+    drop(reader);
+
+    fs::rename(&tmp_path, &gz_path)
+        .with_context(|| format!("Failed to rename {:?}", tmp_path))?;
+    fs::remove_file(path).with_context(|| format!("Failed to remove {:?}", path))?;
+    Ok(true)
+}
+
+/// Compresses every plain partition in `dir` whose name sorts before
+/// `open_stem` (partition names are zero-padded dates, so this means older).
+/// Returns the number of segments compressed.
+pub fn compress_closed_segments(dir: &Path, open_stem: &str) -> Result<usize> {
+    if !dir.exists() {
+        return Ok(0);
+    }
+
+    let mut compressed = 0;
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let is_plain = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .is_some_and(|n| n.ends_with(PLAIN_EXTENSION));
+        if !is_plain {
+            continue;
+        }
+
+        let closed = segment_stem(&path).is_some_and(|stem| stem < open_stem);
+        if closed && compress_segment(&path)? {
+            compressed += 1;
+        }
+    }
+    Ok(compressed)
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    fn write_plain(path: &Path, text: &str) -> Result<()> {
+        let mut file = fs::OpenOptions::new().create(true).append(true).open(path)?;
+        file.write_all(text.as_bytes())?;
+        Ok(())
+    }
+
+    fn read_lines(path: &Path) -> Vec<String> {
+        open_segment(path)
+            .map(|r| r.lines().map_while(Result::ok).collect())
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn compressed_and_late_plain_rows_read_back_in_order() {
+        let dir = std::env::temp_dir().join(format!("rcd-segment-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("2025-02-14.rcd");
+        let open = dir.join("2025-02-15.rcd");
+
+        write_plain(&path, "1|a\n2|b\n").unwrap();
+        write_plain(&open, "9|z\n").unwrap();
+        assert_eq!(compress_closed_segments(&dir, "2025-02-15").unwrap(), 1);
+        assert!(!path.exists() && compressed_path(&path).exists() && open.exists());
+        assert_eq!(segment_stem(&compressed_path(&path)), Some("2025-02-14"));
+
+        // A late append goes to a new plain file and is merged on the next run
+        write_plain(&path, "3|c\n").unwrap();
+        assert_eq!(read_lines(&path), ["1|a", "2|b", "3|c"]);
+        assert!(compress_segment(&path).unwrap());
+        assert_eq!(read_lines(&path), ["1|a", "2|b", "3|c"]);
+        assert!(read_lines(&dir.join("2020-01-01.rcd")).is_empty());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
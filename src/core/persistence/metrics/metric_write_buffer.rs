@@ -0,0 +1,122 @@
+use anyhow::{anyhow, Result};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, MutexGuard, OnceLock};
+use tracing::{debug, error};
+
+use crate::core::persistence::storage_backend::storage_backend;
+
+/// Default for `InfoSettingEntity::metrics_batch_size`.
+const DEFAULT_BATCH_SIZE: usize = 500;
+
+static BATCH_SIZE: AtomicUsize = AtomicUsize::new(DEFAULT_BATCH_SIZE);
+static BUFFER: OnceLock<Mutex<MetricWriteBuffer>> = OnceLock::new();
+
+/// Minute rows waiting to be written, grouped by partition file so each file
+/// is opened once per flush instead of once per row.
+#[derive(Debug, Default)]
+struct MetricWriteBuffer {
+    /// Partition path → newline-terminated rows, in arrival order.
+    rows: HashMap<PathBuf, String>,
+    /// Rows buffered across all partitions.
+    pending: usize,
+}
+
+fn buffer() -> MutexGuard<'static, MetricWriteBuffer> {
+    BUFFER
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+}
+
+/// Applies `metrics_batch_size`; called by the minute task with the current settings.
+pub fn set_metrics_batch_size(size: u32) {
+    BATCH_SIZE.store((size as usize).max(1), Ordering::Relaxed);
+}
+
+/// Buffers one serialized row for `path`. Flushes everything once the
+/// buffer holds `metrics_batch_size` rows.
+pub fn buffer_metric_row(path: PathBuf, row: String) -> Result<()> {
+    let mut buf = buffer();
+    buf.rows.entry(path).or_default().push_str(&row);
+    buf.pending += 1;
+
+    if buf.pending >= BATCH_SIZE.load(Ordering::Relaxed) {
+        flush(&mut buf)?;
+    }
+    Ok(())
+}
+
+/// Writes all buffered rows. Called at the end of every minute tick and on
+/// shutdown, so readers never miss a completed minute.
+pub fn flush_metric_writes() -> Result<()> {
+    flush(&mut buffer())
+}
+
+/// Flushes, then runs `f` with the buffer held, so no collector row lands in a
+/// partition while `f` rewrites it.
+pub fn with_metric_writes_paused<T>(f: impl FnOnce() -> Result<T>) -> Result<T> {
+    let mut buf = buffer();
+    flush(&mut buf)?;
+    f()
+}
+
+/// Writes every partition; partitions that fail stay buffered for the next flush.
+fn flush(buf: &mut MetricWriteBuffer) -> Result<()> {
+    if buf.pending == 0 {
+        return Ok(());
+    }
+
+    let rows = std::mem::take(&mut buf.rows);
+    let total = buf.pending;
+    buf.pending = 0;
+
+    let mut failed = 0;
+    for (path, text) in rows {
+        if let Err(e) = storage_backend().append_rows(&path, &text) {
+            error!("Failed to write buffered rows to {:?}: {}", path, e);
+            buf.pending += text.lines().count();
+            buf.rows.insert(path, text);
+            failed += 1;
+        }
+    }
+
+    if failed > 0 {
+        return Err(anyhow!("{} metric partitions could not be written; rows kept for retry", failed));
+    }
+    debug!("Flushed {} buffered metric rows", total);
+    Ok(())
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn groups_rows_per_file_and_flushes_on_batch_size() {
+        let dir = std::env::temp_dir().join(format!("rcd-wbuf-{}", std::process::id()));
+        let a = dir.join("a/m/2025-02-14.rcd");
+        let b = dir.join("b/m/2025-02-14.rcd");
+        set_metrics_batch_size(3);
+
+        buffer_metric_row(a.clone(), "2025-02-14T00:00:00+00:00|1\n".into()).unwrap();
+        buffer_metric_row(b.clone(), "2025-02-14T00:00:00+00:00|2\n".into()).unwrap();
+        assert!(!a.exists(), "rows stay buffered below the batch size");
+
+        // Third row reaches the batch size and writes both files
+        buffer_metric_row(a.clone(), "2025-02-14T00:01:00+00:00|3\n".into()).unwrap();
+        assert_eq!(fs::read_to_string(&a).unwrap().lines().count(), 2);
+        assert_eq!(fs::read_to_string(&b).unwrap().lines().count(), 1);
+
+        buffer_metric_row(b.clone(), "2025-02-14T00:01:00+00:00|4\n".into()).unwrap();
+        flush_metric_writes().unwrap();
+        assert_eq!(fs::read_to_string(&b).unwrap().lines().count(), 2);
+
+        set_metrics_batch_size(DEFAULT_BATCH_SIZE as u32);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -1,2 +1,8 @@
+pub mod metric_archive;
+pub mod metric_checksum;
 pub mod metric_fs_adapter_base_trait;
-pub mod k8s;
\ No newline at end of file
+pub mod metric_index;
+pub mod metric_rollup;
+pub mod metric_segment;
+pub mod metric_write_buffer;
+pub mod k8s;
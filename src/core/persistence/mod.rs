@@ -1,4 +1,8 @@
+pub mod data_backup;
 pub mod info;
 pub mod metrics;
 pub mod storage_path;
-pub mod logs;
\ No newline at end of file
+pub mod storage_backend;
+pub mod storage_budget;
+pub mod storage_fsck;
+pub mod logs;
@@ -0,0 +1,251 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use std::{
+    collections::BTreeSet,
+    fs::{self, File, OpenOptions},
+    io::{self, BufRead, Read, Seek, SeekFrom, Write},
+    path::{Path, PathBuf},
+};
+
+use super::storage_backend_trait::StorageBackend;
+use crate::core::persistence::metrics::metric_archive::restored_path;
+use crate::core::persistence::metrics::metric_checksum::{seal_rows, verified_rows};
+use crate::core::persistence::metrics::metric_index::{index_path, open_segment_from};
+use crate::core::persistence::metrics::metric_segment::{
+    compressed_path, open_segment, segment_exists, segment_stem, with_segment_lock,
+};
+use crate::core::persistence::storage_path::get_rustcost_base_path;
+
+/// Top-level directories holding partitions and records.
+const DATA_DIRS: [&str; 2] = ["info", "metric"];
+
+/// Default backend: one `.rcd` file per metric partition and one `.rci` file
+/// per info record, in the directory layout described by the path builders.
+#[derive(Debug, Default)]
+pub struct FsStorageBackend;
+
+/// True if the file is non-empty and doesn't end with a newline.
+fn ends_mid_row(file: &mut File) -> io::Result<bool> {
+    if file.metadata()?.len() == 0 {
+        return Ok(false);
+    }
+    let mut last = [0u8; 1];
+    file.seek(SeekFrom::End(-1))?;
+    file.read_exact(&mut last)?;
+    Ok(last[0] != b'\n')
+}
+
+impl StorageBackend for FsStorageBackend {
+    fn name(&self) -> &'static str {
+        "fs"
+    }
+
+    /// Rows are sealed with a checksum (see `metric_checksum`). If an earlier
+    /// write was torn, the partial row is closed off first so it can't merge
+    /// with the new ones. Holds the partition lock so a concurrent
+    /// compression can't drop the rows.
+    fn append_rows(&self, path: &Path, rows: &str) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        with_segment_lock(path, || {
+            let mut file = OpenOptions::new().create(true).read(true).append(true).open(path)?;
+            let mut text = String::new();
+            if ends_mid_row(&mut file)? {
+                text.push('\n');
+            }
+            text.push_str(&seal_rows(rows));
+            file.write_all(text.as_bytes())?;
+            Ok(())
+        })
+    }
+
+    /// Partitions restored from an archive count as existing.
+    fn segment_exists(&self, path: &Path) -> bool {
+        segment_exists(path) || restored_path(path).is_some_and(|restored| segment_exists(&restored))
+    }
+
+    /// Falls back to the restored tree when the live partition is missing.
+    fn open_segment_from(&self, path: &Path, start: DateTime<Utc>) -> io::Result<Box<dyn BufRead>> {
+        match open_segment_from(path, start) {
+            Ok(reader) => verified_rows(reader, path),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                let restored = restored_path(path).ok_or(e)?;
+                verified_rows(open_segment_from(&restored, start)?, &restored)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    fn list_segment_files(&self, dir: &Path) -> Result<Vec<PathBuf>> {
+        if !dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut files = Vec::new();
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            if segment_stem(&path).is_some() {
+                files.push(path);
+            }
+        }
+        Ok(files)
+    }
+
+    fn remove_segment_file(&self, path: &Path) -> Result<()> {
+        fs::remove_file(path).with_context(|| format!("Failed to delete {:?}", path))
+    }
+
+    fn replace_segment(&self, path: &Path, rows: &str) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut tmp_name = path.as_os_str().to_owned();
+        tmp_name.push(".tmp");
+        let tmp_path = PathBuf::from(tmp_name);
+
+        let mut file = File::create(&tmp_path)
+            .with_context(|| format!("Failed to create {:?}", tmp_path))?;
+        file.write_all(seal_rows(rows).as_bytes())?;
+        file.sync_all()?;
+
+        // Rename before dropping the old `.gz`: a crash in between leaves
+        // duplicate rows, never missing ones
+        fs::rename(&tmp_path, path).with_context(|| format!("Failed to replace {:?}", path))?;
+        for file in [compressed_path(path), index_path(path)] {
+            if file.exists() {
+                fs::remove_file(&file).with_context(|| format!("Failed to delete {:?}", file))?;
+            }
+        }
+        Ok(())
+    }
+
+    fn read_record(&self, path: &Path) -> Result<Option<String>> {
+        if !path.exists() {
+            return Ok(None);
+        }
+        let text = fs::read_to_string(path).with_context(|| format!("Failed to read {:?}", path))?;
+        Ok(Some(text))
+    }
+
+    /// Writes `<path>.tmp`, fsyncs it and renames it over `path`.
+    fn write_record(&self, path: &Path, text: &str) -> Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).with_context(|| format!("Failed to create {:?}", dir))?;
+        }
+
+        let mut tmp_name = path.as_os_str().to_owned();
+        tmp_name.push(".tmp");
+        let tmp_path = PathBuf::from(tmp_name);
+
+        let mut f = File::create(&tmp_path)
+            .with_context(|| format!("Failed to create {:?}", tmp_path))?;
+        f.write_all(text.as_bytes())?;
+        f.sync_all()
+            .with_context(|| format!("Failed to fsync {:?}", tmp_path))?;
+
+        // Windows-safe: remove existing file before rename
+        #[cfg(windows)]
+        if path.exists() {
+            fs::remove_file(path).with_context(|| format!("Failed to remove old {:?}", path))?;
+        }
+
+        fs::rename(&tmp_path, path)
+            .with_context(|| format!("Failed to atomically replace {:?}", path))?;
+
+        // On Unix, also fsync the directory so the rename is durable
+        #[cfg(unix)]
+        if let Some(dir) = path.parent() {
+            File::open(dir)
+                .and_then(|d| d.sync_all())
+                .with_context(|| format!("Failed to fsync {:?}", dir))?;
+        }
+        Ok(())
+    }
+
+    fn delete_record(&self, path: &Path) -> Result<()> {
+        if path.exists() {
+            fs::remove_file(path).with_context(|| format!("Failed to delete {:?}", path))?;
+        }
+        Ok(())
+    }
+
+    fn record_exists(&self, path: &Path) -> Result<bool> {
+        Ok(path.exists())
+    }
+
+    fn list_records(&self, dir: &Path) -> Result<Vec<PathBuf>> {
+        if !dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut files = Vec::new();
+        for entry in fs::read_dir(dir)? {
+            let entry = entry?;
+            if entry.file_type()?.is_file() {
+                files.push(entry.path());
+            }
+        }
+        Ok(files)
+    }
+
+    fn list_keys(&self, dir: &Path) -> Result<Vec<String>> {
+        if !dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut keys = Vec::new();
+        for entry in fs::read_dir(dir)? {
+            let entry = entry?;
+            if entry.file_type()?.is_dir() {
+                if let Some(key) = entry.file_name().to_str() {
+                    keys.push(key.to_string());
+                }
+            }
+        }
+        Ok(keys)
+    }
+
+    fn snapshot(&self, visit: &mut dyn FnMut(&str, &[u8]) -> Result<()>) -> Result<()> {
+        let base = get_rustcost_base_path();
+        let mut dirs: Vec<PathBuf> = DATA_DIRS.iter().map(|d| base.join(d)).collect();
+
+        while let Some(dir) = dirs.pop() {
+            if !dir.exists() {
+                continue;
+            }
+            let mut segments = BTreeSet::new();
+            for entry in fs::read_dir(&dir)? {
+                let path = entry?.path();
+                if path.is_dir() {
+                    dirs.push(path);
+                } else if let Some(stem) = segment_stem(&path) {
+                    segments.insert(dir.join(format!("{}.rcd", stem)));
+                } else if path.extension().is_some_and(|e| e == "rci") {
+                    visit(&relative_key(&base, &path), &fs::read(&path)?)?;
+                }
+            }
+
+            // Compressed and plain rows as one stream, cut at the last full row
+            // in case the open partition is being appended to
+            for path in segments {
+                let mut bytes = Vec::new();
+                open_segment(&path)?.read_to_end(&mut bytes)?;
+                let complete = bytes.iter().rposition(|b| *b == b'\n').map_or(0, |i| i + 1);
+                visit(&relative_key(&base, &path), &bytes[..complete])?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// `/`-joined path relative to the data directory.
+fn relative_key(base: &Path, path: &Path) -> String {
+    let relative = path.strip_prefix(base).unwrap_or(path);
+    relative
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
@@ -0,0 +1,83 @@
+//! Storage backend chosen at startup via `RUSTCOST_STORAGE_BACKEND`.
+//!
+//! - `fs` (default): plain `.rcd` / `.rci` files under `RUSTCOST_BASE_PATH`.
+//! - `redb`: a single embedded database file (`rustcost.redb`) in the same
+//!   directory, for clusters with many short-lived pods where one directory
+//!   per object is slow (e.g. on network-attached volumes).
+//!
+//! Partition compression, `.idx` sidecars, archiving, `max_storage_gb`
+//! eviction and the startup fsck work on files and only apply to the `fs`
+//! backend. On `redb` they are skipped (logged once at startup), and the
+//! `archive` retention policy is rejected rather than deleting data.
+
+pub mod fs_storage_backend;
+pub mod redb_storage_backend;
+pub mod storage_backend_trait;
+
+use anyhow::{anyhow, Result};
+use std::env;
+use std::sync::{Mutex, OnceLock};
+
+use crate::core::persistence::storage_path::get_rustcost_base_path;
+use fs_storage_backend::FsStorageBackend;
+use redb_storage_backend::RedbStorageBackend;
+use storage_backend_trait::StorageBackend;
+
+static BACKEND: OnceLock<Box<dyn StorageBackend>> = OnceLock::new();
+static INIT_LOCK: Mutex<()> = Mutex::new(());
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StorageBackendKind {
+    Fs,
+    Redb,
+}
+
+impl StorageBackendKind {
+    pub fn parse(value: &str) -> Result<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "" | "fs" | "file" => Ok(Self::Fs),
+            "redb" => Ok(Self::Redb),
+            other => Err(anyhow!(
+                "Unknown storage backend '{}' (expected 'fs' or 'redb')",
+                other
+            )),
+        }
+    }
+
+    pub fn from_env() -> Result<Self> {
+        Self::parse(&env::var("RUSTCOST_STORAGE_BACKEND").unwrap_or_default())
+    }
+}
+
+fn open_backend(kind: StorageBackendKind) -> Result<Box<dyn StorageBackend>> {
+    Ok(match kind {
+        StorageBackendKind::Fs => Box::new(FsStorageBackend),
+        StorageBackendKind::Redb => Box::new(RedbStorageBackend::open(&get_rustcost_base_path())?),
+    })
+}
+
+/// Opens the configured backend once; later calls return the same instance.
+pub fn init_storage_backend() -> Result<&'static dyn StorageBackend> {
+    if let Some(backend) = BACKEND.get() {
+        return Ok(backend.as_ref());
+    }
+
+    // The database file can only be opened once per process
+    let _guard = INIT_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(backend) = BACKEND.get() {
+        return Ok(backend.as_ref());
+    }
+    let backend = open_backend(StorageBackendKind::from_env()?)?;
+    Ok(BACKEND.get_or_init(|| backend).as_ref())
+}
+
+/// The active backend. `main` initializes it at startup, so this only
+/// panics if the backend could not be opened there either.
+pub fn storage_backend() -> &'static dyn StorageBackend {
+    init_storage_backend().expect("Failed to initialize storage backend")
+}
+
+/// True when data lives in plain files (file-level maintenance applies).
+pub fn uses_fs_backend() -> bool {
+    storage_backend().name() == "fs"
+}
@@ -0,0 +1,341 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use redb::{
+    Database, ReadOnlyTable, ReadableDatabase, ReadableTable, TableDefinition, WriteTransaction,
+};
+use std::{
+    collections::BTreeSet,
+    fs,
+    io::{self, BufRead, Cursor},
+    path::{Component, Path, PathBuf},
+};
+
+use super::storage_backend_trait::StorageBackend;
+
+/// Database file created under `RUSTCOST_BASE_PATH`.
+pub const REDB_FILE_NAME: &str = "rustcost.redb";
+
+/// `(partition key, row time in unix seconds)` → rows with that time.
+const ROWS: TableDefinition<(&str, i64), &str> = TableDefinition::new("metric_rows");
+/// Partition key → number of rows appended.
+const SEGMENTS: TableDefinition<&str, u64> = TableDefinition::new("metric_segments");
+/// Record key → `KEY:VALUE` text.
+const RECORDS: TableDefinition<&str, &str> = TableDefinition::new("info_records");
+
+/// Embedded single-file backend on redb.
+///
+/// Keys are the relative paths the filesystem backend would use
+/// (e.g. `metric/k8s/pod/<uid>/m/2025-02-14.rcd`), so adapters and path
+/// builders are shared, but no directory is ever created per object.
+/// Metric rows are keyed by time, which makes range reads a B-tree seek.
+pub struct RedbStorageBackend {
+    db: Database,
+    base: PathBuf,
+}
+
+impl RedbStorageBackend {
+    /// Opens (or creates) `<base>/rustcost.redb`.
+    pub fn open(base: &Path) -> Result<Self> {
+        fs::create_dir_all(base).with_context(|| format!("Failed to create {:?}", base))?;
+        let path = base.join(REDB_FILE_NAME);
+        let db = Database::create(&path).with_context(|| format!("Failed to open {:?}", path))?;
+
+        // Create all tables up front so read transactions can always open them
+        let txn = db.begin_write()?;
+        {
+            txn.open_table(ROWS)?;
+            txn.open_table(SEGMENTS)?;
+            txn.open_table(RECORDS)?;
+        }
+        txn.commit()?;
+
+        Ok(Self { db, base: base.to_path_buf() })
+    }
+
+    /// `/`-joined path relative to the data directory.
+    fn key(&self, path: &Path) -> String {
+        path.strip_prefix(&self.base)
+            .unwrap_or(path)
+            .components()
+            .filter_map(|c| match c {
+                Component::Normal(part) => Some(part.to_string_lossy()),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+
+    /// Rows of a partition from `from` (unix seconds) on, or `None` if absent.
+    fn segment_text_from(&self, key: &str, from: i64) -> Result<Option<String>> {
+        let txn = self.db.begin_read()?;
+        if txn.open_table(SEGMENTS)?.get(key)?.is_none() {
+            return Ok(None);
+        }
+
+        let rows = txn.open_table(ROWS)?;
+        let mut text = String::new();
+        for entry in rows.range((key, from)..=(key, i64::MAX))? {
+            let (_, value) = entry?;
+            text.push_str(value.value());
+        }
+        Ok(Some(text))
+    }
+}
+
+/// Unix seconds of a row's leading RFC3339 column; unparsable rows sort first.
+fn row_time(line: &str) -> i64 {
+    line.split('|')
+        .next()
+        .and_then(|v| v.trim().parse::<DateTime<Utc>>().ok())
+        .map(|t| t.timestamp())
+        .unwrap_or(i64::MIN)
+}
+
+/// Adds the first path component below `prefix` of every key that has one.
+/// Skips over each object's keys instead of visiting them all.
+fn collect_child_names<V: redb::Value + 'static>(
+    table: &ReadOnlyTable<&'static str, V>,
+    prefix: &str,
+    names: &mut BTreeSet<String>,
+) -> Result<()> {
+    let mut from = prefix.to_string();
+    loop {
+        let Some(entry) = table.range(from.as_str()..)?.next() else {
+            return Ok(());
+        };
+        let (key, _) = entry?;
+        let key = key.value();
+        let Some(rest) = key.strip_prefix(prefix) else {
+            return Ok(());
+        };
+
+        from = match rest.split_once('/') {
+            Some((name, _)) => {
+                names.insert(name.to_string());
+                // '0' is the character after '/', so this skips `<name>/...`
+                format!("{}{}0", prefix, name)
+            }
+            // A file directly in `prefix`, not an object
+            None => format!("{}\0", key),
+        };
+    }
+}
+
+/// Adds rows to a partition inside `txn`, dropping its old rows first if `replace`.
+fn write_rows(txn: &WriteTransaction, key: &str, rows: &str, replace: bool) -> Result<()> {
+    let mut table = txn.open_table(ROWS)?;
+    let mut segments = txn.open_table(SEGMENTS)?;
+    let mut count = segments.get(key)?.map(|v| v.value()).unwrap_or(0);
+    if replace {
+        table.retain_in((key, i64::MIN)..=(key, i64::MAX), |_, _| false)?;
+        count = 0;
+    }
+
+    for line in rows.lines().filter(|l| !l.trim().is_empty()) {
+        let row_key = (key, row_time(line));
+        let mut value = table
+            .get(row_key)?
+            .map(|v| v.value().to_string())
+            .unwrap_or_default();
+        value.push_str(line);
+        value.push('\n');
+        table.insert(row_key, value.as_str())?;
+        count += 1;
+    }
+    segments.insert(key, count)?;
+    Ok(())
+}
+
+impl StorageBackend for RedbStorageBackend {
+    fn name(&self) -> &'static str {
+        "redb"
+    }
+
+    fn append_rows(&self, path: &Path, rows: &str) -> Result<()> {
+        let txn = self.db.begin_write()?;
+        write_rows(&txn, &self.key(path), rows, false)?;
+        txn.commit()?;
+        Ok(())
+    }
+
+    fn segment_exists(&self, path: &Path) -> bool {
+        let key = self.key(path);
+        let exists = || -> Result<bool> {
+            let txn = self.db.begin_read()?;
+            Ok(txn.open_table(SEGMENTS)?.get(key.as_str())?.is_some())
+        };
+        exists().unwrap_or_else(|e| {
+            tracing::warn!("Failed to look up {}: {}", key, e);
+            false
+        })
+    }
+
+    fn open_segment_from(&self, path: &Path, start: DateTime<Utc>) -> io::Result<Box<dyn BufRead>> {
+        let key = self.key(path);
+        match self.segment_text_from(&key, start.timestamp()).map_err(io::Error::other)? {
+            Some(text) => Ok(Box::new(Cursor::new(text.into_bytes()))),
+            None => Err(io::ErrorKind::NotFound.into()),
+        }
+    }
+
+    fn list_segment_files(&self, dir: &Path) -> Result<Vec<PathBuf>> {
+        let prefix = format!("{}/", self.key(dir));
+        let txn = self.db.begin_read()?;
+        let segments = txn.open_table(SEGMENTS)?;
+
+        let mut files = Vec::new();
+        for entry in segments.range(prefix.as_str()..)? {
+            let (key, _) = entry?;
+            let Some(rest) = key.value().strip_prefix(prefix.as_str()) else {
+                break;
+            };
+            if !rest.contains('/') {
+                files.push(dir.join(rest));
+            }
+        }
+        Ok(files)
+    }
+
+    fn remove_segment_file(&self, path: &Path) -> Result<()> {
+        let key = self.key(path);
+        let txn = self.db.begin_write()?;
+        {
+            let mut rows = txn.open_table(ROWS)?;
+            rows.retain_in((key.as_str(), i64::MIN)..=(key.as_str(), i64::MAX), |_, _| false)?;
+            txn.open_table(SEGMENTS)?.remove(key.as_str())?;
+        }
+        txn.commit()?;
+        Ok(())
+    }
+
+    fn replace_segment(&self, path: &Path, rows: &str) -> Result<()> {
+        let txn = self.db.begin_write()?;
+        write_rows(&txn, &self.key(path), rows, true)?;
+        txn.commit()?;
+        Ok(())
+    }
+
+    fn read_record(&self, path: &Path) -> Result<Option<String>> {
+        let key = self.key(path);
+        let txn = self.db.begin_read()?;
+        let records = txn.open_table(RECORDS)?;
+        let text = records.get(key.as_str())?.map(|v| v.value().to_string());
+        Ok(text)
+    }
+
+    fn write_record(&self, path: &Path, text: &str) -> Result<()> {
+        let key = self.key(path);
+        let txn = self.db.begin_write()?;
+        txn.open_table(RECORDS)?.insert(key.as_str(), text)?;
+        txn.commit()?;
+        Ok(())
+    }
+
+    fn delete_record(&self, path: &Path) -> Result<()> {
+        let key = self.key(path);
+        let txn = self.db.begin_write()?;
+        txn.open_table(RECORDS)?.remove(key.as_str())?;
+        txn.commit()?;
+        Ok(())
+    }
+
+    fn record_exists(&self, path: &Path) -> Result<bool> {
+        Ok(self.read_record(path)?.is_some())
+    }
+
+    fn list_records(&self, dir: &Path) -> Result<Vec<PathBuf>> {
+        let prefix = format!("{}/", self.key(dir));
+        let txn = self.db.begin_read()?;
+        let records = txn.open_table(RECORDS)?;
+
+        let mut files = Vec::new();
+        for entry in records.range(prefix.as_str()..)? {
+            let (key, _) = entry?;
+            let Some(rest) = key.value().strip_prefix(prefix.as_str()) else {
+                break;
+            };
+            if !rest.contains('/') {
+                files.push(dir.join(rest));
+            }
+        }
+        Ok(files)
+    }
+
+    fn list_keys(&self, dir: &Path) -> Result<Vec<String>> {
+        let prefix = format!("{}/", self.key(dir));
+        let txn = self.db.begin_read()?;
+
+        let mut names = BTreeSet::new();
+        collect_child_names(&txn.open_table(SEGMENTS)?, &prefix, &mut names)?;
+        collect_child_names(&txn.open_table(RECORDS)?, &prefix, &mut names)?;
+        Ok(names.into_iter().collect())
+    }
+
+    /// Everything is read from one transaction, so the snapshot is consistent.
+    fn snapshot(&self, visit: &mut dyn FnMut(&str, &[u8]) -> Result<()>) -> Result<()> {
+        let txn = self.db.begin_read()?;
+
+        for entry in txn.open_table(RECORDS)?.iter()? {
+            let (key, text) = entry?;
+            visit(key.value(), text.value().as_bytes())?;
+        }
+
+        let rows = txn.open_table(ROWS)?;
+        for entry in txn.open_table(SEGMENTS)?.iter()? {
+            let (key, _) = entry?;
+            let key = key.value();
+            let mut text = String::new();
+            for row in rows.range((key, i64::MIN)..=(key, i64::MAX))? {
+                text.push_str(row?.1.value());
+            }
+            visit(key, text.as_bytes())?;
+        }
+        Ok(())
+    }
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn read_all(backend: &RedbStorageBackend, path: &Path, start: DateTime<Utc>) -> Vec<String> {
+        backend
+            .open_segment_from(path, start)
+            .map(|r| r.lines().map_while(Result::ok).collect())
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn stores_rows_records_and_keys_in_one_file() {
+        let base = std::env::temp_dir().join(format!("rcd-redb-{}", std::process::id()));
+        let backend = RedbStorageBackend::open(&base).unwrap();
+        let minute_dir = base.join("metric/k8s/pod/p1/m");
+        let path = minute_dir.join("2025-02-14.rcd");
+
+        // Rows come back in time order, from the requested start
+        backend
+            .append_rows(&path, "2025-02-14T00:02:00+00:00|2\n2025-02-14T00:00:00+00:00|0\n")
+            .unwrap();
+        backend.append_rows(&path, "2025-02-14T00:01:00+00:00|1\n").unwrap();
+        let from = |m| Utc.with_ymd_and_hms(2025, 2, 14, 0, m, 0).unwrap();
+        assert_eq!(read_all(&backend, &path, from(0)).len(), 3);
+        assert_eq!(read_all(&backend, &path, from(1))[0], "2025-02-14T00:01:00+00:00|1");
+
+        backend.write_record(&base.join("info/k8s/pod/p2/info.rci"), "POD_UID:p2\n").unwrap();
+        backend.append_rows(&base.join("metric/k8s/pod/p3/h/2025-02.rcd"), "x\n").unwrap();
+        assert_eq!(backend.list_keys(&base.join("metric/k8s/pod")).unwrap(), ["p1", "p3"]);
+        assert_eq!(backend.list_keys(&base.join("info/k8s/pod")).unwrap(), ["p2"]);
+
+        // Cleanup goes through the same file listing as the fs backend
+        assert_eq!(backend.list_segment_files(&minute_dir).unwrap(), std::slice::from_ref(&path));
+        backend.remove_segment_file(&path).unwrap();
+        assert!(!backend.segment_exists(&path));
+        assert!(backend.open_segment_from(&path, from(0)).is_err());
+
+        drop(backend);
+        fs::remove_dir_all(&base).unwrap();
+    }
+}
@@ -0,0 +1,61 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use std::io::{self, BufRead};
+use std::path::{Path, PathBuf};
+
+/// Where persisted bytes live.
+///
+/// Adapters keep their own text formats (`|`-delimited metric rows and
+/// `KEY:VALUE` info records) and address data by the path it has in the
+/// filesystem layout under `RUSTCOST_BASE_PATH`. A backend decides how those
+/// paths are stored: as real files, or as keys in an embedded database.
+pub trait StorageBackend: Send + Sync {
+    /// Short name used in logs (`fs`, `redb`).
+    fn name(&self) -> &'static str;
+
+    // === Metric partitions (`*.rcd`) ===
+    /// Appends newline-terminated rows to a metric partition, creating it if needed.
+    fn append_rows(&self, path: &Path, rows: &str) -> Result<()>;
+
+    /// True if the partition holds any rows.
+    fn segment_exists(&self, path: &Path) -> bool;
+
+    /// Opens a partition positioned at or shortly before the first row with
+    /// `time >= start`. Fails with `NotFound` when the partition is absent.
+    fn open_segment_from(&self, path: &Path, start: DateTime<Utc>) -> io::Result<Box<dyn BufRead>>;
+
+    /// Partition files in a tier directory (e.g. `<key>/m`).
+    fn list_segment_files(&self, dir: &Path) -> Result<Vec<PathBuf>>;
+
+    /// Removes one partition file returned by `list_segment_files`.
+    fn remove_segment_file(&self, path: &Path) -> Result<()>;
+
+    /// Replaces all rows of a partition (restore, re-aggregation).
+    fn replace_segment(&self, path: &Path, rows: &str) -> Result<()>;
+
+    // === Info records (`*.rci`) ===
+    /// Whole record, or `None` if it was never written.
+    fn read_record(&self, path: &Path) -> Result<Option<String>>;
+
+    /// Replaces a record atomically.
+    fn write_record(&self, path: &Path, text: &str) -> Result<()>;
+
+    /// Deletes a record if present.
+    fn delete_record(&self, path: &Path) -> Result<()>;
+
+    fn record_exists(&self, path: &Path) -> Result<bool>;
+
+    /// Records stored directly in `dir` (e.g. `unit_price_history/*.rci`).
+    fn list_records(&self, dir: &Path) -> Result<Vec<PathBuf>>;
+
+    // === Objects ===
+    /// Object keys (node names, pod UIDs, ...) stored under a kind directory
+    /// such as `metric/k8s/pod` or `info/k8s/node`.
+    fn list_keys(&self, dir: &Path) -> Result<Vec<String>>;
+
+    // === Backup ===
+    /// Visits every partition and record as `(path relative to the data
+    /// directory, contents in the fs file format)`. Partitions are visited as
+    /// their plain `.rcd` path with complete rows only.
+    fn snapshot(&self, visit: &mut dyn FnMut(&str, &[u8]) -> Result<()>) -> Result<()>;
+}
@@ -0,0 +1,249 @@
+//! Disk usage accounting under `RUSTCOST_BASE_PATH` and enforcement of
+//! `max_storage_gb`.
+//!
+//! Only metric and info data count against the budget. Archives, backups,
+//! logs, restored partitions and quarantined files are reported but never
+//! evicted, so they don't push live metrics out either.
+//!
+//! When usage exceeds the budget, the oldest minute partitions are evicted
+//! first, then the oldest hour partitions. Day partitions and the open
+//! partition of each tier are never evicted.
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tracing::warn;
+
+use crate::core::persistence::metrics::metric_segment::segment_stem;
+use crate::core::persistence::storage_path::get_rustcost_base_path;
+
+const GIB: u64 = 1024 * 1024 * 1024;
+
+/// Metric object kinds (`metric/k8s/<kind>`).
+const KINDS: [&str; 3] = ["node", "pod", "container"];
+
+/// Bytes per tier for one object kind.
+#[derive(Debug, Clone, Default, Serialize, PartialEq)]
+pub struct StorageTierUsage {
+    pub minute_bytes: u64,
+    pub hour_bytes: u64,
+    pub day_bytes: u64,
+}
+
+/// Disk usage breakdown of the data directory.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct StorageUsage {
+    pub total_bytes: u64,
+    /// Metric and info bytes, the part counted against `budget_bytes`
+    pub budgeted_bytes: u64,
+    pub budget_bytes: u64,
+    /// Metric bytes per object kind (`node`, `pod`, `container`)
+    pub metrics: BTreeMap<String, StorageTierUsage>,
+    pub info_bytes: u64,
+    pub logs_bytes: u64,
+    /// Anything else under the data directory (archives, backups, restored
+    /// partitions, quarantine)
+    pub other_bytes: u64,
+}
+
+/// What a budget enforcement run removed.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct StorageEviction {
+    pub evicted_partitions: usize,
+    pub freed_bytes: u64,
+}
+
+/// Usage of the configured data directory against `max_storage_gb`.
+pub fn measure_storage_usage(max_storage_gb: u32) -> Result<StorageUsage> {
+    measure_storage_usage_at(&get_rustcost_base_path(), u64::from(max_storage_gb) * GIB)
+}
+
+/// Evicts old minute, then hour partitions until usage fits `max_storage_gb`.
+/// A budget of 0 disables enforcement.
+pub fn enforce_storage_budget(max_storage_gb: u32, now: DateTime<Utc>) -> Result<StorageEviction> {
+    if max_storage_gb == 0 {
+        return Ok(StorageEviction::default());
+    }
+    enforce_storage_budget_at(&get_rustcost_base_path(), u64::from(max_storage_gb) * GIB, now)
+}
+
+fn measure_storage_usage_at(base: &Path, budget_bytes: u64) -> Result<StorageUsage> {
+    let mut usage = StorageUsage {
+        total_bytes: dir_size(base),
+        budgeted_bytes: budgeted_size(base),
+        budget_bytes,
+        info_bytes: dir_size(&base.join("info")),
+        logs_bytes: dir_size(&base.join("logs")),
+        ..Default::default()
+    };
+
+    let mut metric_bytes = 0;
+    for kind in KINDS {
+        let mut tiers = StorageTierUsage::default();
+        for key_dir in subdirs(&kind_dir(base, kind)) {
+            tiers.minute_bytes += dir_size(&key_dir.join("m"));
+            tiers.hour_bytes += dir_size(&key_dir.join("h"));
+            tiers.day_bytes += dir_size(&key_dir.join("d"));
+        }
+        metric_bytes += tiers.minute_bytes + tiers.hour_bytes + tiers.day_bytes;
+        usage.metrics.insert(kind.to_string(), tiers);
+    }
+
+    usage.other_bytes = usage
+        .total_bytes
+        .saturating_sub(metric_bytes + usage.info_bytes + usage.logs_bytes);
+    Ok(usage)
+}
+
+fn enforce_storage_budget_at(base: &Path, budget: u64, now: DateTime<Utc>) -> Result<StorageEviction> {
+    let mut total = budgeted_size(base);
+    let mut eviction = StorageEviction::default();
+    if total <= budget {
+        return Ok(eviction);
+    }
+
+    // Tier directory → open partition, which stays appendable
+    let tiers = [
+        ("m", now.format("%Y-%m-%d").to_string()),
+        ("h", now.format("%Y-%m").to_string()),
+    ];
+
+    for (tier, open_stem) in &tiers {
+        for (stem, files) in closed_partitions(base, tier, open_stem) {
+            if total <= budget {
+                return Ok(eviction);
+            }
+            for path in files {
+                let size = fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+                match fs::remove_file(&path) {
+                    Ok(()) => {
+                        total = total.saturating_sub(size);
+                        eviction.freed_bytes += size;
+                    }
+                    Err(e) => warn!("Failed to evict {:?}: {}", path, e),
+                }
+            }
+            eviction.evicted_partitions += 1;
+            tracing::debug!("Evicted {} partition {} to stay within storage budget", tier, stem);
+        }
+    }
+
+    if total > budget {
+        warn!(
+            total_bytes = total,
+            budget_bytes = budget,
+            "Storage budget still exceeded after evicting minute and hour data"
+        );
+    }
+    Ok(eviction)
+}
+
+/// Closed partitions of one tier across all objects, oldest first. A
+/// partition groups its `.rcd`, `.rcd.gz` and `.rcd.idx` files.
+fn closed_partitions(base: &Path, tier: &str, open_stem: &str) -> Vec<(String, Vec<PathBuf>)> {
+    let mut partitions: BTreeMap<(String, PathBuf), Vec<PathBuf>> = BTreeMap::new();
+    for kind in KINDS {
+        for key_dir in subdirs(&kind_dir(base, kind)) {
+            let tier_dir = key_dir.join(tier);
+            let Ok(entries) = fs::read_dir(&tier_dir) else {
+                continue;
+            };
+            for path in entries.flatten().map(|e| e.path()) {
+                let Some(stem) = segment_stem(&path).map(str::to_string) else {
+                    continue;
+                };
+                if stem.as_str() < open_stem {
+                    partitions.entry((stem, tier_dir.clone())).or_default().push(path);
+                }
+            }
+        }
+    }
+
+    partitions.into_iter().map(|((stem, _), files)| (stem, files)).collect()
+}
+
+/// Bytes counted against the budget: the metric and info trees.
+fn budgeted_size(base: &Path) -> u64 {
+    dir_size(&base.join("metric")) + dir_size(&base.join("info"))
+}
+
+fn kind_dir(base: &Path, kind: &str) -> PathBuf {
+    base.join("metric").join("k8s").join(kind)
+}
+
+fn subdirs(dir: &Path) -> Vec<PathBuf> {
+    fs::read_dir(dir)
+        .map(|entries| entries.flatten().map(|e| e.path()).filter(|p| p.is_dir()).collect())
+        .unwrap_or_default()
+}
+
+/// Recursive size of all files under `path` (0 if missing).
+fn dir_size(path: &Path) -> u64 {
+    let Ok(entries) = fs::read_dir(path) else {
+        return fs::metadata(path).map(|m| m.len()).unwrap_or(0);
+    };
+    entries
+        .flatten()
+        .map(|entry| match entry.file_type() {
+            Ok(t) if t.is_dir() => dir_size(&entry.path()),
+            _ => entry.metadata().map(|m| m.len()).unwrap_or(0),
+        })
+        .sum()
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn evicts_oldest_minute_partitions_before_hour_data() {
+        let base = std::env::temp_dir().join(format!("rcd-budget-{}", std::process::id()));
+        let node = base.join("metric/k8s/node/n1");
+        let pod = base.join("metric/k8s/pod/p1");
+        for dir in [node.join("m"), node.join("h"), pod.join("m"), base.join("logs"), base.join("archive")] {
+            fs::create_dir_all(dir).unwrap();
+        }
+        let kib = vec![b'x'; 1024];
+        for path in [
+            node.join("m/2025-02-12.rcd"),
+            node.join("m/2025-02-12.rcd.idx"),
+            pod.join("m/2025-02-13.rcd.gz"),
+            node.join("m/2025-02-14.rcd"),
+            node.join("h/2025-01.rcd"),
+            base.join("logs/app.log"),
+            base.join("archive/old.tar.gz"),
+        ] {
+            fs::write(path, &kib).unwrap();
+        }
+
+        let usage = measure_storage_usage_at(&base, 0).unwrap();
+        assert_eq!(usage.total_bytes, 7 * 1024);
+        assert_eq!(usage.budgeted_bytes, 5 * 1024);
+        assert_eq!(usage.other_bytes, 1024);
+        assert_eq!(usage.metrics["node"].minute_bytes, 3 * 1024);
+        assert_eq!(usage.metrics["pod"].minute_bytes, 1024);
+        assert_eq!(usage.logs_bytes, 1024);
+
+        // Oldest minute partition (with its index) goes first; logs and
+        // archives don't count
+        let now = Utc.with_ymd_and_hms(2025, 2, 14, 12, 0, 0).unwrap();
+        let eviction = enforce_storage_budget_at(&base, 3 * 1024, now).unwrap();
+        assert_eq!((eviction.evicted_partitions, eviction.freed_bytes), (1, 2 * 1024));
+        assert!(pod.join("m/2025-02-13.rcd.gz").exists());
+
+        // Then the remaining closed minute data, then hour data; the open
+        // minute partition survives
+        let eviction = enforce_storage_budget_at(&base, 1024, now).unwrap();
+        assert_eq!(eviction.evicted_partitions, 2);
+        assert!(!node.join("h/2025-01.rcd").exists());
+        assert!(node.join("m/2025-02-14.rcd").exists());
+
+        fs::remove_dir_all(&base).unwrap();
+    }
+}
@@ -0,0 +1,224 @@
+//! Startup consistency check of the data directory (fs backend only).
+//!
+//! - Metric partitions (`*.rcd`, `*.rcd.gz`) are scanned row by row (see
+//!   `metric_checksum`). A partition with damaged rows is moved to
+//!   `quarantine/` and its valid rows are written back in its place.
+//! - Info records (`*.rci`) must be UTF-8 `KEY:VALUE` lines ending in a
+//!   newline; anything else is moved to `quarantine/` as a whole.
+//! - Leftover `*.tmp` files from an interrupted atomic write are quarantined.
+//!
+//! Quarantined files keep their path relative to the data directory, with the
+//! check time appended, so nothing is ever discarded silently.
+
+use anyhow::{Context, Result};
+use chrono::Utc;
+use flate2::read::GzDecoder;
+use serde::Serialize;
+use std::fs::{self, File};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::path::{Path, PathBuf};
+use tracing::warn;
+
+use crate::core::persistence::metrics::metric_checksum::{check_raw_row, RawRow};
+use crate::core::persistence::metrics::metric_index::index_path;
+use crate::core::persistence::storage_path::get_rustcost_base_path;
+
+/// Directory under the data directory that receives damaged files.
+pub const QUARANTINE_DIR: &str = "quarantine";
+
+/// Outcome of one check.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct FsckReport {
+    pub files_checked: usize,
+    pub corrupted_rows: usize,
+    /// Quarantined files, relative to the data directory
+    pub quarantined: Vec<String>,
+    /// Partitions rewritten with their valid rows
+    pub salvaged: usize,
+}
+
+/// Checks the configured data directory.
+pub fn run_fsck() -> Result<FsckReport> {
+    run_fsck_at(&get_rustcost_base_path())
+}
+
+fn run_fsck_at(base: &Path) -> Result<FsckReport> {
+    let mut report = FsckReport::default();
+    if !base.exists() {
+        return Ok(report);
+    }
+
+    let stamp = Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
+    let mut dirs = vec![base.to_path_buf()];
+    while let Some(dir) = dirs.pop() {
+        for entry in fs::read_dir(&dir)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                if dir != base || path.file_name().is_some_and(|n| n != QUARANTINE_DIR) {
+                    dirs.push(path);
+                }
+                continue;
+            }
+            let Some(name) = path.file_name().and_then(|n| n.to_str()).map(str::to_string) else {
+                continue;
+            };
+
+            let checked = if name.ends_with(".tmp") {
+                warn!("Found unfinished write {:?}", path);
+                quarantine(base, &path, &stamp, &mut report)?;
+                continue;
+            } else if name.ends_with(".rcd") || name.ends_with(".rcd.gz") {
+                check_partition(base, &path, &stamp, &mut report)
+            } else if name.ends_with(".rci") {
+                check_record(base, &path, &stamp, &mut report)
+            } else {
+                continue;
+            };
+            report.files_checked += 1;
+            if let Err(e) = checked {
+                warn!("Failed to check {:?}: {}", path, e);
+            }
+        }
+    }
+    Ok(report)
+}
+
+/// Moves `path` to `<base>/quarantine/<relative path>.<stamp>`.
+fn quarantine(base: &Path, path: &Path, stamp: &str, report: &mut FsckReport) -> Result<()> {
+    let relative = path.strip_prefix(base).unwrap_or(path);
+    let mut target = base.join(QUARANTINE_DIR).join(relative).into_os_string();
+    target.push(format!(".{}", stamp));
+    let target = PathBuf::from(target);
+
+    if let Some(parent) = target.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::rename(path, &target).with_context(|| format!("Failed to quarantine {:?}", path))?;
+    report.quarantined.push(relative.to_string_lossy().into_owned());
+    Ok(())
+}
+
+fn check_partition(base: &Path, path: &Path, stamp: &str, report: &mut FsckReport) -> Result<()> {
+    let compressed = path.extension().is_some_and(|e| e == "gz");
+    let file = File::open(path)?;
+    let mut reader: Box<dyn BufRead> = if compressed {
+        Box::new(BufReader::new(GzDecoder::new(file)))
+    } else {
+        Box::new(BufReader::new(file))
+    };
+
+    let mut valid = String::new();
+    let mut corrupt = 0;
+    let mut raw = Vec::new();
+    loop {
+        raw.clear();
+        match reader.read_until(b'\n', &mut raw) {
+            Ok(0) => break,
+            Ok(_) => {}
+            Err(e) => {
+                // Damaged gzip stream: keep what decoded so far
+                warn!("Partition {:?} is unreadable past {} bytes: {}", path, valid.len(), e);
+                corrupt += 1;
+                break;
+            }
+        }
+        match check_raw_row(&raw) {
+            // Keep the stored form (with checksum) of every valid row
+            RawRow::Valid(_) => valid.push_str(std::str::from_utf8(&raw).unwrap_or_default()),
+            RawRow::Corrupt => corrupt += 1,
+        }
+    }
+    drop(reader);
+
+    if corrupt == 0 {
+        return Ok(());
+    }
+    warn!("Partition {:?} has {} corrupted rows; quarantining it", path, corrupt);
+    report.corrupted_rows += corrupt;
+
+    // Salvaged rows go to the plain file, ahead of any rows already there
+    // (compressed rows always come first)
+    let plain = if compressed { path.with_extension("") } else { path.to_path_buf() };
+    let existing = if compressed && plain.exists() {
+        let mut text = String::new();
+        File::open(&plain)?.read_to_string(&mut text)?;
+        text
+    } else {
+        String::new()
+    };
+
+    quarantine(base, path, stamp, report)?;
+    if !valid.is_empty() || !existing.is_empty() {
+        let tmp = plain.with_extension("rcd.fsck");
+        let mut f = File::create(&tmp)?;
+        f.write_all(valid.as_bytes())?;
+        f.write_all(existing.as_bytes())?;
+        f.sync_all()?;
+        fs::rename(&tmp, &plain)?;
+        report.salvaged += 1;
+    }
+
+    // Offsets changed; the index is rebuilt on the next read
+    let idx = index_path(&plain);
+    if idx.exists() {
+        fs::remove_file(idx)?;
+    }
+    Ok(())
+}
+
+fn check_record(base: &Path, path: &Path, stamp: &str, report: &mut FsckReport) -> Result<()> {
+    let bytes = fs::read(path)?;
+    let valid = match std::str::from_utf8(&bytes) {
+        Ok(text) => {
+            (text.is_empty() || text.ends_with('\n'))
+                && text.lines().all(|l| l.trim().is_empty() || l.contains(':'))
+        }
+        Err(_) => false,
+    };
+
+    if !valid {
+        warn!("Info record {:?} is damaged; quarantining it", path);
+        quarantine(base, path, stamp, report)?;
+    }
+    Ok(())
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::persistence::metrics::metric_checksum::seal_rows;
+
+    #[test]
+    fn quarantines_damage_and_keeps_valid_rows() {
+        let base = std::env::temp_dir().join(format!("rcd-fsck-{}", std::process::id()));
+        let minute_dir = base.join("metric/k8s/pod/p1/m");
+        let info_dir = base.join("info");
+        fs::create_dir_all(&minute_dir).unwrap();
+        fs::create_dir_all(&info_dir).unwrap();
+
+        // Two good rows, one bit-flipped row, then a torn tail
+        let good = seal_rows("2025-02-14T00:00:00+00:00|1\n2025-02-14T00:01:00+00:00|2\n");
+        let bad = seal_rows("2025-02-14T00:02:00+00:00|3\n").replace("|3#", "|4#");
+        let segment = minute_dir.join("2025-02-14.rcd");
+        fs::write(&segment, format!("{}{}2025-02-14T00:03", good, bad)).unwrap();
+        fs::write(info_dir.join("settings.rci"), "VERSION:1\nMINUTE_RETEN").unwrap();
+        fs::write(info_dir.join("alerts.rci"), "ENABLED:true\n").unwrap();
+        fs::write(info_dir.join("unit_prices.rci.tmp"), "CPU").unwrap();
+
+        let report = run_fsck_at(&base).unwrap();
+        assert_eq!(report.corrupted_rows, 2);
+        assert_eq!(report.salvaged, 1);
+        assert_eq!(report.quarantined.len(), 3);
+        assert_eq!(fs::read_to_string(&segment).unwrap(), good);
+        assert!(!info_dir.join("settings.rci").exists());
+        assert!(info_dir.join("alerts.rci").exists());
+
+        // A second pass finds nothing and leaves the quarantine alone
+        let again = run_fsck_at(&base).unwrap();
+        assert!(again.quarantined.is_empty() && again.corrupted_rows == 0);
+
+        fs::remove_dir_all(&base).unwrap();
+    }
+}
@@ -13,8 +13,13 @@ pub fn get_rustcost_base_path() -> PathBuf {
 // Re-export info path builders from the new module
 pub use crate::core::persistence::info::path::{
     info_alert_path,
+    info_exchange_rate_path,
     info_llm_path,
+    info_ownership_path,
     info_setting_path,
+    info_storage_class_price_path,
+    info_unit_price_history_dir_path,
+    info_unit_price_history_file_path,
     info_unit_price_path,
     info_version_path,
 };
@@ -29,10 +29,20 @@ impl CostUtil {
 
     #[inline]
     pub fn compute_cpu_cost_from_core_nano_seconds(core_nano_seconds: f64, prices: &InfoUnitPriceEntity) -> f64 {
+        let core_hours = Self::core_nano_seconds_to_core_hours(core_nano_seconds);
+        Self::compute_cpu_cost_from_core_hours(core_hours, prices)
+    }
+
+    #[inline]
+    pub fn core_nano_seconds_to_core_hours(core_nano_seconds: f64) -> f64 {
         // core_nano_seconds = core * nanoseconds
         // 1) to core-seconds: / 1e9
         // 2) to core-hours  : / 3600
-        let core_hours = (core_nano_seconds / 1_000_000_000.0) / 3600.0;
+        (core_nano_seconds / 1_000_000_000.0) / 3600.0
+    }
+
+    #[inline]
+    pub fn compute_cpu_cost_from_core_hours(core_hours: f64, prices: &InfoUnitPriceEntity) -> f64 {
         core_hours * prices.cpu_core_hour
     }
 }
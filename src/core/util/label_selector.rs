@@ -0,0 +1,156 @@
+use anyhow::{bail, Result};
+use std::collections::BTreeMap;
+
+/// One requirement of a Kubernetes label selector.
+#[derive(Debug, Clone, PartialEq)]
+enum Requirement {
+    Equals(String, String),
+    NotEquals(String, String),
+    In(String, Vec<String>),
+    NotIn(String, Vec<String>),
+    Exists(String),
+    NotExists(String),
+}
+
+/// Label selector in the Kubernetes syntax, e.g.
+/// `app=api,tier!=cache,env in (prod,stage),!canary`.
+///
+/// All requirements must match. As in Kubernetes, `!=` and `notin` also match
+/// objects that do not carry the key at all.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LabelSelector {
+    requirements: Vec<Requirement>,
+}
+
+impl LabelSelector {
+    pub fn parse(selector: &str) -> Result<Self> {
+        let mut requirements = Vec::new();
+        for part in split_top_level(selector) {
+            let part = part.trim();
+            if part.is_empty() {
+                continue;
+            }
+            requirements.push(parse_requirement(part)?);
+        }
+        Ok(Self { requirements })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.requirements.is_empty()
+    }
+
+    pub fn matches(&self, labels: &BTreeMap<String, String>) -> bool {
+        self.requirements.iter().all(|r| match r {
+            Requirement::Equals(k, v) => labels.get(k) == Some(v),
+            Requirement::NotEquals(k, v) => labels.get(k) != Some(v),
+            Requirement::In(k, vs) => labels.get(k).is_some_and(|v| vs.contains(v)),
+            Requirement::NotIn(k, vs) => labels.get(k).is_none_or(|v| !vs.contains(v)),
+            Requirement::Exists(k) => labels.contains_key(k),
+            Requirement::NotExists(k) => !labels.contains_key(k),
+        })
+    }
+}
+
+/// Parses the flattened `key=value,...` form stored in info records.
+pub fn parse_flattened_labels(flat: Option<&str>) -> BTreeMap<String, String> {
+    flat.unwrap_or_default()
+        .split(',')
+        .filter_map(|pair| pair.split_once('='))
+        .map(|(k, v)| (k.trim().to_string(), v.trim().to_string()))
+        .filter(|(k, _)| !k.is_empty())
+        .collect()
+}
+
+/// Splits on commas that are not inside a `( … )` value set.
+fn split_top_level(selector: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (i, c) in selector.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                parts.push(&selector[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&selector[start..]);
+    parts
+}
+
+fn parse_requirement(part: &str) -> Result<Requirement> {
+    if let Some(key) = part.strip_prefix('!') {
+        return Ok(Requirement::NotExists(valid_key(key, part)?));
+    }
+    if let Some((key, value)) = part.split_once("!=") {
+        return Ok(Requirement::NotEquals(valid_key(key, part)?, value.trim().to_string()));
+    }
+    if let Some((key, value)) = part.split_once("==").or_else(|| part.split_once('=')) {
+        return Ok(Requirement::Equals(valid_key(key, part)?, value.trim().to_string()));
+    }
+
+    if let Some(open) = part.find('(') {
+        let Some(values) = part[open + 1..].strip_suffix(')') else {
+            bail!("Invalid label selector requirement '{}': missing ')'", part);
+        };
+        let values: Vec<String> = values
+            .split(',')
+            .map(|v| v.trim().to_string())
+            .filter(|v| !v.is_empty())
+            .collect();
+
+        let head: Vec<&str> = part[..open].split_whitespace().collect();
+        return match head.as_slice() {
+            [key, op] if op.eq_ignore_ascii_case("in") => Ok(Requirement::In(valid_key(key, part)?, values)),
+            [key, op] if op.eq_ignore_ascii_case("notin") => {
+                Ok(Requirement::NotIn(valid_key(key, part)?, values))
+            }
+            _ => bail!("Invalid label selector requirement '{}': expected 'in' or 'notin'", part),
+        };
+    }
+
+    Ok(Requirement::Exists(valid_key(part, part)?))
+}
+
+fn valid_key(key: &str, part: &str) -> Result<String> {
+    let key = key.trim();
+    if key.is_empty() || key.contains(char::is_whitespace) {
+        bail!("Invalid label selector requirement '{}'", part);
+    }
+    Ok(key.to_string())
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_all_operators() {
+        let labels = parse_flattened_labels(Some("app=api,tier=backend,env=prod"));
+        let yes = [
+            "app=api",
+            "app==api,tier=backend",
+            "tier!=cache",
+            "missing!=x",
+            "env in (prod, stage)",
+            "env notin (dev),missing notin (x)",
+            "app,!canary",
+        ];
+        for s in yes {
+            assert!(LabelSelector::parse(s).unwrap().matches(&labels), "{s}");
+        }
+
+        let no = ["app=web", "tier!=backend", "env in (dev)", "env notin (prod)", "canary", "!app"];
+        for s in no {
+            assert!(!LabelSelector::parse(s).unwrap().matches(&labels), "{s}");
+        }
+
+        assert!(LabelSelector::parse("env in prod").is_err());
+        assert!(LabelSelector::parse("env in (prod").is_err());
+    }
+}
@@ -1 +1,4 @@
-pub mod cost_util;
\ No newline at end of file
+pub mod cost_util;
+pub mod prometheus_text;
+pub mod peak_stats;
+pub mod label_selector;
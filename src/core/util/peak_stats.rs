@@ -0,0 +1,105 @@
+use std::cmp::Ordering;
+
+/// Nearest-rank percentile of an ascending slice (`pct` in 0–100).
+pub fn percentile_sorted<T: Copy>(sorted: &[T], pct: f64) -> Option<T> {
+    if sorted.is_empty() {
+        return None;
+    }
+    let rank = ((pct / 100.0) * sorted.len() as f64).ceil() as usize;
+    Some(sorted[rank.clamp(1, sorted.len()) - 1])
+}
+
+/// Max, p95 and p99 of a set of gauge samples.
+///
+/// Hour rows store the peaks of their minute samples. Day rows keep the
+/// highest hourly value: for p95/p99 this is an upper bound (no hour has more
+/// than 5% of its samples above its p95, so neither can the day). Hours
+/// written before the peak columns existed contribute their average.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct PeakStats<T> {
+    pub max: Option<T>,
+    pub p95: Option<T>,
+    pub p99: Option<T>,
+}
+
+impl<T: Copy + PartialOrd> PeakStats<T> {
+    pub fn from_samples(samples: impl IntoIterator<Item = T>) -> Self {
+        let mut sorted: Vec<T> = samples.into_iter().collect();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+
+        Self {
+            max: sorted.last().copied(),
+            p95: percentile_sorted(&sorted, 95.0),
+            p99: percentile_sorted(&sorted, 99.0),
+        }
+    }
+}
+
+/// Percentile of a gauge over a window of metric points.
+///
+/// Minute points are samples themselves, so the percentile over them is exact.
+/// Hour and day points carry their own percentile; the window takes the highest
+/// of those, an upper bound for the same reason as the day tier of
+/// [`PeakStats`]. With both kinds, the larger value is used.
+#[derive(Debug, Clone, Default)]
+pub struct WindowPercentile {
+    samples: Vec<f64>,
+    point_max: Option<f64>,
+}
+
+impl WindowPercentile {
+    /// Adds a point: its own percentile when it has one, otherwise its value.
+    pub fn add(&mut self, point_percentile: Option<f64>, value: f64) {
+        match point_percentile {
+            Some(p) => self.point_max = Some(self.point_max.map_or(p, |max| max.max(p))),
+            None => self.samples.push(value),
+        }
+    }
+
+    pub fn value(self, pct: f64) -> f64 {
+        let mut sorted = self.samples;
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+        let from_samples = percentile_sorted(&sorted, pct);
+
+        match (from_samples, self.point_max) {
+            (Some(a), Some(b)) => a.max(b),
+            (a, b) => a.or(b).unwrap_or(0.0),
+        }
+    }
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nearest_rank_percentiles() {
+        let stats = PeakStats::from_samples((1..=100u64).rev());
+        assert_eq!(stats.max, Some(100));
+        assert_eq!(stats.p95, Some(95));
+        assert_eq!(stats.p99, Some(99));
+
+        // Single hourly spike out of 60 minutes survives p99 but not p95
+        let minutes = std::iter::repeat_n(10u64, 59).chain([500]);
+        let stats = PeakStats::from_samples(minutes);
+        assert_eq!((stats.max, stats.p95, stats.p99), (Some(500), Some(10), Some(500)));
+
+        assert_eq!(PeakStats::<u64>::from_samples([]), PeakStats::default());
+    }
+
+    #[test]
+    fn window_percentile_is_exact_for_samples_and_bounded_for_points() {
+        let mut minutes = WindowPercentile::default();
+        (1..=100).for_each(|v| minutes.add(None, v as f64));
+        assert_eq!(minutes.value(95.0), 95.0);
+
+        // Hourly p95s: the highest one, not the p95 of them
+        let mut hours = WindowPercentile::default();
+        (1..=100).for_each(|v| hours.add(Some(v as f64), 0.0));
+        assert_eq!(hours.value(95.0), 100.0);
+
+        assert_eq!(WindowPercentile::default().value(95.0), 0.0);
+    }
+}
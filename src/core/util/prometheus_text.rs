@@ -0,0 +1,141 @@
+use std::collections::HashMap;
+
+/// One sample line of the Prometheus text exposition format.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PromSample {
+    pub name: String,
+    pub labels: HashMap<String, String>,
+    pub value: f64,
+}
+
+impl PromSample {
+    /// Returns the first non-empty label among `keys`.
+    pub fn label(&self, keys: &[&str]) -> Option<&str> {
+        keys.iter()
+            .filter_map(|k| self.labels.get(*k))
+            .map(|v| v.as_str())
+            .find(|v| !v.is_empty())
+    }
+}
+
+/// Parses Prometheus text exposition output into samples.
+///
+/// Comment (`# HELP` / `# TYPE`) and malformed lines are skipped; a trailing
+/// sample timestamp is ignored.
+pub fn parse_prometheus_text(text: &str) -> Vec<PromSample> {
+    text.lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty() && !l.starts_with('#'))
+        .filter_map(parse_line)
+        .collect()
+}
+
+fn parse_line(line: &str) -> Option<PromSample> {
+    let name_end = line
+        .find(|c: char| c == '{' || c.is_whitespace())
+        .unwrap_or(line.len());
+    let name = &line[..name_end];
+    if name.is_empty() {
+        return None;
+    }
+
+    let mut rest = &line[name_end..];
+    let mut labels = HashMap::new();
+    if let Some(body) = rest.strip_prefix('{') {
+        let (parsed, consumed) = parse_labels(body)?;
+        labels = parsed;
+        rest = &body[consumed..];
+    }
+
+    let value = parse_value(rest.split_whitespace().next()?)?;
+    Some(PromSample { name: name.to_string(), labels, value })
+}
+
+/// Parses `key="value",...}` and returns the labels plus the bytes consumed,
+/// including the closing brace.
+fn parse_labels(body: &str) -> Option<(HashMap<String, String>, usize)> {
+    let mut labels = HashMap::new();
+    let mut chars = body.char_indices().peekable();
+
+    loop {
+        // Skip separators before the next key.
+        while let Some(&(_, c)) = chars.peek() {
+            if c == ',' || c.is_whitespace() {
+                chars.next();
+            } else {
+                break;
+            }
+        }
+
+        let (start, c) = chars.next()?;
+        if c == '}' {
+            return Some((labels, start + 1));
+        }
+
+        let mut key = String::from(c);
+        for (_, c) in chars.by_ref() {
+            if c == '=' {
+                break;
+            }
+            key.push(c);
+        }
+
+        if chars.next()?.1 != '"' {
+            return None;
+        }
+
+        let mut value = String::new();
+        loop {
+            match chars.next()?.1 {
+                '"' => break,
+                '\\' => match chars.next()?.1 {
+                    'n' => value.push('\n'),
+                    other => value.push(other),
+                },
+                other => value.push(other),
+            }
+        }
+
+        labels.insert(key.trim().to_string(), value);
+    }
+}
+
+fn parse_value(raw: &str) -> Option<f64> {
+    match raw {
+        "NaN" => Some(f64::NAN),
+        "+Inf" | "Inf" => Some(f64::INFINITY),
+        "-Inf" => Some(f64::NEG_INFINITY),
+        _ => raw.parse().ok(),
+    }
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_labels_values_and_skips_comments() {
+        let text = r#"
+# HELP DCGM_FI_DEV_GPU_UTIL GPU utilization (in %).
+# TYPE DCGM_FI_DEV_GPU_UTIL gauge
+DCGM_FI_DEV_GPU_UTIL{gpu="0",UUID="GPU-1",pod="train-0",namespace="ml",container="main"} 87 1700000000000
+DCGM_FI_DEV_FB_USED{gpu="0",modelName="A100 \"SXM\"",pod=""} 4096
+up 1
+broken{gpu="0" 3
+"#;
+        let samples = parse_prometheus_text(text);
+        assert_eq!(samples.len(), 3);
+
+        assert_eq!(samples[0].name, "DCGM_FI_DEV_GPU_UTIL");
+        assert_eq!(samples[0].value, 87.0);
+        assert_eq!(samples[0].label(&["exported_pod", "pod"]), Some("train-0"));
+
+        assert_eq!(samples[1].labels["modelName"], "A100 \"SXM\"");
+        assert_eq!(samples[1].label(&["pod"]), None);
+
+        assert_eq!(samples[2].name, "up");
+        assert!(samples[2].labels.is_empty());
+    }
+}
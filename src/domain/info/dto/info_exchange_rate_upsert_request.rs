@@ -0,0 +1,24 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use validator::{Validate, ValidationError};
+
+use crate::core::persistence::info::fixed::unit_price::info_unit_price_entity::Currency;
+
+/// Represents an upsert request for the exchange-rate table.
+///
+/// Only the listed currencies are updated; others keep their stored rate.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct InfoExchangeRateUpsertRequest {
+    /// Units of each currency per 1 USD (e.g. `{"EUR": 0.92, "KRW": 1380}`).
+    #[validate(custom(function = "validate_positive_rates"))]
+    pub rates: BTreeMap<Currency, f64>,
+}
+
+fn validate_positive_rates(rates: &BTreeMap<Currency, f64>) -> Result<(), ValidationError> {
+    if rates.values().all(|r| r.is_finite() && *r > 0.0) {
+        Ok(())
+    } else {
+        Err(ValidationError::new("exchange rates must be positive"))
+    }
+}
@@ -1,4 +1,5 @@
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 use crate::core::persistence::info::k8s::node::info_node_entity::NodePricePeriod;
@@ -14,8 +15,13 @@ pub struct InfoK8sNodePatchRequest {
 #[derive(Debug, Clone, Serialize, Deserialize, Validate)]
 pub struct InfoK8sNodePricePatchRequest {
     /// Fixed price for this node in USD (instance / VM / bare metal)
+    #[validate(range(min = 0.0))]
     pub fixed_instance_usd: Option<f64>,
 
     /// Billing period for `fixed_instance`
     pub price_period: Option<NodePricePeriod>,
+
+    /// Time the new price takes effect (defaults to now). Earlier points keep
+    /// the price that applied to them.
+    pub effective_from: Option<DateTime<Utc>>,
 }
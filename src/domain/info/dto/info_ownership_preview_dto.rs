@@ -0,0 +1,48 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::core::persistence::info::fixed::ownership::ownership_rule_entity::{
+    OwnershipRuleEntity, OwnershipTarget,
+};
+
+/// Dry run of the ownership rules against the pods currently in the cluster.
+#[derive(Debug, Clone, Serialize)]
+pub struct InfoOwnershipPreviewDto {
+    pub generated_at: DateTime<Utc>,
+    pub pods_scanned: usize,
+    /// One entry per configured rule, in evaluation order
+    pub rules: Vec<InfoOwnershipRulePreviewDto>,
+    /// Pods that would fall back to a configured default
+    pub defaults: Vec<InfoOwnershipDefaultPreviewDto>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct InfoOwnershipRulePreviewDto {
+    pub rule: OwnershipRuleEntity,
+    /// Pods carrying the rule's key
+    pub matched: usize,
+    /// Pods this rule would actually tag
+    pub applied: usize,
+    pub objects: Vec<InfoOwnershipPreviewObjectDto>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct InfoOwnershipDefaultPreviewDto {
+    pub target: OwnershipTarget,
+    pub value: String,
+    pub objects: Vec<InfoOwnershipPreviewObjectDto>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct InfoOwnershipPreviewObjectDto {
+    pub namespace: String,
+    pub pod_name: String,
+    pub pod_uid: String,
+    /// Value the rule (or default) resolves to
+    pub value: String,
+    /// Tag already stored for the pod; stored tags are never overwritten
+    pub current: Option<String>,
+    /// `false` when a higher-priority rule wins, the rule is disabled or
+    /// the pod is already tagged
+    pub applied: bool,
+}
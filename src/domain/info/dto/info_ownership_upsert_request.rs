@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::core::persistence::info::fixed::ownership::ownership_rule_entity::{
+    OwnershipRuleEntity, OwnershipSource, OwnershipTarget,
+};
+
+/// Upsert payload for ownership rules. Also the body of a dry run, where
+/// omitted fields fall back to the stored configuration.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Validate)]
+pub struct InfoOwnershipUpsertRequest {
+    /// Ownership rules; replaces the stored list.
+    #[validate(nested)]
+    pub rules: Option<Vec<OwnershipRuleUpsertRequest>>,
+
+    /// Team applied when no rule matches (empty string clears it).
+    #[validate(length(max = 100))]
+    pub default_team: Option<String>,
+
+    /// Service applied when no rule matches (empty string clears it).
+    #[validate(length(max = 100))]
+    pub default_service: Option<String>,
+
+    /// Env applied when no rule matches (empty string clears it).
+    #[validate(length(max = 100))]
+    pub default_env: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct OwnershipRuleUpsertRequest {
+    #[validate(length(min = 1))]
+    pub id: String,
+    pub source: OwnershipSource,
+    #[validate(length(min = 1, max = 317))]
+    pub key: String,
+    pub target: OwnershipTarget,
+    #[serde(default)]
+    pub priority: i32,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+impl From<OwnershipRuleUpsertRequest> for OwnershipRuleEntity {
+    fn from(value: OwnershipRuleUpsertRequest) -> Self {
+        Self {
+            id: value.id,
+            source: value.source,
+            key: value.key.trim().to_string(),
+            target: value.target,
+            priority: value.priority,
+            enabled: value.enabled,
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::domain::info::model::price_catalog::PriceCatalogFormat;
+
+/// Outcome of matching stored nodes against the provider price catalog.
+#[derive(Debug, Clone, Serialize)]
+pub struct InfoPriceCatalogReportDto {
+    pub catalog_path: String,
+    pub format: PriceCatalogFormat,
+    /// Number of (instance type, region) prices in the catalog
+    pub catalog_entries: usize,
+    /// `false` for a dry run: nodes were matched but not updated
+    pub applied: bool,
+    pub generated_at: DateTime<Utc>,
+
+    /// Nodes priced from the catalog
+    pub priced: Vec<InfoPriceCatalogNodeDto>,
+    /// Nodes with a manually set price, left untouched
+    pub manual: Vec<InfoPriceCatalogNodeDto>,
+    /// Nodes the catalog could not price, with the reason
+    pub unpriced: Vec<InfoPriceCatalogNodeDto>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct InfoPriceCatalogNodeDto {
+    pub node_name: String,
+    pub instance_type: Option<String>,
+    pub region: Option<String>,
+    pub hourly_usd: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
@@ -26,19 +26,33 @@ pub struct InfoSettingUpsertRequest {
     #[validate(length(min = 3))]
     pub retention_policy: Option<String>,
 
+    /// Directory for archived partitions (empty string resets to default).
+    pub archive_dir: Option<String>,
+
     // ===== File-based Persistence Options =====
     /// Whether to include line numbers when writing records.
     pub enable_line_num_tracking: Option<bool>,
 
-    /// Whether to create `.idx` index sidecar files for faster reads.
+    /// Whether the day task writes `.idx` sidecars for closed partitions.
+    /// Existing sidecars are used by readers either way.
     pub enable_index_file: Option<bool>,
 
-    /// Maximum local storage capacity in gigabytes before cleanup triggers.
+    /// Maximum metric + info data in gigabytes before old minute/hour data is evicted (0 disables the limit).
     pub max_storage_gb: Option<u32>,
 
-    /// Enables on-disk compression (gzip or zstd).
+    /// Gzip-compresses closed metric partitions during the day task.
     pub compression_enabled: Option<bool>,
 
+    // ===== Backup =====
+    /// Directory for backup archives (empty string resets to default).
+    pub backup_dir: Option<String>,
+
+    /// Hours between scheduled backups (0 disables them).
+    pub backup_interval_hours: Option<u32>,
+
+    /// Number of backup archives kept (0 keeps all).
+    pub backup_retention_count: Option<u32>,
+
     // ===== Metrics Collection =====
     /// Scrape interval in seconds (e.g. 60 = every minute).
     pub scrape_interval_sec: Option<u32>,
@@ -46,6 +60,13 @@ pub struct InfoSettingUpsertRequest {
     /// Number of metrics batched together when written to disk.
     pub metrics_batch_size: Option<u32>,
 
+    // ===== Cost =====
+    /// Extra `key=value` node labels (comma-separated) marking spot nodes.
+    pub spot_node_label_selector: Option<String>,
+
+    /// Path of a provider price catalog file (CSV or AWS/GCP/Azure pricing JSON).
+    pub price_catalog_path: Option<String>,
+
     // ===== LLM Integration =====
     /// Endpoint for an external LLM API (e.g., OpenAI, Anthropic).
     #[validate(url)]
@@ -79,6 +100,15 @@ pub struct InfoSettingUpsertRequest {
     /// Container exporter endpoint URLs.
     pub container_exporter_urls: Option<Vec<String>>,
 
+    /// Skip TLS certificate verification for the container exporter URLs.
+    pub container_exporter_insecure_tls: Option<bool>,
+
+    /// Pod/container metrics source: "kubelet" or "cadvisor".
+    pub container_metrics_source: Option<String>,
+
+    /// Flow exporter endpoint URLs (per source/destination IP byte counters).
+    pub network_flow_exporter_urls: Option<Vec<String>>,
+
     /// Optional Kubernetes API endpoint.
     #[validate(url)]
     pub k8s_api_url: Option<String>,
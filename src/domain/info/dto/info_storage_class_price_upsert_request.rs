@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::core::persistence::info::fixed::unit_price::info_unit_price_entity::Currency;
+
+/// Upsert payload for StorageClass prices; replaces the stored list from now
+/// on. Earlier windows keep the prices that applied then.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct InfoStorageClassPriceUpsertRequest {
+    #[validate(nested)]
+    pub prices: Vec<StorageClassPriceUpsertRequest>,
+
+    /// Currency of the prices; keeps the stored currency (USD by default) when omitted.
+    pub currency: Option<Currency>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct StorageClassPriceUpsertRequest {
+    #[validate(length(min = 1, max = 253))]
+    pub storage_class: String,
+
+    /// Price per GB-hour of provisioned capacity, in the request currency.
+    #[validate(range(min = 0.0))]
+    #[serde(alias = "gb_hour_usd")]
+    pub gb_hour: f64,
+}
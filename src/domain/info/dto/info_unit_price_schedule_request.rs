@@ -0,0 +1,18 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::domain::info::dto::info_unit_price_upsert_request::InfoUnitPriceUpsertRequest;
+
+/// Schedules a future unit price change.
+///
+/// Omitted prices are copied from the version in force at `effective_from`.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct InfoUnitPriceScheduleRequest {
+    /// When the new prices take effect. Must be in the future.
+    pub effective_from: DateTime<Utc>,
+
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub prices: InfoUnitPriceUpsertRequest,
+}
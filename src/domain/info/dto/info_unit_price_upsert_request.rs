@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 use validator::Validate;
+use crate::core::persistence::info::fixed::unit_price::info_unit_price_entity::Currency;
 
 /// Represents an upsert (create/update) request for `InfoUnitPriceEntity`.
 ///
@@ -41,4 +42,18 @@ pub struct InfoUnitPriceUpsertRequest {
 
     /// Price per GB transferred to external networks (internet egress).
     pub network_external_gb: Option<f64>,
+
+    // --- Service endpoints ---
+    /// Price per hour of a `type: LoadBalancer` service.
+    pub load_balancer_hour: Option<f64>,
+
+    /// Price per hour of a `type: NodePort` service.
+    pub node_port_hour: Option<f64>,
+
+    /// Price per hour of an Ingress.
+    pub ingress_hour: Option<f64>,
+
+    // --- Currency ---
+    /// Currency the prices are expressed in. Existing prices are not converted.
+    pub currency: Option<Currency>,
 }
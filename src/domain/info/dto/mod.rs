@@ -5,5 +5,11 @@ pub mod info_k8s_container_patch_request;
 pub mod info_k8s_node_patch_request;
 pub mod info_k8s_pod_patch_request;
 pub mod info_llm_upsert_request;
+pub mod info_ownership_preview_dto;
+pub mod info_ownership_upsert_request;
+pub mod info_price_catalog_report_dto;
 pub mod info_setting_upsert_request;
+pub mod info_storage_class_price_upsert_request;
+pub mod info_unit_price_schedule_request;
 pub mod info_unit_price_upsert_request;
+pub mod info_exchange_rate_upsert_request;
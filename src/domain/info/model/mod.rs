@@ -1,2 +1,5 @@
 //! Domain entities for info (NodeInfo, PodInfo, UnitPrice, Settings, etc.)
 
+pub mod price_catalog;
+pub mod network_locality;
+pub mod ownership_rules;
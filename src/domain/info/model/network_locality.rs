@@ -0,0 +1,176 @@
+//! Traffic locality (same zone / cross zone / external) from cluster topology.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::net::IpAddr;
+
+use crate::core::persistence::info::k8s::node::info_node_entity::InfoNodeEntity;
+use crate::core::persistence::info::k8s::pod::info_pod_entity::InfoPodEntity;
+use crate::domain::info::model::price_catalog::REGION_LABELS;
+
+/// Labels holding the node zone, most specific first.
+const ZONE_LABELS: &[&str] = &[
+    "topology.kubernetes.io/zone",
+    "failure-domain.beta.kubernetes.io/zone",
+];
+
+/// Where the other end of a connection lives, which decides the transfer rate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NetworkLocality {
+    /// Same zone (priced at `network_local_gb`)
+    Local,
+    /// Same region, different or unknown zone (priced at `network_regional_gb`)
+    Regional,
+    /// Another region or the internet (priced at `network_external_gb`)
+    External,
+}
+
+#[derive(Debug, Clone, Default)]
+struct Placement {
+    zone: Option<String>,
+    region: Option<String>,
+}
+
+/// Pod IP → node → zone/region lookup built from stored pod and node info.
+#[derive(Debug, Clone, Default)]
+pub struct NetworkTopology {
+    /// Pod IP → (pod UID, node name). Host-network pods are left out since
+    /// they share the node IP.
+    pods: HashMap<IpAddr, (String, Option<String>)>,
+    /// Node IP → node name
+    node_ips: HashMap<IpAddr, String>,
+    nodes: HashMap<String, Placement>,
+}
+
+impl NetworkTopology {
+    pub fn new(pods: &[InfoPodEntity], nodes: &[InfoNodeEntity]) -> Self {
+        let mut topology = Self::default();
+
+        for node in nodes {
+            let Some(name) = node.node_name.clone() else {
+                continue;
+            };
+            let labels: BTreeMap<String, String> = node
+                .label
+                .as_deref()
+                .and_then(|l| serde_json::from_str(l).ok())
+                .unwrap_or_default();
+            let first = |keys: &[&str]| keys.iter().find_map(|k| labels.get(*k)).cloned();
+
+            topology.nodes.insert(
+                name,
+                Placement {
+                    zone: first(ZONE_LABELS),
+                    region: first(REGION_LABELS),
+                },
+            );
+        }
+
+        for pod in pods {
+            let parse = |ip: &Option<String>| ip.as_deref().and_then(|v| v.parse::<IpAddr>().ok());
+            let (pod_ip, host_ip) = (parse(&pod.pod_ip), parse(&pod.host_ip));
+
+            if let (Some(host_ip), Some(node)) = (host_ip, pod.node_name.clone()) {
+                topology.node_ips.insert(host_ip, node);
+            }
+            match (pod_ip, pod.pod_uid.clone()) {
+                (Some(ip), Some(uid)) if Some(ip) != host_ip => {
+                    topology.pods.insert(ip, (uid, pod.node_name.clone()));
+                }
+                _ => {}
+            }
+        }
+
+        topology
+    }
+
+    /// UID of the pod owning `ip`, if any.
+    pub fn pod_uid(&self, ip: IpAddr) -> Option<&str> {
+        self.pods.get(&ip).map(|(uid, _)| uid.as_str())
+    }
+
+    /// Locality of traffic between two addresses.
+    ///
+    /// Both ends placed on known nodes are compared by zone and region. An
+    /// unknown private address is assumed to be inside the cluster network
+    /// (regional); an unknown public address is external.
+    pub fn classify(&self, a: IpAddr, b: IpAddr) -> NetworkLocality {
+        match (self.placement(a), self.placement(b)) {
+            (Some(pa), Some(pb)) => {
+                let same = |x: &Option<String>, y: &Option<String>| x.is_some() && x == y;
+                if same(&pa.zone, &pb.zone) {
+                    NetworkLocality::Local
+                } else if same(&pa.region, &pb.region) || (pa.region.is_none() && pb.region.is_none()) {
+                    NetworkLocality::Regional
+                } else {
+                    NetworkLocality::External
+                }
+            }
+            (Some(_), None) if is_private(b) => NetworkLocality::Regional,
+            (None, Some(_)) if is_private(a) => NetworkLocality::Regional,
+            (None, None) if is_private(a) && is_private(b) => NetworkLocality::Regional,
+            _ => NetworkLocality::External,
+        }
+    }
+
+    fn placement(&self, ip: IpAddr) -> Option<&Placement> {
+        let node = match self.pods.get(&ip) {
+            Some((_, node)) => node.as_ref()?,
+            None => self.node_ips.get(&ip)?,
+        };
+        self.nodes.get(node)
+    }
+}
+
+fn is_private(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(v4) => v4.is_private() || v4.is_loopback() || v4.is_link_local(),
+        // Unique local (fc00::/7) and link-local (fe80::/10)
+        IpAddr::V6(v6) => {
+            v6.is_loopback() || (v6.segments()[0] & 0xfe00) == 0xfc00 || (v6.segments()[0] & 0xffc0) == 0xfe80
+        }
+    }
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(name: &str, zone: &str) -> InfoNodeEntity {
+        InfoNodeEntity {
+            node_name: Some(name.to_string()),
+            label: Some(format!(
+                r#"{{"topology.kubernetes.io/zone":"{zone}","topology.kubernetes.io/region":"us-east-1"}}"#
+            )),
+            ..Default::default()
+        }
+    }
+
+    fn pod(uid: &str, ip: &str, node: &str) -> InfoPodEntity {
+        InfoPodEntity {
+            pod_uid: Some(uid.to_string()),
+            pod_ip: Some(ip.to_string()),
+            host_ip: Some("192.168.0.1".to_string()),
+            node_name: Some(node.to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn classifies_by_zone_region_and_address_scope() {
+        let topology = NetworkTopology::new(
+            &[pod("a", "10.0.0.1", "n1"), pod("b", "10.0.0.2", "n1"), pod("c", "10.0.1.1", "n2")],
+            &[node("n1", "us-east-1a"), node("n2", "us-east-1b")],
+        );
+        let ip = |v: &str| v.parse::<IpAddr>().unwrap();
+
+        assert_eq!(topology.pod_uid(ip("10.0.0.2")), Some("b"));
+        assert_eq!(topology.classify(ip("10.0.0.1"), ip("10.0.0.2")), NetworkLocality::Local);
+        assert_eq!(topology.classify(ip("10.0.0.1"), ip("10.0.1.1")), NetworkLocality::Regional);
+        assert_eq!(topology.classify(ip("10.0.0.1"), ip("10.9.9.9")), NetworkLocality::Regional);
+        assert_eq!(topology.classify(ip("10.0.0.1"), ip("8.8.8.8")), NetworkLocality::External);
+    }
+}
@@ -0,0 +1,224 @@
+//! Team/service/env ownership derived from labels and annotations.
+//!
+//! For each target the enabled rule with the highest priority whose key is
+//! present wins (ties go to the rule listed first); the configured default
+//! applies when none matches. Objects record where each tag came from: tags
+//! set by a PATCH are never overwritten, while rule-derived ones are
+//! recomputed (and cleared once nothing matches) whenever the object is
+//! re-tagged.
+
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+
+use crate::core::client::kube_resources::{Namespace, Pod};
+use crate::core::persistence::info::fixed::ownership::info_ownership_entity::InfoOwnershipEntity;
+use crate::core::persistence::info::fixed::ownership::ownership_rule_entity::{
+    OwnershipRuleEntity, OwnershipSource, OwnershipTagSource, OwnershipTarget,
+};
+
+/// Metadata of one pod and its namespace, as seen by the rules.
+#[derive(Debug, Clone, Default)]
+pub struct OwnershipSubject {
+    pub labels: BTreeMap<String, String>,
+    pub annotations: BTreeMap<String, String>,
+    pub namespace_labels: BTreeMap<String, String>,
+    pub namespace_annotations: BTreeMap<String, String>,
+}
+
+impl OwnershipSubject {
+    pub fn from_pod(pod: &Pod, namespace: Option<&Namespace>) -> Self {
+        let ns_meta = namespace.map(|n| &n.metadata);
+        Self {
+            labels: pod.metadata.labels.clone().unwrap_or_default(),
+            annotations: pod.metadata.annotations.clone().unwrap_or_default(),
+            namespace_labels: ns_meta.and_then(|m| m.labels.clone()).unwrap_or_default(),
+            namespace_annotations: ns_meta.and_then(|m| m.annotations.clone()).unwrap_or_default(),
+        }
+    }
+
+    /// Value `rule` reads from this subject; blank values do not match.
+    pub fn value_for(&self, rule: &OwnershipRuleEntity) -> Option<&str> {
+        let map = match rule.source {
+            OwnershipSource::PodLabel => &self.labels,
+            OwnershipSource::PodAnnotation => &self.annotations,
+            OwnershipSource::NamespaceLabel => &self.namespace_labels,
+            OwnershipSource::NamespaceAnnotation => &self.namespace_annotations,
+        };
+        map.get(&rule.key).map(|v| v.trim()).filter(|v| !v.is_empty())
+    }
+}
+
+/// Tags resolved for one object.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct OwnershipTags {
+    pub team: Option<String>,
+    pub service: Option<String>,
+    pub env: Option<String>,
+    pub team_source: Option<OwnershipTagSource>,
+    pub service_source: Option<OwnershipTagSource>,
+    pub env_source: Option<OwnershipTagSource>,
+}
+
+impl OwnershipTags {
+    pub fn get(&self, target: OwnershipTarget) -> Option<&str> {
+        match target {
+            OwnershipTarget::Team => self.team.as_deref(),
+            OwnershipTarget::Service => self.service.as_deref(),
+            OwnershipTarget::Env => self.env.as_deref(),
+        }
+    }
+
+    pub fn source(&self, target: OwnershipTarget) -> Option<&OwnershipTagSource> {
+        match target {
+            OwnershipTarget::Team => self.team_source.as_ref(),
+            OwnershipTarget::Service => self.service_source.as_ref(),
+            OwnershipTarget::Env => self.env_source.as_ref(),
+        }
+    }
+
+    fn set(&mut self, target: OwnershipTarget, value: String, source: OwnershipTagSource) {
+        let (field, field_source) = match target {
+            OwnershipTarget::Team => (&mut self.team, &mut self.team_source),
+            OwnershipTarget::Service => (&mut self.service, &mut self.service_source),
+            OwnershipTarget::Env => (&mut self.env, &mut self.env_source),
+        };
+        *field = Some(value);
+        *field_source = Some(source);
+    }
+
+    /// Writes the resolved tags into an object's `(tag, source)` fields, in
+    /// `OwnershipTarget::ALL` order (see `ownership_fields` on the entities).
+    /// Unset and rule-derived tags are replaced, so they follow rule changes;
+    /// manual tags, and tags stored without a source, are kept. Returns
+    /// whether anything changed.
+    pub fn apply(&self, fields: [(&mut Option<String>, &mut Option<OwnershipTagSource>); 3]) -> bool {
+        let mut changed = false;
+        for (target, (field, source)) in OwnershipTarget::ALL.into_iter().zip(fields) {
+            let derived = field.is_none() || source.as_ref().is_some_and(OwnershipTagSource::is_derived);
+            if !derived {
+                continue;
+            }
+            let value = self.get(target).map(str::to_string);
+            let value_source = self.source(target).cloned();
+            if *field != value || *source != value_source {
+                *field = value;
+                *source = value_source;
+                changed = true;
+            }
+        }
+        changed
+    }
+}
+
+/// Enabled rules in evaluation order.
+pub fn ordered_rules(config: &InfoOwnershipEntity) -> Vec<&OwnershipRuleEntity> {
+    let mut rules: Vec<&OwnershipRuleEntity> = config.rules.iter().filter(|r| r.enabled).collect();
+    // Stable sort keeps list order among equal priorities
+    rules.sort_by_key(|r| Reverse(r.priority));
+    rules
+}
+
+/// Rule that decides `target` for `subject`, with the value it reads.
+pub fn winning_rule<'a>(
+    rules: &[&'a OwnershipRuleEntity],
+    subject: &OwnershipSubject,
+    target: OwnershipTarget,
+) -> Option<(&'a OwnershipRuleEntity, String)> {
+    rules
+        .iter()
+        .filter(|r| r.target == target)
+        .find_map(|r| subject.value_for(r).map(|v| (*r, v.to_string())))
+}
+
+/// Resolves all targets for `subject`, falling back to the defaults.
+pub fn resolve_ownership(config: &InfoOwnershipEntity, subject: &OwnershipSubject) -> OwnershipTags {
+    let rules = ordered_rules(config);
+    let mut tags = OwnershipTags::default();
+    for target in OwnershipTarget::ALL {
+        let value = winning_rule(&rules, subject, target)
+            .map(|(rule, v)| (v, OwnershipTagSource::Rule(rule.id.clone())))
+            .or_else(|| config.default_for(target).map(|v| (v.to_string(), OwnershipTagSource::Default)));
+        if let Some((value, source)) = value {
+            tags.set(target, value, source);
+        }
+    }
+    tags
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::persistence::info::k8s::pod::info_pod_entity::InfoPodEntity;
+
+    fn rule(id: &str, source: OwnershipSource, key: &str, target: OwnershipTarget, priority: i32) -> OwnershipRuleEntity {
+        OwnershipRuleEntity { id: id.into(), source, key: key.into(), target, priority, enabled: true }
+    }
+
+    #[test]
+    fn highest_priority_match_wins_then_defaults() {
+        let config = InfoOwnershipEntity {
+            rules: vec![
+                rule("ns-team", OwnershipSource::NamespaceAnnotation, "cost-center", OwnershipTarget::Team, 0),
+                rule("pod-team", OwnershipSource::PodLabel, "team", OwnershipTarget::Team, 10),
+                rule("part-of", OwnershipSource::PodLabel, "app.kubernetes.io/part-of", OwnershipTarget::Service, 0),
+            ],
+            default_env: Some("prod".into()),
+            ..Default::default()
+        };
+        let mut subject = OwnershipSubject::default();
+        subject.namespace_annotations.insert("cost-center".into(), "finance".into());
+        subject.labels.insert("app.kubernetes.io/part-of".into(), "checkout".into());
+
+        // The pod label outranks the namespace annotation only when present
+        let tags = resolve_ownership(&config, &subject);
+        assert_eq!(tags.team.as_deref(), Some("finance"));
+        assert_eq!(tags.service.as_deref(), Some("checkout"));
+        assert_eq!(tags.env.as_deref(), Some("prod"));
+
+        subject.labels.insert("team".into(), "payments".into());
+        let tags = resolve_ownership(&config, &subject);
+        assert_eq!(tags.team.as_deref(), Some("payments"));
+        assert_eq!(tags.team_source, Some(OwnershipTagSource::Rule("pod-team".into())));
+        assert_eq!(tags.env_source, Some(OwnershipTagSource::Default));
+
+        // Tags set by hand are kept
+        let (mut team, mut service, mut env) = (Some("manual".to_string()), None, None);
+        let (mut team_source, mut service_source, mut env_source) = (Some(OwnershipTagSource::Manual), None, None);
+        assert!(tags.apply([
+            (&mut team, &mut team_source),
+            (&mut service, &mut service_source),
+            (&mut env, &mut env_source),
+        ]));
+        assert_eq!(team.as_deref(), Some("manual"));
+        assert_eq!(service.as_deref(), Some("checkout"));
+        assert_eq!(service_source, Some(OwnershipTagSource::Rule("part-of".into())));
+    }
+
+    #[test]
+    fn rule_derived_tags_follow_rule_changes() {
+        let mut config = InfoOwnershipEntity {
+            rules: vec![rule("part-of", OwnershipSource::PodLabel, "app.kubernetes.io/part-of", OwnershipTarget::Service, 0)],
+            ..Default::default()
+        };
+        let mut subject = OwnershipSubject::default();
+        subject.labels.insert("app.kubernetes.io/part-of".into(), "checkout".into());
+        subject.labels.insert("app".into(), "cart".into());
+
+        let mut pod = InfoPodEntity::default();
+        assert!(resolve_ownership(&config, &subject).apply(pod.ownership_fields()));
+
+        // The rule now reads another label: the derived tag is recomputed
+        config.rules[0].key = "app".into();
+        assert!(resolve_ownership(&config, &subject).apply(pod.ownership_fields()));
+        assert!(!resolve_ownership(&config, &subject).apply(pod.ownership_fields()));
+        assert_eq!(pod.service.as_deref(), Some("cart"));
+
+        // Nothing matches anymore: the derived tag is cleared
+        config.rules.clear();
+        resolve_ownership(&config, &subject).apply(pod.ownership_fields());
+        assert_eq!(pod.service, None);
+        assert_eq!(pod.service_source, None);
+    }
+}
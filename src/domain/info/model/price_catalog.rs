@@ -0,0 +1,404 @@
+//! Offline cloud provider price catalog (instance type × region → hourly USD).
+
+use anyhow::{anyhow, Context, Result};
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::Path;
+
+/// Labels holding the node instance type, most specific first.
+const INSTANCE_TYPE_LABELS: &[&str] = &[
+    "node.kubernetes.io/instance-type",
+    "beta.kubernetes.io/instance-type",
+];
+
+/// Labels holding the node region, most specific first.
+pub(crate) const REGION_LABELS: &[&str] = &[
+    "topology.kubernetes.io/region",
+    "failure-domain.beta.kubernetes.io/region",
+];
+
+/// Catalog file layout, detected from the extension and the JSON shape.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PriceCatalogFormat {
+    /// `instance_type,region,hourly_usd` with a header row
+    Csv,
+    /// AWS Price List API offer file (`products` + `terms.OnDemand`)
+    Aws,
+    /// Azure Retail Prices API response (`Items`)
+    Azure,
+    /// GCP pricing calculator list (`gcp_price_list`)
+    Gcp,
+    /// JSON array of `{instance_type, region, hourly_usd}`
+    Json,
+}
+
+/// On-demand hourly USD prices keyed by lower-cased `(instance_type, region)`.
+///
+/// An empty region matches nodes in any region.
+#[derive(Debug, Clone)]
+pub struct PriceCatalog {
+    pub format: PriceCatalogFormat,
+    prices: HashMap<(String, String), f64>,
+}
+
+impl PriceCatalog {
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read price catalog {}", path.display()))?;
+
+        let is_csv = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .is_some_and(|e| e.eq_ignore_ascii_case("csv"));
+
+        if is_csv {
+            Self::parse_csv(&text)
+        } else {
+            Self::parse_json(&text)
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.prices.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.prices.is_empty()
+    }
+
+    /// Hourly USD price for an instance type in a region, falling back to a
+    /// region-less entry.
+    pub fn lookup(&self, instance_type: &str, region: Option<&str>) -> Option<f64> {
+        let instance_type = instance_type.to_lowercase();
+        region
+            .and_then(|r| self.prices.get(&(instance_type.clone(), r.to_lowercase())))
+            .or_else(|| self.prices.get(&(instance_type, String::new())))
+            .copied()
+    }
+
+    pub fn parse_csv(text: &str) -> Result<Self> {
+        let mut lines = text
+            .lines()
+            .map(str::trim)
+            .filter(|l| !l.is_empty() && !l.starts_with('#'));
+
+        let header: Vec<String> = lines
+            .next()
+            .ok_or_else(|| anyhow!("Price catalog CSV is empty"))
+            .map(split_csv_line)?
+            .into_iter()
+            .map(|c| c.to_lowercase())
+            .collect();
+        let column = |name: &str| {
+            header
+                .iter()
+                .position(|c| c == name)
+                .ok_or_else(|| anyhow!("Price catalog CSV is missing the '{}' column", name))
+        };
+        let (type_col, price_col) = (column("instance_type")?, column("hourly_usd")?);
+        let region_col = column("region").ok();
+
+        let mut catalog = Self::empty(PriceCatalogFormat::Csv);
+        for (idx, line) in lines.enumerate() {
+            let cells = split_csv_line(line);
+            let cell = |col: usize| cells.get(col).map(String::as_str).unwrap_or_default();
+
+            let price: f64 = cell(price_col)
+                .parse()
+                .with_context(|| format!("Invalid hourly_usd on data row {}", idx + 1))?;
+            catalog.insert(cell(type_col), region_col.map(cell).unwrap_or_default(), price);
+        }
+
+        Ok(catalog)
+    }
+
+    pub fn parse_json(text: &str) -> Result<Self> {
+        let value: Value = serde_json::from_str(text).context("Price catalog is not valid JSON")?;
+
+        if value.get("products").is_some() && value.get("terms").is_some() {
+            Self::from_aws(serde_json::from_value(value)?)
+        } else if value.get("Items").is_some() {
+            Self::from_azure(serde_json::from_value(value)?)
+        } else if let Some(list) = value.get("gcp_price_list") {
+            Ok(Self::from_gcp(list))
+        } else if value.is_array() {
+            Self::from_entries(serde_json::from_value(value)?)
+        } else {
+            Err(anyhow!("Unrecognized price catalog JSON format"))
+        }
+    }
+
+    fn empty(format: PriceCatalogFormat) -> Self {
+        Self {
+            format,
+            prices: HashMap::new(),
+        }
+    }
+
+    fn insert(&mut self, instance_type: &str, region: &str, hourly_usd: f64) {
+        if instance_type.is_empty() || !hourly_usd.is_finite() || hourly_usd < 0.0 {
+            return;
+        }
+        self.prices
+            .insert((instance_type.to_lowercase(), region.to_lowercase()), hourly_usd);
+    }
+
+    /// Linux, shared-tenancy, on-demand instance prices.
+    fn from_aws(offer: AwsOffer) -> Result<Self> {
+        let mut catalog = Self::empty(PriceCatalogFormat::Aws);
+
+        for (sku, product) in &offer.products {
+            let attr = |key: &str| product.attributes.get(key).map(String::as_str);
+            let is = |key: &str, expected: &str| attr(key).is_none_or(|v| v == expected);
+
+            let (Some(instance_type), Some(region)) = (attr("instanceType"), attr("regionCode")) else {
+                continue;
+            };
+            if !(is("operatingSystem", "Linux")
+                && is("tenancy", "Shared")
+                && is("preInstalledSw", "NA")
+                && is("capacitystatus", "Used"))
+            {
+                continue;
+            }
+
+            let hourly = offer
+                .terms
+                .on_demand
+                .get(sku)
+                .into_iter()
+                .flat_map(|terms| terms.values())
+                .flat_map(|term| term.price_dimensions.values())
+                .filter(|d| d.unit.eq_ignore_ascii_case("hrs"))
+                .find_map(|d| d.price_per_unit.get("USD").and_then(|p| p.parse::<f64>().ok()));
+
+            if let Some(hourly) = hourly {
+                catalog.insert(instance_type, region, hourly);
+            }
+        }
+
+        Ok(catalog)
+    }
+
+    /// Pay-as-you-go Linux VM prices in USD (spot and low priority excluded).
+    fn from_azure(prices: AzurePrices) -> Result<Self> {
+        let mut catalog = Self::empty(PriceCatalogFormat::Azure);
+
+        for item in &prices.items {
+            let discounted = ["Spot", "Low Priority"]
+                .iter()
+                .any(|s| item.sku_name.contains(s) || item.meter_name.contains(s));
+            if item.price_type != "Consumption"
+                || item.unit_of_measure != "1 Hour"
+                || item.currency_code != "USD"
+                || item.product_name.contains("Windows")
+                || discounted
+            {
+                continue;
+            }
+            catalog.insert(&item.arm_sku_name, &item.arm_region_name, item.retail_price);
+        }
+
+        Ok(catalog)
+    }
+
+    /// `CP-COMPUTEENGINE-VMIMAGE-<TYPE>` entries, one price per region key.
+    fn from_gcp(list: &Value) -> Self {
+        const PREFIX: &str = "CP-COMPUTEENGINE-VMIMAGE-";
+        let mut catalog = Self::empty(PriceCatalogFormat::Gcp);
+
+        let Some(entries) = list.as_object() else {
+            return catalog;
+        };
+        for (key, prices) in entries {
+            let Some(instance_type) = key.strip_prefix(PREFIX) else {
+                continue;
+            };
+            if instance_type.contains("PREEMPTIBLE") {
+                continue;
+            }
+            let Some(prices) = prices.as_object() else {
+                continue;
+            };
+
+            // Region keys look like "us-central1"; other keys are specs ("cores", "memory", ...)
+            for (region, price) in prices {
+                let is_region = region.contains('-') && region.ends_with(|c: char| c.is_ascii_digit());
+                if let (true, Some(price)) = (is_region, price.as_f64()) {
+                    catalog.insert(instance_type, region, price);
+                }
+            }
+        }
+
+        catalog
+    }
+
+    fn from_entries(entries: Vec<PriceCatalogEntry>) -> Result<Self> {
+        let mut catalog = Self::empty(PriceCatalogFormat::Json);
+        for entry in entries {
+            catalog.insert(
+                &entry.instance_type,
+                entry.region.as_deref().unwrap_or_default(),
+                entry.hourly_usd,
+            );
+        }
+        Ok(catalog)
+    }
+}
+
+/// Splits one CSV line into trimmed cells. Cells may be double-quoted to hold
+/// commas; `""` inside a quoted cell is a literal quote.
+fn split_csv_line(line: &str) -> Vec<String> {
+    let mut cells = Vec::new();
+    let mut cell = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match (c, quoted) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                cell.push('"');
+                chars.next();
+            }
+            ('"', _) => quoted = !quoted,
+            (',', false) => cells.push(std::mem::take(&mut cell).trim().to_string()),
+            _ => cell.push(c),
+        }
+    }
+    cells.push(cell.trim().to_string());
+
+    cells
+}
+
+/// Instance type and region of a node, read from its well-known labels.
+pub fn node_instance_type_and_region(
+    labels: &BTreeMap<String, String>,
+) -> (Option<String>, Option<String>) {
+    let first = |keys: &[&str]| keys.iter().find_map(|k| labels.get(*k)).cloned();
+    (first(INSTANCE_TYPE_LABELS), first(REGION_LABELS))
+}
+
+#[derive(Debug, Deserialize)]
+struct PriceCatalogEntry {
+    instance_type: String,
+    region: Option<String>,
+    hourly_usd: f64,
+}
+
+#[derive(Debug, Deserialize)]
+struct AwsOffer {
+    products: HashMap<String, AwsProduct>,
+    terms: AwsTerms,
+}
+
+#[derive(Debug, Deserialize)]
+struct AwsProduct {
+    #[serde(default)]
+    attributes: HashMap<String, String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AwsTerms {
+    #[serde(rename = "OnDemand", default)]
+    on_demand: HashMap<String, HashMap<String, AwsTerm>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AwsTerm {
+    #[serde(rename = "priceDimensions", default)]
+    price_dimensions: HashMap<String, AwsPriceDimension>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AwsPriceDimension {
+    #[serde(default)]
+    unit: String,
+    #[serde(rename = "pricePerUnit", default)]
+    price_per_unit: HashMap<String, String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AzurePrices {
+    #[serde(rename = "Items")]
+    items: Vec<AzurePriceItem>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AzurePriceItem {
+    #[serde(default)]
+    arm_sku_name: String,
+    #[serde(default)]
+    arm_region_name: String,
+    #[serde(default)]
+    retail_price: f64,
+    #[serde(default)]
+    unit_of_measure: String,
+    #[serde(rename = "type", default)]
+    price_type: String,
+    #[serde(default)]
+    currency_code: String,
+    #[serde(default)]
+    sku_name: String,
+    #[serde(default)]
+    meter_name: String,
+    #[serde(default)]
+    product_name: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn csv_lookup_falls_back_to_region_less_rows() {
+        let catalog = PriceCatalog::parse_csv(
+            "instance_type,region,hourly_usd\n\
+             m5.large,us-east-1,0.096\n\
+             m5.large,,0.11\n",
+        )
+        .unwrap();
+
+        assert_eq!(catalog.lookup("M5.large", Some("us-east-1")), Some(0.096));
+        assert_eq!(catalog.lookup("m5.large", Some("eu-west-1")), Some(0.11));
+        assert_eq!(catalog.lookup("m5.xlarge", Some("us-east-1")), None);
+    }
+
+    #[test]
+    fn csv_cells_may_be_quoted() {
+        let catalog = PriceCatalog::parse_csv(
+            "\"instance_type\",\"region\",\"note\",\"hourly_usd\"\n\
+             \"m5.large\",\"us-east-1\",\"general purpose, \"\"m5\"\"\",\"0.096\"\n",
+        )
+        .unwrap();
+
+        assert_eq!(catalog.lookup("m5.large", Some("us-east-1")), Some(0.096));
+        assert_eq!(
+            split_csv_line(r#"a, "b, c" ,"d""e""#),
+            vec!["a", "b, c", "d\"e"]
+        );
+    }
+
+    #[test]
+    fn aws_offer_keeps_linux_shared_on_demand_prices() {
+        let offer = r#"{
+            "products": {
+                "A": {"attributes": {"instanceType": "m5.large", "regionCode": "us-east-1",
+                      "operatingSystem": "Linux", "tenancy": "Shared", "preInstalledSw": "NA", "capacitystatus": "Used"}},
+                "B": {"attributes": {"instanceType": "m5.large", "regionCode": "us-east-1",
+                      "operatingSystem": "Windows", "tenancy": "Shared", "preInstalledSw": "NA", "capacitystatus": "Used"}}
+            },
+            "terms": {"OnDemand": {
+                "A": {"A.T": {"priceDimensions": {"A.T.D": {"unit": "Hrs", "pricePerUnit": {"USD": "0.0960000000"}}}}},
+                "B": {"B.T": {"priceDimensions": {"B.T.D": {"unit": "Hrs", "pricePerUnit": {"USD": "0.1880000000"}}}}}
+            }}
+        }"#;
+
+        let catalog = PriceCatalog::parse_json(offer).unwrap();
+        assert_eq!(catalog.format, PriceCatalogFormat::Aws);
+        assert_eq!(catalog.lookup("m5.large", Some("us-east-1")), Some(0.096));
+    }
+}
@@ -0,0 +1,26 @@
+use anyhow::Result;
+use serde_json::Value;
+use crate::core::persistence::info::fixed::exchange_rate::info_exchange_rate_api_repository_trait::InfoExchangeRateApiRepository;
+use crate::core::persistence::info::fixed::exchange_rate::info_exchange_rate_entity::InfoExchangeRateEntity;
+use crate::core::persistence::info::fixed::exchange_rate::info_exchange_rate_repository::InfoExchangeRateRepository;
+use crate::domain::info::dto::info_exchange_rate_upsert_request::InfoExchangeRateUpsertRequest;
+use validator::Validate;
+
+pub async fn get_info_exchange_rates() -> Result<InfoExchangeRateEntity> {
+    let repo = InfoExchangeRateRepository::new();
+    repo.read()
+}
+
+pub async fn upsert_info_exchange_rates(req: InfoExchangeRateUpsertRequest) -> Result<Value> {
+    req.validate()?;
+    let repo = InfoExchangeRateRepository::new();
+
+    let mut rates = repo.read()?;
+    rates.apply_update(req);
+    repo.update(&rates)?;
+
+    Ok(serde_json::json!({
+        "message": "Exchange rates updated successfully",
+        "updated_at": rates.updated_at.to_rfc3339(),
+    }))
+}
@@ -1,7 +1,10 @@
+use std::collections::HashSet;
+
 use anyhow::{anyhow, Context, Result};
 use chrono::{DateTime, Duration, Utc};
 use tracing::{debug, error, warn};
 
+use crate::core::persistence::storage_backend::storage_backend;
 use crate::api::dto::info_dto::K8sListQuery;
 use crate::core::client::k8s::client_k8s_pod::{fetch_pods, fetch_pods_by_namespace, fetch_pods_by_node, fetch_pods_by_label};
 use crate::core::client::k8s::util::{build_client, read_token};
@@ -10,7 +13,8 @@ use crate::core::persistence::info::k8s::container::info_container_entity::InfoC
 use crate::core::persistence::info::k8s::container::info_container_repository::InfoContainerRepository;
 use crate::core::persistence::info::path::info_k8s_container_dir_path;
 use crate::domain::info::dto::info_k8s_container_patch_request::InfoK8sContainerPatchRequest;
-use std::fs;
+use crate::core::persistence::info::fixed::ownership::ownership_rule_entity::OwnershipTagSource;
+use crate::domain::info::service::info_ownership_service::OwnershipResolver;
 use k8s_openapi::api::core::v1::{ContainerStatus, Pod};
 use kube::Api;
 use validator::Validate;
@@ -46,17 +50,28 @@ pub async fn get_info_k8s_container(container_id: String) -> Result<InfoContaine
     let container_name = container_name.unwrap_or_default();
 
     // ---- 4. Query Kubernetes API ----
+    let mut ownership = OwnershipResolver::load()?;
     let client = build_kube_client().await?;
-    let pod_api: Api<Pod> = Api::namespaced(client, &ns);
+    let pod_api: Api<Pod> = Api::namespaced(client.clone(), &ns);
 
     let pod_result = pod_api.get(&pod_name).await;
 
     match pod_result {
         // ---- 4A. POD FOUND ----
         Ok(pod) => {
-            let updated = map_container_from_pod(&pod, &container_name)
+            let mut updated = map_container_from_pod(&pod, &container_name)
                 .context("Container not found in pod")?;
 
+            // Keep team/service/env set on the cached record, then apply the rules
+            for ((field, source), cached) in updated.ownership_fields().into_iter().zip(entity.ownership_fields()) {
+                if cached.0.is_some() {
+                    *field = cached.0.take();
+                    *source = cached.1.take();
+                }
+            }
+            let tags = ownership.tags_for(&client, &pod).await;
+            tags.apply(updated.ownership_fields());
+
             repo.update(&updated)?;
             Ok(updated)
         }
@@ -290,6 +305,9 @@ pub fn map_container_from_pod(pod: &Pod, cname: &str) -> Result<InfoContainerEnt
         team: None,
         service: None,
         env: None,
+        team_source: None,
+        service_source: None,
+        env_source: None,
     })
 }
 
@@ -307,25 +325,21 @@ pub async fn list_k8s_containers(filter: K8sListQuery) -> Result<Vec<InfoContain
     // -------------------------------------------------------------
     // 1️⃣ Load cache entries from filesystem
     // -------------------------------------------------------------
-    let container_dir = info_k8s_container_dir_path();
-    if container_dir.exists() {
-        if let Ok(entries) = fs::read_dir(&container_dir) {
-            for entry in entries.flatten() {
-                let id = entry.file_name().to_string_lossy().to_string();
-                if let Ok(existing) = repo.read(&id) {
-                    if let Some(ts) = existing.last_updated_info_at {
-                        if Utc::now().signed_duration_since(ts) <= Duration::hours(1) {
-                            debug!("✅ Using cached container info: {}", id);
-                            cached_entities.push(existing);
-                            continue;
-                        }
+    if let Ok(ids) = storage_backend().list_keys(&info_k8s_container_dir_path()) {
+        for id in ids {
+            if let Ok(existing) = repo.read(&id) {
+                if let Some(ts) = existing.last_updated_info_at {
+                    if Utc::now().signed_duration_since(ts) <= Duration::hours(1) {
+                        debug!("✅ Using cached container info: {}", id);
+                        cached_entities.push(existing);
+                        continue;
                     }
-
-                    debug!("⚠️ Cache expired for container '{}'", id);
-                    expired_or_missing = true;
-                } else {
-                    expired_or_missing = true;
                 }
+
+                debug!("⚠️ Cache expired for container '{}'", id);
+                expired_or_missing = true;
+            } else {
+                expired_or_missing = true;
             }
         }
     }
@@ -373,8 +387,8 @@ pub async fn list_k8s_containers(filter: K8sListQuery) -> Result<Vec<InfoContain
                         .as_ref()
                         .and_then(|statuses| statuses.iter().find(|s| &s.name == cname));
 
-                    // TODO: Implement proper container mapping
-                    let mut mapped = InfoContainerEntity::default();
+                    // Full mapping so requests/limits are stored with the rest
+                    let mut mapped = map_container_from_pod(&pod, cname).unwrap_or_default();
 
                 mapped.namespace = Some(ns.clone());
                 mapped.pod_uid = Some(pod_uid.clone());
@@ -406,6 +420,54 @@ pub async fn list_k8s_containers(filter: K8sListQuery) -> Result<Vec<InfoContain
     Ok(apply_container_label_filter(results, filter.label_selector))
 }
 
+/// Stored container info of the given pods, including pods that no longer exist.
+///
+/// Containers are keyed `{pod_uid}-{container_name}`, so the rows are matched on
+/// their key prefix without reading every file.
+pub fn list_stored_containers_for_pods(pod_uids: &HashSet<String>) -> Result<Vec<InfoContainerEntity>> {
+    let repo = InfoContainerRepository::new();
+    let keys = storage_backend().list_keys(&info_k8s_container_dir_path())?;
+
+    Ok(keys
+        .into_iter()
+        .filter(|key| key.match_indices('-').any(|(i, _)| pod_uids.contains(&key[..i])))
+        .filter_map(|key| repo.read(&key).ok())
+        .collect())
+}
+
+/// Copies the container requests/limits of a live pod onto its stored
+/// container info, so they outlive the pod for chargeback.
+pub fn record_container_resources(pod: &Pod) -> Result<()> {
+    let Some(pod_uid) = pod.metadata.uid.as_deref() else {
+        return Ok(());
+    };
+    let Some(spec) = pod.spec.as_ref() else {
+        return Ok(());
+    };
+
+    let repo = InfoContainerRepository::new();
+    for container in &spec.containers {
+        let mapped = map_container_from_pod(pod, &container.name)?;
+        let key = format!("{}-{}", pod_uid, container.name);
+
+        match repo.read(&key) {
+            Ok(mut stored) => {
+                stored.cpu_request_millicores = mapped.cpu_request_millicores;
+                stored.memory_request_bytes = mapped.memory_request_bytes;
+                stored.cpu_limit_millicores = mapped.cpu_limit_millicores;
+                stored.memory_limit_bytes = mapped.memory_limit_bytes;
+                repo.update(&stored)?;
+            }
+            // Not collected yet: store the full mapping, the collector keeps it
+            Err(_) => repo.update(&InfoContainerEntity {
+                container_id: Some(key),
+                ..mapped
+            })?,
+        }
+    }
+    Ok(())
+}
+
 fn apply_container_label_filter(
     containers: Vec<InfoContainerEntity>,
     label_selector: Option<String>,
@@ -442,16 +504,20 @@ pub async fn patch_info_k8s_container(
     }
 
     // 2️⃣ Apply patch — only update fields that are Some()
+    // Tags set by hand are never replaced by the ownership rules
     if let Some(team) = patch.team {
         entity.team = Some(team);
+        entity.team_source = Some(OwnershipTagSource::Manual);
     }
 
     if let Some(service) = patch.service {
         entity.service = Some(service);
+        entity.service_source = Some(OwnershipTagSource::Manual);
     }
 
     if let Some(env) = patch.env {
         entity.env = Some(env);
+        entity.env_source = Some(OwnershipTagSource::Manual);
     }
 
     // 3️⃣ Update timestamp
@@ -1,8 +1,11 @@
+use crate::core::persistence::storage_backend::storage_backend;
 use crate::core::client::kube_client::build_kube_client;
 use crate::core::client::mappers::map_node_to_info_entity;
 use crate::core::client::nodes::{fetch_node_by_name, fetch_nodes};
+use crate::core::persistence::info::fixed::setting::info_setting_collector_repository_trait::InfoSettingCollectorRepository;
+use crate::core::persistence::info::fixed::setting::info_setting_repository::InfoSettingRepository;
 use crate::core::persistence::info::k8s::node::info_node_api_repository_trait::InfoNodeApiRepository;
-use crate::core::persistence::info::k8s::node::info_node_entity::InfoNodeEntity;
+use crate::core::persistence::info::k8s::node::info_node_entity::{InfoNodeEntity, NodePriceSource};
 use crate::core::persistence::info::k8s::node::info_node_repository::InfoNodeRepository;
 use crate::core::persistence::info::path::info_k8s_node_dir_path;
 use crate::api::dto::info_dto::K8sListNodeQuery;
@@ -13,10 +16,17 @@ use crate::domain::info::dto::info_k8s_node_patch_request::{
 use anyhow::{anyhow, Result};
 use chrono::{Duration, Utc};
 use serde_json::Map;
-use std::fs;
 use tracing::debug;
 use validator::Validate;
 
+/// The `spot_node_label_selector` setting, read once per request.
+fn spot_node_label_selector() -> Option<String> {
+    InfoSettingRepository::new()
+        .read()
+        .ok()
+        .and_then(|s| s.spot_node_label_selector)
+}
+
 pub async fn get_info_k8s_node(node_name: String) -> Result<InfoNodeEntity> {
     let now = Utc::now();
     let repo = InfoNodeRepository::new();
@@ -40,7 +50,8 @@ pub async fn get_info_k8s_node(node_name: String) -> Result<InfoNodeEntity> {
 
         // Fetch from K8s API
         let node = fetch_node_by_name(&client, &node_name).await?;
-        let updated_entity = map_node_to_info_entity(&node, now)?;
+        let spot_selector = spot_node_label_selector();
+        let updated_entity = map_node_to_info_entity(&node, now, spot_selector.as_deref())?;
 
         // Save refreshed info
         repo.update(&updated_entity)?;
@@ -73,25 +84,20 @@ pub async fn list_k8s_nodes(filter: K8sListNodeQuery) -> Result<Vec<InfoNodeEnti
     let mut expired_or_missing = false;
 
     // 1) Load local cache
-    let node_dir = info_k8s_node_dir_path();
-    if node_dir.exists() {
-        if let Ok(entries) = fs::read_dir(&node_dir) {
-            for entry in entries.flatten() {
-                let node_name = entry.file_name().to_string_lossy().to_string();
-
-                if let Ok(existing) = repo.read(&node_name) {
-                    if let Some(ts) = existing.last_updated_info_at {
-                        if now.signed_duration_since(ts) <= Duration::hours(1) {
-                            debug!("Using cached node info for '{}'", node_name);
-                            cached_entities.push(existing);
-                            continue;
-                        }
+    if let Ok(node_names) = storage_backend().list_keys(&info_k8s_node_dir_path()) {
+        for node_name in node_names {
+            if let Ok(existing) = repo.read(&node_name) {
+                if let Some(ts) = existing.last_updated_info_at {
+                    if now.signed_duration_since(ts) <= Duration::hours(1) {
+                        debug!("Using cached node info for '{}'", node_name);
+                        cached_entities.push(existing);
+                        continue;
                     }
                 }
-
-                debug!("Cache expired or missing for '{}'", node_name);
-                expired_or_missing = true;
             }
+
+            debug!("Cache expired or missing for '{}'", node_name);
+            expired_or_missing = true;
         }
     }
 
@@ -107,13 +113,14 @@ pub async fn list_k8s_nodes(filter: K8sListNodeQuery) -> Result<Vec<InfoNodeEnti
     debug!("Fetched {} node(s) from API", node_list.len());
 
     let mut result_entities = cached_entities;
+    let spot_selector = spot_node_label_selector();
 
     // 4) Process each node
     for node in node_list {
         let node_name = node.metadata.name.clone().unwrap_or_default();
 
         // Map API → entity
-        let mapped = map_node_to_info_entity(&node, now)?;
+        let mapped = map_node_to_info_entity(&node, now, spot_selector.as_deref())?;
 
         // If cache exists → merge
         let merged = if let Ok(mut existing) = repo.read(&node_name) {
@@ -246,17 +253,22 @@ pub async fn patch_info_k8s_node_price(
         .read(&id)
         .map_err(|_| anyhow!("Node '{}' not found", id))?;
 
-    // 2) Apply patch – only update fields that are Some()
-    if let Some(fixed_instance_usd) = patch.fixed_instance_usd {
-        entity.fixed_instance_usd = Some(fixed_instance_usd);
+    // 2) Apply patch – only update fields that are Some(); the previous price
+    //    is kept in the history so earlier points keep their price
+    let now = Utc::now();
+    let effective_from = patch.effective_from.unwrap_or(now);
+    if effective_from > now {
+        return Err(anyhow!("effective_from must not be in the future"));
     }
+    let fixed_instance_usd = patch.fixed_instance_usd.or(entity.fixed_instance_usd);
+    let price_period = patch.price_period.or(entity.price_period.clone());
+    entity.set_fixed_price(fixed_instance_usd, price_period, effective_from);
 
-    if let Some(price_period) = patch.price_period {
-        entity.price_period = Some(price_period);
-    }
+    // Manual prices take precedence over catalog imports
+    entity.price_source = Some(NodePriceSource::Manual);
 
     // 3) Update timestamp
-    entity.last_updated_info_at = Some(Utc::now());
+    entity.last_updated_info_at = Some(now);
 
     // 4) Store back
     repo.update(&entity)?;
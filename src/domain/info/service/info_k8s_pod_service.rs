@@ -17,6 +17,10 @@ use crate::core::persistence::info::k8s::pod::info_pod_repository::InfoPodReposi
 use crate::core::state::runtime::k8s::k8s_runtime_state::RuntimePod;
 use crate::core::state::runtime::k8s::k8s_runtime_state_repository_trait::K8sRuntimeStateRepositoryTrait;
 use crate::domain::info::dto::info_k8s_pod_patch_request::InfoK8sPodPatchRequest;
+use crate::core::persistence::info::fixed::ownership::ownership_rule_entity::OwnershipTagSource;
+use crate::domain::info::service::info_ownership_service::OwnershipResolver;
+use crate::domain::metric::k8s::workload::owner::WorkloadOwnerIndex;
+use k8s_openapi::api::core::v1::Pod;
 
 pub async fn get_info_k8s_pod(pod_uid: String) -> Result<InfoPodEntity> {
     let repo = InfoPodRepository::new();
@@ -34,9 +38,12 @@ pub async fn get_info_k8s_pod(pod_uid: String) -> Result<InfoPodEntity> {
             let kube_client = build_kube_client().await?;
             let pod = fetch_pod_by_name_and_namespace(&kube_client, &ns, &name).await?;
 
-            let mut updated = map_pod_to_info_entity(&pod)?;
+            // Keep team/service/env set on the cached record
+            let mut updated = existing;
+            updated.merge_from(map_pod_to_info_entity(&pod)?);
             updated.last_updated_info_at = Some(Utc::now());
             updated.pod_uid = Some(pod_uid.clone());
+            OwnershipResolver::load()?.tag_pod_entity(&kube_client, &pod, &mut updated).await;
             repo.update(&updated)?;
 
             return Ok(updated);
@@ -52,6 +59,7 @@ pub async fn get_info_k8s_pod(pod_uid: String) -> Result<InfoPodEntity> {
     let mut entity = map_pod_to_info_entity(&pod)?;
     entity.last_updated_info_at = Some(Utc::now());
     entity.pod_uid = Some(pod_uid.clone());
+    OwnershipResolver::load()?.tag_pod_entity(&kube_client, &pod, &mut entity).await;
     repo.insert(&entity)?;
 
     Ok(entity)
@@ -90,6 +98,7 @@ pub async fn load_pod_entities(uids: &[String], state: AppState) -> Result<Vec<I
     }
 
     let client = build_kube_client().await?;
+    let mut ownership = OwnershipResolver::load()?;
 
     for uid in uids {
         let Some(rpod) = runtime.pods.get(uid) else {
@@ -111,6 +120,7 @@ pub async fn load_pod_entities(uids: &[String], state: AppState) -> Result<Vec<I
         };
 
         entity.last_updated_info_at = Some(Utc::now());
+        ownership.tag_pod_entity(&client, &pod, &mut entity).await;
 
         if let Err(err) = repo.update(&entity) {
             debug!("Update failed for pod {uid}, attempting insert: {err:?}");
@@ -262,16 +272,20 @@ pub async fn patch_info_k8s_pod(
         .read(&id)
         .map_err(|_| anyhow!("Pod '{}' not found", id))?;
 
+    // Tags set by hand are never replaced by the ownership rules
     if let Some(team) = patch.team {
         entity.team = Some(team);
+        entity.team_source = Some(OwnershipTagSource::Manual);
     }
 
     if let Some(service) = patch.service {
         entity.service = Some(service);
+        entity.service_source = Some(OwnershipTagSource::Manual);
     }
 
     if let Some(env) = patch.env {
         entity.env = Some(env);
+        entity.env_source = Some(OwnershipTagSource::Manual);
     }
 
     entity.last_updated_info_at = Some(Utc::now());
@@ -280,3 +294,28 @@ pub async fn patch_info_k8s_pod(
 
     Ok(serde_json::to_value(&entity)?)
 }
+
+/// Stores the owner of a live pod and its top-level controller on the pod
+/// info, so workload costs still group correctly once the ReplicaSet or Job
+/// behind the pod is gone.
+pub fn record_pod_controller(pod: &Pod, owners: &WorkloadOwnerIndex) -> Result<()> {
+    let Some(pod_uid) = pod.metadata.uid.as_deref() else {
+        return Ok(());
+    };
+
+    let repo = InfoPodRepository::new();
+    let mut stored = repo.read(pod_uid)?;
+    let mapped = map_pod_to_info_entity(pod)?;
+    stored.owner_kind = mapped.owner_kind;
+    stored.owner_name = mapped.owner_name;
+    stored.owner_uid = mapped.owner_uid;
+
+    // Resolve from the owner references, not a previously stored controller
+    stored.controller_kind = None;
+    stored.controller_name = None;
+    let (kind, name) = owners.controller_of(&stored).unzip();
+    stored.controller_kind = kind;
+    stored.controller_name = name;
+
+    repo.update(&stored)
+}
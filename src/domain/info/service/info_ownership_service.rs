@@ -0,0 +1,254 @@
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+use anyhow::Result;
+use chrono::Utc;
+use kube::Client;
+use serde_json::Value;
+use tracing::{debug, warn};
+use validator::Validate;
+
+use crate::core::client::kube_client::build_kube_client;
+use crate::core::client::kube_resources::{Namespace, Pod};
+use crate::core::client::namespaces::{fetch_namespace_by_name, fetch_namespaces};
+use crate::core::client::pods::fetch_pods;
+use crate::core::persistence::info::fixed::ownership::info_ownership_api_repository_trait::InfoOwnershipApiRepository;
+use crate::core::persistence::info::fixed::ownership::info_ownership_entity::InfoOwnershipEntity;
+use crate::core::persistence::info::fixed::ownership::info_ownership_repository::InfoOwnershipRepository;
+use crate::core::persistence::info::fixed::ownership::ownership_rule_entity::{OwnershipSource, OwnershipTarget};
+use crate::core::persistence::info::k8s::container::info_container_api_repository_trait::InfoContainerApiRepository;
+use crate::core::persistence::info::k8s::container::info_container_repository::InfoContainerRepository;
+use crate::core::persistence::info::k8s::pod::info_pod_api_repository_trait::InfoPodApiRepository;
+use crate::core::persistence::info::k8s::pod::info_pod_entity::InfoPodEntity;
+use crate::core::persistence::info::k8s::pod::info_pod_repository::InfoPodRepository;
+use crate::domain::info::dto::info_ownership_preview_dto::{
+    InfoOwnershipDefaultPreviewDto, InfoOwnershipPreviewDto, InfoOwnershipPreviewObjectDto,
+    InfoOwnershipRulePreviewDto,
+};
+use crate::domain::info::dto::info_ownership_upsert_request::InfoOwnershipUpsertRequest;
+use crate::domain::info::model::ownership_rules::{
+    ordered_rules, resolve_ownership, winning_rule, OwnershipSubject, OwnershipTags,
+};
+
+pub async fn get_info_ownership() -> Result<InfoOwnershipEntity> {
+    let repo = InfoOwnershipRepository::new();
+    get_info_ownership_with_repo(&repo).await
+}
+
+pub async fn upsert_info_ownership(req: InfoOwnershipUpsertRequest) -> Result<Value> {
+    req.validate()?;
+    let repo = InfoOwnershipRepository::new();
+    let mut response = upsert_info_ownership_with_repo(&repo, req).await?;
+
+    // Tags derived from the old rules follow the new ones right away
+    match retag_stored_pods().await {
+        Ok(retagged) => response["retagged_pods"] = retagged.into(),
+        Err(e) => warn!(?e, "Re-applying ownership rules to stored pods failed"),
+    }
+    Ok(response)
+}
+
+async fn get_info_ownership_with_repo<R: InfoOwnershipApiRepository>(
+    repo: &R,
+) -> Result<InfoOwnershipEntity> {
+    repo.read()
+}
+
+async fn upsert_info_ownership_with_repo<R: InfoOwnershipApiRepository>(
+    repo: &R,
+    req: InfoOwnershipUpsertRequest,
+) -> Result<Value> {
+    let mut ownership = repo.read()?;
+    ownership.apply_update(req);
+
+    repo.update(&ownership)?;
+
+    Ok(serde_json::json!({
+        "message": "Ownership rules updated successfully",
+        "updated_at": ownership.updated_at.to_rfc3339(),
+    }))
+}
+
+/// Evaluates the stored rules, with the fields given in `req` applied on top,
+/// against every pod in the cluster without storing anything.
+pub async fn preview_info_ownership(req: InfoOwnershipUpsertRequest) -> Result<InfoOwnershipPreviewDto> {
+    req.validate()?;
+    let mut config = InfoOwnershipRepository::new().read()?;
+    config.apply_update(req);
+
+    let client = build_kube_client().await?;
+    let pods = fetch_pods(&client).await?;
+    let namespaces: HashMap<String, Namespace> = fetch_namespaces(&client)
+        .await?
+        .into_iter()
+        .filter_map(|ns| ns.metadata.name.clone().map(|name| (name, ns)))
+        .collect();
+
+    let ordered = ordered_rules(&config);
+    let mut rules: Vec<InfoOwnershipRulePreviewDto> = config
+        .rules
+        .iter()
+        .map(|rule| InfoOwnershipRulePreviewDto { rule: rule.clone(), matched: 0, applied: 0, objects: Vec::new() })
+        .collect();
+    // Evaluation order: by priority, then list order
+    rules.sort_by_key(|p| Reverse(p.rule.priority));
+
+    let mut defaults: Vec<InfoOwnershipDefaultPreviewDto> = OwnershipTarget::ALL
+        .iter()
+        .filter_map(|t| {
+            config.default_for(*t).map(|v| InfoOwnershipDefaultPreviewDto {
+                target: *t,
+                value: v.to_string(),
+                objects: Vec::new(),
+            })
+        })
+        .collect();
+
+    let pod_repo = InfoPodRepository::new();
+    for pod in &pods {
+        let namespace = pod.metadata.namespace.clone().unwrap_or_default();
+        let subject = OwnershipSubject::from_pod(pod, namespaces.get(&namespace));
+        let uid = pod.metadata.uid.clone().unwrap_or_default();
+        let stored = pod_repo.read(&uid).unwrap_or_default();
+        let current = OwnershipTags {
+            team: stored.team,
+            service: stored.service,
+            env: stored.env,
+            ..Default::default()
+        };
+        let object = |value: &str, target: OwnershipTarget, wins: bool| InfoOwnershipPreviewObjectDto {
+            namespace: namespace.clone(),
+            pod_name: pod.metadata.name.clone().unwrap_or_default(),
+            pod_uid: uid.clone(),
+            value: value.to_string(),
+            current: current.get(target).map(str::to_string),
+            applied: wins && current.get(target).is_none(),
+        };
+
+        for target in OwnershipTarget::ALL {
+            let winner = winning_rule(&ordered, &subject, target).map(|(r, _)| r.id.clone());
+
+            for preview in rules.iter_mut().filter(|p| p.rule.target == target) {
+                let Some(value) = subject.value_for(&preview.rule) else {
+                    continue;
+                };
+                let entry = object(value, target, preview.rule.enabled && winner.as_deref() == Some(preview.rule.id.as_str()));
+                preview.matched += 1;
+                preview.applied += entry.applied as usize;
+                preview.objects.push(entry);
+            }
+
+            if winner.is_none() {
+                if let Some(default) = defaults.iter_mut().find(|d| d.target == target) {
+                    let entry = object(&default.value, target, true);
+                    default.objects.push(entry);
+                }
+            }
+        }
+    }
+
+    Ok(InfoOwnershipPreviewDto {
+        generated_at: Utc::now(),
+        pods_scanned: pods.len(),
+        rules,
+        defaults,
+    })
+}
+
+/// Resolves ownership tags for pods fetched from the API, caching namespace
+/// metadata for the lifetime of the resolver.
+pub struct OwnershipResolver {
+    config: InfoOwnershipEntity,
+    uses_namespaces: bool,
+    namespaces: HashMap<String, Option<Namespace>>,
+}
+
+impl OwnershipResolver {
+    pub fn load() -> Result<Self> {
+        let config = InfoOwnershipRepository::new().read()?;
+        let uses_namespaces = config.rules.iter().any(|r| {
+            r.enabled
+                && matches!(r.source, OwnershipSource::NamespaceLabel | OwnershipSource::NamespaceAnnotation)
+        });
+        Ok(Self { config, uses_namespaces, namespaces: HashMap::new() })
+    }
+
+    pub async fn tags_for(&mut self, client: &Client, pod: &Pod) -> OwnershipTags {
+        let namespace = match (&pod.metadata.namespace, self.uses_namespaces) {
+            (Some(name), true) => {
+                if !self.namespaces.contains_key(name) {
+                    let fetched = fetch_namespace_by_name(client, name)
+                        .await
+                        .map_err(|e| debug!("Namespace '{name}' unavailable for ownership rules: {e:?}"))
+                        .ok();
+                    self.namespaces.insert(name.clone(), fetched);
+                }
+                self.namespaces.get(name).and_then(Option::as_ref)
+            }
+            _ => None,
+        };
+        resolve_ownership(&self.config, &OwnershipSubject::from_pod(pod, namespace))
+    }
+
+    /// Applies the rules to `entity` (see [`OwnershipTags::apply`]); returns
+    /// whether anything changed. Without rules, derived tags are cleared.
+    pub async fn tag_pod_entity(&mut self, client: &Client, pod: &Pod, entity: &mut InfoPodEntity) -> bool {
+        let tags = self.tags_for(client, pod).await;
+        tags.apply(entity.ownership_fields())
+    }
+}
+
+/// Re-applies the rules to every running pod that has an info record, so
+/// tags derived from a changed or removed rule are recomputed. Returns the
+/// number of pods whose tags changed.
+pub async fn retag_stored_pods() -> Result<usize> {
+    let mut resolver = OwnershipResolver::load()?;
+    let client = build_kube_client().await?;
+    let pod_repo = InfoPodRepository::new();
+
+    let mut retagged = 0;
+    for pod in fetch_pods(&client).await? {
+        let Some(uid) = pod.metadata.uid.clone() else {
+            continue;
+        };
+        if pod_repo.read(&uid).is_err() {
+            continue;
+        }
+        let containers: Vec<String> = pod
+            .spec
+            .as_ref()
+            .map(|s| s.containers.iter().map(|c| c.name.clone()).collect())
+            .unwrap_or_default();
+
+        let tags = resolver.tags_for(&client, &pod).await;
+        if tag_stored_pod(&uid, &containers, &tags)? {
+            retagged += 1;
+        }
+    }
+    Ok(retagged)
+}
+
+/// Applies `tags` to the stored pod and its containers, keeping manual tags.
+/// Returns whether any record changed.
+pub fn tag_stored_pod(pod_uid: &str, container_names: &[String], tags: &OwnershipTags) -> Result<bool> {
+    let pod_repo = InfoPodRepository::new();
+    let mut pod = pod_repo.read(pod_uid)?;
+    let mut changed = false;
+    if tags.apply(pod.ownership_fields()) {
+        pod_repo.update(&pod)?;
+        changed = true;
+    }
+
+    let container_repo = InfoContainerRepository::new();
+    for name in container_names {
+        // Containers are keyed "<pod uid>-<container name>" by the collectors
+        let Ok(mut container) = container_repo.read(&format!("{}-{}", pod_uid, name)) else {
+            continue;
+        };
+        if tags.apply(container.ownership_fields()) {
+            container_repo.update(&container)?;
+            changed = true;
+        }
+    }
+    Ok(changed)
+}
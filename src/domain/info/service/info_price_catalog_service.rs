@@ -0,0 +1,128 @@
+use anyhow::{anyhow, Result};
+use chrono::Utc;
+use std::collections::BTreeMap;
+use std::path::Path;
+use tracing::{debug, warn};
+
+use crate::core::persistence::storage_backend::storage_backend;
+use crate::core::persistence::info::fixed::setting::info_setting_api_repository_trait::InfoSettingApiRepository;
+use crate::core::persistence::info::fixed::setting::info_setting_repository::InfoSettingRepository;
+use crate::core::persistence::info::k8s::node::info_node_api_repository_trait::InfoNodeApiRepository;
+use crate::core::persistence::info::k8s::node::info_node_entity::{
+    InfoNodeEntity, NodeCapacityType, NodePricePeriod, NodePriceSource,
+};
+use crate::core::persistence::info::k8s::node::info_node_repository::InfoNodeRepository;
+use crate::core::persistence::info::path::info_k8s_node_dir_path;
+use crate::domain::info::dto::info_price_catalog_report_dto::{
+    InfoPriceCatalogNodeDto, InfoPriceCatalogReportDto,
+};
+use crate::domain::info::model::price_catalog::{node_instance_type_and_region, PriceCatalog};
+
+/// Match every stored node against the configured price catalog.
+///
+/// With `apply`, matched nodes get `fixed_instance_usd` (hourly) from the
+/// catalog. Nodes whose price was set manually are never overwritten. Spot
+/// nodes are skipped: catalog prices are on-demand, spot nodes keep the spot
+/// unit prices. Works fully offline: only the mounted catalog file is read.
+pub async fn import_price_catalog(apply: bool) -> Result<InfoPriceCatalogReportDto> {
+    let settings = InfoSettingRepository::new().read()?;
+    let catalog_path = settings
+        .price_catalog_path
+        .ok_or_else(|| anyhow!("No price catalog configured (set price_catalog_path)"))?;
+    let catalog = PriceCatalog::load(Path::new(&catalog_path))?;
+    if catalog.is_empty() {
+        warn!(path = %catalog_path, "Price catalog contains no usable instance prices");
+    }
+
+    let repo = InfoNodeRepository::new();
+    let mut report = InfoPriceCatalogReportDto {
+        catalog_path,
+        format: catalog.format,
+        catalog_entries: catalog.len(),
+        applied: apply,
+        generated_at: Utc::now(),
+        priced: Vec::new(),
+        manual: Vec::new(),
+        unpriced: Vec::new(),
+    };
+
+    for mut node in list_stored_nodes(&repo) {
+        let node_name = node.node_name.clone().unwrap_or_default();
+        let labels: BTreeMap<String, String> = node
+            .label
+            .as_deref()
+            .and_then(|l| serde_json::from_str(l).ok())
+            .unwrap_or_default();
+        let (instance_type, region) = node_instance_type_and_region(&labels);
+
+        let mut entry = InfoPriceCatalogNodeDto {
+            node_name,
+            instance_type: instance_type.clone(),
+            region: region.clone(),
+            hourly_usd: None,
+            reason: None,
+        };
+
+        if node.price_source == Some(NodePriceSource::Manual) {
+            entry.hourly_usd = node.fixed_hourly_usd();
+            entry.reason = Some("manual price kept".to_string());
+            report.manual.push(entry);
+            continue;
+        }
+
+        if node.capacity_type == Some(NodeCapacityType::Spot) {
+            if apply && node.price_source == Some(NodePriceSource::Catalog) {
+                // Drop a catalog price imported before the node was detected as spot
+                node.set_fixed_price(None, None, Utc::now());
+                node.price_source = None;
+                repo.update(&node)?;
+            }
+            entry.reason = Some("spot node billed at spot unit prices".to_string());
+            report.unpriced.push(entry);
+            continue;
+        }
+
+        let Some(instance_type) = instance_type else {
+            entry.reason = Some("node has no instance-type label".to_string());
+            report.unpriced.push(entry);
+            continue;
+        };
+
+        match catalog.lookup(&instance_type, region.as_deref()) {
+            Some(hourly_usd) => {
+                entry.hourly_usd = Some(hourly_usd);
+                if apply {
+                    node.set_fixed_price(Some(hourly_usd), Some(NodePricePeriod::Hour), Utc::now());
+                    node.price_source = Some(NodePriceSource::Catalog);
+                    repo.update(&node)?;
+                }
+                report.priced.push(entry);
+            }
+            None => {
+                entry.reason = Some(match region {
+                    Some(region) => format!("{} in {} not found in catalog", instance_type, region),
+                    None => format!("{} not found in catalog (no region label)", instance_type),
+                });
+                report.unpriced.push(entry);
+            }
+        }
+    }
+
+    if !report.unpriced.is_empty() {
+        warn!(count = report.unpriced.len(), "Price catalog could not price some nodes");
+    }
+    debug!(priced = report.priced.len(), apply, "Price catalog matched nodes");
+
+    Ok(report)
+}
+
+fn list_stored_nodes(repo: &InfoNodeRepository) -> Vec<InfoNodeEntity> {
+    let Ok(names) = storage_backend().list_keys(&info_k8s_node_dir_path()) else {
+        return Vec::new();
+    };
+
+    names.iter()
+        .filter_map(|name| repo.read(name).ok())
+        .filter(|node| node.deleted != Some(true))
+        .collect()
+}
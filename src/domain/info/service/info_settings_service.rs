@@ -1,8 +1,9 @@
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use serde_json::Value;
 use crate::core::persistence::info::fixed::setting::info_setting_api_repository_trait::InfoSettingApiRepository;
 use crate::core::persistence::info::fixed::setting::info_setting_entity::InfoSettingEntity;
 use crate::core::persistence::info::fixed::setting::info_setting_repository::InfoSettingRepository;
+use crate::core::persistence::storage_backend::uses_fs_backend;
 use crate::domain::info::dto::info_setting_upsert_request::InfoSettingUpsertRequest;
 use validator::Validate;
 
@@ -13,6 +14,13 @@ pub async fn get_info_settings() -> Result<InfoSettingEntity> {
 
 pub async fn upsert_info_settings(req: InfoSettingUpsertRequest) -> Result<Value> {
     req.validate()?;
+    let archives = req
+        .retention_policy
+        .as_deref()
+        .is_some_and(|policy| policy.eq_ignore_ascii_case("archive"));
+    if archives && !uses_fs_backend() {
+        return Err(anyhow!("retention_policy 'archive' needs the fs storage backend"));
+    }
     let repo = InfoSettingRepository::new();
     upsert_info_settings_with_repo(&repo, req).await
 }
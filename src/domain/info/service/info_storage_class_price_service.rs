@@ -0,0 +1,40 @@
+use anyhow::Result;
+use serde_json::Value;
+use validator::Validate;
+
+use crate::core::persistence::info::fixed::storage_class_price::info_storage_class_price_api_repository_trait::InfoStorageClassPriceApiRepository;
+use crate::core::persistence::info::fixed::storage_class_price::info_storage_class_price_entity::InfoStorageClassPriceEntity;
+use crate::core::persistence::info::fixed::storage_class_price::info_storage_class_price_repository::InfoStorageClassPriceRepository;
+use crate::domain::info::dto::info_storage_class_price_upsert_request::InfoStorageClassPriceUpsertRequest;
+
+pub async fn get_info_storage_class_prices() -> Result<InfoStorageClassPriceEntity> {
+    let repo = InfoStorageClassPriceRepository::new();
+    get_info_storage_class_prices_with_repo(&repo).await
+}
+
+pub async fn upsert_info_storage_class_prices(req: InfoStorageClassPriceUpsertRequest) -> Result<Value> {
+    req.validate()?;
+    let repo = InfoStorageClassPriceRepository::new();
+    upsert_info_storage_class_prices_with_repo(&repo, req).await
+}
+
+async fn get_info_storage_class_prices_with_repo<R: InfoStorageClassPriceApiRepository>(
+    repo: &R,
+) -> Result<InfoStorageClassPriceEntity> {
+    repo.read()
+}
+
+async fn upsert_info_storage_class_prices_with_repo<R: InfoStorageClassPriceApiRepository>(
+    repo: &R,
+    req: InfoStorageClassPriceUpsertRequest,
+) -> Result<Value> {
+    let mut prices = repo.read()?;
+    prices.apply_update(req);
+
+    repo.update(&prices)?;
+
+    Ok(serde_json::json!({
+        "message": "Storage class prices updated successfully",
+        "updated_at": prices.updated_at.to_rfc3339(),
+    }))
+}
@@ -1,14 +1,57 @@
-use anyhow::Result;
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
 use serde_json::Value;
 use crate::core::persistence::info::fixed::unit_price::info_unit_price_api_repository_trait::InfoUnitPriceApiRepository;
-use crate::core::persistence::info::fixed::unit_price::info_unit_price_entity::InfoUnitPriceEntity;
+use crate::core::persistence::info::fixed::exchange_rate::info_exchange_rate_api_repository_trait::InfoExchangeRateApiRepository;
+use crate::core::persistence::info::fixed::exchange_rate::info_exchange_rate_entity::InfoExchangeRateEntity;
+use crate::core::persistence::info::fixed::exchange_rate::info_exchange_rate_repository::InfoExchangeRateRepository;
+use crate::core::persistence::info::fixed::unit_price::info_unit_price_entity::{Currency, InfoUnitPriceEntity};
 use crate::core::persistence::info::fixed::unit_price::info_unit_price_repository::InfoUnitPriceRepository;
+use crate::domain::info::dto::info_unit_price_schedule_request::InfoUnitPriceScheduleRequest;
 use crate::domain::info::dto::info_unit_price_upsert_request::InfoUnitPriceUpsertRequest;
+use tracing::warn;
 use validator::Validate;
 
+/// Unit prices in force now, carrying the full price history so costs can be
+/// computed at the rates that applied at each point in time.
 pub async fn get_info_unit_prices() -> Result<InfoUnitPriceEntity> {
     let repo = InfoUnitPriceRepository::new();
-    get_info_unit_prices_with_repo(&repo).await
+    let mut unit_prices = get_info_unit_prices_with_repo(&repo).await?;
+
+    let rates = InfoExchangeRateRepository::new().read()?;
+    let resolve_rate = |prices: &mut InfoUnitPriceEntity| {
+        prices.usd_exchange_rate = rates.rate(prices.currency);
+    };
+    resolve_rate(&mut unit_prices);
+    unit_prices.history.iter_mut().for_each(resolve_rate);
+
+    if unit_prices.usd_exchange_rate.is_none() {
+        warn!(
+            "No USD exchange rate configured for {}; USD fixed node and StorageClass prices are ignored",
+            unit_prices.currency.code()
+        );
+    }
+
+    Ok(unit_prices)
+}
+
+/// Unit prices converted to `currency` (the configured currency when `None`).
+///
+/// Cost endpoints use this so every computed cost is in the requested currency.
+pub async fn get_info_unit_prices_in(currency: Option<Currency>) -> Result<InfoUnitPriceEntity> {
+    let unit_prices = get_info_unit_prices().await?;
+    let rates = InfoExchangeRateRepository::new().read()?;
+    prices_in(&unit_prices, currency, &rates)
+}
+
+/// Converts every price version, since versions stored before a currency
+/// change keep their own currency even when the current one already matches.
+fn prices_in(
+    unit_prices: &InfoUnitPriceEntity,
+    currency: Option<Currency>,
+    rates: &InfoExchangeRateEntity,
+) -> Result<InfoUnitPriceEntity> {
+    unit_prices.converted(currency.unwrap_or(unit_prices.currency), rates)
 }
 
 pub async fn upsert_info_unit_prices(req: InfoUnitPriceUpsertRequest) -> Result<Value> {
@@ -17,24 +60,161 @@ pub async fn upsert_info_unit_prices(req: InfoUnitPriceUpsertRequest) -> Result<
     upsert_info_unit_prices_with_repo(&repo, req).await
 }
 
+/// All effective-dated price versions, oldest first, including scheduled ones.
+pub async fn get_info_unit_price_history() -> Result<Vec<InfoUnitPriceEntity>> {
+    InfoUnitPriceRepository::new().list_history()
+}
+
+pub async fn schedule_info_unit_prices(req: InfoUnitPriceScheduleRequest) -> Result<Value> {
+    req.validate()?;
+    if req.effective_from <= Utc::now() {
+        return Err(anyhow!(
+            "effective_from must be in the future; past costs are never repriced"
+        ));
+    }
+
+    let repo = InfoUnitPriceRepository::new();
+    let unit_prices = get_info_unit_prices_with_repo(&repo).await?;
+    seed_price_history(&repo, &unit_prices)?;
+
+    let mut version = InfoUnitPriceEntity {
+        history: Vec::new(),
+        ..unit_prices.at(req.effective_from).clone()
+    };
+    version.apply_update(req.prices);
+    version.effective_from = Some(req.effective_from);
+
+    repo.insert_history(&version)?;
+
+    Ok(serde_json::json!({
+        "message": "Unit price change scheduled successfully",
+        "effective_from": req.effective_from.to_rfc3339(),
+    }))
+}
+
+/// Removes a scheduled price change. Versions already in force cannot be removed.
+pub async fn delete_info_unit_price_version(effective_from: DateTime<Utc>) -> Result<Value> {
+    if effective_from <= Utc::now() {
+        return Err(anyhow!(
+            "Only scheduled price changes can be removed; past costs are never repriced"
+        ));
+    }
+
+    let repo = InfoUnitPriceRepository::new();
+    let exists = repo
+        .list_history()?
+        .iter()
+        .any(|v| v.effective_from == Some(effective_from));
+    if !exists {
+        return Err(anyhow!("No price change scheduled at {}", effective_from.to_rfc3339()));
+    }
+
+    repo.delete_history(effective_from)?;
+
+    Ok(serde_json::json!({
+        "message": "Scheduled unit price change removed",
+        "effective_from": effective_from.to_rfc3339(),
+    }))
+}
+
 async fn get_info_unit_prices_with_repo<R: InfoUnitPriceApiRepository>(
     repo: &R,
 ) -> Result<InfoUnitPriceEntity> {
-    let entity = repo.read()?;
-    Ok(entity)
+    let stored = repo.read()?;
+    let history = repo.list_history()?;
+    Ok(resolve_price_history(stored, history, Utc::now()))
+}
+
+/// The version in force at `now` (the stored prices while none applies yet),
+/// carrying the full history.
+fn resolve_price_history(
+    stored: InfoUnitPriceEntity,
+    history: Vec<InfoUnitPriceEntity>,
+    now: DateTime<Utc>,
+) -> InfoUnitPriceEntity {
+    let mut resolved = history
+        .iter()
+        .rev()
+        .find(|v| v.effective_from.is_some_and(|from| from <= now))
+        .cloned()
+        .unwrap_or(stored);
+    resolved.history = history;
+    resolved
+}
+
+/// Records the prices used so far as the first history version, so the
+/// first price change does not reprice everything collected before it.
+fn seed_price_history<R: InfoUnitPriceApiRepository>(
+    repo: &R,
+    unit_prices: &InfoUnitPriceEntity,
+) -> Result<()> {
+    if !unit_prices.history.is_empty() {
+        return Ok(());
+    }
+
+    repo.insert_history(&InfoUnitPriceEntity {
+        effective_from: Some(DateTime::UNIX_EPOCH),
+        ..unit_prices.clone()
+    })
 }
 
 async fn upsert_info_unit_prices_with_repo<R: InfoUnitPriceApiRepository>(
     repo: &R,
     req: InfoUnitPriceUpsertRequest,
 ) -> Result<Value> {
-    let mut unit_prices = repo.read()?;
-    unit_prices.apply_update(req);
+    let mut unit_prices = get_info_unit_prices_with_repo(repo).await?;
+    seed_price_history(repo, &unit_prices)?;
 
+    unit_prices.apply_update(req);
+    unit_prices.history.clear();
+    unit_prices.effective_from = None;
     repo.update(&unit_prices)?;
 
+    // Takes effect now; earlier points keep the rates that applied then
+    repo.insert_history(&InfoUnitPriceEntity {
+        effective_from: Some(unit_prices.updated_at),
+        ..unit_prices.clone()
+    })?;
+
     Ok(serde_json::json!({
         "message": "Unit prices updated successfully",
         "updated_at": unit_prices.updated_at.to_rfc3339(),
     }))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn converts_history_stored_in_an_earlier_currency() {
+        let rates = InfoExchangeRateEntity {
+            rates: BTreeMap::from([(Currency::EUR, 0.5)]),
+            ..Default::default()
+        };
+        let old = InfoUnitPriceEntity {
+            cpu_core_hour: 1.0,
+            currency: Currency::EUR,
+            effective_from: Some(DateTime::UNIX_EPOCH),
+            ..Default::default()
+        };
+        let new = InfoUnitPriceEntity {
+            cpu_core_hour: 3.0,
+            currency: Currency::USD,
+            effective_from: Some(Utc::now()),
+            ..Default::default()
+        };
+        let current = InfoUnitPriceEntity { history: vec![old, new.clone()], ..new };
+
+        // Current currency: the EUR version is still converted
+        let usd = prices_in(&current, None, &rates).unwrap();
+        assert_eq!(usd.currency, Currency::USD);
+        assert!((usd.at(DateTime::UNIX_EPOCH).cpu_core_hour - 2.0).abs() < 1e-9);
+        assert!((usd.cpu_core_hour - 3.0).abs() < 1e-9);
+
+        let eur = prices_in(&current, Some(Currency::EUR), &rates).unwrap();
+        assert!((eur.at(DateTime::UNIX_EPOCH).cpu_core_hour - 1.0).abs() < 1e-9);
+        assert!((eur.cpu_core_hour - 1.5).abs() < 1e-9);
+    }
+}
@@ -2,8 +2,12 @@
 
 pub mod info_settings_service;
 pub mod info_alerts_service;
+pub mod info_ownership_service;
+pub mod info_storage_class_price_service;
 pub mod info_llm_service;
 pub mod info_unit_price_service;
+pub mod info_exchange_rate_service;
+pub mod info_price_catalog_service;
 pub mod info_version_service;
 pub mod info_k8s_node_service;
 pub mod info_k8s_pod_service;
@@ -165,11 +165,14 @@ async fn build_node_summary(time_window_minutes: Option<u32>) -> Result<Option<S
         offset: Some(0),
         sort: None,
         mode: CostMode::Showback,
+        share_idle: None,
+        currency: None,
         team: None,
         service: None,
         env: None,
         namespace: None,
         labels: None,
+        group_by: None,
         key: None,
     };
 
@@ -1,4 +1,4 @@
-use crate::api::dto::metrics_dto::RangeQuery;
+use crate::api::dto::metrics_dto::{CostMode, RangeQuery};
 use crate::core::persistence::info::fixed::unit_price::info_unit_price_entity::InfoUnitPriceEntity;
 use crate::core::persistence::info::k8s::node::info_node_api_repository_trait::InfoNodeApiRepository;
 use crate::core::persistence::info::k8s::node::info_node_entity::InfoNodeEntity;
@@ -7,16 +7,22 @@ use crate::core::persistence::metrics::k8s::node::day::metric_node_day_repositor
 use crate::core::persistence::metrics::k8s::node::hour::metric_node_hour_api_repository_trait::MetricNodeHourApiRepository;
 use crate::core::persistence::metrics::k8s::node::hour::metric_node_hour_repository::MetricNodeHourRepository;
 use crate::core::persistence::metrics::k8s::node::minute::metric_node_minute_api_repository_trait::MetricNodeMinuteApiRepository;
+use crate::core::util::peak_stats::WindowPercentile;
 use crate::domain::metric::k8s::common::dto::metric_k8s_raw_efficiency_dto::{MetricRawEfficiencyDto, MetricRawEfficiencyResponseDto};
 use crate::domain::metric::k8s::common::dto::metric_k8s_raw_summary_dto::{MetricRawSummaryDto, MetricRawSummaryResponseDto};
-use crate::domain::metric::k8s::common::dto::{CommonMetricValuesDto, FilesystemMetricDto, MetricGetResponseDto, MetricGranularity, MetricScope, MetricSeriesDto, NetworkMetricDto, UniversalMetricPointDto};
-use crate::domain::metric::k8s::common::service_helpers::{apply_costs, build_cost_trend_dto, resolve_time_window};
+use crate::domain::metric::k8s::common::dto::{CommonMetricValuesDto, CostMetricDto, FilesystemMetricDto, GpuMetricDto, MetricGetResponseDto, MetricGranularity, MetricScope, MetricSeriesDto, NetworkMetricDto, UniversalMetricPointDto};
+use crate::domain::metric::k8s::common::service_helpers::{apply_costs, build_cost_trend_dto, node_unit_prices, resolve_time_window, TimeWindow};
 use crate::domain::common::service::day_granularity::{split_day_granularity_rows};
 use crate::domain::metric::k8s::common::util::k8s_metric_repository_resolve::resolve_k8s_metric_repository;
 use crate::domain::metric::k8s::common::util::k8s_metric_repository_variant::K8sMetricRepositoryVariant;
+use crate::domain::metric::k8s::load_balancer::service::load_balancer_cost;
+use crate::domain::metric::k8s::node::service::{build_node_cost_breakdown, load_node_unit_prices, load_pods_on_nodes};
+use crate::domain::metric::k8s::pod::service::build_pod_request_uplift;
+use crate::domain::metric::k8s::pvc::service::build_pvc_cost_breakdown;
 use anyhow::{anyhow, Result};
 use chrono::{DateTime, Utc};
 use serde_json::{json, Value};
+use std::collections::{BTreeMap, HashMap};
 use tracing::log;
 use crate::domain::metric::k8s::common::dto::metric_k8s_cost_summary_dto::{MetricCostSummaryDto, MetricCostSummaryResponseDto};
 
@@ -95,19 +101,31 @@ pub async fn get_metric_k8s_cluster_cost_summary(
         let memory_gb = node_info.memory_capacity_bytes.unwrap_or(0) as f64 / 1_073_741_824.0;
         let storage_gb = node_info.ephemeral_storage_capacity_bytes.unwrap_or(0) as f64 / 1_073_741_824.0;
 
-        total_cpu_cost += cpu_cores * running_hours * unit_prices.cpu_core_hour;
-        total_memory_cost += memory_gb * running_hours * unit_prices.memory_gb_hour;
-        total_storage_cost += storage_gb * running_hours * unit_prices.storage_gb_hour;
+        let node_prices = node_unit_prices(&node_info, &unit_prices);
+
+        // Running hours are spread over the window, split across price versions
+        for (prices, share) in node_prices.periods(window.start, window.end) {
+            let hours = running_hours * share;
+            total_cpu_cost += cpu_cores * hours * prices.cpu_core_hour;
+            total_memory_cost += memory_gb * hours * prices.memory_gb_hour;
+            total_storage_cost += storage_gb * hours * prices.storage_gb_hour;
+        }
     }
 
-    let summary = MetricCostSummaryDto {
-        cpu_cost_usd: total_cpu_cost,
-        memory_cost_usd: total_memory_cost,
-        ephemeral_storage_cost_usd: total_storage_cost,
-        persistent_storage_cost_usd: 0.0,
-        total_cost_usd: total_cpu_cost + total_memory_cost + total_storage_cost,
-        network_cost_usd: 0.0,
+    let mut summary = MetricCostSummaryDto {
+        cpu_cost: total_cpu_cost,
+        memory_cost: total_memory_cost,
+        ephemeral_storage_cost: total_storage_cost,
+        persistent_storage_cost: 0.0,
+        total_cost: total_cpu_cost + total_memory_cost + total_storage_cost,
+        network_cost: 0.0,
+        gpu_cost: 0.0,
+        load_balancer_cost: 0.0,
     };
+    summary.add_load_balancer_cost(load_balancer_cost(&q, &[], window.start, window.end, &unit_prices)?);
+    // Claims and unbound / released volumes, so the cluster covers every namespace total
+    let pvc = build_pvc_cost_breakdown(&q, &unit_prices).await?;
+    summary.set_persistent_storage_cost(pvc.total_cost());
 
     let resp = MetricCostSummaryResponseDto {
         start: window.start,
@@ -115,23 +133,20 @@ pub async fn get_metric_k8s_cluster_cost_summary(
         scope: MetricScope::Cluster,
         target: None,
         granularity: window.granularity.clone(),
+        currency: unit_prices.currency,
         summary,
     };
 
     Ok(serde_json::to_value(resp)?)
 }
 
-pub async fn get_metric_k8s_cluster_raw(
-    node_names: Vec<String>,
-    q: RangeQuery,
-) -> Result<Value, anyhow::Error> {
-
-    let window = resolve_time_window(&q);
+/// Raw metric series of every node over the window, keyed by node name.
+/// Nodes whose rows cannot be loaded get an empty series.
+fn load_node_series(node_names: &[String], window: &TimeWindow) -> Vec<MetricSeriesDto> {
     let repo = resolve_k8s_metric_repository(&MetricScope::Node, &window.granularity);
+    let mut series = Vec::with_capacity(node_names.len());
 
-    let mut aggregated_points: Vec<UniversalMetricPointDto> = Vec::new();
-
-    for node_name in &node_names {
+    for node_name in node_names {
 
         // Load per-node metric rows
         let rows = match &repo {
@@ -170,7 +185,7 @@ pub async fn get_metric_k8s_cluster_raw(
         });
 
         // Convert to universal struct ??preserve missing values (None/null)
-        aggregated_points.extend(rows.into_iter().map(|m| {
+        let points = rows.into_iter().map(|m| {
             UniversalMetricPointDto {
                 time: m.time,
                 cpu_memory: CommonMetricValuesDto {
@@ -180,6 +195,16 @@ pub async fn get_metric_k8s_cluster_raw(
                     memory_working_set_bytes: m.memory_working_set_bytes.map(|v| v as f64),
                     memory_rss_bytes: m.memory_rss_bytes.map(|v| v as f64),
                     memory_page_faults: m.memory_page_faults.map(|v| v as f64),
+                    cpu_usage_nano_cores_max: m.cpu_usage_nano_cores_max.map(|v| v as f64),
+                    cpu_usage_nano_cores_p95: m.cpu_usage_nano_cores_p95.map(|v| v as f64),
+                    cpu_usage_nano_cores_p99: m.cpu_usage_nano_cores_p99.map(|v| v as f64),
+                    memory_working_set_bytes_max: m.memory_working_set_bytes_max.map(|v| v as f64),
+                    memory_working_set_bytes_p95: m.memory_working_set_bytes_p95.map(|v| v as f64),
+                    memory_working_set_bytes_p99: m.memory_working_set_bytes_p99.map(|v| v as f64),
+                    system_cpu_usage_nano_cores: m.system_cpu_usage_nano_cores.map(|v| v as f64),
+                    system_memory_working_set_bytes: m
+                        .system_memory_working_set_bytes
+                        .map(|v| v as f64),
                 },
                 filesystem: Some(FilesystemMetricDto {
                     used_bytes: m.fs_used_bytes.map(|v| v as f64),
@@ -192,13 +217,42 @@ pub async fn get_metric_k8s_cluster_raw(
                     tx_bytes: m.network_physical_tx_bytes.map(|v| v as f64),
                     rx_errors: m.network_physical_rx_errors.map(|v| v as f64),
                     tx_errors: m.network_physical_tx_errors.map(|v| v as f64),
+                    ..Default::default()
                 }),
                 storage: None,
+                gpu: m.gpu_count.map(|count| GpuMetricDto {
+                    gpu_count: Some(count as f64),
+                    utilization_percent: m.gpu_utilization_percent.map(|v| v as f64),
+                    memory_used_bytes: m.gpu_memory_used_bytes.map(|v| v as f64),
+                }),
                 cost: None,
             }
-        }));
+        }).collect();
+
+        series.push(MetricSeriesDto {
+            key: node_name.clone(),
+            name: node_name.clone(),
+            scope: MetricScope::Node,
+            points,
+            running_hours: None,
+            cost_summary: None,
+        });
     }
 
+    series
+}
+
+pub async fn get_metric_k8s_cluster_raw(
+    node_names: Vec<String>,
+    q: RangeQuery,
+) -> Result<Value, anyhow::Error> {
+
+    let window = resolve_time_window(&q);
+    let aggregated_points = load_node_series(&node_names, &window)
+        .into_iter()
+        .flat_map(|s| s.points)
+        .collect();
+
     // Aggregate multiple nodes ??cluster values
     let cluster_points = aggregate_cluster_points(aggregated_points);
 
@@ -220,6 +274,7 @@ pub async fn get_metric_k8s_cluster_raw(
         total: None,
         limit: None,
         offset: None,
+        currency: None,
     };
 
     Ok(serde_json::to_value(response)?)
@@ -258,6 +313,10 @@ pub async fn get_metric_k8s_cluster_raw_summary(
     let mut max_mem_gib = 0.0;
     let mut mem_samples = 0u64;
 
+    // p95/p99 over minute samples, bounded by the hour/day points' own peak columns
+    let (mut cpu_p95, mut cpu_p99) = (WindowPercentile::default(), WindowPercentile::default());
+    let (mut mem_p95, mut mem_p99) = (WindowPercentile::default(), WindowPercentile::default());
+
     let mut total_storage_gib = 0.0;
     let mut max_storage_gib = 0.0;
     let mut storage_samples = 0u64;
@@ -285,8 +344,14 @@ pub async fn get_metric_k8s_cluster_raw_summary(
                     total_cpu_cores += cores;
                     cpu_samples += 1;
 
-                    if cores > max_cpu_cores {
-                        max_cpu_cores = cores;
+                    // Hour/day points carry their own peaks; minute points are samples themselves
+                    let to_cores = |v: Option<f64>| v.map(|v| v / NANOCORES_PER_CORE);
+                    cpu_p95.add(to_cores(point.cpu_memory.cpu_usage_nano_cores_p95), cores);
+                    cpu_p99.add(to_cores(point.cpu_memory.cpu_usage_nano_cores_p99), cores);
+
+                    let peak_cores = to_cores(point.cpu_memory.cpu_usage_nano_cores_max).unwrap_or(cores);
+                    if peak_cores > max_cpu_cores {
+                        max_cpu_cores = peak_cores;
                     }
                 } else {
                     // warn!("Invalid CPU value: {}", cores);
@@ -303,8 +368,13 @@ pub async fn get_metric_k8s_cluster_raw_summary(
                     total_mem_gib += mem_gib;
                     mem_samples += 1;
 
-                    if mem_gib > max_mem_gib {
-                        max_mem_gib = mem_gib;
+                    let to_gib = |v: Option<f64>| v.map(|v| v / BYTES_PER_GIB);
+                    mem_p95.add(to_gib(point.cpu_memory.memory_working_set_bytes_p95), mem_gib);
+                    mem_p99.add(to_gib(point.cpu_memory.memory_working_set_bytes_p99), mem_gib);
+
+                    let peak_gib = to_gib(point.cpu_memory.memory_working_set_bytes_max).unwrap_or(mem_gib);
+                    if peak_gib > max_mem_gib {
+                        max_mem_gib = peak_gib;
                     }
                 } else {
                     // warn!("Invalid memory value: {}", mem_gib);
@@ -410,6 +480,10 @@ pub async fn get_metric_k8s_cluster_raw_summary(
         max_storage_gb: max_storage_gib,
         avg_network_gb,
         max_network_gb,
+        p95_cpu_cores: cpu_p95.value(95.0),
+        p99_cpu_cores: cpu_p99.value(99.0),
+        p95_memory_gb: mem_p95.value(95.0),
+        p99_memory_gb: mem_p99.value(99.0),
         node_count: node_names.len(),
     };
 
@@ -425,22 +499,135 @@ pub async fn get_metric_k8s_cluster_raw_summary(
 }
 
 
-/// Compute derived cluster costs based on node metrics and unit prices
+/// Compute derived cluster costs based on node metrics and unit prices.
+///
+/// Every node is priced at its own rates (spot, fixed price) and the node costs
+/// are summed per point. In chargeback mode each pod that ran on the nodes adds
+/// what its requests cost above its usage, so the cluster is billed
+/// `max(usage, request)` per pod. Requests come from the stored container info,
+/// so pods deleted since the window keep their floor.
+///
+/// Service endpoint (load balancer, NodePort, Ingress) cost has no per-point
+/// samples and is only part of the cost summary.
 pub async fn get_metric_k8s_cluster_cost(
     node_names: Vec<String>,
     unit_prices: InfoUnitPriceEntity,
     q: RangeQuery,
 ) -> Result<Value> {
-    // Get raw cluster metrics first
-    let raw_value = get_metric_k8s_cluster_raw(node_names, q).await?;
-    let mut resp: MetricGetResponseDto = serde_json::from_value(raw_value)?;
+    let window = resolve_time_window(&q);
 
-    apply_costs(&mut resp, &unit_prices);
+    let mut nodes = MetricGetResponseDto {
+        start: window.start,
+        end: window.end,
+        scope: "node".into(),
+        target: None,
+        granularity: window.granularity.clone(),
+        series: load_node_series(&node_names, &window),
+        total: None,
+        limit: None,
+        offset: None,
+        currency: None,
+    };
+    let node_prices = load_node_unit_prices(&unit_prices)?;
+    apply_costs(&mut nodes, &unit_prices, &CostMode::Showback, &HashMap::new(), &node_prices);
+
+    let uplift = match q.mode {
+        CostMode::Showback => BTreeMap::new(),
+        CostMode::Chargeback => {
+            let pods = load_pods_on_nodes(&node_names)?;
+            let q = RangeQuery { limit: None, offset: None, ..q.clone() };
+            build_pod_request_uplift(&q, &pods, &unit_prices)?
+        }
+    };
+
+    let resp = MetricGetResponseDto {
+        scope: "cluster".into(),
+        series: vec![MetricSeriesDto {
+            key: "cluster".into(),
+            name: "cluster".into(),
+            scope: MetricScope::Cluster,
+            points: build_cluster_cost_points(&nodes.series, &uplift),
+            running_hours: None,
+            cost_summary: None,
+        }],
+        currency: Some(unit_prices.currency),
+        ..nodes
+    };
 
     Ok(serde_json::to_value(resp)?)
 }
 
-/// Analyze cluster cost trend (growth, regression, prediction)
+/// Cluster points with the per-node costs summed per time. Request uplift is
+/// added to the cluster point at or before its time.
+fn build_cluster_cost_points(
+    node_series: &[MetricSeriesDto],
+    uplift: &BTreeMap<DateTime<Utc>, (f64, f64)>,
+) -> Vec<UniversalMetricPointDto> {
+    let mut costs: BTreeMap<DateTime<Utc>, CostMetricDto> = BTreeMap::new();
+    for point in node_series.iter().flat_map(|s| &s.points) {
+        if let Some(cost) = &point.cost {
+            add_cost(costs.entry(point.time).or_default(), cost);
+        }
+    }
+
+    for (time, (cpu, memory)) in uplift {
+        let slot = match costs.range_mut(..=*time).next_back() {
+            Some((_, cost)) => cost,
+            None => match costs.values_mut().next() {
+                Some(cost) => cost,
+                None => continue,
+            },
+        };
+        add_cost(slot, &CostMetricDto {
+            total_cost: Some(cpu + memory),
+            cpu_cost: Some(*cpu),
+            memory_cost: Some(*memory),
+            ..Default::default()
+        });
+    }
+
+    let points = node_series.iter().flat_map(|s| s.points.iter().cloned()).collect();
+    let mut points = aggregate_cluster_points(points);
+    for point in &mut points {
+        point.cost = costs.remove(&point.time);
+    }
+    points
+}
+
+fn add_cost(total: &mut CostMetricDto, cost: &CostMetricDto) {
+    let sum = |a: Option<f64>, b: Option<f64>| match (a, b) {
+        (None, None) => None,
+        (a, b) => Some(a.unwrap_or(0.0) + b.unwrap_or(0.0)),
+    };
+    total.total_cost = sum(total.total_cost, cost.total_cost);
+    total.cpu_cost = sum(total.cpu_cost, cost.cpu_cost);
+    total.memory_cost = sum(total.memory_cost, cost.memory_cost);
+    total.storage_cost = sum(total.storage_cost, cost.storage_cost);
+    total.gpu_cost = sum(total.gpu_cost, cost.gpu_cost);
+
+    if let Some(network) = &cost.network_cost {
+        let total = total.network_cost.get_or_insert_with(Default::default);
+        total.total_cost += network.total_cost;
+        total.local_cost += network.local_cost;
+        total.regional_cost += network.regional_cost;
+        total.external_cost += network.external_cost;
+    }
+}
+
+/// Split cluster capacity cost into allocated, system overhead and idle cost.
+/// Covers node CPU and memory only; service endpoint cost is summary-only.
+pub async fn get_metric_k8s_cluster_cost_breakdown(
+    node_names: Vec<String>,
+    unit_prices: InfoUnitPriceEntity,
+    q: RangeQuery,
+) -> Result<Value> {
+    let response =
+        build_node_cost_breakdown(q, node_names, MetricScope::Cluster, None, &unit_prices).await?;
+    Ok(serde_json::to_value(response)?)
+}
+
+/// Analyze cluster cost trend (growth, regression, prediction). Built from the
+/// cost series, so service endpoint cost is not included.
 pub async fn get_metric_k8s_cluster_cost_trend(
     node_names: Vec<String>,
     unit_prices: InfoUnitPriceEntity,
@@ -499,6 +686,8 @@ pub async fn get_metric_k8s_cluster_raw_efficiency(
 
     let overall_eff = (cpu_eff + mem_eff + storage_eff) / 3.0;
 
+    let ratio = |used: f64, alloc: f64| if alloc > 0.0 { (used / alloc).clamp(0.0, 1.0) } else { 0.0 };
+
     // 4️⃣ Build DTO
     let dto = MetricRawEfficiencyResponseDto {
         start: summary.start,
@@ -510,6 +699,10 @@ pub async fn get_metric_k8s_cluster_raw_efficiency(
             memory_efficiency: mem_eff,
             storage_efficiency: storage_eff,
             overall_efficiency: overall_eff,
+            peak_cpu_efficiency: ratio(summary.summary.max_cpu_cores, total_cpu_alloc),
+            p95_cpu_efficiency: ratio(summary.summary.p95_cpu_cores, total_cpu_alloc),
+            peak_memory_efficiency: ratio(summary.summary.max_memory_gb, total_mem_alloc_gb),
+            p95_memory_efficiency: ratio(summary.summary.p95_memory_gb, total_mem_alloc_gb),
             total_cpu_allocatable_cores: total_cpu_alloc,
             total_memory_allocatable_gb: total_mem_alloc_gb,
             total_storage_allocatable_gb: total_storage_alloc_gb,
@@ -560,6 +753,11 @@ pub fn aggregate_cluster_points(
         let mut rx_err_sum = 0.0;
         let mut tx_err_sum = 0.0;
 
+        // GPU SUM (utilization weighted by device count)
+        let mut gpu: Option<GpuMetricDto> = None;
+        let mut gpu_util_weighted = 0.0;
+        let mut gpu_util_count = 0.0;
+
         for p in &bucket {
             // CPU AVG
             if let Some(v) = p.cpu_memory.cpu_usage_nano_cores {
@@ -601,8 +799,32 @@ pub fn aggregate_cluster_points(
                 rx_err_sum += net.rx_errors.unwrap_or(0.0);
                 tx_err_sum += net.tx_errors.unwrap_or(0.0);
             }
+
+            // GPU SUM
+            if let Some(g) = &p.gpu {
+                let total = gpu.get_or_insert_with(Default::default);
+                let count = g.gpu_count.unwrap_or(0.0);
+                total.gpu_count = Some(total.gpu_count.unwrap_or(0.0) + count);
+                if let Some(mem) = g.memory_used_bytes {
+                    total.memory_used_bytes = Some(total.memory_used_bytes.unwrap_or(0.0) + mem);
+                }
+                if let Some(util) = g.utilization_percent {
+                    gpu_util_weighted += util * count;
+                    gpu_util_count += count;
+                }
+            }
         }
 
+        if let Some(g) = gpu.as_mut() {
+            g.utilization_percent = (gpu_util_count > 0.0).then(|| gpu_util_weighted / gpu_util_count);
+        }
+
+        // Peak and system columns follow the same per-node average as the values above
+        let avg = |f: fn(&CommonMetricValuesDto) -> Option<f64>| -> Option<f64> {
+            let values: Vec<f64> = bucket.iter().filter_map(|p| f(&p.cpu_memory)).collect();
+            (!values.is_empty()).then(|| values.iter().sum::<f64>() / values.len() as f64)
+        };
+
         result.push(UniversalMetricPointDto {
             time,
             cpu_memory: CommonMetricValuesDto {
@@ -616,6 +838,14 @@ pub fn aggregate_cluster_points(
                     .then(|| mem_rss_sum / mem_rss_count),
                 memory_page_faults: (mem_pf_count > 0.0)
                     .then(|| mem_pf_sum / mem_pf_count),
+                cpu_usage_nano_cores_max: avg(|v| v.cpu_usage_nano_cores_max),
+                cpu_usage_nano_cores_p95: avg(|v| v.cpu_usage_nano_cores_p95),
+                cpu_usage_nano_cores_p99: avg(|v| v.cpu_usage_nano_cores_p99),
+                memory_working_set_bytes_max: avg(|v| v.memory_working_set_bytes_max),
+                memory_working_set_bytes_p95: avg(|v| v.memory_working_set_bytes_p95),
+                memory_working_set_bytes_p99: avg(|v| v.memory_working_set_bytes_p99),
+                system_cpu_usage_nano_cores: avg(|v| v.system_cpu_usage_nano_cores),
+                system_memory_working_set_bytes: avg(|v| v.system_memory_working_set_bytes),
             },
             filesystem: Some(FilesystemMetricDto {
                 used_bytes: Some(fs_used_sum),
@@ -628,8 +858,10 @@ pub fn aggregate_cluster_points(
                 tx_bytes: Some(tx_sum),
                 rx_errors: Some(rx_err_sum),
                 tx_errors: Some(tx_err_sum),
+                ..Default::default()
             }),
             storage: None,
+            gpu,
             cost: None,
         });
     }
@@ -639,3 +871,89 @@ pub fn aggregate_cluster_points(
 
 
 
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::metric::k8s::common::service_helpers::{request_uplift_by_time, ResourceRequests};
+
+    fn series(key: &str, scope: MetricScope, time: DateTime<Utc>, cores: f64) -> MetricSeriesDto {
+        MetricSeriesDto {
+            key: key.into(),
+            name: key.into(),
+            scope,
+            points: vec![UniversalMetricPointDto {
+                time,
+                cpu_memory: CommonMetricValuesDto {
+                    cpu_usage_core_nano_seconds: Some(cores * 3600.0 * 1e9),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }],
+            running_hours: None,
+            cost_summary: None,
+        }
+    }
+
+    fn response(series: Vec<MetricSeriesDto>, time: DateTime<Utc>) -> MetricGetResponseDto {
+        MetricGetResponseDto {
+            start: time,
+            end: time + chrono::Duration::hours(1),
+            scope: "node".into(),
+            target: None,
+            granularity: MetricGranularity::Hour,
+            series,
+            total: None,
+            limit: None,
+            offset: None,
+            currency: None,
+        }
+    }
+
+    #[test]
+    fn chargeback_floors_each_pod_across_nodes() {
+        let time = DateTime::parse_from_rfc3339("2026-01-01T00:00:00Z").unwrap().with_timezone(&Utc);
+        let base = InfoUnitPriceEntity { cpu_core_hour: 1.0, ..Default::default() };
+        let prices = HashMap::from([
+            ("node-a".to_string(), base.clone()),
+            ("node-b".to_string(), InfoUnitPriceEntity { cpu_core_hour: 2.0, ..Default::default() }),
+            ("pod-a".to_string(), base.clone()),
+            ("pod-b".to_string(), InfoUnitPriceEntity { cpu_core_hour: 2.0, ..Default::default() }),
+        ]);
+
+        // node-a uses 1 core, node-b 2 cores
+        let mut nodes = response(
+            vec![
+                series("node-a", MetricScope::Node, time, 1.0),
+                series("node-b", MetricScope::Node, time, 2.0),
+            ],
+            time,
+        );
+        apply_costs(&mut nodes, &base, &CostMode::Showback, &HashMap::new(), &prices);
+
+        // pod-a uses 0.5 of its 2 requested cores; pod-b uses more than it requested
+        let pods = response(
+            vec![
+                series("pod-a", MetricScope::Pod, time, 0.5),
+                series("pod-b", MetricScope::Pod, time, 1.0),
+            ],
+            time,
+        );
+        let requests = HashMap::from([
+            ("pod-a".to_string(), ResourceRequests { cpu_cores: 2.0, memory_bytes: 0.0 }),
+            ("pod-b".to_string(), ResourceRequests { cpu_cores: 0.5, memory_bytes: 0.0 }),
+        ]);
+        let uplift = request_uplift_by_time(&pods, &base, &requests, &prices);
+
+        let points = build_cluster_cost_points(&nodes.series, &uplift);
+        assert_eq!(points.len(), 1);
+        let cost = points[0].cost.clone().unwrap();
+        assert!((cost.cpu_cost.unwrap() - (1.0 + 4.0 + 1.5)).abs() < 1e-9);
+        assert!((cost.total_cost.unwrap() - 6.5).abs() < 1e-9);
+
+        let showback = build_cluster_cost_points(&nodes.series, &BTreeMap::new());
+        assert!((showback[0].cost.clone().unwrap().cpu_cost.unwrap() - 5.0).abs() < 1e-9);
+    }
+}
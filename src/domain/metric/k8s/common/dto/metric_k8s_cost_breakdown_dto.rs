@@ -0,0 +1,65 @@
+use chrono::{DateTime, Utc};
+use serde::{Serialize, Deserialize};
+use crate::core::persistence::info::fixed::unit_price::info_unit_price_entity::Currency;
+use crate::domain::metric::k8s::common::dto::{MetricGranularity, MetricScope};
+
+/// Capacity cost split into allocated, system overhead and idle shares (Node, Cluster).
+/// Only node CPU and memory are covered; service endpoint cost is summary-only.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricCostBreakdownResponseDto {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub scope: MetricScope,
+    pub target: Option<String>,             // Node name (None for cluster / all nodes)
+    pub granularity: MetricGranularity,
+    /// Currency of every cost value. The `*_usd` field names predate
+    /// currency support and are kept for existing clients.
+    pub currency: Currency,
+    pub nodes: Vec<MetricNodeCostBreakdownDto>,
+    pub total: MetricCostBreakdownDto,
+}
+
+/// Breakdown for a single node
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricNodeCostBreakdownDto {
+    pub node_name: String,
+    pub running_hours: f64,
+    pub breakdown: MetricCostBreakdownDto,
+}
+
+/// CPU + memory cost breakdown, in the response currency.
+///
+/// `capacity = allocated + system_overhead + idle` unless pods are billed
+/// above the node capacity, in which case `idle` is clamped to zero.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct MetricCostBreakdownDto {
+    /// Cost of the full node CPU/memory capacity over the running hours
+    #[serde(rename = "capacity_cost_usd")]
+    pub capacity_cost: f64,
+
+    /// Cost attributed to pods scheduled on the node
+    #[serde(rename = "allocated_cost_usd")]
+    pub allocated_cost: f64,
+
+    /// Cost of kubelet / runtime / OS system containers
+    #[serde(rename = "system_overhead_cost_usd")]
+    pub system_overhead_cost: f64,
+
+    /// Capacity not consumed by pods or system containers
+    #[serde(rename = "idle_cost_usd")]
+    pub idle_cost: f64,
+}
+
+impl MetricCostBreakdownDto {
+    pub fn add(&mut self, other: &MetricCostBreakdownDto) {
+        self.capacity_cost += other.capacity_cost;
+        self.allocated_cost += other.allocated_cost;
+        self.system_overhead_cost += other.system_overhead_cost;
+        self.idle_cost += other.idle_cost;
+    }
+
+    /// Cost left unattributed to workloads (system overhead + idle).
+    pub fn unallocated_cost(&self) -> f64 {
+        self.system_overhead_cost + self.idle_cost
+    }
+}
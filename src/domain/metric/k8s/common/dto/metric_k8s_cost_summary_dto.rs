@@ -1,5 +1,6 @@
 use chrono::{DateTime, Utc};
 use serde::{Serialize, Deserialize};
+use crate::core::persistence::info::fixed::unit_price::info_unit_price_entity::Currency;
 use crate::domain::metric::k8s::common::dto::{MetricGranularity, MetricScope};
 
 /// Summarized cost view for any Kubernetes metric scope (Cluster, Node, Pod, Container)
@@ -10,27 +11,60 @@ pub struct MetricCostSummaryResponseDto {
     pub scope: MetricScope,
     pub target: Option<String>,             // Node / Pod / Container name
     pub granularity: MetricGranularity,
+    /// Currency of every cost value. The `*_usd` field names predate
+    /// currency support and are kept for existing clients.
+    pub currency: Currency,
     pub summary: MetricCostSummaryDto,
 }
 
 /// Aggregated cost breakdown (includes PV and network)
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct MetricCostSummaryDto {
-    /// Total combined cost in USD
-    pub total_cost_usd: f64,
+    /// Total combined cost
+    #[serde(rename = "total_cost_usd")]
+    pub total_cost: f64,
 
-    /// CPU resource cost in USD
-    pub cpu_cost_usd: f64,
+    /// CPU resource cost
+    #[serde(rename = "cpu_cost_usd")]
+    pub cpu_cost: f64,
 
-    /// Memory resource cost in USD
-    pub memory_cost_usd: f64,
+    /// Memory resource cost
+    #[serde(rename = "memory_cost_usd")]
+    pub memory_cost: f64,
 
     /// Ephemeral storage cost (e.g. rootfs, node FS)
-    pub ephemeral_storage_cost_usd: f64,
+    #[serde(rename = "ephemeral_storage_cost_usd")]
+    pub ephemeral_storage_cost: f64,
 
     /// Persistent volume (PV) storage cost
-    pub persistent_storage_cost_usd: f64,
+    #[serde(rename = "persistent_storage_cost_usd")]
+    pub persistent_storage_cost: f64,
 
-    /// Network transfer cost in USD
-    pub network_cost_usd: f64,
+    /// Network transfer cost
+    #[serde(rename = "network_cost_usd")]
+    pub network_cost: f64,
+
+    /// GPU cost
+    #[serde(default, rename = "gpu_cost_usd")]
+    pub gpu_cost: f64,
+
+    /// LoadBalancer / NodePort service and Ingress cost. Summary-only: the
+    /// cost series, trends and breakdowns do not include it.
+    #[serde(default, rename = "load_balancer_cost_usd")]
+    pub load_balancer_cost: f64,
+}
+
+impl MetricCostSummaryDto {
+    /// Adds service endpoint cost to its breakdown field and the total.
+    pub fn add_load_balancer_cost(&mut self, cost: f64) {
+        self.load_balancer_cost += cost;
+        self.total_cost += cost;
+    }
+
+    /// Replaces the persistent storage cost (used bytes at the flat price)
+    /// with the provisioned claim capacity cost, keeping the total in step.
+    pub fn set_persistent_storage_cost(&mut self, cost: f64) {
+        self.total_cost += cost - self.persistent_storage_cost;
+        self.persistent_storage_cost = cost;
+    }
 }
@@ -1,16 +1,23 @@
 use chrono::{DateTime, Utc};
 use serde::{Serialize, Deserialize};
+use crate::core::persistence::info::fixed::unit_price::info_unit_price_entity::Currency;
 use crate::domain::metric::k8s::common::dto::{MetricGranularity, MetricScope};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MetricCostTrendPointDto {
     pub time: DateTime<Utc>,
-    pub total_cost_usd: f64,
-    pub cpu_cost_usd: f64,
-    pub memory_cost_usd: f64,
-    pub storage_cost_usd: f64,
+    #[serde(rename = "total_cost_usd")]
+    pub total_cost: f64,
+    #[serde(rename = "cpu_cost_usd")]
+    pub cpu_cost: f64,
+    #[serde(rename = "memory_cost_usd")]
+    pub memory_cost: f64,
+    #[serde(rename = "storage_cost_usd")]
+    pub storage_cost: f64,
 }
 
+/// Cost trend over a cost series. Service endpoint cost is not part of the
+/// series and therefore not of the trend.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MetricCostTrendResponseDto {
     pub start: DateTime<Utc>,
@@ -18,6 +25,9 @@ pub struct MetricCostTrendResponseDto {
     pub scope: MetricScope,
     pub target: Option<String>,
     pub granularity: MetricGranularity,
+    /// Currency of every cost value. The `*_usd` field names predate
+    /// currency support and are kept for existing clients.
+    pub currency: Option<Currency>,
     pub trend: MetricCostTrendDto,
     pub points: Vec<MetricCostTrendPointDto>,
 }
@@ -26,16 +36,21 @@ pub struct MetricCostTrendResponseDto {
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct MetricCostTrendDto {
     /// Total cost at start and end
-    pub start_cost_usd: f64,
-    pub end_cost_usd: f64,
+    #[serde(rename = "start_cost_usd")]
+    pub start_cost: f64,
+    #[serde(rename = "end_cost_usd")]
+    pub end_cost: f64,
 
     /// Change in absolute and percentage terms
-    pub cost_diff_usd: f64,
+    #[serde(rename = "cost_diff_usd")]
+    pub cost_diff: f64,
     pub growth_rate_percent: f64,
 
-    /// Linear regression slope (USD per hour or per granularity)
-    pub regression_slope_usd_per_granularity: f64,
+    /// Linear regression slope (cost per granularity step)
+    #[serde(rename = "regression_slope_usd_per_granularity")]
+    pub regression_slope_per_granularity: f64,
 
     /// Optional next predicted cost point (simple extrapolation)
-    pub predicted_next_cost_usd: Option<f64>,
+    #[serde(rename = "predicted_next_cost_usd")]
+    pub predicted_next_cost: Option<f64>,
 }
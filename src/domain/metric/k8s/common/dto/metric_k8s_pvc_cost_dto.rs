@@ -0,0 +1,127 @@
+use chrono::{DateTime, Utc};
+use serde::{Serialize, Deserialize};
+use crate::core::persistence::info::fixed::unit_price::info_unit_price_entity::Currency;
+use crate::domain::metric::k8s::common::dto::MetricGranularity;
+
+/// Provisioned-capacity cost of persistent volume claims, with the volumes
+/// that are paid for but not used by any workload.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricPvcCostBreakdownResponseDto {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub granularity: MetricGranularity,
+    /// Currency of every cost value. The `*_usd` field names predate
+    /// currency support and are kept for existing clients.
+    pub currency: Currency,
+    pub claims: Vec<MetricPvcCostDto>,
+    pub waste: Vec<MetricPvWasteDto>,
+    /// Cost of all claims, including orphaned ones
+    #[serde(rename = "claims_cost_usd")]
+    pub claims_cost: f64,
+    /// Cost of unbound and released volumes plus orphaned claims
+    #[serde(rename = "waste_cost_usd")]
+    pub waste_cost: f64,
+    /// Claim cost per namespace
+    pub namespaces: Vec<MetricPvcNamespaceCostDto>,
+    /// Claim cost per top-level controller of the mounting pods. A claim
+    /// mounted by several controllers is split evenly between them.
+    pub owners: Vec<MetricPvcOwnerCostDto>,
+}
+
+impl MetricPvcCostBreakdownResponseDto {
+    /// Cost of every claim plus unbound and released volumes. Orphaned claims
+    /// are already part of `claims_cost`.
+    pub fn total_cost(&self) -> f64 {
+        self.claims_cost
+            + self
+                .waste
+                .iter()
+                .filter(|w| w.reason != PvWasteReason::Orphaned)
+                .map(|w| w.cost)
+                .sum::<f64>()
+    }
+
+    /// Claim cost of `namespaces` (all when empty).
+    pub fn namespace_cost(&self, namespaces: &[String]) -> f64 {
+        self.namespaces
+            .iter()
+            .filter(|n| namespaces.is_empty() || namespaces.contains(&n.namespace))
+            .map(|n| n.cost)
+            .sum()
+    }
+
+    /// Claim cost of the `owner_kind` controllers picked by `selected(namespace, name)`.
+    pub fn owner_cost(&self, owner_kind: &str, selected: impl Fn(&str, &str) -> bool) -> f64 {
+        self.owners
+            .iter()
+            .filter(|o| o.owner_kind == owner_kind && selected(&o.namespace, &o.owner_name))
+            .map(|o| o.cost)
+            .sum()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricPvcNamespaceCostDto {
+    pub namespace: String,
+    #[serde(rename = "cost_usd")]
+    pub cost: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricPvcOwnerCostDto {
+    pub namespace: String,
+    /// Top-level controller, e.g. `StatefulSet`
+    pub owner_kind: String,
+    pub owner_name: String,
+    #[serde(rename = "cost_usd")]
+    pub cost: f64,
+}
+
+/// Cost of a single claim and the pods it is attributed to
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricPvcCostDto {
+    pub namespace: String,
+    pub name: String,
+    pub volume_name: Option<String>,
+    pub storage_class: Option<String>,
+    pub capacity_bytes: f64,
+    /// Price per GB-hour at the end of the window
+    pub gb_hour: f64,
+    #[serde(rename = "cost_usd")]
+    pub cost: f64,
+    /// Pods mounting the claim; empty for orphaned claims
+    pub consumers: Vec<MetricPvcConsumerDto>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricPvcConsumerDto {
+    pub pod_uid: String,
+    pub pod_name: Option<String>,
+    /// Top-level controller, e.g. `Deployment` rather than `ReplicaSet`
+    pub owner_kind: Option<String>,
+    pub owner_name: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PvWasteReason {
+    /// Volume is `Available` and not bound to any claim
+    Unbound,
+    /// Claim was deleted but the volume was retained; billed from the release
+    Released,
+    /// Claim is bound but no pod mounts it
+    Orphaned,
+}
+
+/// Volume capacity that is billed without serving a workload
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricPvWasteDto {
+    pub volume_name: Option<String>,
+    /// `<namespace>/<claim>` for released volumes and orphaned claims
+    pub claim: Option<String>,
+    pub storage_class: Option<String>,
+    pub capacity_bytes: f64,
+    pub reason: PvWasteReason,
+    #[serde(rename = "cost_usd")]
+    pub cost: f64,
+}
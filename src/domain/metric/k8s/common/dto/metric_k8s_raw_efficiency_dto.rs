@@ -24,6 +24,16 @@ pub struct MetricRawEfficiencyDto {
     /// Overall mean efficiency across all resources
     pub overall_efficiency: f64,
 
+    /// Peak and p95 usage vs allocatable (0.0–1.0), for right-sizing headroom
+    #[serde(default)]
+    pub peak_cpu_efficiency: f64,
+    #[serde(default)]
+    pub p95_cpu_efficiency: f64,
+    #[serde(default)]
+    pub peak_memory_efficiency: f64,
+    #[serde(default)]
+    pub p95_memory_efficiency: f64,
+
     /// Optional details for reference
     pub total_cpu_allocatable_cores: f64,
     pub total_memory_allocatable_gb: f64,
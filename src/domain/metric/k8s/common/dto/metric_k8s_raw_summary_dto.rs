@@ -22,5 +22,15 @@ pub struct MetricRawSummaryDto {
     pub max_storage_gb: f64,
     pub avg_network_gb: f64,
     pub max_network_gb: f64,
+    /// 95th/99th percentile usage. Exact over minute points; with hour/day
+    /// points, the highest point percentile, which is an upper bound
+    #[serde(default)]
+    pub p95_cpu_cores: f64,
+    #[serde(default)]
+    pub p99_cpu_cores: f64,
+    #[serde(default)]
+    pub p95_memory_gb: f64,
+    #[serde(default)]
+    pub p99_memory_gb: f64,
     pub node_count: usize,
 }
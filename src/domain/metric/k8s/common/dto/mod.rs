@@ -1,8 +1,14 @@
 pub mod metric_k8s_cost_summary_dto;
 pub mod metric_k8s_cost_trend_dto;
+pub mod metric_k8s_cost_breakdown_dto;
+pub mod metric_k8s_pvc_cost_dto;
 pub mod metric_k8s_raw_summary_dto;
 pub mod metric_k8s_raw_efficiency_dto;
 
+/// Metric or cost series of a scope.
+///
+/// Cost series carry per-point costs only: load balancer, NodePort and
+/// Ingress cost appears in the cost summaries, not in the points.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MetricGetResponseDto {
     pub start: DateTime<Utc>,
@@ -16,6 +22,11 @@ pub struct MetricGetResponseDto {
     pub total: Option<usize>,  // total points in range (not just returned count)
     pub limit: Option<usize>,  // how many points returned max
     pub offset: Option<usize>, // starting index of current page
+
+    /// Currency of every cost value once costs are applied. The `*_usd`
+    /// field names predate currency support and are kept for existing clients.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -26,6 +37,7 @@ pub struct MetricSeriesDto {
     /// - pod: "<pod_uid>"
     /// - container: "<pod_uid>:<container_name>"
     /// - namespace: "<namespace>"
+    /// - pvc: "<namespace>/<claim>"
     pub key: String,
 
     /// Human readable name (for UI)
@@ -52,6 +64,7 @@ pub enum MetricGranularity {
 
 use chrono::{DateTime, Utc};
 use serde::{Serialize, Deserialize};
+use crate::core::persistence::info::fixed::unit_price::info_unit_price_entity::Currency;
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct UniversalMetricPointDto {
@@ -68,6 +81,9 @@ pub struct UniversalMetricPointDto {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub storage: Option<StorageMetricDto>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gpu: Option<GpuMetricDto>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cost: Option<CostMetricDto>, // <-- add this
 }
@@ -84,6 +100,15 @@ pub struct NetworkMetricDto {
     pub tx_bytes: Option<f64>,
     pub rx_errors: Option<f64>,
     pub tx_errors: Option<f64>,
+    /// Bytes exchanged within the same zone (flow exporter)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub local_bytes: Option<f64>,
+    /// Bytes exchanged across zones within the region (flow exporter)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub regional_bytes: Option<f64>,
+    /// Bytes exchanged with other regions or the internet (flow exporter)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_bytes: Option<f64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -94,6 +119,14 @@ pub struct FilesystemMetricDto {
     pub inodes: Option<f64>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct GpuMetricDto {
+    pub gpu_count: Option<f64>,
+    /// Average utilization of the attached GPUs (0–100)
+    pub utilization_percent: Option<f64>,
+    pub memory_used_bytes: Option<f64>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct CommonMetricValuesDto {
     // CPU
@@ -105,6 +138,26 @@ pub struct CommonMetricValuesDto {
     pub memory_working_set_bytes: Option<f64>,
     pub memory_rss_bytes: Option<f64>,
     pub memory_page_faults: Option<f64>,
+
+    // Peaks within the point's interval (hour/day granularity)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cpu_usage_nano_cores_max: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cpu_usage_nano_cores_p95: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cpu_usage_nano_cores_p99: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory_working_set_bytes_max: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory_working_set_bytes_p95: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory_working_set_bytes_p99: Option<f64>,
+
+    // Kubelet/runtime system containers (node points only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub system_cpu_usage_nano_cores: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub system_memory_working_set_bytes: Option<f64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -116,13 +169,42 @@ pub enum MetricScope {
     Container,
     Namespace,
     Deployment,
+    StatefulSet,
+    DaemonSet,
+    Job,
+    CronJob,
+    PersistentVolumeClaim,
+    Label,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct CostMetricDto {
-    pub total_cost_usd: Option<f64>,
-    pub cpu_cost_usd: Option<f64>,
-    pub memory_cost_usd: Option<f64>,
-    pub storage_cost_usd: Option<f64>,
+    #[serde(rename = "total_cost_usd")]
+    pub total_cost: Option<f64>,
+    #[serde(rename = "cpu_cost_usd")]
+    pub cpu_cost: Option<f64>,
+    #[serde(rename = "memory_cost_usd")]
+    pub memory_cost: Option<f64>,
+    #[serde(rename = "storage_cost_usd")]
+    pub storage_cost: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "gpu_cost_usd")]
+    pub gpu_cost: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub network_cost: Option<NetworkCostDto>,
+}
+
+/// Network transfer cost split by traffic locality.
+///
+/// Traffic without locality data is billed at the external rate.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default)]
+pub struct NetworkCostDto {
+    #[serde(rename = "total_cost_usd")]
+    pub total_cost: f64,
+    #[serde(rename = "local_cost_usd")]
+    pub local_cost: f64,
+    #[serde(rename = "regional_cost_usd")]
+    pub regional_cost: f64,
+    #[serde(rename = "external_cost_usd")]
+    pub external_cost: f64,
 }
 
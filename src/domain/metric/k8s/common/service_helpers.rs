@@ -1,11 +1,15 @@
 use anyhow::{anyhow, Result};
 use chrono::{DateTime, Utc};
 use serde_json::{json, Value};
+use std::collections::{BTreeMap, HashMap};
 
-use crate::api::dto::metrics_dto::RangeQuery;
+use crate::api::dto::metrics_dto::{CostMode, RangeQuery};
 use crate::core::persistence::info::fixed::unit_price::info_unit_price_entity::InfoUnitPriceEntity;
-use crate::core::persistence::info::k8s::node::info_node_entity::InfoNodeEntity;
+use crate::core::persistence::info::k8s::container::info_container_entity::InfoContainerEntity;
+use crate::core::persistence::info::k8s::node::info_node_entity::{InfoNodeEntity, NodeCapacityType, NodePriceSource};
 use crate::core::util::cost_util::CostUtil;
+use crate::core::util::peak_stats::WindowPercentile;
+use crate::domain::metric::k8s::common::dto::metric_k8s_cost_breakdown_dto::MetricCostBreakdownDto;
 use crate::domain::metric::k8s::common::dto::metric_k8s_cost_summary_dto::{
     MetricCostSummaryDto, MetricCostSummaryResponseDto,
 };
@@ -19,13 +23,66 @@ use crate::domain::metric::k8s::common::dto::metric_k8s_raw_summary_dto::{
     MetricRawSummaryDto, MetricRawSummaryResponseDto,
 };
 use crate::domain::metric::k8s::common::dto::{
-    CostMetricDto, MetricGetResponseDto, MetricGranularity, MetricScope, UniversalMetricPointDto,
+    CostMetricDto, MetricGetResponseDto, MetricGranularity, MetricScope, NetworkCostDto,
+    NetworkMetricDto,
 };
 use crate::domain::metric::k8s::common::util::k8s_metric_determine_granularity::determine_granularity;
 use tracing::log::warn;
 
 pub const BYTES_PER_GB: f64 = 1_073_741_824.0;
 
+/// Reserved capacity of a metric series, used as the floor in chargeback mode.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ResourceRequests {
+    pub cpu_cores: f64,
+    pub memory_bytes: f64,
+}
+
+impl ResourceRequests {
+    pub fn from_container(container: &InfoContainerEntity) -> Self {
+        Self {
+            cpu_cores: container.cpu_request_millicores.unwrap_or(0) as f64 / 1000.0,
+            memory_bytes: container.memory_request_bytes.unwrap_or(0) as f64,
+        }
+    }
+
+    pub fn add(&mut self, other: ResourceRequests) {
+        self.cpu_cores += other.cpu_cores;
+        self.memory_bytes += other.memory_bytes;
+    }
+}
+
+/// Sum container requests per pod UID (the pod series key).
+pub fn pod_requests_by_uid(containers: &[InfoContainerEntity]) -> HashMap<String, ResourceRequests> {
+    let mut map: HashMap<String, ResourceRequests> = HashMap::new();
+
+    for container in containers {
+        if let Some(pod_uid) = &container.pod_uid {
+            map.entry(pod_uid.clone())
+                .or_default()
+                .add(ResourceRequests::from_container(container));
+        }
+    }
+
+    map
+}
+
+/// Container requests keyed by `{pod_uid}-{container_name}` (the container series key).
+pub fn container_requests_by_key(
+    containers: &[InfoContainerEntity],
+) -> HashMap<String, ResourceRequests> {
+    containers
+        .iter()
+        .filter_map(|c| match (&c.pod_uid, &c.container_name) {
+            (Some(pod_uid), Some(name)) => Some((
+                format!("{}-{}", pod_uid, name),
+                ResourceRequests::from_container(c),
+            )),
+            _ => None,
+        })
+        .collect()
+}
+
 #[derive(Clone)]
 pub struct TimeWindow {
     pub start: DateTime<Utc>,
@@ -120,11 +177,27 @@ pub fn build_raw_summary_value(
     let mut total_network = 0.0;
     let mut max_network = 0.0;
     let mut point_count = 0.0;
+    let mut cpu_p95 = WindowPercentile::default();
+    let mut cpu_p99 = WindowPercentile::default();
+    let mut mem_p95 = WindowPercentile::default();
+    let mut mem_p99 = WindowPercentile::default();
 
     for series in &metrics.series {
         for point in &series.points {
-            let cpu = point.cpu_memory.cpu_usage_nano_cores.unwrap_or(0.0) / 1_000_000_000.0;
-            let mem_gb = point.cpu_memory.memory_usage_bytes.unwrap_or(0.0) / BYTES_PER_GB;
+            let values = &point.cpu_memory;
+            let cpu = values.cpu_usage_nano_cores.unwrap_or(0.0) / 1_000_000_000.0;
+            let mem_gb = values.memory_usage_bytes.unwrap_or(0.0) / BYTES_PER_GB;
+
+            // Hour/day points carry their own peaks; minute points are samples themselves
+            let to_cores = |v: Option<f64>| v.map(|v| v / 1_000_000_000.0);
+            let to_gb = |v: Option<f64>| v.map(|v| v / BYTES_PER_GB);
+            cpu_p95.add(to_cores(values.cpu_usage_nano_cores_p95), cpu);
+            cpu_p99.add(to_cores(values.cpu_usage_nano_cores_p99), cpu);
+            mem_p95.add(to_gb(values.memory_working_set_bytes_p95), mem_gb);
+            mem_p99.add(to_gb(values.memory_working_set_bytes_p99), mem_gb);
+            let peak_cpu = to_cores(values.cpu_usage_nano_cores_max).unwrap_or(cpu);
+            let peak_mem_gb = to_gb(values.memory_working_set_bytes_max).unwrap_or(mem_gb);
+
             let fs_gb = point
                 .filesystem
                 .as_ref()
@@ -142,11 +215,11 @@ pub fn build_raw_summary_value(
             total_storage += fs_gb;
             total_network += net_gb;
 
-            if cpu > max_cpu {
-                max_cpu = cpu;
+            if peak_cpu > max_cpu {
+                max_cpu = peak_cpu;
             }
-            if mem_gb > max_mem {
-                max_mem = mem_gb;
+            if peak_mem_gb > max_mem {
+                max_mem = peak_mem_gb;
             }
             if fs_gb > max_storage {
                 max_storage = fs_gb;
@@ -172,6 +245,10 @@ pub fn build_raw_summary_value(
         max_storage_gb: max_storage,
         avg_network_gb: total_network / point_count,
         max_network_gb: max_network,
+        p95_cpu_cores: cpu_p95.value(95.0),
+        p99_cpu_cores: cpu_p99.value(99.0),
+        p95_memory_gb: mem_p95.value(95.0),
+        p99_memory_gb: mem_p99.value(99.0),
         node_count: member_count,
     };
 
@@ -186,7 +263,7 @@ pub fn build_raw_summary_value(
     Ok(serde_json::to_value(dto)?)
 }
 
-fn granularity_interval_hours(granularity: &MetricGranularity) -> f64 {
+pub(crate) fn granularity_interval_hours(granularity: &MetricGranularity) -> f64 {
     match granularity {
         MetricGranularity::Minute => 1.0 / 60.0,
         MetricGranularity::Hour => 1.0,
@@ -194,19 +271,7 @@ fn granularity_interval_hours(granularity: &MetricGranularity) -> f64 {
     }
 }
 
-fn point_interval_hours(points: &[UniversalMetricPointDto], idx: usize, default: f64) -> f64 {
-    if let Some(next) = points.get(idx + 1) {
-        let delta_seconds = next
-            .time
-            .signed_duration_since(points[idx].time)
-            .num_seconds();
-        if delta_seconds > 0 {
-            return delta_seconds as f64 / 3600.0;
-        }
-    }
-    default
-}
-fn point_interval_hours_from_timestamps(
+pub(crate) fn point_interval_hours_from_timestamps(
     timestamps: &[DateTime<Utc>],
     idx: usize,
     default_interval_hours: f64,
@@ -230,16 +295,41 @@ fn point_interval_hours_from_timestamps(
         default_interval_hours
     }
 }
-pub fn apply_costs(response: &mut MetricGetResponseDto, unit_prices: &InfoUnitPriceEntity) {
+/// Attach per-point costs to every series.
+///
+/// Each point is priced at the unit prices in force at its timestamp (see
+/// [`InfoUnitPriceEntity::at`]), so later price changes never reprice history.
+///
+/// - `Showback`: CPU and memory are billed on actual usage.
+/// - `Chargeback`: CPU and memory are billed on `max(usage, request)`, where the
+///   request is looked up in `requests` by series key. Series without an entry
+///   fall back to usage, and points without a usage sample are not billed.
+pub fn apply_costs(
+    response: &mut MetricGetResponseDto,
+    unit_prices: &InfoUnitPriceEntity,
+    mode: &CostMode,
+    requests: &HashMap<String, ResourceRequests>,
+    series_prices: &HashMap<String, InfoUnitPriceEntity>,
+) {
     let default_interval_hours = granularity_interval_hours(&response.granularity);
+    response.currency = Some(unit_prices.currency);
 
     for series in &mut response.series {
         // Precompute timestamps (avoids borrow conflicts)
         let timestamps: Vec<_> = series.points.iter().map(|p| p.time).collect();
 
+        // Workloads are billed at the rates of the node they run on (spot, fixed price)
+        let unit_prices = series_prices.get(&series.key).unwrap_or(unit_prices);
+
+        let floor = match mode {
+            CostMode::Showback => None,
+            CostMode::Chargeback => requests.get(&series.key).copied(),
+        };
+
         for (idx, point) in series.points.iter_mut().enumerate() {
             let interval_hours =
                 point_interval_hours_from_timestamps(&timestamps, idx, default_interval_hours);
+            let unit_prices = unit_prices.at(point.time);
 
             // ---------------------------
             // CPU (usage-based)
@@ -248,16 +338,20 @@ pub fn apply_costs(response: &mut MetricGetResponseDto, unit_prices: &InfoUnitPr
             // - cpu_usage_nano_cores is a gauge (instantaneous), suitable for graphs, not cost.
             // - cpu_usage_core_nano_seconds should already represent "usage within the interval"
             //   after minute->hour (increase) and hour->day (sum).
-            let cpu_cost_usd =
-                point
-                    .cpu_memory
-                    .cpu_usage_core_nano_seconds
-                    .map(|core_nano_seconds| {
-                        CostUtil::compute_cpu_cost_from_core_nano_seconds(
-                            core_nano_seconds,
-                            unit_prices,
-                        )
-                    });
+            let cpu_core_hours = point
+                .cpu_memory
+                .cpu_usage_core_nano_seconds
+                .map(CostUtil::core_nano_seconds_to_core_hours);
+
+            // Chargeback: bill at least the requested cores for the interval.
+            // Points without a usage sample are not billed in either mode.
+            let cpu_core_hours = match floor {
+                Some(req) => cpu_core_hours.map(|h| h.max(req.cpu_cores * interval_hours)),
+                None => cpu_core_hours,
+            };
+
+            let cpu_cost = cpu_core_hours
+                .map(|core_hours| CostUtil::compute_cpu_cost_from_core_hours(core_hours, unit_prices));
 
             // ---------------------------
             // MEMORY (gauge * time)
@@ -268,7 +362,13 @@ pub fn apply_costs(response: &mut MetricGetResponseDto, unit_prices: &InfoUnitPr
                 .memory_working_set_bytes
                 .or(point.cpu_memory.memory_usage_bytes);
 
-            let memory_cost_usd = memory_bytes_for_cost
+            // Chargeback: bill at least the requested memory.
+            let memory_bytes_for_cost = match floor {
+                Some(req) => memory_bytes_for_cost.map(|b| b.max(req.memory_bytes)),
+                None => memory_bytes_for_cost,
+            };
+
+            let memory_cost = memory_bytes_for_cost
                 .map(|bytes| CostUtil::compute_memory_cost(bytes, interval_hours, unit_prices));
 
             // ---------------------------
@@ -290,47 +390,215 @@ pub fn apply_costs(response: &mut MetricGetResponseDto, unit_prices: &InfoUnitPr
                 .unwrap_or(0.0);
 
             let total_storage_gb_hours = ephemeral_gb_hours + persistent_gb_hours;
-            let storage_cost_usd = Some(total_storage_gb_hours * unit_prices.storage_gb_hour);
+            let storage_cost = total_storage_gb_hours * unit_prices.storage_gb_hour;
 
             // ---------------------------
             // NETWORK (usage-based)
             // ---------------------------
             // If rx/tx are interval usage (bytes), do NOT multiply by interval_hours.
-            let network_cost_usd: f64 = point
+            let network_cost = point
                 .network
                 .as_ref()
-                .map(|n| {
-                    let rx_gb = CostUtil::bytes_to_gb(n.rx_bytes.unwrap_or(0.0));
-                    let tx_gb = CostUtil::bytes_to_gb(n.tx_bytes.unwrap_or(0.0));
-                    (rx_gb + tx_gb) * unit_prices.network_external_gb
-                })
-                .unwrap_or(0.0);
+                .map(|n| network_cost_by_locality(n, unit_prices));
+            let network_total = network_cost.map(|c| c.total_cost).unwrap_or(0.0);
+
+            // ---------------------------
+            // GPU (attached GPUs * time)
+            // ---------------------------
+            let gpu_cost = point
+                .gpu
+                .as_ref()
+                .and_then(|g| g.gpu_count)
+                .map(|count| count * interval_hours * unit_prices.gpu_hour);
 
             // ---------------------------
             // TOTAL
             // ---------------------------
-            let total_cost_usd = Some(
-                cpu_cost_usd.unwrap_or(0.0)
-                    + memory_cost_usd.unwrap_or(0.0)
-                    + storage_cost_usd.unwrap_or(0.0)
-                    + network_cost_usd,
+            let total_cost = Some(
+                cpu_cost.unwrap_or(0.0)
+                    + memory_cost.unwrap_or(0.0)
+                    + storage_cost
+                    + network_total
+                    + gpu_cost.unwrap_or(0.0),
             );
 
             point.cost = Some(CostMetricDto {
-                total_cost_usd,
-                cpu_cost_usd,
-                memory_cost_usd,
-                storage_cost_usd,
+                total_cost,
+                cpu_cost,
+                memory_cost,
+                storage_cost: Some(storage_cost),
+                gpu_cost,
+                network_cost,
             });
         }
     }
 }
 
+/// CPU and memory cost the chargeback floor adds on top of usage, summed per
+/// point time as `(cpu, memory)`.
+///
+/// Every series is floored at its own requests, so adding the result to a usage
+/// cost that already covers those series bills `max(usage, request)` for each
+/// of them.
+pub(crate) fn request_uplift_by_time(
+    response: &MetricGetResponseDto,
+    unit_prices: &InfoUnitPriceEntity,
+    requests: &HashMap<String, ResourceRequests>,
+    series_prices: &HashMap<String, InfoUnitPriceEntity>,
+) -> BTreeMap<DateTime<Utc>, (f64, f64)> {
+    let mut usage = response.clone();
+    let mut floored = response.clone();
+    apply_costs(&mut usage, unit_prices, &CostMode::Showback, requests, series_prices);
+    apply_costs(&mut floored, unit_prices, &CostMode::Chargeback, requests, series_prices);
+
+    let mut uplift: BTreeMap<DateTime<Utc>, (f64, f64)> = BTreeMap::new();
+    for (usage, floored) in usage.series.iter().zip(&floored.series) {
+        for (usage, floored) in usage.points.iter().zip(&floored.points) {
+            let (Some(usage_cost), Some(floored_cost)) = (&usage.cost, &floored.cost) else {
+                continue;
+            };
+            let extra = |a: Option<f64>, b: Option<f64>| b.unwrap_or(0.0) - a.unwrap_or(0.0);
+            let entry = uplift.entry(floored.time).or_default();
+            entry.0 += extra(usage_cost.cpu_cost, floored_cost.cpu_cost);
+            entry.1 += extra(usage_cost.memory_cost, floored_cost.memory_cost);
+        }
+    }
+    uplift
+}
+
+/// Network cost of one point, split by traffic locality.
+///
+/// Bytes classified by the flow exporter are billed at the local, regional and
+/// external rates. Whatever rx/tx traffic is left unclassified is billed as
+/// external, which is also the full amount when no flow data exists.
+pub fn network_cost_by_locality(
+    network: &NetworkMetricDto,
+    unit_prices: &InfoUnitPriceEntity,
+) -> NetworkCostDto {
+    let local_bytes = network.local_bytes.unwrap_or(0.0);
+    let regional_bytes = network.regional_bytes.unwrap_or(0.0);
+    let external_bytes = network.external_bytes.unwrap_or(0.0);
+
+    let physical_bytes = network.rx_bytes.unwrap_or(0.0) + network.tx_bytes.unwrap_or(0.0);
+    let unclassified_bytes = (physical_bytes - local_bytes - regional_bytes - external_bytes).max(0.0);
+
+    let local_cost = CostUtil::bytes_to_gb(local_bytes) * unit_prices.network_local_gb;
+    let regional_cost = CostUtil::bytes_to_gb(regional_bytes) * unit_prices.network_regional_gb;
+    let external_cost =
+        CostUtil::bytes_to_gb(external_bytes + unclassified_bytes) * unit_prices.network_external_gb;
+
+    NetworkCostDto {
+        total_cost: local_cost + regional_cost + external_cost,
+        local_cost,
+        regional_cost,
+        external_cost,
+    }
+}
+
+/// Effective unit prices for a node and the workloads scheduled on it.
+///
+/// - Spot/preemptible nodes use the spot rates.
+/// - Fixed-price nodes derive CPU and memory rates from the node's hourly price,
+///   split by the weighted (unit price × capacity) share of each resource. A pod
+///   is then billed its share of the node; unused capacity shows up as idle.
+///
+/// Changes of the fixed node price start new price versions.
+pub fn node_unit_prices(node: &InfoNodeEntity, unit_prices: &InfoUnitPriceEntity) -> InfoUnitPriceEntity {
+    unit_prices.map_versions_at(&node.fixed_price_changes(), |prices, time| {
+        node_version_prices(node, time, prices)
+    })
+}
+
+/// Prices of `node` for one unit price version, with the fixed node price in
+/// force at `time`.
+pub(crate) fn node_version_prices(
+    node: &InfoNodeEntity,
+    time: DateTime<Utc>,
+    unit_prices: &InfoUnitPriceEntity,
+) -> InfoUnitPriceEntity {
+    let base = unit_prices.for_capacity_type(node.capacity_type);
+
+    // Catalog prices are on-demand instance prices: spot nodes keep the spot rates
+    let from_catalog = node.price_source == Some(NodePriceSource::Catalog);
+    if from_catalog && node.capacity_type == Some(NodeCapacityType::Spot) {
+        return base;
+    }
+
+    // Fixed node prices are stored in USD; without a rate the unit prices apply
+    let hourly = match node.fixed_hourly_usd_at(time).and_then(|v| unit_prices.from_usd(v)) {
+        Some(v) => v,
+        None => return base,
+    };
+
+    let cpu_cores = node.cpu_capacity_cores.unwrap_or(0) as f64;
+    let memory_gb = node.memory_capacity_bytes.unwrap_or(0) as f64 / BYTES_PER_GB;
+    let cpu_weight = cpu_cores * base.cpu_core_hour;
+    let memory_weight = memory_gb * base.memory_gb_hour;
+    let total_weight = cpu_weight + memory_weight;
+
+    if cpu_cores <= 0.0 || memory_gb <= 0.0 || total_weight <= 0.0 {
+        return base;
+    }
+
+    let cpu_core_hour = hourly * (cpu_weight / total_weight) / cpu_cores;
+    let memory_gb_hour = hourly * (memory_weight / total_weight) / memory_gb;
+
+    // Catalog prices only replace the CPU and memory rates; GPUs and disks
+    // keep their unit prices
+    if from_catalog {
+        return InfoUnitPriceEntity { cpu_core_hour, memory_gb_hour, ..base };
+    }
+
+    // A manual node price already covers any attached GPUs and the local disk
+    InfoUnitPriceEntity {
+        cpu_core_hour,
+        memory_gb_hour,
+        gpu_hour: 0.0,
+        gpu_spot_hour: 0.0,
+        storage_gb_hour: 0.0,
+        ..base
+    }
+}
+
+/// Scale CPU/memory costs so a workload also carries its share of the
+/// unallocated (system overhead + idle) cluster cost.
+///
+/// Shares are proportional to allocated cost, so every workload is scaled by
+/// the same `1 + unallocated / allocated` factor.
+pub fn apply_idle_share(response: &mut MetricGetResponseDto, cluster: &MetricCostBreakdownDto) {
+    if cluster.allocated_cost <= 0.0 {
+        return;
+    }
+    let factor = 1.0 + cluster.unallocated_cost() / cluster.allocated_cost;
+
+    for series in &mut response.series {
+        for point in &mut series.points {
+            if let Some(cost) = point.cost.as_mut() {
+                let cpu = cost.cpu_cost.map(|v| v * factor);
+                let memory = cost.memory_cost.map(|v| v * factor);
+                let added = (cpu.unwrap_or(0.0) - cost.cpu_cost.unwrap_or(0.0))
+                    + (memory.unwrap_or(0.0) - cost.memory_cost.unwrap_or(0.0));
+
+                cost.cpu_cost = cpu;
+                cost.memory_cost = memory;
+                cost.total_cost = Some(cost.total_cost.unwrap_or(0.0) + added);
+            }
+        }
+    }
+}
+
+/// Attach capacity-based costs to each node series.
+///
+/// A node is billed for its full capacity while running, which already covers
+/// every request scheduled on it, so the result is the same in both cost modes.
+/// Spot and fixed-price nodes are billed through [`node_unit_prices`].
 pub fn apply_node_costs(
     response: &mut MetricGetResponseDto,
     unit_prices: &InfoUnitPriceEntity,
     node_infos: &Vec<InfoNodeEntity>,
 ) {
+    response.currency = Some(unit_prices.currency);
+
     for series in &mut response.series {
         // 🔹 series.key == node_name
         let node_name = &series.key;
@@ -350,30 +618,33 @@ pub fn apply_node_costs(
             _ => continue,
         };
 
+        let unit_prices = node_unit_prices(node_info, unit_prices);
+
         // Get Resource Capacity
         let cpu_cores = node_info.cpu_capacity_cores.unwrap_or(0) as f64;
         let memory_gb = node_info.memory_capacity_bytes.unwrap_or(0) as f64 / 1_073_741_824.0;
         let storage_gb =
             node_info.ephemeral_storage_capacity_bytes.unwrap_or(0) as f64 / 1_073_741_824.0;
 
-        let cpu_cost_usd = Some(cpu_cores * running_hours * unit_prices.cpu_core_hour);
-        let memory_cost_usd = Some(memory_gb * running_hours * unit_prices.memory_gb_hour);
-        let storage_cost_usd = Some(storage_gb * running_hours * unit_prices.storage_gb_hour);
-
-        let network_cost_usd = 0.0;
+        // Running hours are spread over the window, split across price versions
+        let (mut cpu_cost, mut memory_cost, mut storage_cost) = (0.0, 0.0, 0.0);
+        for (prices, share) in unit_prices.periods(response.start, response.end) {
+            let hours = running_hours * share;
+            cpu_cost += cpu_cores * hours * prices.cpu_core_hour;
+            memory_cost += memory_gb * hours * prices.memory_gb_hour;
+            storage_cost += storage_gb * hours * prices.storage_gb_hour;
+        }
 
-        let total_cost_usd = Some(
-            cpu_cost_usd.unwrap_or(0.0)
-                + memory_cost_usd.unwrap_or(0.0)
-                + storage_cost_usd.unwrap_or(0.0)
-                + network_cost_usd,
-        );
+        let network_cost = 0.0;
+        let total_cost = cpu_cost + memory_cost + storage_cost + network_cost;
 
         series.cost_summary = Some(CostMetricDto {
-            total_cost_usd,
-            cpu_cost_usd,
-            memory_cost_usd,
-            storage_cost_usd,
+            total_cost: Some(total_cost),
+            cpu_cost: Some(cpu_cost),
+            memory_cost: Some(memory_cost),
+            storage_cost: Some(storage_cost),
+            gpu_cost: None,
+            network_cost: None,
         });
     }
 }
@@ -385,49 +656,48 @@ pub fn build_cost_summary_dto(
     unit_prices: &InfoUnitPriceEntity,
 ) -> MetricCostSummaryResponseDto {
     let mut summary = MetricCostSummaryDto::default();
-    let default_interval_hours = granularity_interval_hours(&metrics.granularity);
 
+    // Sum the point costs `apply_costs` computed, so the summary uses the same
+    // per-series prices as the series
     for series in &metrics.series {
-        for (idx, point) in series.points.iter().enumerate() {
-            let interval_hours = point_interval_hours(&series.points, idx, default_interval_hours);
-
+        for point in &series.points {
             if let Some(cost) = &point.cost {
-                let cpu_cost = cost.cpu_cost_usd.unwrap_or(0.0);
-                let memory_cost = cost.memory_cost_usd.unwrap_or(0.0);
-
-                let ephemeral_cost = point
-                    .filesystem
-                    .as_ref()
-                    .and_then(|fs| fs.used_bytes)
-                    .map(|b| (b / BYTES_PER_GB) * interval_hours * unit_prices.storage_gb_hour)
-                    .unwrap_or(0.0);
+                let cpu_cost = cost.cpu_cost.unwrap_or(0.0);
+                let memory_cost = cost.memory_cost.unwrap_or(0.0);
+                let gpu_cost = cost.gpu_cost.unwrap_or(0.0);
 
-                let persistent_cost = point
+                // The point storage cost covers both disks, split by used bytes
+                let ephemeral_bytes = point.filesystem.as_ref().and_then(|fs| fs.used_bytes).unwrap_or(0.0);
+                let persistent_bytes = point
                     .storage
                     .as_ref()
                     .and_then(|s| s.persistent.as_ref())
                     .and_then(|fs| fs.used_bytes)
-                    .map(|b| (b / BYTES_PER_GB) * interval_hours * unit_prices.storage_gb_hour)
                     .unwrap_or(0.0);
-
-                let network_cost = point
-                    .network
-                    .as_ref()
-                    .map(|n| {
-                        let rx_gb = n.rx_bytes.unwrap_or(0.0) / BYTES_PER_GB;
-                        let tx_gb = n.tx_bytes.unwrap_or(0.0) / BYTES_PER_GB;
-                        (rx_gb + tx_gb) * unit_prices.network_external_gb
-                    })
-                    .unwrap_or(0.0);
-
-                summary.cpu_cost_usd += cpu_cost;
-                summary.memory_cost_usd += memory_cost;
-                summary.ephemeral_storage_cost_usd += ephemeral_cost;
-                summary.persistent_storage_cost_usd += persistent_cost;
-                summary.network_cost_usd += network_cost;
-
-                summary.total_cost_usd +=
-                    cpu_cost + memory_cost + ephemeral_cost + persistent_cost + network_cost;
+                let storage_cost = cost.storage_cost.unwrap_or(0.0);
+                let used_bytes = ephemeral_bytes + persistent_bytes;
+                let (ephemeral_cost, persistent_cost) = if used_bytes > 0.0 {
+                    let persistent_cost = storage_cost * persistent_bytes / used_bytes;
+                    (storage_cost - persistent_cost, persistent_cost)
+                } else {
+                    (storage_cost, 0.0)
+                };
+
+                let network_cost = cost.network_cost.map(|n| n.total_cost).unwrap_or(0.0);
+
+                summary.cpu_cost += cpu_cost;
+                summary.memory_cost += memory_cost;
+                summary.ephemeral_storage_cost += ephemeral_cost;
+                summary.persistent_storage_cost += persistent_cost;
+                summary.network_cost += network_cost;
+                summary.gpu_cost += gpu_cost;
+
+                summary.total_cost += cpu_cost
+                    + memory_cost
+                    + ephemeral_cost
+                    + persistent_cost
+                    + network_cost
+                    + gpu_cost;
             }
         }
     }
@@ -438,6 +708,7 @@ pub fn build_cost_summary_dto(
         scope,
         target,
         granularity: metrics.granularity.clone(),
+        currency: unit_prices.currency,
         summary,
     }
 }
@@ -457,36 +728,32 @@ pub fn build_node_cost_summary_dto(
                 network_cost = point
                     .network
                     .as_ref()
-                    .map(|n| {
-                        let rx_gb = n.rx_bytes.unwrap_or(0.0) / BYTES_PER_GB;
-                        let tx_gb = n.tx_bytes.unwrap_or(0.0) / BYTES_PER_GB;
-                        (rx_gb + tx_gb) * unit_prices.network_external_gb
-                    })
+                    .map(|n| network_cost_by_locality(n, unit_prices.at(point.time)).total_cost)
                     .unwrap_or(0.0);
             }
-            summary.network_cost_usd += network_cost;
+            summary.network_cost += network_cost;
         }
-        summary.cpu_cost_usd += series
+        summary.cpu_cost += series
             .cost_summary
             .as_ref()
-            .map(|c| c.cpu_cost_usd.unwrap_or(0.0))
+            .map(|c| c.cpu_cost.unwrap_or(0.0))
             .unwrap_or(0.0);
-        summary.memory_cost_usd += series
+        summary.memory_cost += series
             .cost_summary
             .as_ref()
-            .map(|c| c.memory_cost_usd.unwrap_or(0.0))
+            .map(|c| c.memory_cost.unwrap_or(0.0))
             .unwrap_or(0.0);
-        summary.ephemeral_storage_cost_usd += series
+        summary.ephemeral_storage_cost += series
             .cost_summary
             .as_ref()
-            .map(|c| c.storage_cost_usd.unwrap_or(0.0))
+            .map(|c| c.storage_cost.unwrap_or(0.0))
             .unwrap_or(0.0);
-        summary.total_cost_usd += series
+        summary.total_cost += series
             .cost_summary
             .as_ref()
-            .map(|c| c.total_cost_usd.unwrap_or(0.0))
+            .map(|c| c.total_cost.unwrap_or(0.0))
             .unwrap_or(0.0)
-            + summary.network_cost_usd;
+            + summary.network_cost;
     }
 
     MetricCostSummaryResponseDto {
@@ -495,6 +762,7 @@ pub fn build_node_cost_summary_dto(
         scope,
         target,
         granularity: metrics.granularity.clone(),
+        currency: unit_prices.currency,
         summary,
     }
 }
@@ -511,12 +779,12 @@ pub fn build_cost_trend_dto(
         .flat_map(|series| {
             series.points.iter().filter_map(|p| {
                 p.cost.as_ref().and_then(|c| {
-                    c.total_cost_usd.map(|total| MetricCostTrendPointDto {
+                    c.total_cost.map(|total| MetricCostTrendPointDto {
                         time: p.time,
-                        total_cost_usd: total,
-                        cpu_cost_usd: c.cpu_cost_usd.unwrap_or(0.0),
-                        memory_cost_usd: c.memory_cost_usd.unwrap_or(0.0),
-                        storage_cost_usd: c.storage_cost_usd.unwrap_or(0.0),
+                        total_cost: total,
+                        cpu_cost: c.cpu_cost.unwrap_or(0.0),
+                        memory_cost: c.memory_cost.unwrap_or(0.0),
+                        storage_cost: c.storage_cost.unwrap_or(0.0),
                     })
                 })
             })
@@ -531,8 +799,8 @@ pub fn build_cost_trend_dto(
     trend_points.sort_by_key(|p| p.time);
 
     // 3️⃣ Start/end cost
-    let start_cost = trend_points.first().unwrap().total_cost_usd;
-    let end_cost = trend_points.last().unwrap().total_cost_usd;
+    let start_cost = trend_points.first().unwrap().total_cost;
+    let end_cost = trend_points.last().unwrap().total_cost;
     let diff = end_cost - start_cost;
 
     let growth_rate_percent = if start_cost > 0.0 {
@@ -547,7 +815,7 @@ pub fn build_cost_trend_dto(
         .map(|p| p.time.timestamp() as f64)
         .collect();
 
-    let ys: Vec<f64> = trend_points.iter().map(|p| p.total_cost_usd).collect();
+    let ys: Vec<f64> = trend_points.iter().map(|p| p.total_cost).collect();
 
     let n = xs.len() as f64;
 
@@ -566,7 +834,7 @@ pub fn build_cost_trend_dto(
 
     // Predict the next point — simple linear extrapolation
     let last_x = xs.last().copied().unwrap_or(0.0);
-    let predicted_next_cost_usd = Some(end_cost + slope * (last_x + 1.0 - last_x));
+    let predicted_next_cost = Some(end_cost + slope * (last_x + 1.0 - last_x));
 
     Ok(MetricCostTrendResponseDto {
         start: metrics.start,
@@ -574,14 +842,15 @@ pub fn build_cost_trend_dto(
         scope,
         target,
         granularity: metrics.granularity.clone(),
+        currency: metrics.currency,
 
         trend: MetricCostTrendDto {
-            start_cost_usd: start_cost,
-            end_cost_usd: end_cost,
-            cost_diff_usd: diff,
+            start_cost,
+            end_cost,
+            cost_diff: diff,
             growth_rate_percent,
-            regression_slope_usd_per_granularity: slope,
-            predicted_next_cost_usd,
+            regression_slope_per_granularity: slope,
+            predicted_next_cost,
         },
 
         points: trend_points,
@@ -613,6 +882,8 @@ pub fn build_efficiency_value(
         0.0
     };
 
+    let ratio = |used: f64, alloc: f64| if alloc > 0.0 { (used / alloc).clamp(0.0, 1.0) } else { 0.0 };
+
     let dto = MetricRawEfficiencyResponseDto {
         start: summary.start,
         end: summary.end,
@@ -623,6 +894,10 @@ pub fn build_efficiency_value(
             memory_efficiency: mem_eff,
             storage_efficiency: storage_eff,
             overall_efficiency: (cpu_eff + mem_eff + storage_eff) / 3.0,
+            peak_cpu_efficiency: ratio(summary.summary.max_cpu_cores, total_cpu_alloc),
+            p95_cpu_efficiency: ratio(summary.summary.p95_cpu_cores, total_cpu_alloc),
+            peak_memory_efficiency: ratio(summary.summary.max_memory_gb, total_mem_alloc_gb),
+            p95_memory_efficiency: ratio(summary.summary.p95_memory_gb, total_mem_alloc_gb),
             total_cpu_allocatable_cores: total_cpu_alloc,
             total_memory_allocatable_gb: total_mem_alloc_gb,
             total_storage_allocatable_gb: total_storage_alloc_gb,
@@ -631,3 +906,377 @@ pub fn build_efficiency_value(
 
     Ok(serde_json::to_value(dto)?)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::metric::k8s::common::dto::{
+        CommonMetricValuesDto, FilesystemMetricDto, MetricSeriesDto, UniversalMetricPointDto,
+    };
+
+    fn one_hour_series(key: &str, core_nano_seconds: f64, memory_bytes: f64) -> MetricGetResponseDto {
+        let start = Utc::now();
+        let point = |time| UniversalMetricPointDto {
+            time,
+            cpu_memory: CommonMetricValuesDto {
+                cpu_usage_core_nano_seconds: Some(core_nano_seconds),
+                memory_working_set_bytes: Some(memory_bytes),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        MetricGetResponseDto {
+            start,
+            end: start + chrono::Duration::hours(1),
+            scope: "pod".to_string(),
+            target: None,
+            granularity: MetricGranularity::Hour,
+            series: vec![MetricSeriesDto {
+                key: key.to_string(),
+                name: key.to_string(),
+                scope: MetricScope::Pod,
+                points: vec![point(start), point(start + chrono::Duration::hours(1))],
+                running_hours: None,
+                cost_summary: None,
+            }],
+            total: None,
+            limit: None,
+            offset: None,
+            currency: None,
+        }
+    }
+
+    #[test]
+    fn chargeback_bills_request_when_usage_is_lower() {
+        let prices = InfoUnitPriceEntity {
+            cpu_core_hour: 1.0,
+            memory_gb_hour: 1.0,
+            ..Default::default()
+        };
+        // 0.5 core-hour and 1 GB used, 2 cores and 4 GB requested
+        let requests = HashMap::from([(
+            "pod-a".to_string(),
+            ResourceRequests { cpu_cores: 2.0, memory_bytes: 4.0 * BYTES_PER_GB },
+        )]);
+
+        let mut showback = one_hour_series("pod-a", 0.5 * 3600.0 * 1e9, BYTES_PER_GB);
+        apply_costs(&mut showback, &prices, &CostMode::Showback, &requests, &HashMap::new());
+        let cost = showback.series[0].points[0].cost.clone().unwrap();
+        assert!((cost.cpu_cost.unwrap() - 0.5).abs() < 1e-9);
+        assert!((cost.memory_cost.unwrap() - 1.0).abs() < 1e-9);
+
+        let mut chargeback = one_hour_series("pod-a", 0.5 * 3600.0 * 1e9, BYTES_PER_GB);
+        apply_costs(&mut chargeback, &prices, &CostMode::Chargeback, &requests, &HashMap::new());
+        let cost = chargeback.series[0].points[0].cost.clone().unwrap();
+        assert!((cost.cpu_cost.unwrap() - 2.0).abs() < 1e-9);
+        assert!((cost.memory_cost.unwrap() - 4.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn chargeback_bills_usage_when_above_request() {
+        let prices = InfoUnitPriceEntity {
+            cpu_core_hour: 1.0,
+            memory_gb_hour: 1.0,
+            ..Default::default()
+        };
+        let requests = HashMap::from([(
+            "pod-a".to_string(),
+            ResourceRequests { cpu_cores: 1.0, memory_bytes: BYTES_PER_GB },
+        )]);
+
+        let mut response = one_hour_series("pod-a", 3.0 * 3600.0 * 1e9, 2.0 * BYTES_PER_GB);
+        apply_costs(&mut response, &prices, &CostMode::Chargeback, &requests, &HashMap::new());
+        let cost = response.series[0].points[0].cost.clone().unwrap();
+        assert!((cost.cpu_cost.unwrap() - 3.0).abs() < 1e-9);
+        assert!((cost.memory_cost.unwrap() - 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn chargeback_skips_points_without_usage() {
+        let prices = InfoUnitPriceEntity {
+            cpu_core_hour: 1.0,
+            memory_gb_hour: 1.0,
+            ..Default::default()
+        };
+        let requests = HashMap::from([(
+            "pod-a".to_string(),
+            ResourceRequests { cpu_cores: 2.0, memory_bytes: 4.0 * BYTES_PER_GB },
+        )]);
+
+        let mut response = one_hour_series("pod-a", 0.0, 0.0);
+        response.series[0].points[0].cpu_memory = CommonMetricValuesDto::default();
+        apply_costs(&mut response, &prices, &CostMode::Chargeback, &requests, &HashMap::new());
+        let cost = response.series[0].points[0].cost.clone().unwrap();
+        assert!(cost.cpu_cost.is_none());
+        assert!(cost.memory_cost.is_none());
+    }
+
+    #[test]
+    fn summary_sums_point_costs_at_series_prices() {
+        let prices = InfoUnitPriceEntity {
+            cpu_core_hour: 1.0,
+            storage_gb_hour: 1.0,
+            network_external_gb: 1.0,
+            ..Default::default()
+        };
+        // A manual node price already covers the disk and the traffic is free
+        let series_prices = HashMap::from([(
+            "pod-a".to_string(),
+            InfoUnitPriceEntity { storage_gb_hour: 0.0, network_external_gb: 0.0, ..prices.clone() },
+        )]);
+
+        let mut response = one_hour_series("pod-a", 3600.0 * 1e9, 0.0);
+        for point in &mut response.series[0].points {
+            point.filesystem = Some(FilesystemMetricDto { used_bytes: Some(BYTES_PER_GB), ..Default::default() });
+            point.network = Some(NetworkMetricDto { rx_bytes: Some(BYTES_PER_GB), ..Default::default() });
+        }
+        apply_costs(&mut response, &prices, &CostMode::Showback, &HashMap::new(), &series_prices);
+
+        let summary = build_cost_summary_dto(&response, MetricScope::Pod, None, &prices).summary;
+        assert_eq!(summary.ephemeral_storage_cost, 0.0);
+        assert_eq!(summary.network_cost, 0.0);
+        assert!((summary.cpu_cost - 2.0).abs() < 1e-9);
+        assert!((summary.total_cost - 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn cost_fields_keep_their_usd_names() {
+        let summary = serde_json::to_value(MetricCostSummaryDto::default()).unwrap();
+        assert!(summary.get("total_cost_usd").is_some());
+        assert!(summary.get("total_cost").is_none());
+
+        let point = serde_json::to_value(CostMetricDto::default()).unwrap();
+        assert!(point.get("cpu_cost_usd").is_some());
+    }
+
+    #[test]
+    fn idle_share_scales_cpu_and_memory_only() {
+        let prices = InfoUnitPriceEntity {
+            cpu_core_hour: 1.0,
+            memory_gb_hour: 1.0,
+            storage_gb_hour: 1.0,
+            ..Default::default()
+        };
+        let mut response = one_hour_series("pod-a", 3600.0 * 1e9, BYTES_PER_GB);
+        apply_costs(&mut response, &prices, &CostMode::Showback, &HashMap::new(), &HashMap::new());
+
+        // Half of the cluster capacity is unallocated: workloads carry 2x.
+        let cluster = MetricCostBreakdownDto {
+            capacity_cost: 20.0,
+            allocated_cost: 10.0,
+            system_overhead_cost: 2.0,
+            idle_cost: 8.0,
+        };
+        apply_idle_share(&mut response, &cluster);
+
+        let cost = response.series[0].points[0].cost.clone().unwrap();
+        assert!((cost.cpu_cost.unwrap() - 2.0).abs() < 1e-9);
+        assert!((cost.memory_cost.unwrap() - 2.0).abs() < 1e-9);
+        assert!((cost.total_cost.unwrap() - 4.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn spot_series_use_spot_rates() {
+        let prices = InfoUnitPriceEntity {
+            cpu_core_hour: 1.0,
+            cpu_spot_core_hour: 0.25,
+            memory_gb_hour: 1.0,
+            memory_spot_gb_hour: 0.5,
+            ..Default::default()
+        };
+        let spot = HashMap::from([("pod-a".to_string(), prices.spot_prices())]);
+
+        let mut response = one_hour_series("pod-a", 3600.0 * 1e9, BYTES_PER_GB);
+        apply_costs(&mut response, &prices, &CostMode::Showback, &HashMap::new(), &spot);
+        let cost = response.series[0].points[0].cost.clone().unwrap();
+        assert!((cost.cpu_cost.unwrap() - 0.25).abs() < 1e-9);
+        assert!((cost.memory_cost.unwrap() - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn gpu_cost_bills_attached_gpus_per_hour() {
+        use crate::domain::metric::k8s::common::dto::GpuMetricDto;
+
+        let prices = InfoUnitPriceEntity {
+            gpu_hour: 2.0,
+            gpu_spot_hour: 0.5,
+            ..Default::default()
+        };
+        let spot = HashMap::from([("pod-b".to_string(), prices.spot_prices())]);
+
+        let mut response = one_hour_series("pod-a", 0.0, 0.0);
+        response.series.push(one_hour_series("pod-b", 0.0, 0.0).series.remove(0));
+        for point in response.series.iter_mut().flat_map(|s| s.points.iter_mut()) {
+            point.gpu = Some(GpuMetricDto { gpu_count: Some(2.0), ..Default::default() });
+        }
+
+        apply_costs(&mut response, &prices, &CostMode::Showback, &HashMap::new(), &spot);
+        let on_demand = response.series[0].points[0].cost.clone().unwrap();
+        let spot = response.series[1].points[0].cost.clone().unwrap();
+        assert!((on_demand.gpu_cost.unwrap() - 4.0).abs() < 1e-9);
+        assert!((on_demand.total_cost.unwrap() - 4.0).abs() < 1e-9);
+        assert!((spot.gpu_cost.unwrap() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn network_cost_prices_each_locality_and_bills_the_rest_as_external() {
+        let prices = InfoUnitPriceEntity {
+            network_local_gb: 0.0,
+            network_regional_gb: 0.01,
+            network_external_gb: 0.1,
+            ..Default::default()
+        };
+        // 10 GB sent: 4 GB same zone, 2 GB cross zone, 1 GB internet, 3 GB unclassified
+        let network = NetworkMetricDto {
+            tx_bytes: Some(10.0 * BYTES_PER_GB),
+            local_bytes: Some(4.0 * BYTES_PER_GB),
+            regional_bytes: Some(2.0 * BYTES_PER_GB),
+            external_bytes: Some(BYTES_PER_GB),
+            ..Default::default()
+        };
+
+        let cost = network_cost_by_locality(&network, &prices);
+        assert!(cost.local_cost.abs() < 1e-9);
+        assert!((cost.regional_cost - 0.02).abs() < 1e-9);
+        assert!((cost.external_cost - 0.4).abs() < 1e-9);
+        assert!((cost.total_cost - 0.42).abs() < 1e-9);
+
+        // Without flow data everything is external, as before
+        let legacy = NetworkMetricDto { tx_bytes: Some(10.0 * BYTES_PER_GB), ..Default::default() };
+        assert!((network_cost_by_locality(&legacy, &prices).total_cost - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn fixed_price_node_splits_hourly_price_by_weighted_share() {
+        use crate::core::persistence::info::k8s::node::info_node_entity::NodePricePeriod;
+
+        let prices = InfoUnitPriceEntity {
+            cpu_core_hour: 3.0,
+            memory_gb_hour: 1.0,
+            ..Default::default()
+        };
+        // 4 cores + 4 GB: CPU weighs 12, memory 4 → 75% / 25% of $24/day
+        let node = InfoNodeEntity {
+            cpu_capacity_cores: Some(4),
+            memory_capacity_bytes: Some(4 * 1_073_741_824),
+            fixed_instance_usd: Some(24.0),
+            price_period: Some(NodePricePeriod::Day),
+            ..Default::default()
+        };
+
+        let effective = node_unit_prices(&node, &prices);
+        assert!((effective.cpu_core_hour * 4.0 - 0.75).abs() < 1e-9);
+        assert!((effective.memory_gb_hour * 4.0 - 0.25).abs() < 1e-9);
+    }
+
+    #[test]
+    fn catalog_price_skips_spot_nodes_and_keeps_gpu_price() {
+        use crate::core::persistence::info::k8s::node::info_node_entity::NodePricePeriod;
+
+        let prices = InfoUnitPriceEntity {
+            cpu_core_hour: 3.0,
+            cpu_spot_core_hour: 1.0,
+            memory_gb_hour: 1.0,
+            gpu_hour: 2.0,
+            ..Default::default()
+        };
+        let mut node = InfoNodeEntity {
+            cpu_capacity_cores: Some(4),
+            memory_capacity_bytes: Some(4 * 1_073_741_824),
+            fixed_instance_usd: Some(1.0),
+            price_period: Some(NodePricePeriod::Hour),
+            price_source: Some(NodePriceSource::Catalog),
+            ..Default::default()
+        };
+
+        let on_demand = node_unit_prices(&node, &prices);
+        assert!((on_demand.cpu_core_hour * 4.0 - 0.75).abs() < 1e-9);
+        assert_eq!(on_demand.gpu_hour, 2.0);
+
+        node.capacity_type = Some(NodeCapacityType::Spot);
+        assert_eq!(node_unit_prices(&node, &prices).cpu_core_hour, 1.0);
+    }
+
+    #[test]
+    fn fixed_node_price_change_keeps_earlier_price() {
+        use crate::core::persistence::info::k8s::node::info_node_entity::NodePricePeriod;
+
+        let prices = InfoUnitPriceEntity {
+            cpu_core_hour: 3.0,
+            memory_gb_hour: 1.0,
+            ..Default::default()
+        };
+        let mut node = InfoNodeEntity {
+            cpu_capacity_cores: Some(4),
+            memory_capacity_bytes: Some(4 * 1_073_741_824),
+            fixed_instance_usd: Some(24.0),
+            price_period: Some(NodePricePeriod::Day),
+            ..Default::default()
+        };
+        let changed_at = Utc::now() - chrono::Duration::days(1);
+        node.set_fixed_price(Some(48.0), Some(NodePricePeriod::Day), changed_at);
+
+        let effective = node_unit_prices(&node, &prices);
+        let before = effective.at(changed_at - chrono::Duration::hours(1));
+        let after = effective.at(changed_at + chrono::Duration::hours(1));
+        assert!((before.cpu_core_hour * 4.0 - 0.75).abs() < 1e-9);
+        assert!((after.cpu_core_hour * 4.0 - 1.5).abs() < 1e-9);
+        assert!((effective.cpu_core_hour * 4.0 - 1.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn converted_prices_scale_fixed_node_prices_too() {
+        use crate::core::persistence::info::fixed::exchange_rate::info_exchange_rate_entity::InfoExchangeRateEntity;
+        use crate::core::persistence::info::fixed::unit_price::info_unit_price_entity::Currency;
+        use crate::core::persistence::info::k8s::node::info_node_entity::NodePricePeriod;
+
+        let mut rates = InfoExchangeRateEntity::default();
+        rates.rates.insert(Currency::EUR, 0.5);
+        let prices = InfoUnitPriceEntity {
+            cpu_core_hour: 3.0,
+            memory_gb_hour: 1.0,
+            ..Default::default()
+        };
+
+        let eur = prices.converted(Currency::EUR, &rates).unwrap();
+        assert_eq!(eur.currency, Currency::EUR);
+        assert!((eur.cpu_core_hour - 1.5).abs() < 1e-9);
+        assert!(prices.converted(Currency::KRW, &rates).is_err());
+
+        // $24/day fixed node → €12/day split 75% / 25%
+        let node = InfoNodeEntity {
+            cpu_capacity_cores: Some(4),
+            memory_capacity_bytes: Some(4 * 1_073_741_824),
+            fixed_instance_usd: Some(24.0),
+            price_period: Some(NodePricePeriod::Day),
+            ..Default::default()
+        };
+        let effective = node_unit_prices(&node, &eur);
+        assert!((effective.cpu_core_hour * 4.0 - 0.375).abs() < 1e-9);
+    }
+
+    #[test]
+    fn price_change_does_not_reprice_earlier_points() {
+        let mut response = one_hour_series("pod-a", 3_600e9, 0.0);
+        let first = response.series[0].points[0].time;
+        let second = response.series[0].points[1].time;
+
+        let version = |cpu_core_hour, from| InfoUnitPriceEntity {
+            cpu_core_hour,
+            effective_from: Some(from),
+            ..Default::default()
+        };
+        let prices = InfoUnitPriceEntity {
+            history: vec![version(1.0, DateTime::UNIX_EPOCH), version(5.0, second)],
+            ..version(5.0, second)
+        };
+
+        apply_costs(&mut response, &prices, &CostMode::Showback, &HashMap::new(), &HashMap::new());
+
+        let cpu = |idx: usize| response.series[0].points[idx].cost.as_ref().unwrap().cpu_cost.unwrap();
+        assert!((cpu(0) - 1.0).abs() < 1e-9);
+        assert!((cpu(1) - 5.0).abs() < 1e-9);
+        assert_eq!(prices.at(first).cpu_core_hour, 1.0);
+    }
+}
@@ -31,7 +31,14 @@ pub fn resolve_k8s_metric_repository(
             Hour => NodeHour(Default::default()),
             Day => NodeDay(Default::default()),
         },
-        MetricScope::Namespace | MetricScope::Deployment => match granularity {
+        MetricScope::Namespace
+        | MetricScope::Deployment
+        | MetricScope::StatefulSet
+        | MetricScope::DaemonSet
+        | MetricScope::Job
+        | MetricScope::CronJob
+        | MetricScope::PersistentVolumeClaim
+        | MetricScope::Label => match granularity {
             Minute => PodMinute(Default::default()),
             Hour => PodHour(Default::default()),
             Day => PodDay(Default::default()),
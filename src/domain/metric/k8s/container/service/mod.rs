@@ -1,8 +1,11 @@
 use anyhow::Result;
 use serde_json::Value;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
-use crate::api::dto::{info_dto::K8sListQuery, metrics_dto::RangeQuery};
+use crate::api::dto::{
+    info_dto::K8sListQuery,
+    metrics_dto::{CostMode, RangeQuery},
+};
 use crate::core::persistence::info::fixed::unit_price::info_unit_price_entity::InfoUnitPriceEntity;
 use crate::core::persistence::info::k8s::container::info_container_entity::InfoContainerEntity;
 use crate::core::persistence::metrics::k8s::container::day::metric_container_day_api_repository_trait::MetricContainerDayApiRepository;
@@ -11,16 +14,18 @@ use crate::core::persistence::metrics::k8s::container::metric_container_entity::
 use crate::core::persistence::metrics::k8s::container::minute::metric_container_minute_api_repository_trait::MetricContainerMinuteApiRepository;
 use crate::domain::info::service::{info_k8s_container_service, info_unit_price_service};
 use crate::domain::metric::k8s::common::dto::{
-    CommonMetricValuesDto, FilesystemMetricDto, MetricGetResponseDto, MetricScope, MetricSeriesDto,
-    UniversalMetricPointDto,
+    CommonMetricValuesDto, FilesystemMetricDto, GpuMetricDto, MetricGetResponseDto, MetricScope,
+    MetricSeriesDto, UniversalMetricPointDto,
 };
 use crate::domain::metric::k8s::common::dto::metric_k8s_raw_summary_dto::MetricRawSummaryResponseDto;
 use crate::domain::metric::k8s::common::service_helpers::{
     apply_costs, build_cost_summary_dto, build_cost_trend_dto, build_efficiency_value,
-    build_raw_summary_value, resolve_time_window, TimeWindow, BYTES_PER_GB,
+    build_raw_summary_value, container_requests_by_key, resolve_time_window, TimeWindow,
+    BYTES_PER_GB,
 };
 use crate::domain::metric::k8s::common::util::k8s_metric_repository_resolve::resolve_k8s_metric_repository;
 use crate::domain::metric::k8s::common::util::k8s_metric_repository_variant::K8sMetricRepositoryVariant;
+use crate::domain::metric::k8s::node::service::load_node_unit_prices;
 
 fn container_metric_key(info: &InfoContainerEntity) -> Option<String> {
     match (&info.pod_uid, &info.container_name) {
@@ -60,6 +65,13 @@ fn metric_container_entity_to_point(entity: MetricContainerEntity) -> UniversalM
             memory_working_set_bytes: entity.memory_working_set_bytes.map(|v| v as f64),
             memory_rss_bytes: entity.memory_rss_bytes.map(|v| v as f64),
             memory_page_faults: entity.memory_page_faults.map(|v| v as f64),
+            cpu_usage_nano_cores_max: entity.cpu_usage_nano_cores_max.map(|v| v as f64),
+            cpu_usage_nano_cores_p95: entity.cpu_usage_nano_cores_p95.map(|v| v as f64),
+            cpu_usage_nano_cores_p99: entity.cpu_usage_nano_cores_p99.map(|v| v as f64),
+            memory_working_set_bytes_max: entity.memory_working_set_bytes_max.map(|v| v as f64),
+            memory_working_set_bytes_p95: entity.memory_working_set_bytes_p95.map(|v| v as f64),
+            memory_working_set_bytes_p99: entity.memory_working_set_bytes_p99.map(|v| v as f64),
+            ..Default::default()
         },
         filesystem: Some(FilesystemMetricDto {
             used_bytes: entity.fs_used_bytes.map(|v| v as f64),
@@ -67,6 +79,11 @@ fn metric_container_entity_to_point(entity: MetricContainerEntity) -> UniversalM
             inodes_used: entity.fs_inodes_used.map(|v| v as f64),
             inodes: entity.fs_inodes.map(|v| v as f64),
         }),
+        gpu: entity.gpu_count.map(|count| GpuMetricDto {
+            gpu_count: Some(count as f64),
+            utilization_percent: entity.gpu_utilization_percent.map(|v| v as f64),
+            memory_used_bytes: entity.gpu_memory_used_bytes.map(|v| v as f64),
+        }),
         ..Default::default()
     }
 }
@@ -149,6 +166,7 @@ async fn build_container_raw_data(
         total: None,
         limit: None,
         offset: None,
+        currency: None,
     };
 
     Ok((response, container_infos))
@@ -171,8 +189,24 @@ async fn build_container_cost_response(
     container_keys: Vec<String>,
     unit_prices: InfoUnitPriceEntity,
 ) -> Result<MetricGetResponseDto> {
-    let (mut response, _) = build_container_raw_data(q, container_keys).await?;
-    apply_costs(&mut response, &unit_prices);
+    let mode = q.mode.clone();
+    let (mut response, containers) = build_container_raw_data(q, container_keys).await?;
+
+    let requests = match mode {
+        CostMode::Showback => HashMap::new(),
+        CostMode::Chargeback => container_requests_by_key(&containers),
+    };
+
+    let node_prices = load_node_unit_prices(&unit_prices)?;
+    let series_prices = containers
+        .iter()
+        .filter_map(|c| {
+            let prices = node_prices.get(c.node_name.as_ref()?)?;
+            Some((container_metric_key(c)?, prices.clone()))
+        })
+        .collect::<HashMap<_, _>>();
+
+    apply_costs(&mut response, &unit_prices, &mode, &requests, &series_prices);
     Ok(response)
 }
 
@@ -266,7 +300,7 @@ pub async fn get_metric_k8s_containers_cost(
     q: RangeQuery,
     container_keys: Vec<String>,
 ) -> Result<Value> {
-    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
+    let unit_prices = info_unit_price_service::get_info_unit_prices_in(q.currency).await?;
     let response = build_container_cost_response(q, container_keys, unit_prices).await?;
     Ok(serde_json::to_value(response)?)
 }
@@ -275,7 +309,7 @@ pub async fn get_metric_k8s_containers_cost_summary(
     q: RangeQuery,
     container_keys: Vec<String>,
 ) -> Result<Value> {
-    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
+    let unit_prices = info_unit_price_service::get_info_unit_prices_in(q.currency).await?;
     let response =
         build_container_cost_response(q, container_keys, unit_prices.clone()).await?;
     let dto =
@@ -287,7 +321,7 @@ pub async fn get_metric_k8s_containers_cost_trend(
     q: RangeQuery,
     container_keys: Vec<String>,
 ) -> Result<Value> {
-    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
+    let unit_prices = info_unit_price_service::get_info_unit_prices_in(q.currency).await?;
     let response = build_container_cost_response(q, container_keys, unit_prices).await?;
     let dto = build_cost_trend_dto(&response, MetricScope::Container, None)?;
     Ok(serde_json::to_value(dto)?)
@@ -300,7 +334,7 @@ pub async fn get_metric_k8s_container_cost(
     q: RangeQuery,
 ) -> Result<Value> {
     let keys = vec![id.clone()];
-    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
+    let unit_prices = info_unit_price_service::get_info_unit_prices_in(q.currency).await?;
     let response = build_container_cost_response(q, keys, unit_prices).await?;
     Ok(serde_json::to_value(response)?)
 }
@@ -310,7 +344,7 @@ pub async fn get_metric_k8s_container_cost_summary(
     q: RangeQuery,
 ) -> Result<Value> {
     let keys = vec![id.clone()];
-    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
+    let unit_prices = info_unit_price_service::get_info_unit_prices_in(q.currency).await?;
     let response =
         build_container_cost_response(q, keys, unit_prices.clone()).await?;
     let dto =
@@ -323,7 +357,7 @@ pub async fn get_metric_k8s_container_cost_trend(
     q: RangeQuery,
 ) -> Result<Value> {
     let keys = vec![id.clone()];
-    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
+    let unit_prices = info_unit_price_service::get_info_unit_prices_in(q.currency).await?;
     let response = build_container_cost_response(q, keys, unit_prices).await?;
     let dto = build_cost_trend_dto(&response, MetricScope::Container, Some(id))?;
     Ok(serde_json::to_value(dto)?)
@@ -0,0 +1,215 @@
+use anyhow::{anyhow, bail, Result};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+
+use crate::api::dto::metrics_dto::RangeQuery;
+use crate::core::persistence::info::fixed::unit_price::info_unit_price_entity::InfoUnitPriceEntity;
+use crate::core::persistence::info::k8s::pod::info_pod_api_repository_trait::InfoPodApiRepository;
+use crate::core::persistence::info::{
+    k8s::pod::{info_pod_entity::InfoPodEntity, info_pod_repository::InfoPodRepository},
+    path::info_k8s_pod_dir_path,
+};
+use crate::core::persistence::storage_backend::storage_backend;
+use crate::core::util::label_selector::parse_flattened_labels;
+use crate::domain::info::service::info_unit_price_service;
+use crate::domain::metric::k8s::common::dto::{MetricGetResponseDto, MetricScope, MetricSeriesDto};
+use crate::domain::metric::k8s::common::service_helpers::{
+    apply_idle_share, build_cost_summary_dto, build_cost_trend_dto,
+};
+use crate::domain::metric::k8s::namespace::service::aggregate_namespace_points;
+use crate::domain::metric::k8s::node::service::build_cluster_cost_breakdown;
+use crate::domain::metric::k8s::pod::service::{build_pod_cost_response_from_infos, filter_pod_infos};
+
+/// Series key for pods that do not carry the grouping label.
+pub const UNLABELED_GROUP: &str = "__unlabeled__";
+
+// ------------------------------
+// Helpers
+// ------------------------------
+
+/// Reads the label key out of `group_by=label:<key>`.
+fn parse_group_by_label(group_by: Option<&str>) -> Result<String> {
+    let Some(group_by) = group_by.map(str::trim).filter(|g| !g.is_empty()) else {
+        bail!("group_by is required, e.g. 'label:app'");
+    };
+    match group_by.split_once(':') {
+        Some((kind, key)) if kind.trim().eq_ignore_ascii_case("label") && !key.trim().is_empty() => {
+            Ok(key.trim().to_string())
+        }
+        _ => bail!("Unsupported group_by '{}': expected 'label:<key>'", group_by),
+    }
+}
+
+/// Load all pods from local pod info that match the tag and label filters of `q`.
+fn load_filtered_pods(q: &RangeQuery) -> Result<Vec<InfoPodEntity>> {
+    let repo = InfoPodRepository::new();
+    let mut pods = Vec::new();
+
+    for pod_uid in storage_backend().list_keys(&info_k8s_pod_dir_path())? {
+        if let Ok(pod) = repo.read(&pod_uid) {
+            pods.push(pod);
+        }
+    }
+
+    filter_pod_infos(q, pods)
+}
+
+/// Group value of each pod, keyed by pod UID.
+fn group_values_by_pod(pods: &[InfoPodEntity], key: &str) -> HashMap<String, String> {
+    pods.iter()
+        .filter_map(|pod| {
+            let uid = pod.pod_uid.clone()?;
+            let value = parse_flattened_labels(pod.label.as_deref())
+                .remove(key)
+                .filter(|v| !v.is_empty())
+                .unwrap_or_else(|| UNLABELED_GROUP.to_string());
+            Some((uid, value))
+        })
+        .collect()
+}
+
+/// Folds per-pod series into one series per label value.
+fn aggregate_label_response(
+    key: &str,
+    per_pod_response: MetricGetResponseDto,
+    groups: &HashMap<String, String>,
+) -> MetricGetResponseDto {
+    let mut grouped: BTreeMap<String, Vec<_>> = BTreeMap::new();
+    for series in per_pod_response.series {
+        let value = groups
+            .get(&series.key)
+            .cloned()
+            .unwrap_or_else(|| UNLABELED_GROUP.to_string());
+        grouped.entry(value).or_default().extend(series.points);
+    }
+
+    let series = grouped
+        .into_iter()
+        .map(|(value, points)| MetricSeriesDto {
+            key: value.clone(),
+            name: format!("{}={}", key, value),
+            scope: MetricScope::Label,
+            points: aggregate_namespace_points(points),
+            running_hours: None,
+            cost_summary: None,
+        })
+        .collect();
+
+    MetricGetResponseDto {
+        start: per_pod_response.start,
+        end: per_pod_response.end,
+        scope: "label".to_string(),
+        target: Some(key.to_string()),
+        granularity: per_pod_response.granularity,
+        series,
+        total: None,
+        limit: None,
+        offset: None,
+        currency: per_pod_response.currency,
+    }
+}
+
+/// Applies `offset` / `limit` to the label groups (ordered by value).
+fn page_label_groups(
+    response: &mut MetricGetResponseDto,
+    offset: Option<usize>,
+    limit: Option<usize>,
+) {
+    let total = response.series.len();
+    let series = std::mem::take(&mut response.series);
+    response.series = series
+        .into_iter()
+        .skip(offset.unwrap_or(0))
+        .take(limit.unwrap_or(total))
+        .collect();
+    response.total = Some(total);
+    response.limit = limit;
+    response.offset = offset;
+}
+
+// ------------------------------
+// COST (HELPERS)
+// ------------------------------
+
+async fn build_label_cost(
+    q: RangeQuery,
+    unit_prices: &InfoUnitPriceEntity,
+) -> Result<(String, MetricGetResponseDto)> {
+    let key = parse_group_by_label(q.group_by.as_deref())?;
+    let pods = load_filtered_pods(&q)?;
+
+    if pods.is_empty() {
+        return Err(anyhow!("no pods available for label cost calculation"));
+    }
+
+    let groups = group_values_by_pod(&pods, &key);
+    let share_idle = q.share_idle.unwrap_or(false);
+    // Every pod goes into its group; paging applies to the groups afterwards
+    let pod_query = RangeQuery { limit: None, offset: None, ..q.clone() };
+    let per_pod =
+        build_pod_cost_response_from_infos(pod_query, pods, Some(key.clone()), unit_prices).await?;
+    let mut response = aggregate_label_response(&key, per_pod, &groups);
+
+    if share_idle {
+        let cluster = build_cluster_cost_breakdown(q, unit_prices).await?;
+        apply_idle_share(&mut response, &cluster);
+    }
+
+    Ok((key, response))
+}
+
+// ------------------------------
+// COST
+// ------------------------------
+
+pub async fn get_metric_k8s_labels_cost(q: RangeQuery) -> Result<Value> {
+    let unit_prices = info_unit_price_service::get_info_unit_prices_in(q.currency).await?;
+    let (offset, limit) = (q.offset, q.limit);
+    let (_, mut dto) = build_label_cost(q, &unit_prices).await?;
+    page_label_groups(&mut dto, offset, limit);
+
+    Ok(serde_json::to_value(dto)?)
+}
+
+pub async fn get_metric_k8s_labels_cost_summary(q: RangeQuery) -> Result<Value> {
+    let unit_prices = info_unit_price_service::get_info_unit_prices_in(q.currency).await?;
+    let (key, dto) = build_label_cost(q, &unit_prices).await?;
+
+    let summary = build_cost_summary_dto(&dto, MetricScope::Label, Some(key), &unit_prices);
+    Ok(serde_json::to_value(summary)?)
+}
+
+pub async fn get_metric_k8s_labels_cost_trend(q: RangeQuery) -> Result<Value> {
+    let unit_prices = info_unit_price_service::get_info_unit_prices_in(q.currency).await?;
+    let (key, dto) = build_label_cost(q, &unit_prices).await?;
+
+    let trend = build_cost_trend_dto(&dto, MetricScope::Label, Some(key))?;
+    Ok(serde_json::to_value(trend)?)
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn group_by_label_parsing_and_grouping() {
+        assert_eq!(parse_group_by_label(Some("label:app")).unwrap(), "app");
+        assert_eq!(parse_group_by_label(Some(" LABEL: app.kubernetes.io/name ")).unwrap(), "app.kubernetes.io/name");
+        assert!(parse_group_by_label(None).is_err());
+        assert!(parse_group_by_label(Some("namespace")).is_err());
+        assert!(parse_group_by_label(Some("label:")).is_err());
+
+        let pod = |uid: &str, label: Option<&str>| InfoPodEntity {
+            pod_uid: Some(uid.into()),
+            label: label.map(str::to_string),
+            ..Default::default()
+        };
+        let pods = [pod("a", Some("app=api,tier=web")), pod("b", Some("tier=db")), pod("c", None)];
+        let groups = group_values_by_pod(&pods, "app");
+        assert_eq!(groups["a"], "api");
+        assert_eq!(groups["b"], UNLABELED_GROUP);
+        assert_eq!(groups["c"], UNLABELED_GROUP);
+    }
+}
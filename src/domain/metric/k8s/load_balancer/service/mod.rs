@@ -0,0 +1,190 @@
+//! LoadBalancer, NodePort and Ingress cost.
+//!
+//! Every Service / Ingress is billed at its hourly price for the part of its
+//! recorded lifetime that falls in the window, using the price version in force
+//! for each hour. Records outlive the objects, so deleted load balancers stay
+//! billed for the windows they existed in. A Service whose type changed is
+//! billed per recorded type segment, e.g. NodePort then LoadBalancer.
+//!
+//! The cost is summary-only: it is added to the cluster and namespace cost
+//! summaries, not to the cost series or trends, which are built from
+//! per-interval metric samples.
+
+use anyhow::Result;
+use chrono::{DateTime, Duration, Utc};
+use std::collections::HashSet;
+
+use crate::api::dto::metrics_dto::RangeQuery;
+use crate::core::persistence::info::fixed::unit_price::info_unit_price_entity::InfoUnitPriceEntity;
+use crate::core::persistence::info::k8s::lifecycle::segment_ranges;
+use crate::core::persistence::info::k8s::service::info_service_api_repository_trait::InfoServiceApiRepository;
+use crate::core::persistence::info::k8s::service::info_service_entity::InfoServiceEntity;
+use crate::core::persistence::info::k8s::service::info_service_repository::InfoServiceRepository;
+use crate::core::util::label_selector::{parse_flattened_labels, LabelSelector};
+
+/// Hourly price of a Service type. ClusterIP and ExternalName services are free.
+fn hourly_price(service_type: &str, prices: &InfoUnitPriceEntity) -> f64 {
+    match service_type {
+        "LoadBalancer" => prices.load_balancer_hour,
+        "NodePort" => prices.node_port_hour,
+        _ => 0.0,
+    }
+}
+
+/// Cost of `info` during `[start, end)`, priced hour by hour. Services are
+/// billed per recorded type segment; records written before type history
+/// was kept fall back to the current type for the whole lifetime.
+fn service_cost(
+    info: &InfoServiceEntity,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    unit_prices: &InfoUnitPriceEntity,
+    now: DateTime<Utc>,
+) -> f64 {
+    let Some((created, gone)) = info.lifetime(now) else {
+        return 0.0;
+    };
+
+    let from = created.max(start);
+    let until = gone.min(end);
+    if info.is_ingress() {
+        return priced_hours(from, until, unit_prices, |p| p.ingress_hour);
+    }
+    if info.service_types.is_empty() {
+        let service_type = info.service_type.as_deref().unwrap_or_default();
+        return priced_hours(from, until, unit_prices, |p| hourly_price(service_type, p));
+    }
+
+    segment_ranges(&info.service_types, from, until)
+        .into_iter()
+        .map(|(service_type, from, to)| priced_hours(from, to, unit_prices, |p| hourly_price(service_type, p)))
+        .sum()
+}
+
+/// Sum of `price` over `[from, until)`, using the price version in force for each hour.
+fn priced_hours(
+    from: DateTime<Utc>,
+    until: DateTime<Utc>,
+    unit_prices: &InfoUnitPriceEntity,
+    price: impl Fn(&InfoUnitPriceEntity) -> f64,
+) -> f64 {
+    let mut cost = 0.0;
+    let mut t = from;
+    while t < until {
+        let next = (t + Duration::hours(1)).min(until);
+        let hours = (next - t).num_seconds() as f64 / 3600.0;
+        cost += hours * price(unit_prices.at(t));
+        t = next;
+    }
+    cost
+}
+
+/// LoadBalancer / NodePort / Ingress cost of the given namespaces (all when
+/// empty) over `[start, end)`.
+///
+/// Services carry no team/service/env tags, so nothing is billed when `q`
+/// filters on them. A label selector in `q` is matched against the Service /
+/// Ingress labels. Only the summaries include this cost; it is not spread
+/// over the cost series.
+pub(crate) fn load_balancer_cost(
+    q: &RangeQuery,
+    namespaces: &[String],
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    unit_prices: &InfoUnitPriceEntity,
+) -> Result<f64> {
+    if q.team.is_some() || q.service.is_some() || q.env.is_some() {
+        return Ok(0.0);
+    }
+    let selector = match q.labels.as_deref() {
+        Some(labels) => LabelSelector::parse(labels)?,
+        None => LabelSelector::default(),
+    };
+
+    let services = InfoServiceRepository::new().list()?;
+    Ok(sum_service_costs(&services, namespaces, &selector, start, end, unit_prices, Utc::now()))
+}
+
+fn sum_service_costs(
+    services: &[InfoServiceEntity],
+    namespaces: &[String],
+    selector: &LabelSelector,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    unit_prices: &InfoUnitPriceEntity,
+    now: DateTime<Utc>,
+) -> f64 {
+    let filters: HashSet<&str> = namespaces.iter().map(String::as_str).collect();
+
+    services
+        .iter()
+        .filter(|s| {
+            filters.is_empty() || s.namespace.as_deref().is_some_and(|ns| filters.contains(ns))
+        })
+        .filter(|s| selector.is_empty() || selector.matches(&parse_flattened_labels(s.label.as_deref())))
+        .map(|s| service_cost(s, start, end, unit_prices, now))
+        .sum()
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::persistence::info::k8s::lifecycle::LifecycleSegment;
+
+    #[test]
+    fn bills_endpoints_for_their_lifetime_within_the_window() {
+        let start = DateTime::parse_from_rfc3339("2026-01-01T00:00:00Z").unwrap().with_timezone(&Utc);
+        let end = start + Duration::hours(10);
+        let now = end + Duration::hours(1);
+
+        let old = InfoUnitPriceEntity {
+            load_balancer_hour: 0.02,
+            ingress_hour: 0.01,
+            node_port_hour: 0.005,
+            effective_from: Some(DateTime::UNIX_EPOCH),
+            ..Default::default()
+        };
+        let new = InfoUnitPriceEntity {
+            load_balancer_hour: 0.04,
+            effective_from: Some(start + Duration::hours(5)),
+            ..old.clone()
+        };
+        let prices = InfoUnitPriceEntity { history: vec![old, new.clone()], ..new };
+
+        let endpoint = |ns: &str, kind: &str, service_type: Option<&str>| InfoServiceEntity {
+            namespace: Some(ns.into()),
+            kind: Some(kind.into()),
+            service_type: service_type.map(Into::into),
+            creation_timestamp: Some(start - Duration::hours(2)),
+            ..Default::default()
+        };
+
+        // Deleted after 8h: 5h at the old price, 3h at the new one
+        let mut lb = endpoint("shop", "Service", Some("LoadBalancer"));
+        lb.deleted = Some(true);
+        lb.deleted_at = Some(start + Duration::hours(8));
+        let ingress = endpoint("shop", "Ingress", None);
+        let cluster_ip = endpoint("shop", "Service", Some("ClusterIP"));
+        let mut other = endpoint("billing", "Service", Some("LoadBalancer"));
+        other.label = Some("tier=edge".into());
+
+        // NodePort for the first 4h of the window, then LoadBalancer
+        let mut promoted = endpoint("shop", "Service", Some("LoadBalancer"));
+        promoted.service_types = vec![
+            LifecycleSegment { value: "NodePort".into(), since: start - Duration::hours(2) },
+            LifecycleSegment { value: "LoadBalancer".into(), since: start + Duration::hours(4) },
+        ];
+        let promoted_cost = 4.0 * 0.005 + 1.0 * 0.02 + 5.0 * 0.04;
+
+        let services = vec![lb, ingress, cluster_ip, other, promoted];
+        let all = LabelSelector::default();
+        let shop = sum_service_costs(&services, &["shop".into()], &all, start, end, &prices, now);
+        assert!((shop - (5.0 * 0.02 + 3.0 * 0.04 + 10.0 * 0.01 + promoted_cost)).abs() < 1e-9);
+
+        let edge = LabelSelector::parse("tier=edge").unwrap();
+        let total = sum_service_costs(&services, &[], &edge, start, end, &prices, now);
+        assert!((total - (5.0 * 0.02 + 5.0 * 0.04)).abs() < 1e-9);
+    }
+}
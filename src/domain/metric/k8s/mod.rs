@@ -1,4 +1,4 @@
-//! K8s metrics subdomain (cluster, node, pod, container)
+//! K8s metrics subdomain (cluster, node, pod, container, namespace, workloads, label, pvc, load balancer)
 
 pub mod cluster;
 pub mod node;
@@ -6,4 +6,8 @@ pub mod pod;
 pub mod container;
 pub mod namespace;
 pub mod deployment;
+pub mod label;
+pub mod pvc;
+pub mod load_balancer;
+pub mod workload;
 pub mod common;
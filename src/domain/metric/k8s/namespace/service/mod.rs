@@ -1,12 +1,11 @@
 use anyhow::{anyhow, Result};
 use chrono::{DateTime, Utc};
 use serde_json::{json, Value};
-use std::{
-    collections::{BTreeMap, HashMap, HashSet},
-    fs,
-};
+use std::collections::{BTreeMap, HashMap, HashSet};
 
+use crate::core::persistence::storage_backend::storage_backend;
 use crate::api::dto::metrics_dto::RangeQuery;
+use crate::core::persistence::info::fixed::unit_price::info_unit_price_entity::InfoUnitPriceEntity;
 use crate::core::persistence::info::{
     k8s::pod::{info_pod_entity::InfoPodEntity, info_pod_repository::InfoPodRepository},
     path::info_k8s_pod_dir_path,
@@ -15,41 +14,47 @@ use crate::core::persistence::info::k8s::pod::info_pod_api_repository_trait::Inf
 use crate::domain::info::service::info_unit_price_service;
 
 use crate::domain::metric::k8s::common::dto::{
-    FilesystemMetricDto, MetricGetResponseDto, MetricScope,
-    MetricSeriesDto, NetworkMetricDto, UniversalMetricPointDto,
+    CostMetricDto, FilesystemMetricDto, GpuMetricDto, MetricGetResponseDto, MetricScope,
+    MetricSeriesDto, NetworkCostDto, NetworkMetricDto, UniversalMetricPointDto,
 };
 use crate::domain::metric::k8s::common::service_helpers::{
-    apply_costs, build_cost_summary_dto, build_cost_trend_dto, build_raw_summary_value,
+    apply_idle_share, build_cost_summary_dto, build_cost_trend_dto, build_raw_summary_value,
 };
+use crate::domain::metric::k8s::load_balancer::service::load_balancer_cost;
+use crate::domain::metric::k8s::pvc::service::build_pvc_cost_breakdown;
+use crate::domain::metric::k8s::node::service::build_cluster_cost_breakdown;
 
-use crate::domain::metric::k8s::pod::service::build_pod_response_from_infos;
+use crate::domain::metric::k8s::pod::service::{
+    build_pod_cost_response_from_infos, build_pod_response_from_infos, filter_pod_infos,
+};
 
 // =====================================================================
 // HELPERS
 // =====================================================================
 
 /// Load pods grouped by namespace from the local repository.
-fn load_pods_by_namespace(namespaces: &[String]) -> Result<HashMap<String, Vec<InfoPodEntity>>> {
-    let mut map = HashMap::new();
-    let dir = info_k8s_pod_dir_path();
-
-    if !dir.exists() {
-        return Ok(map);
-    }
-
+///
+/// Pods not matching the tag and label filters of `q` are left out.
+fn load_pods_by_namespace(
+    q: &RangeQuery,
+    namespaces: &[String],
+) -> Result<HashMap<String, Vec<InfoPodEntity>>> {
+    let mut map: HashMap<String, Vec<InfoPodEntity>> = HashMap::new();
     let filters: HashSet<String> = namespaces.iter().cloned().collect();
     let allow_all = filters.is_empty();
     let repo = InfoPodRepository::new();
 
-    for entry in fs::read_dir(dir)? {
-        let entry = entry?;
-        let pod_uid = entry.file_name().to_string_lossy().to_string();
-
+    let mut pods = Vec::new();
+    for pod_uid in storage_backend().list_keys(&info_k8s_pod_dir_path())? {
         if let Ok(pod) = repo.read(&pod_uid) {
-            if let Some(ns) = pod.namespace.clone() {
-                if allow_all || filters.contains(&ns) {
-                    map.entry(ns).or_default().push(pod);
-                }
+            pods.push(pod);
+        }
+    }
+
+    for pod in filter_pod_infos(q, pods)? {
+        if let Some(ns) = pod.namespace.clone() {
+            if allow_all || filters.contains(&ns) {
+                map.entry(ns).or_default().push(pod);
             }
         }
     }
@@ -58,8 +63,8 @@ fn load_pods_by_namespace(namespaces: &[String]) -> Result<HashMap<String, Vec<I
 }
 
 /// Load all pods for a specific namespace (errors if none found).
-fn namespace_pods(ns: &str) -> Result<Vec<InfoPodEntity>> {
-    let map = load_pods_by_namespace(&[ns.to_string()])?;
+fn namespace_pods(q: &RangeQuery, ns: &str) -> Result<Vec<InfoPodEntity>> {
+    let map = load_pods_by_namespace(q, &[ns.to_string()])?;
 
     if let Some(pods) = map.get(ns) {
         if !pods.is_empty() {
@@ -70,8 +75,8 @@ fn namespace_pods(ns: &str) -> Result<Vec<InfoPodEntity>> {
     Err(anyhow!("namespace '{}' has no pods", ns))
 }
 
-fn all_pods_for(namespaces: &[String]) -> Result<Vec<InfoPodEntity>> {
-    let map = load_pods_by_namespace(namespaces)?;
+fn all_pods_for(q: &RangeQuery, namespaces: &[String]) -> Result<Vec<InfoPodEntity>> {
+    let map = load_pods_by_namespace(q, namespaces)?;
     Ok(map.into_values().flatten().collect())
 }
 
@@ -106,6 +111,7 @@ fn build_namespace_response(
         total: None,
         limit: None,
         offset: None,
+        currency: per_pod.currency,
     }
 }
 
@@ -161,7 +167,43 @@ pub fn aggregate_namespace_points(
                 sum(&mut outnet.tx_bytes, net.tx_bytes);
                 sum(&mut outnet.rx_errors, net.rx_errors);
                 sum(&mut outnet.tx_errors, net.tx_errors);
+                sum(&mut outnet.local_bytes, net.local_bytes);
+                sum(&mut outnet.regional_bytes, net.regional_bytes);
+                sum(&mut outnet.external_bytes, net.external_bytes);
             }
+
+            // Costs are applied per pod, so the aggregate is a plain sum.
+            if let Some(cost) = p.cost.as_ref() {
+                let outcost = acc.cost.get_or_insert(CostMetricDto::default());
+                sum(&mut outcost.total_cost, cost.total_cost);
+                sum(&mut outcost.cpu_cost, cost.cpu_cost);
+                sum(&mut outcost.memory_cost, cost.memory_cost);
+                sum(&mut outcost.storage_cost, cost.storage_cost);
+                sum(&mut outcost.gpu_cost, cost.gpu_cost);
+                if let Some(net) = cost.network_cost {
+                    let outnet = outcost.network_cost.get_or_insert_with(NetworkCostDto::default);
+                    outnet.total_cost += net.total_cost;
+                    outnet.local_cost += net.local_cost;
+                    outnet.regional_cost += net.regional_cost;
+                    outnet.external_cost += net.external_cost;
+                }
+            }
+
+            if let Some(gpu) = p.gpu.as_ref() {
+                let outgpu = acc.gpu.get_or_insert(GpuMetricDto::default());
+                sum(&mut outgpu.gpu_count, gpu.gpu_count);
+                sum(&mut outgpu.memory_used_bytes, gpu.memory_used_bytes);
+                // Weighted by GPU count here, normalized after the loop
+                let weight = gpu.gpu_count.unwrap_or(0.0);
+                sum(&mut outgpu.utilization_percent, gpu.utilization_percent.map(|u| u * weight));
+            }
+        }
+
+        if let Some(gpu) = acc.gpu.as_mut() {
+            gpu.utilization_percent = match (gpu.utilization_percent, gpu.gpu_count) {
+                (Some(weighted), Some(count)) if count > 0.0 => Some(weighted / count),
+                _ => None,
+            };
         }
 
         out.push(acc);
@@ -180,7 +222,7 @@ pub async fn get_metric_k8s_namespaces_raw(
     namespaces: Vec<String>
 ) -> Result<Value> {
 
-    let ns_map = load_pods_by_namespace(&namespaces)?;
+    let ns_map = load_pods_by_namespace(&q, &namespaces)?;
 
     let targets =
         if namespaces.is_empty() {
@@ -227,7 +269,7 @@ pub async fn get_metric_k8s_namespace_raw(
     q: RangeQuery
 ) -> Result<Value> {
 
-    let pods = namespace_pods(&ns)?;
+    let pods = namespace_pods(&q, &ns)?;
     let per_pod = build_pod_response_from_infos(q, pods, Some(ns.clone()))?;
     let aggregated = build_namespace_response(&ns, &per_pod);
 
@@ -244,7 +286,7 @@ pub async fn get_metric_k8s_namespaces_raw_summary(
     namespaces: Vec<String>
 ) -> Result<Value> {
 
-    let ns_map = load_pods_by_namespace(&namespaces)?;
+    let ns_map = load_pods_by_namespace(&q, &namespaces)?;
 
     let targets =
         if namespaces.is_empty() {
@@ -277,7 +319,7 @@ pub async fn get_metric_k8s_namespace_raw_summary(
     q: RangeQuery
 ) -> Result<Value> {
 
-    let pods = namespace_pods(&ns)?;
+    let pods = namespace_pods(&q, &ns)?;
     let per_pod = build_pod_response_from_infos(q, pods.clone(), Some(ns.clone()))?;
     let aggregated = build_namespace_response(&ns, &per_pod);
 
@@ -318,40 +360,56 @@ async fn build_namespace_cost(
     namespace: Option<String>,
     q: RangeQuery,
     filter_namespaces: &[String],
+    unit_prices: &InfoUnitPriceEntity,
 ) -> Result<MetricGetResponseDto> {
 
     let pods = match namespace.as_ref() {
-        Some(ns) => namespace_pods(ns)?,
-        None => all_pods_for(filter_namespaces)?,
+        Some(ns) => namespace_pods(&q, ns)?,
+        None => all_pods_for(&q, filter_namespaces)?,
     };
 
     if pods.is_empty() {
         return Err(anyhow!("no pods available for namespace cost calculation"));
     }
 
-    let per_pod = build_pod_response_from_infos(q, pods, namespace.clone())?;
+    let share_idle = q.share_idle.unwrap_or(false);
+    let per_pod =
+        build_pod_cost_response_from_infos(q.clone(), pods, namespace.clone(), unit_prices).await?;
 
-    Ok(build_namespace_response(
+    let mut response = build_namespace_response(
         namespace.as_deref().unwrap_or("all"),
         &per_pod,
-    ))
+    );
+
+    if share_idle {
+        let cluster = build_cluster_cost_breakdown(q, unit_prices).await?;
+        apply_idle_share(&mut response, &cluster);
+    }
+
+    Ok(response)
 }
 
 
 // MULTIPLE NS
+/// Per-point cost of each namespace. Load balancer, NodePort and Ingress
+/// cost is not spread over points; only the cost summaries include it.
 pub async fn get_metric_k8s_namespaces_cost(
     q: RangeQuery,
     namespaces: Vec<String>
 ) -> Result<Value> {
-    let aggregated = build_namespace_cost(None, q, &namespaces).await?;
+    let unit_prices = info_unit_price_service::get_info_unit_prices_in(q.currency).await?;
+    let aggregated = build_namespace_cost(None, q, &namespaces, &unit_prices).await?;
     Ok(serde_json::to_value(aggregated)?)
 }
 
+/// Per-point cost of one namespace, without service endpoint cost (see
+/// [`get_metric_k8s_namespaces_cost`]).
 pub async fn get_metric_k8s_namespace_cost(
     ns: String,
     q: RangeQuery
 ) -> Result<Value> {
-    let aggregated = build_namespace_cost(Some(ns), q, &[]).await?;
+    let unit_prices = info_unit_price_service::get_info_unit_prices_in(q.currency).await?;
+    let aggregated = build_namespace_cost(Some(ns), q, &[], &unit_prices).await?;
     Ok(serde_json::to_value(aggregated)?)
 }
 
@@ -364,13 +422,19 @@ pub async fn get_metric_k8s_namespaces_cost_summary(
     namespaces: Vec<String>
 ) -> Result<Value> {
 
-    let aggregated = build_namespace_cost(None, q.clone(), &namespaces).await?;
-    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
+    let unit_prices = info_unit_price_service::get_info_unit_prices_in(q.currency).await?;
+    let cost_resp = build_namespace_cost(None, q.clone(), &namespaces, &unit_prices).await?;
 
-    let mut cost_resp = aggregated.clone();
-    apply_costs(&mut cost_resp, &unit_prices);
-
-    let dto = build_cost_summary_dto(&cost_resp, MetricScope::Namespace, None, &unit_prices);
+    let mut dto = build_cost_summary_dto(&cost_resp, MetricScope::Namespace, None, &unit_prices);
+    dto.summary.add_load_balancer_cost(load_balancer_cost(
+        &q,
+        &namespaces,
+        dto.start,
+        dto.end,
+        &unit_prices,
+    )?);
+    let pvc = build_pvc_cost_breakdown(&q, &unit_prices).await?;
+    dto.summary.set_persistent_storage_cost(pvc.namespace_cost(&namespaces));
     Ok(serde_json::to_value(dto)?)
 }
 
@@ -379,18 +443,24 @@ pub async fn get_metric_k8s_namespace_cost_summary(
     q: RangeQuery
 ) -> Result<Value> {
 
-    let aggregated = build_namespace_cost(Some(ns.clone()), q.clone(), &[]).await?;
-    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
-
-    let mut cost_resp = aggregated.clone();
-    apply_costs(&mut cost_resp, &unit_prices);
+    let unit_prices = info_unit_price_service::get_info_unit_prices_in(q.currency).await?;
+    let cost_resp = build_namespace_cost(Some(ns.clone()), q.clone(), &[], &unit_prices).await?;
 
-    let dto = build_cost_summary_dto(
+    let mut dto = build_cost_summary_dto(
         &cost_resp,
         MetricScope::Namespace,
-        Some(ns),
+        Some(ns.clone()),
         &unit_prices,
     );
+    dto.summary.add_load_balancer_cost(load_balancer_cost(
+        &q,
+        std::slice::from_ref(&ns),
+        dto.start,
+        dto.end,
+        &unit_prices,
+    )?);
+    let pvc = build_pvc_cost_breakdown(&q, &unit_prices).await?;
+    dto.summary.set_persistent_storage_cost(pvc.namespace_cost(&[ns]));
 
     Ok(serde_json::to_value(dto)?)
 }
@@ -399,31 +469,28 @@ pub async fn get_metric_k8s_namespace_cost_summary(
 
 // COST TREND
 
+/// Trend of the namespace cost series, so it leaves out the service endpoint
+/// cost the summaries add.
 pub async fn get_metric_k8s_namespaces_cost_trend(
     q: RangeQuery,
     namespaces: Vec<String>
 ) -> Result<Value> {
 
-    let aggregated = build_namespace_cost(None, q.clone(), &namespaces).await?;
-    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
-
-    let mut cost_resp = aggregated.clone();
-    apply_costs(&mut cost_resp, &unit_prices);
+    let unit_prices = info_unit_price_service::get_info_unit_prices_in(q.currency).await?;
+    let cost_resp = build_namespace_cost(None, q, &namespaces, &unit_prices).await?;
 
     let dto = build_cost_trend_dto(&cost_resp, MetricScope::Namespace, None)?;
     Ok(serde_json::to_value(dto)?)
 }
 
+/// Trend of one namespace's cost series, without service endpoint cost.
 pub async fn get_metric_k8s_namespace_cost_trend(
     ns: String,
     q: RangeQuery
 ) -> Result<Value> {
 
-    let aggregated = build_namespace_cost(Some(ns.clone()), q.clone(), &[]).await?;
-    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
-
-    let mut cost_resp = aggregated.clone();
-    apply_costs(&mut cost_resp, &unit_prices);
+    let unit_prices = info_unit_price_service::get_info_unit_prices_in(q.currency).await?;
+    let cost_resp = build_namespace_cost(Some(ns.clone()), q, &[], &unit_prices).await?;
 
     let dto =
         build_cost_trend_dto(&cost_resp, MetricScope::Namespace, Some(ns))?;
@@ -1,11 +1,17 @@
 use anyhow::{anyhow, Result};
 use serde_json::Value;
+use std::collections::{HashMap, HashSet};
 
+use crate::core::persistence::storage_backend::storage_backend;
 use crate::api::dto::metrics_dto::RangeQuery;
 use crate::core::persistence::info::fixed::unit_price::info_unit_price_entity::InfoUnitPriceEntity;
 use crate::core::persistence::info::k8s::node::info_node_api_repository_trait::InfoNodeApiRepository;
 use crate::core::persistence::info::k8s::node::info_node_entity::InfoNodeEntity;
 use crate::core::persistence::info::k8s::node::info_node_repository::InfoNodeRepository;
+use crate::core::persistence::info::k8s::pod::info_pod_api_repository_trait::InfoPodApiRepository;
+use crate::core::persistence::info::k8s::pod::info_pod_entity::InfoPodEntity;
+use crate::core::persistence::info::k8s::pod::info_pod_repository::InfoPodRepository;
+use crate::core::persistence::info::path::{info_k8s_node_dir_path, info_k8s_pod_dir_path};
 use crate::core::persistence::metrics::k8s::node::day::metric_node_day_repository::MetricNodeDayRepository;
 use crate::core::persistence::metrics::k8s::node::hour::metric_node_hour_api_repository_trait::MetricNodeHourApiRepository;
 use crate::core::persistence::metrics::k8s::node::hour::metric_node_hour_repository::MetricNodeHourRepository;
@@ -13,18 +19,23 @@ use crate::core::persistence::metrics::k8s::node::metric_node_entity::MetricNode
 use crate::core::persistence::metrics::k8s::node::minute::metric_node_minute_api_repository_trait::MetricNodeMinuteApiRepository;
 use crate::domain::common::service::day_granularity::split_day_granularity_rows;
 use crate::domain::info::service::info_unit_price_service;
+use crate::domain::metric::k8s::common::dto::metric_k8s_cost_breakdown_dto::{
+    MetricCostBreakdownDto, MetricCostBreakdownResponseDto, MetricNodeCostBreakdownDto,
+};
 use crate::domain::metric::k8s::common::dto::metric_k8s_raw_summary_dto::MetricRawSummaryResponseDto;
 use crate::domain::metric::k8s::common::dto::{
-    CommonMetricValuesDto, FilesystemMetricDto, MetricGetResponseDto, MetricScope, MetricSeriesDto,
-    NetworkMetricDto, UniversalMetricPointDto,
+    CommonMetricValuesDto, FilesystemMetricDto, GpuMetricDto, MetricGetResponseDto, MetricGranularity, MetricScope,
+    MetricSeriesDto, NetworkMetricDto, UniversalMetricPointDto,
 };
 use crate::domain::metric::k8s::common::service_helpers::{
     apply_node_costs, build_cost_summary_dto, build_cost_trend_dto, build_efficiency_value,
-    build_node_cost_summary_dto, build_raw_summary_value, resolve_time_window, TimeWindow,
+    build_node_cost_summary_dto, build_raw_summary_value, granularity_interval_hours,
+    node_unit_prices, point_interval_hours_from_timestamps, resolve_time_window, TimeWindow,
     BYTES_PER_GB,
 };
 use crate::domain::metric::k8s::common::util::k8s_metric_repository_resolve::resolve_k8s_metric_repository;
 use crate::domain::metric::k8s::common::util::k8s_metric_repository_variant::K8sMetricRepositoryVariant;
+use crate::domain::metric::k8s::pod::service::build_pod_cost_response_from_infos;
 
 fn fetch_node_points(
     repo: &K8sMetricRepositoryVariant,
@@ -93,6 +104,14 @@ fn metric_node_entity_to_point(entity: MetricNodeEntity) -> UniversalMetricPoint
             memory_working_set_bytes: entity.memory_working_set_bytes.map(|v| v as f64),
             memory_rss_bytes: entity.memory_rss_bytes.map(|v| v as f64),
             memory_page_faults: entity.memory_page_faults.map(|v| v as f64),
+            cpu_usage_nano_cores_max: entity.cpu_usage_nano_cores_max.map(|v| v as f64),
+            cpu_usage_nano_cores_p95: entity.cpu_usage_nano_cores_p95.map(|v| v as f64),
+            cpu_usage_nano_cores_p99: entity.cpu_usage_nano_cores_p99.map(|v| v as f64),
+            memory_working_set_bytes_max: entity.memory_working_set_bytes_max.map(|v| v as f64),
+            memory_working_set_bytes_p95: entity.memory_working_set_bytes_p95.map(|v| v as f64),
+            memory_working_set_bytes_p99: entity.memory_working_set_bytes_p99.map(|v| v as f64),
+            system_cpu_usage_nano_cores: entity.system_cpu_usage_nano_cores.map(|v| v as f64),
+            system_memory_working_set_bytes: entity.system_memory_working_set_bytes.map(|v| v as f64),
         },
         filesystem: Some(FilesystemMetricDto {
             used_bytes: entity.fs_used_bytes.map(|v| v as f64),
@@ -105,6 +124,12 @@ fn metric_node_entity_to_point(entity: MetricNodeEntity) -> UniversalMetricPoint
             tx_bytes: entity.network_physical_tx_bytes.map(|v| v as f64),
             rx_errors: entity.network_physical_rx_errors.map(|v| v as f64),
             tx_errors: entity.network_physical_tx_errors.map(|v| v as f64),
+            ..Default::default()
+        }),
+        gpu: entity.gpu_count.map(|count| GpuMetricDto {
+            gpu_count: Some(count as f64),
+            utilization_percent: entity.gpu_utilization_percent.map(|v| v as f64),
+            memory_used_bytes: entity.gpu_memory_used_bytes.map(|v| v as f64),
         }),
         ..Default::default()
     }
@@ -199,6 +224,7 @@ async fn build_node_raw_data(
         total: Some(total),
         limit: Some(limit),
         offset: Some(offset),
+        currency: None,
     };
 
     Ok((response, page_slice))
@@ -307,7 +333,7 @@ async fn build_node_cost_response_v2(
 }
 
 pub async fn get_metric_k8s_nodes_cost(q: RangeQuery, node_names: Vec<String>) -> Result<Value> {
-    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
+    let unit_prices = info_unit_price_service::get_info_unit_prices_in(q.currency).await?;
     let response = build_node_cost_response(q, node_names, unit_prices).await?;
     Ok(serde_json::to_value(response)?)
 }
@@ -316,7 +342,7 @@ pub async fn get_metric_k8s_nodes_cost_summary(
     q: RangeQuery,
     node_names: Vec<String>,
 ) -> Result<Value> {
-    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
+    let unit_prices = info_unit_price_service::get_info_unit_prices_in(q.currency).await?;
     let response = build_node_cost_response(q, node_names, unit_prices.clone()).await?;
     let dto = build_node_cost_summary_dto(&response, MetricScope::Node, None, &unit_prices);
     Ok(serde_json::to_value(dto)?)
@@ -327,7 +353,7 @@ pub async fn get_metric_k8s_nodes_cost_summary_v2(
     q: RangeQuery,
     node_names: Vec<String>,
 ) -> Result<Value> {
-    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
+    let unit_prices = info_unit_price_service::get_info_unit_prices_in(q.currency).await?;
     let response = build_node_cost_response(q, node_names, unit_prices.clone()).await?;
     let dto = build_cost_summary_dto(&response, MetricScope::Node, None, &unit_prices);
     Ok(serde_json::to_value(dto)?)
@@ -337,7 +363,7 @@ pub async fn get_metric_k8s_nodes_cost_trend(
     q: RangeQuery,
     node_names: Vec<String>,
 ) -> Result<Value> {
-    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
+    let unit_prices = info_unit_price_service::get_info_unit_prices_in(q.currency).await?;
     let response = build_node_cost_response(q, node_names, unit_prices).await?;
     let dto = build_cost_trend_dto(&response, MetricScope::Node, None)?;
     Ok(serde_json::to_value(dto)?)
@@ -345,14 +371,14 @@ pub async fn get_metric_k8s_nodes_cost_trend(
 
 pub async fn get_metric_k8s_node_cost(node_name: String, q: RangeQuery) -> Result<Value> {
     let names = vec![node_name];
-    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
+    let unit_prices = info_unit_price_service::get_info_unit_prices_in(q.currency).await?;
     let response = build_node_cost_response(q, names, unit_prices).await?;
     Ok(serde_json::to_value(response)?)
 }
 
 pub async fn get_metric_k8s_node_cost_summary(node_name: String, q: RangeQuery) -> Result<Value> {
     let names = vec![node_name.clone()];
-    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
+    let unit_prices = info_unit_price_service::get_info_unit_prices_in(q.currency).await?;
     let response = build_node_cost_response(q, names, unit_prices.clone()).await?;
     let dto = build_cost_summary_dto(&response, MetricScope::Node, Some(node_name), &unit_prices);
     Ok(serde_json::to_value(dto)?)
@@ -360,8 +386,227 @@ pub async fn get_metric_k8s_node_cost_summary(node_name: String, q: RangeQuery)
 
 pub async fn get_metric_k8s_node_cost_trend(node_name: String, q: RangeQuery) -> Result<Value> {
     let names = vec![node_name.clone()];
-    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
+    let unit_prices = info_unit_price_service::get_info_unit_prices_in(q.currency).await?;
     let response = build_node_cost_response(q, names, unit_prices).await?;
     let dto = build_cost_trend_dto(&response, MetricScope::Node, Some(node_name))?;
     Ok(serde_json::to_value(dto)?)
 }
+
+// =====================================================================
+// COST BREAKDOWN (capacity = allocated + system overhead + idle)
+// =====================================================================
+
+/// All node names known to the local info store.
+pub(crate) fn list_node_names() -> Result<Vec<String>> {
+    let mut names = storage_backend().list_keys(&info_k8s_node_dir_path())?;
+    names.sort();
+    Ok(names)
+}
+
+/// Effective unit prices per node name (spot rates, fixed node prices).
+pub(crate) fn load_node_unit_prices(
+    unit_prices: &InfoUnitPriceEntity,
+) -> Result<HashMap<String, InfoUnitPriceEntity>> {
+    let repo = InfoNodeRepository::new();
+    let prices = list_node_names()?
+        .into_iter()
+        .filter_map(|name| {
+            let node = repo.read(&name).ok()?;
+            Some((name, node_unit_prices(&node, unit_prices)))
+        })
+        .collect();
+    Ok(prices)
+}
+
+/// Load pods scheduled on any of the given nodes from the local repository.
+pub(crate) fn load_pods_on_nodes(node_names: &[String]) -> Result<Vec<InfoPodEntity>> {
+    let nodes: HashSet<&str> = node_names.iter().map(String::as_str).collect();
+    let repo = InfoPodRepository::new();
+    let mut pods = Vec::new();
+
+    for pod_uid in storage_backend().list_keys(&info_k8s_pod_dir_path())? {
+        if let Ok(pod) = repo.read(&pod_uid) {
+            if pod.node_name.as_deref().is_some_and(|n| nodes.contains(n)) {
+                pods.push(pod);
+            }
+        }
+    }
+
+    Ok(pods)
+}
+
+/// CPU + memory cost of every series, keyed by series key.
+fn cpu_memory_cost_by_series(response: &MetricGetResponseDto) -> HashMap<String, f64> {
+    response
+        .series
+        .iter()
+        .map(|series| {
+            let cost = series
+                .points
+                .iter()
+                .filter_map(|p| p.cost.as_ref())
+                .map(|c| c.cpu_cost.unwrap_or(0.0) + c.memory_cost.unwrap_or(0.0))
+                .sum::<f64>();
+            (series.key.clone(), cost)
+        })
+        .collect()
+}
+
+/// Cost of the system container usage recorded on each node point, priced at
+/// the rates in force at the point's time.
+///
+/// Points collected before the usage was stored per row carry no overhead.
+fn system_overhead_cost(
+    series: &MetricSeriesDto,
+    granularity: &MetricGranularity,
+    unit_prices: &InfoUnitPriceEntity,
+) -> f64 {
+    let default_interval_hours = granularity_interval_hours(granularity);
+    let timestamps: Vec<_> = series.points.iter().map(|p| p.time).collect();
+
+    series
+        .points
+        .iter()
+        .enumerate()
+        .map(|(idx, point)| {
+            let cores = point.cpu_memory.system_cpu_usage_nano_cores.unwrap_or(0.0) / 1_000_000_000.0;
+            let memory_gb = point.cpu_memory.system_memory_working_set_bytes.unwrap_or(0.0) / BYTES_PER_GB;
+            let hours = point_interval_hours_from_timestamps(&timestamps, idx, default_interval_hours);
+            let prices = unit_prices.at(point.time);
+
+            (cores * prices.cpu_core_hour + memory_gb * prices.memory_gb_hour) * hours
+        })
+        .sum()
+}
+
+/// Split node CPU/memory capacity cost into allocated, system overhead and idle.
+///
+/// Only CPU and memory are split: storage and network are billed on usage
+/// and have no idle share.
+pub(crate) async fn build_node_cost_breakdown(
+    q: RangeQuery,
+    node_names: Vec<String>,
+    scope: MetricScope,
+    target: Option<String>,
+    unit_prices: &InfoUnitPriceEntity,
+) -> Result<MetricCostBreakdownResponseDto> {
+    // 1️⃣ Capacity cost per node (no paging: every node counts)
+    let node_query = RangeQuery {
+        offset: None,
+        limit: Some(node_names.len()),
+        ..q.clone()
+    };
+    let (node_response, node_infos) = build_node_raw_data(node_query, node_names).await?;
+    let mut capacity_response = node_response;
+    apply_node_costs(&mut capacity_response, unit_prices, &node_infos);
+
+    // 2️⃣ Allocated cost of every pod on those nodes (cost mode is honoured)
+    let scheduled: Vec<String> = node_infos.iter().filter_map(|n| n.node_name.clone()).collect();
+    let pods = load_pods_on_nodes(&scheduled)?;
+    let pod_node: HashMap<String, String> = pods
+        .iter()
+        .filter_map(|p| Some((p.pod_uid.clone()?, p.node_name.clone()?)))
+        .collect();
+
+    let pod_query = RangeQuery {
+        offset: None,
+        limit: None,
+        namespace: None,
+        team: None,
+        service: None,
+        env: None,
+        ..q
+    };
+    let pod_response = build_pod_cost_response_from_infos(pod_query, pods, None, unit_prices).await?;
+
+    let mut allocated_by_node: HashMap<String, f64> = HashMap::new();
+    for (pod_uid, cost) in cpu_memory_cost_by_series(&pod_response) {
+        if let Some(node) = pod_node.get(&pod_uid) {
+            *allocated_by_node.entry(node.clone()).or_default() += cost;
+        }
+    }
+
+    // 3️⃣ Per-node breakdown
+    let mut nodes = Vec::new();
+    let mut total = MetricCostBreakdownDto::default();
+
+    for series in &capacity_response.series {
+        let node = match node_infos.iter().find(|n| n.node_name.as_deref() == Some(&series.key)) {
+            Some(n) => n,
+            None => continue,
+        };
+        let running_hours = series.running_hours.unwrap_or(0.0);
+
+        let capacity = series
+            .cost_summary
+            .as_ref()
+            .map(|c| c.cpu_cost.unwrap_or(0.0) + c.memory_cost.unwrap_or(0.0))
+            .unwrap_or(0.0);
+        let allocated = allocated_by_node.get(&series.key).copied().unwrap_or(0.0);
+        let node_prices = node_unit_prices(node, unit_prices);
+        // Overhead can't exceed what is left after allocations
+        let system = system_overhead_cost(series, &capacity_response.granularity, &node_prices)
+            .min(capacity - allocated)
+            .max(0.0);
+
+        let breakdown = MetricCostBreakdownDto {
+            capacity_cost: capacity,
+            allocated_cost: allocated,
+            system_overhead_cost: system,
+            idle_cost: (capacity - allocated - system).max(0.0),
+        };
+
+        total.add(&breakdown);
+        nodes.push(MetricNodeCostBreakdownDto {
+            node_name: series.key.clone(),
+            running_hours,
+            breakdown,
+        });
+    }
+
+    Ok(MetricCostBreakdownResponseDto {
+        start: capacity_response.start,
+        end: capacity_response.end,
+        scope,
+        target,
+        granularity: capacity_response.granularity,
+        currency: unit_prices.currency,
+        nodes,
+        total,
+    })
+}
+
+/// Cluster-wide breakdown over every known node, used to share idle cost.
+pub(crate) async fn build_cluster_cost_breakdown(
+    q: RangeQuery,
+    unit_prices: &InfoUnitPriceEntity,
+) -> Result<MetricCostBreakdownDto> {
+    let node_names = list_node_names()?;
+    let cluster_query = RangeQuery {
+        team: None,
+        service: None,
+        env: None,
+        ..q
+    };
+    let breakdown =
+        build_node_cost_breakdown(cluster_query, node_names, MetricScope::Cluster, None, unit_prices)
+            .await?;
+    Ok(breakdown.total)
+}
+
+pub async fn get_metric_k8s_nodes_cost_breakdown(
+    q: RangeQuery,
+    node_names: Vec<String>,
+) -> Result<Value> {
+    let unit_prices = info_unit_price_service::get_info_unit_prices_in(q.currency).await?;
+    let dto = build_node_cost_breakdown(q, node_names, MetricScope::Node, None, &unit_prices).await?;
+    Ok(serde_json::to_value(dto)?)
+}
+
+pub async fn get_metric_k8s_node_cost_breakdown(node_name: String, q: RangeQuery) -> Result<Value> {
+    let names = vec![node_name.clone()];
+    let unit_prices = info_unit_price_service::get_info_unit_prices_in(q.currency).await?;
+    let dto =
+        build_node_cost_breakdown(q, names, MetricScope::Node, Some(node_name), &unit_prices).await?;
+    Ok(serde_json::to_value(dto)?)
+}
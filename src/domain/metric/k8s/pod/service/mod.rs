@@ -1,4 +1,8 @@
-use crate::api::dto::{info_dto::K8sListQuery, metrics_dto::RangeQuery};
+use crate::api::dto::{
+    info_dto::K8sListQuery,
+    metrics_dto::{CostMode, RangeQuery},
+};
+use crate::core::util::label_selector::{parse_flattened_labels, LabelSelector};
 use crate::core::persistence::info::fixed::unit_price::info_unit_price_entity::InfoUnitPriceEntity;
 use crate::core::persistence::info::k8s::container::info_container_entity::InfoContainerEntity;
 use crate::core::persistence::info::k8s::pod::info_pod_api_repository_trait::InfoPodApiRepository;
@@ -14,16 +18,20 @@ use crate::domain::common::service::day_granularity::split_day_granularity_rows;
 use crate::domain::info::service::{info_k8s_container_service, info_unit_price_service};
 use crate::domain::metric::k8s::common::dto::metric_k8s_raw_summary_dto::MetricRawSummaryResponseDto;
 use crate::domain::metric::k8s::common::dto::{
-    CommonMetricValuesDto, FilesystemMetricDto, MetricGetResponseDto, MetricGranularity,
-    MetricScope, MetricSeriesDto, NetworkMetricDto, StorageMetricDto, UniversalMetricPointDto,
+    CommonMetricValuesDto, FilesystemMetricDto, GpuMetricDto, MetricGetResponseDto,
+    MetricGranularity, MetricScope, MetricSeriesDto, NetworkMetricDto, StorageMetricDto,
+    UniversalMetricPointDto,
 };
 use crate::domain::metric::k8s::common::service_helpers::{
     apply_costs, build_cost_summary_dto, build_cost_trend_dto, build_efficiency_value,
-    build_raw_summary_value, resolve_time_window, TimeWindow, BYTES_PER_GB,
+    build_raw_summary_value, pod_requests_by_uid, request_uplift_by_time, resolve_time_window,
+    ResourceRequests, TimeWindow, BYTES_PER_GB,
 };
+use crate::domain::metric::k8s::node::service::load_node_unit_prices;
 use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
 use serde_json::Value;
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 fn fetch_pod_points(
     pod_uid: &str,
@@ -85,6 +93,13 @@ fn metric_pod_entity_to_point(entity: MetricPodEntity) -> UniversalMetricPointDt
             memory_working_set_bytes: entity.memory_working_set_bytes.map(|v| v as f64),
             memory_rss_bytes: entity.memory_rss_bytes.map(|v| v as f64),
             memory_page_faults: entity.memory_page_faults.map(|v| v as f64),
+            cpu_usage_nano_cores_max: entity.cpu_usage_nano_cores_max.map(|v| v as f64),
+            cpu_usage_nano_cores_p95: entity.cpu_usage_nano_cores_p95.map(|v| v as f64),
+            cpu_usage_nano_cores_p99: entity.cpu_usage_nano_cores_p99.map(|v| v as f64),
+            memory_working_set_bytes_max: entity.memory_working_set_bytes_max.map(|v| v as f64),
+            memory_working_set_bytes_p95: entity.memory_working_set_bytes_p95.map(|v| v as f64),
+            memory_working_set_bytes_p99: entity.memory_working_set_bytes_p99.map(|v| v as f64),
+            ..Default::default()
         },
         filesystem: Some(ephemeral_fs.clone()),
         storage: Some(StorageMetricDto {
@@ -96,6 +111,14 @@ fn metric_pod_entity_to_point(entity: MetricPodEntity) -> UniversalMetricPointDt
             tx_bytes: entity.network_physical_tx_bytes.map(|v| v as f64),
             rx_errors: entity.network_physical_rx_errors.map(|v| v as f64),
             tx_errors: entity.network_physical_tx_errors.map(|v| v as f64),
+            local_bytes: entity.network_local_bytes.map(|v| v as f64),
+            regional_bytes: entity.network_regional_bytes.map(|v| v as f64),
+            external_bytes: entity.network_external_bytes.map(|v| v as f64),
+        }),
+        gpu: entity.gpu_count.map(|count| GpuMetricDto {
+            gpu_count: Some(count as f64),
+            utilization_percent: entity.gpu_utilization_percent.map(|v| v as f64),
+            memory_used_bytes: entity.gpu_memory_used_bytes.map(|v| v as f64),
         }),
         ..Default::default()
     }
@@ -116,6 +139,16 @@ async fn build_pod_raw_data(
     }
 
     // --- filters ---
+    let pod_infos = filter_pod_infos(&q, pod_infos)?;
+
+    // --- build metrics ---
+    let response = build_pod_series_for_infos(&q, &pod_infos, None)?;
+
+    Ok((response, pod_infos))
+}
+
+/// Applies the team/service/env tag filters and the `labels` selector of `q`.
+pub(crate) fn filter_pod_infos(q: &RangeQuery, mut pod_infos: Vec<InfoPodEntity>) -> Result<Vec<InfoPodEntity>> {
     let matches = |value: &Option<String>, filter: &str| {
         value
             .as_deref()
@@ -135,10 +168,14 @@ async fn build_pod_raw_data(
         pod_infos.retain(|p| matches(&p.env, env));
     }
 
-    // --- build metrics ---
-    let response = build_pod_series_for_infos(&q, &pod_infos, None)?;
+    if let Some(ref labels) = q.labels {
+        let selector = LabelSelector::parse(labels)?;
+        if !selector.is_empty() {
+            pod_infos.retain(|p| selector.matches(&parse_flattened_labels(p.label.as_deref())));
+        }
+    }
 
-    Ok((response, pod_infos))
+    Ok(pod_infos)
 }
 
 fn build_pod_series_for_infos(
@@ -192,6 +229,7 @@ fn build_pod_series_for_infos(
         total: Some(pod_infos.len()),
         limit: Some(limit),
         offset: Some(offset),
+        currency: None,
     })
 }
 
@@ -239,16 +277,83 @@ fn sum_container_requests(
     (total_cpu, total_memory_gb)
 }
 
+/// Load per-pod requests for the chargeback floor.
+///
+/// Requests are read from the stored container info, so pods deleted since the
+/// window keep their floor. Showback never needs them, so no lookup is done in
+/// that mode.
+fn load_pod_requests(
+    mode: &CostMode,
+    pod_infos: &[InfoPodEntity],
+) -> Result<HashMap<String, ResourceRequests>> {
+    if matches!(mode, CostMode::Showback) || pod_infos.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    let pod_uids: HashSet<String> = collect_pod_uids(pod_infos).into_iter().collect();
+    let containers = info_k8s_container_service::list_stored_containers_for_pods(&pod_uids)?;
+
+    Ok(pod_requests_by_uid(&containers))
+}
+
+/// Unit prices of the node each pod runs on, keyed by pod UID.
+fn pod_unit_prices(
+    pod_infos: &[InfoPodEntity],
+    unit_prices: &InfoUnitPriceEntity,
+) -> Result<HashMap<String, InfoUnitPriceEntity>> {
+    let node_prices = load_node_unit_prices(unit_prices)?;
+    Ok(pod_infos
+        .iter()
+        .filter_map(|p| {
+            let prices = node_prices.get(p.node_name.as_ref()?)?;
+            Some((p.pod_uid.clone()?, prices.clone()))
+        })
+        .collect())
+}
+
 async fn build_pod_cost_response(
     q: RangeQuery,
     pod_uids: Vec<String>,
     unit_prices: InfoUnitPriceEntity,
 ) -> Result<MetricGetResponseDto> {
-    let (mut response, _) = build_pod_raw_data(q, pod_uids).await?;
-    apply_costs(&mut response, &unit_prices);
+    let mode = q.mode.clone();
+    let (mut response, pod_infos) = build_pod_raw_data(q, pod_uids).await?;
+    let requests = load_pod_requests(&mode, &pod_infos)?;
+    let series_prices = pod_unit_prices(&pod_infos, &unit_prices)?;
+    apply_costs(&mut response, &unit_prices, &mode, &requests, &series_prices);
     Ok(response)
 }
 
+/// Per-pod cost series for an already resolved pod list.
+///
+/// Costs are applied per pod so that aggregating scopes (namespace, deployment)
+/// keep each pod's own chargeback floor.
+pub(crate) async fn build_pod_cost_response_from_infos(
+    q: RangeQuery,
+    pod_infos: Vec<InfoPodEntity>,
+    target: Option<String>,
+    unit_prices: &InfoUnitPriceEntity,
+) -> Result<MetricGetResponseDto> {
+    let mut response = build_pod_series_for_infos(&q, &pod_infos, target)?;
+    let requests = load_pod_requests(&q.mode, &pod_infos)?;
+    let series_prices = pod_unit_prices(&pod_infos, unit_prices)?;
+    apply_costs(&mut response, unit_prices, &q.mode, &requests, &series_prices);
+    Ok(response)
+}
+
+/// CPU and memory cost the requests of `pod_infos` add on top of their usage,
+/// summed per point time (see [`request_uplift_by_time`]).
+pub(crate) fn build_pod_request_uplift(
+    q: &RangeQuery,
+    pod_infos: &[InfoPodEntity],
+    unit_prices: &InfoUnitPriceEntity,
+) -> Result<BTreeMap<DateTime<Utc>, (f64, f64)>> {
+    let response = build_pod_series_for_infos(q, pod_infos, None)?;
+    let requests = load_pod_requests(&CostMode::Chargeback, pod_infos)?;
+    let series_prices = pod_unit_prices(pod_infos, unit_prices)?;
+    Ok(request_uplift_by_time(&response, unit_prices, &requests, &series_prices))
+}
+
 pub async fn get_metric_k8s_pods_raw(q: RangeQuery, pod_uids: Vec<String>) -> Result<Value> {
     let (response, _) = build_pod_raw_data(q, pod_uids).await?;
     Ok(serde_json::to_value(response)?)
@@ -341,7 +446,7 @@ pub async fn get_metric_k8s_pod_raw_efficiency(pod_uid: String, q: RangeQuery) -
 }
 
 pub async fn get_metric_k8s_pods_cost(q: RangeQuery, pod_uids: Vec<String>) -> Result<Value> {
-    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
+    let unit_prices = info_unit_price_service::get_info_unit_prices_in(q.currency).await?;
     let response = build_pod_cost_response(q, pod_uids, unit_prices).await?;
     Ok(serde_json::to_value(response)?)
 }
@@ -350,14 +455,14 @@ pub async fn get_metric_k8s_pods_cost_summary(
     q: RangeQuery,
     pod_uids: Vec<String>,
 ) -> Result<Value> {
-    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
+    let unit_prices = info_unit_price_service::get_info_unit_prices_in(q.currency).await?;
     let response = build_pod_cost_response(q, pod_uids, unit_prices.clone()).await?;
     let dto = build_cost_summary_dto(&response, MetricScope::Pod, None, &unit_prices);
     Ok(serde_json::to_value(dto)?)
 }
 
 pub async fn get_metric_k8s_pods_cost_trend(q: RangeQuery, pod_uids: Vec<String>) -> Result<Value> {
-    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
+    let unit_prices = info_unit_price_service::get_info_unit_prices_in(q.currency).await?;
     let response = build_pod_cost_response(q, pod_uids, unit_prices).await?;
     let dto = build_cost_trend_dto(&response, MetricScope::Pod, None)?;
     Ok(serde_json::to_value(dto)?)
@@ -365,14 +470,14 @@ pub async fn get_metric_k8s_pods_cost_trend(q: RangeQuery, pod_uids: Vec<String>
 
 pub async fn get_metric_k8s_pod_cost(pod_uid: String, q: RangeQuery) -> Result<Value> {
     let pod_uids = vec![pod_uid];
-    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
+    let unit_prices = info_unit_price_service::get_info_unit_prices_in(q.currency).await?;
     let response = build_pod_cost_response(q, pod_uids, unit_prices).await?;
     Ok(serde_json::to_value(response)?)
 }
 
 pub async fn get_metric_k8s_pod_cost_summary(pod_uid: String, q: RangeQuery) -> Result<Value> {
     let pod_uids = vec![pod_uid.clone()];
-    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
+    let unit_prices = info_unit_price_service::get_info_unit_prices_in(q.currency).await?;
     let response = build_pod_cost_response(q, pod_uids, unit_prices.clone()).await?;
     let dto = build_cost_summary_dto(&response, MetricScope::Pod, Some(pod_uid), &unit_prices);
     Ok(serde_json::to_value(dto)?)
@@ -380,7 +485,7 @@ pub async fn get_metric_k8s_pod_cost_summary(pod_uid: String, q: RangeQuery) ->
 
 pub async fn get_metric_k8s_pod_cost_trend(pod_uid: String, q: RangeQuery) -> Result<Value> {
     let pod_uids = vec![pod_uid.clone()];
-    let unit_prices = info_unit_price_service::get_info_unit_prices().await?;
+    let unit_prices = info_unit_price_service::get_info_unit_prices_in(q.currency).await?;
     let response = build_pod_cost_response(q, pod_uids, unit_prices).await?;
     let dto = build_cost_trend_dto(&response, MetricScope::Pod, Some(pod_uid))?;
     Ok(serde_json::to_value(dto)?)
@@ -0,0 +1,525 @@
+//! Persistent volume cost from provisioned capacity.
+//!
+//! Claims and volumes are read from the lifecycle records kept by the volume
+//! collector, so deleted objects stay billed for the windows they existed in.
+//! A claim is billed for the capacity of its volume while it is `Bound`, at
+//! the StorageClass price in force at the time when one is configured,
+//! otherwise at the flat `storage_gb_hour`, and attributed to the pods that
+//! mount it. Volumes are billed as waste while they are `Available`, and from
+//! the time of release while they are `Released` or `Failed`.
+
+use anyhow::Result;
+use chrono::{DateTime, Duration, Utc};
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use crate::api::dto::metrics_dto::RangeQuery;
+use crate::core::persistence::info::fixed::exchange_rate::info_exchange_rate_api_repository_trait::InfoExchangeRateApiRepository;
+use crate::core::persistence::info::fixed::exchange_rate::info_exchange_rate_entity::InfoExchangeRateEntity;
+use crate::core::persistence::info::fixed::exchange_rate::info_exchange_rate_repository::InfoExchangeRateRepository;
+use crate::core::persistence::info::fixed::storage_class_price::info_storage_class_price_api_repository_trait::InfoStorageClassPriceApiRepository;
+use crate::core::persistence::info::fixed::storage_class_price::info_storage_class_price_entity::InfoStorageClassPriceEntity;
+use crate::core::persistence::info::fixed::storage_class_price::info_storage_class_price_repository::InfoStorageClassPriceRepository;
+use crate::core::persistence::info::fixed::unit_price::info_unit_price_entity::InfoUnitPriceEntity;
+use crate::core::persistence::info::k8s::lifecycle::segment_ranges;
+use crate::core::persistence::info::k8s::pod::info_pod_api_repository_trait::InfoPodApiRepository;
+use crate::core::persistence::info::k8s::volume::info_volume_api_repository_trait::InfoVolumeApiRepository;
+use crate::core::persistence::info::k8s::volume::info_volume_entity::InfoVolumeEntity;
+use crate::core::persistence::info::k8s::volume::info_volume_repository::InfoVolumeRepository;
+use crate::core::persistence::info::{
+    k8s::pod::{info_pod_entity::InfoPodEntity, info_pod_repository::InfoPodRepository},
+    path::info_k8s_pod_dir_path,
+};
+use crate::core::persistence::storage_backend::storage_backend;
+use crate::domain::info::service::info_unit_price_service;
+use crate::domain::metric::k8s::common::dto::metric_k8s_cost_summary_dto::{
+    MetricCostSummaryDto, MetricCostSummaryResponseDto,
+};
+use crate::domain::metric::k8s::common::dto::metric_k8s_pvc_cost_dto::{
+    MetricPvWasteDto, MetricPvcConsumerDto, MetricPvcCostBreakdownResponseDto, MetricPvcCostDto,
+    MetricPvcNamespaceCostDto, MetricPvcOwnerCostDto, PvWasteReason,
+};
+use crate::domain::metric::k8s::common::dto::{
+    CommonMetricValuesDto, CostMetricDto, FilesystemMetricDto, MetricGetResponseDto,
+    MetricGranularity, MetricScope, MetricSeriesDto, StorageMetricDto, UniversalMetricPointDto,
+};
+use crate::domain::metric::k8s::common::service_helpers::{
+    build_cost_trend_dto, resolve_time_window, TimeWindow, BYTES_PER_GB,
+};
+use crate::domain::metric::k8s::pod::service::filter_pod_infos;
+use crate::domain::metric::k8s::workload::owner::WorkloadOwnerIndex;
+
+// ------------------------------
+// Helpers
+// ------------------------------
+
+/// Capacity billed for a claim or volume.
+#[derive(Debug, Clone)]
+struct StorageItem {
+    capacity_bytes: f64,
+    /// Billed parts of the window
+    ranges: Vec<(DateTime<Utc>, DateTime<Utc>)>,
+}
+
+/// Unit prices for volumes of `storage_class`: the StorageClass price in force
+/// at each point replaces `storage_gb_hour`.
+fn storage_class_unit_prices(
+    unit_prices: &InfoUnitPriceEntity,
+    class_prices: &InfoStorageClassPriceEntity,
+    rates: &InfoExchangeRateEntity,
+    storage_class: Option<&str>,
+) -> InfoUnitPriceEntity {
+    unit_prices.map_versions_at(&class_prices.price_changes(), |prices, time| {
+        // Converted through USD; without a rate the unit price applies
+        let class_price = class_prices
+            .gb_hour_at(time, storage_class)
+            .and_then(|(price, currency)| Some(price / rates.rate(currency)?))
+            .and_then(|usd| prices.from_usd(usd));
+
+        InfoUnitPriceEntity {
+            storage_gb_hour: class_price.unwrap_or(prices.storage_gb_hour),
+            ..prices.clone()
+        }
+    })
+}
+
+/// Parts of `[start, end)` that `info` spent in one of `phases`.
+fn phase_ranges(
+    info: &InfoVolumeEntity,
+    phases: &[&str],
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    now: DateTime<Utc>,
+) -> Vec<(DateTime<Utc>, DateTime<Utc>)> {
+    let Some((born, gone)) = info.lifetime(now) else {
+        return Vec::new();
+    };
+
+    segment_ranges(&info.phases, start.max(born), end.min(gone))
+        .into_iter()
+        .filter(|(phase, _, _)| phases.contains(phase))
+        .map(|(_, from, to)| (from, to))
+        .collect()
+}
+
+fn bucket_len(granularity: &MetricGranularity) -> Duration {
+    match granularity {
+        MetricGranularity::Minute => Duration::minutes(1),
+        MetricGranularity::Hour => Duration::hours(1),
+        MetricGranularity::Day => Duration::days(1),
+    }
+}
+
+/// Capacity cost of `item` for each bucket of `window` it was billed in.
+fn capacity_cost_points(
+    item: &StorageItem,
+    window: &TimeWindow,
+    prices: &InfoUnitPriceEntity,
+) -> Vec<UniversalMetricPointDto> {
+    let step = bucket_len(&window.granularity);
+    let mut points = Vec::new();
+    let mut time = window.start;
+
+    while time < window.end {
+        let bucket_end = (time + step).min(window.end);
+        let seconds: i64 = item
+            .ranges
+            .iter()
+            .map(|(from, to)| ((*to).min(bucket_end) - (*from).max(time)).num_seconds().max(0))
+            .sum();
+        let hours = seconds as f64 / 3600.0;
+
+        if hours > 0.0 {
+            let cost = item.capacity_bytes / BYTES_PER_GB * hours * prices.at(time).storage_gb_hour;
+            points.push(UniversalMetricPointDto {
+                time,
+                cpu_memory: CommonMetricValuesDto::default(),
+                filesystem: None,
+                network: None,
+                storage: Some(StorageMetricDto {
+                    ephemeral: None,
+                    persistent: Some(FilesystemMetricDto {
+                        capacity_bytes: Some(item.capacity_bytes),
+                        ..Default::default()
+                    }),
+                }),
+                gpu: None,
+                cost: Some(CostMetricDto {
+                    total_cost: Some(cost),
+                    storage_cost: Some(cost),
+                    ..Default::default()
+                }),
+            });
+        }
+        time = bucket_end;
+    }
+
+    points
+}
+
+fn points_cost(points: &[UniversalMetricPointDto]) -> f64 {
+    points
+        .iter()
+        .filter_map(|p| p.cost.as_ref().and_then(|c| c.storage_cost))
+        .sum()
+}
+
+fn has_pod_filters(q: &RangeQuery) -> bool {
+    q.team.is_some() || q.service.is_some() || q.env.is_some() || q.labels.is_some()
+}
+
+/// Pods keyed by the `(namespace, claim)` they mount, deleted ones included
+/// so past claims keep their consumers.
+fn load_claim_consumers(q: &RangeQuery) -> Result<HashMap<(String, String), Vec<InfoPodEntity>>> {
+    let repo = InfoPodRepository::new();
+    let mut pods = Vec::new();
+    for pod_uid in storage_backend().list_keys(&info_k8s_pod_dir_path())? {
+        if let Ok(pod) = repo.read(&pod_uid) {
+            pods.push(pod);
+        }
+    }
+
+    let mut map: HashMap<(String, String), Vec<InfoPodEntity>> = HashMap::new();
+    for pod in filter_pod_infos(q, pods)? {
+        let namespace = pod.namespace.clone().unwrap_or_default();
+        for claim in pod.pvc_names.clone().unwrap_or_default() {
+            map.entry((namespace.clone(), claim)).or_default().push(pod.clone());
+        }
+    }
+    Ok(map)
+}
+
+// ------------------------------
+// COST (HELPERS)
+// ------------------------------
+
+async fn build_pvc_cost(
+    q: RangeQuery,
+    unit_prices: &InfoUnitPriceEntity,
+) -> Result<(MetricGetResponseDto, MetricPvcCostBreakdownResponseDto)> {
+    let window = resolve_time_window(&q);
+    let now = Utc::now();
+    let class_prices = InfoStorageClassPriceRepository::new().read()?;
+    let rates = InfoExchangeRateRepository::new().read()?;
+    let records = InfoVolumeRepository::new().list()?;
+
+    let consumers = load_claim_consumers(&q)?;
+    let owners = WorkloadOwnerIndex::cached().await;
+    let pod_filtered = has_pod_filters(&q);
+    let namespace_matches = |ns: &str| q.namespace.as_deref().is_none_or(|filter| filter == ns);
+    let class_unit_prices = |storage_class: Option<&str>| {
+        storage_class_unit_prices(unit_prices, &class_prices, &rates, storage_class)
+    };
+
+    let mut series = Vec::new();
+    let mut claim_costs = Vec::new();
+    let mut waste = Vec::new();
+    let mut namespace_costs: BTreeMap<String, f64> = BTreeMap::new();
+    let mut owner_costs: BTreeMap<(String, String, String), f64> = BTreeMap::new();
+
+    for claim in records.iter().filter(|r| r.is_claim()) {
+        let namespace = claim.namespace.clone().unwrap_or_default();
+        let name = claim.name.clone().unwrap_or_default();
+        if !namespace_matches(&namespace) {
+            continue;
+        }
+        // Pending claims have nothing provisioned yet
+        let ranges = phase_ranges(claim, &["Bound"], window.start, window.end, now);
+        if ranges.is_empty() {
+            continue;
+        }
+
+        // A live claim is orphaned once its pods are gone
+        let live = claim.deleted != Some(true);
+        let mounted_by: Vec<InfoPodEntity> = consumers
+            .get(&(namespace.clone(), name.clone()))
+            .into_iter()
+            .flatten()
+            .filter(|pod| !live || pod.deleted != Some(true))
+            .cloned()
+            .collect();
+        if pod_filtered && mounted_by.is_empty() {
+            continue;
+        }
+
+        let item = StorageItem { capacity_bytes: claim.capacity_bytes.unwrap_or(0.0), ranges };
+        let prices = class_unit_prices(claim.storage_class.as_deref());
+        let points = capacity_cost_points(&item, &window, &prices);
+        let cost = points_cost(&points);
+
+        if mounted_by.is_empty() {
+            waste.push(MetricPvWasteDto {
+                volume_name: claim.volume_name.clone(),
+                claim: Some(format!("{}/{}", namespace, name)),
+                storage_class: claim.storage_class.clone(),
+                capacity_bytes: item.capacity_bytes,
+                reason: PvWasteReason::Orphaned,
+                cost,
+            });
+        }
+
+        let consumer_dtos: Vec<MetricPvcConsumerDto> = mounted_by
+            .iter()
+            .map(|pod| {
+                let owner = owners.controller_of(pod);
+                MetricPvcConsumerDto {
+                    pod_uid: pod.pod_uid.clone().unwrap_or_default(),
+                    pod_name: pod.pod_name.clone(),
+                    owner_kind: owner.as_ref().map(|(kind, _)| kind.clone()),
+                    owner_name: owner.map(|(_, name)| name),
+                }
+            })
+            .collect();
+
+        // Shared claims are split evenly between the controllers mounting them
+        let claim_owners: BTreeSet<(String, String)> = consumer_dtos
+            .iter()
+            .filter_map(|c| Some((c.owner_kind.clone()?, c.owner_name.clone()?)))
+            .collect();
+        for (kind, owner_name) in &claim_owners {
+            *owner_costs
+                .entry((namespace.clone(), kind.clone(), owner_name.clone()))
+                .or_default() += cost / claim_owners.len() as f64;
+        }
+        *namespace_costs.entry(namespace.clone()).or_default() += cost;
+
+        claim_costs.push(MetricPvcCostDto {
+            namespace: namespace.clone(),
+            name: name.clone(),
+            volume_name: claim.volume_name.clone(),
+            storage_class: claim.storage_class.clone(),
+            capacity_bytes: item.capacity_bytes,
+            gb_hour: prices.at(window.end).storage_gb_hour,
+            cost,
+            consumers: consumer_dtos,
+        });
+
+        series.push(MetricSeriesDto {
+            key: format!("{}/{}", namespace, name),
+            name,
+            scope: MetricScope::PersistentVolumeClaim,
+            points,
+            running_hours: None,
+            cost_summary: None,
+        });
+    }
+
+    // Volume time not covered by a claim serves no workload
+    if !pod_filtered {
+        let reasons = [
+            (PvWasteReason::Unbound, &["Available"][..]),
+            (PvWasteReason::Released, &["Released", "Failed"][..]),
+        ];
+        for volume in records.iter().filter(|r| !r.is_claim()) {
+            let claim_namespace = volume.claim.as_deref().and_then(|c| c.split_once('/')).map(|(ns, _)| ns);
+            match (&q.namespace, claim_namespace) {
+                (Some(filter), Some(ns)) if filter != ns => continue,
+                (Some(_), None) => continue,
+                _ => {}
+            }
+            let prices = class_unit_prices(volume.storage_class.as_deref());
+
+            for (reason, phases) in reasons {
+                let ranges = phase_ranges(volume, phases, window.start, window.end, now);
+                if ranges.is_empty() {
+                    continue;
+                }
+                let item = StorageItem { capacity_bytes: volume.capacity_bytes.unwrap_or(0.0), ranges };
+                let points = capacity_cost_points(&item, &window, &prices);
+
+                waste.push(MetricPvWasteDto {
+                    volume_name: volume.name.clone(),
+                    claim: volume.claim.clone(),
+                    storage_class: volume.storage_class.clone(),
+                    capacity_bytes: item.capacity_bytes,
+                    reason,
+                    cost: points_cost(&points),
+                });
+            }
+        }
+    }
+
+    series.sort_by(|a, b| a.key.cmp(&b.key));
+    claim_costs.sort_by(|a, b| (&a.namespace, &a.name).cmp(&(&b.namespace, &b.name)));
+    waste.sort_by(|a, b| b.cost.total_cmp(&a.cost));
+
+    let response = MetricGetResponseDto {
+        start: window.start,
+        end: window.end,
+        scope: "pvc".to_string(),
+        target: None,
+        granularity: window.granularity.clone(),
+        series,
+        total: None,
+        limit: None,
+        offset: None,
+        currency: Some(unit_prices.currency),
+    };
+    let breakdown = MetricPvcCostBreakdownResponseDto {
+        start: window.start,
+        end: window.end,
+        granularity: window.granularity,
+        currency: unit_prices.currency,
+        claims_cost: claim_costs.iter().map(|c| c.cost).sum(),
+        waste_cost: waste.iter().map(|w| w.cost).sum(),
+        claims: claim_costs,
+        waste,
+        namespaces: namespace_costs
+            .into_iter()
+            .map(|(namespace, cost)| MetricPvcNamespaceCostDto { namespace, cost })
+            .collect(),
+        owners: owner_costs
+            .into_iter()
+            .map(|((namespace, owner_kind, owner_name), cost)| MetricPvcOwnerCostDto {
+                namespace,
+                owner_kind,
+                owner_name,
+                cost,
+            })
+            .collect(),
+    };
+
+    Ok((response, breakdown))
+}
+
+/// Claim capacity cost for `q`, with the per-namespace and per-owner totals
+/// the namespace and workload summaries bill as persistent storage.
+pub(crate) async fn build_pvc_cost_breakdown(
+    q: &RangeQuery,
+    unit_prices: &InfoUnitPriceEntity,
+) -> Result<MetricPvcCostBreakdownResponseDto> {
+    let (_, breakdown) = build_pvc_cost(q.clone(), unit_prices).await?;
+    Ok(breakdown)
+}
+
+// ------------------------------
+// COST
+// ------------------------------
+
+pub async fn get_metric_k8s_pvcs_cost(q: RangeQuery) -> Result<Value> {
+    let unit_prices = info_unit_price_service::get_info_unit_prices_in(q.currency).await?;
+    let (dto, _) = build_pvc_cost(q, &unit_prices).await?;
+
+    Ok(serde_json::to_value(dto)?)
+}
+
+pub async fn get_metric_k8s_pvcs_cost_summary(q: RangeQuery) -> Result<Value> {
+    let unit_prices = info_unit_price_service::get_info_unit_prices_in(q.currency).await?;
+    let (dto, _) = build_pvc_cost(q, &unit_prices).await?;
+
+    // The generic summary re-prices used bytes; claims are billed on capacity
+    let storage_cost: f64 = dto.series.iter().map(|s| points_cost(&s.points)).sum();
+    let summary = MetricCostSummaryResponseDto {
+        start: dto.start,
+        end: dto.end,
+        scope: MetricScope::PersistentVolumeClaim,
+        target: None,
+        granularity: dto.granularity,
+        currency: unit_prices.currency,
+        summary: MetricCostSummaryDto {
+            total_cost: storage_cost,
+            persistent_storage_cost: storage_cost,
+            ..Default::default()
+        },
+    };
+    Ok(serde_json::to_value(summary)?)
+}
+
+pub async fn get_metric_k8s_pvcs_cost_trend(q: RangeQuery) -> Result<Value> {
+    let unit_prices = info_unit_price_service::get_info_unit_prices_in(q.currency).await?;
+    let (dto, _) = build_pvc_cost(q, &unit_prices).await?;
+
+    let trend = build_cost_trend_dto(&dto, MetricScope::PersistentVolumeClaim, None)?;
+    Ok(serde_json::to_value(trend)?)
+}
+
+pub async fn get_metric_k8s_pvcs_cost_breakdown(q: RangeQuery) -> Result<Value> {
+    let unit_prices = info_unit_price_service::get_info_unit_prices_in(q.currency).await?;
+    let (_, breakdown) = build_pvc_cost(q, &unit_prices).await?;
+
+    Ok(serde_json::to_value(breakdown)?)
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::client::mappers::parse_storage_quantity;
+    use crate::core::persistence::info::fixed::storage_class_price::info_storage_class_price_entity::{
+        StorageClassPriceEntity, StorageClassPriceVersion,
+    };
+    use crate::core::persistence::info::fixed::unit_price::info_unit_price_entity::Currency;
+    use crate::core::persistence::info::k8s::lifecycle::LifecycleSegment;
+
+    fn at(hours: i64) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2026-01-01T00:00:00Z").unwrap().with_timezone(&Utc) + Duration::hours(hours)
+    }
+
+    #[test]
+    fn bills_capacity_while_bound_at_the_storage_class_price_in_force() {
+        assert_eq!(parse_storage_quantity("10Gi"), Some(10.0 * BYTES_PER_GB));
+        assert_eq!(parse_storage_quantity("500M"), Some(5e8));
+        assert_eq!(parse_storage_quantity("1024"), Some(1024.0));
+        assert_eq!(parse_storage_quantity("lots"), None);
+
+        let window = TimeWindow { start: at(0), end: at(4), granularity: MetricGranularity::Hour };
+        let unit_prices = InfoUnitPriceEntity { storage_gb_hour: 0.001, ..Default::default() };
+        let rates = InfoExchangeRateEntity::default();
+        // "fast" cost 0.01 until hour 2, then 0.02
+        let class_prices = InfoStorageClassPriceEntity {
+            prices: vec![StorageClassPriceEntity { storage_class: "fast".into(), gb_hour: 0.02 }],
+            history: vec![StorageClassPriceVersion {
+                prices: vec![StorageClassPriceEntity { storage_class: "fast".into(), gb_hour: 0.01 }],
+                currency: Currency::USD,
+                effective_until: at(2),
+            }],
+            ..Default::default()
+        };
+
+        // Created Pending, bound half-way through the second hour
+        let claim = InfoVolumeEntity {
+            kind: Some("PersistentVolumeClaim".into()),
+            creation_timestamp: Some(at(0)),
+            phases: vec![
+                LifecycleSegment { value: "Pending".into(), since: at(0) },
+                LifecycleSegment { value: "Bound".into(), since: at(1) + Duration::minutes(30) },
+            ],
+            ..Default::default()
+        };
+        let item = StorageItem {
+            capacity_bytes: 10.0 * BYTES_PER_GB,
+            ranges: phase_ranges(&claim, &["Bound"], window.start, window.end, at(5)),
+        };
+        let prices = storage_class_unit_prices(&unit_prices, &class_prices, &rates, Some("fast"));
+        let points = capacity_cost_points(&item, &window, &prices);
+        assert_eq!(points.len(), 3);
+        assert!((points_cost(&points) - 10.0 * (0.5 * 0.01 + 2.0 * 0.02)).abs() < 1e-9);
+
+        // Classes without an override use the flat price
+        let prices = storage_class_unit_prices(&unit_prices, &class_prices, &rates, Some("standard"));
+        let points = capacity_cost_points(&item, &window, &prices);
+        assert!((points_cost(&points) - 10.0 * 2.5 * 0.001).abs() < 1e-9);
+    }
+
+    #[test]
+    fn released_volumes_are_waste_from_release_until_deletion() {
+        let mut volume = InfoVolumeEntity {
+            kind: Some("PersistentVolume".into()),
+            creation_timestamp: Some(at(0)),
+            phase: Some("Bound".into()),
+            ..Default::default()
+        };
+        volume.record_phase(at(0));
+        // The API reports when the claim was released
+        volume.phase = Some("Released".into());
+        volume.phase_transition_at = Some(at(3));
+        volume.record_phase(at(5));
+        volume.deleted = Some(true);
+        volume.deleted_at = Some(at(7));
+
+        assert!(phase_ranges(&volume, &["Available"], at(0), at(10), at(10)).is_empty());
+        assert_eq!(phase_ranges(&volume, &["Released", "Failed"], at(0), at(10), at(10)), vec![(at(3), at(7))]);
+        assert_eq!(phase_ranges(&volume, &["Bound"], at(2), at(10), at(10)), vec![(at(2), at(3))]);
+    }
+}
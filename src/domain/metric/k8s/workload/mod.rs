@@ -0,0 +1,2 @@
+pub mod owner;
+pub mod service;
@@ -0,0 +1,298 @@
+//! Controller ownership of pods.
+//!
+//! A pod only references its direct owner, so pods of a Deployment point at a
+//! ReplicaSet and pods of a CronJob point at a Job. The index walks one more
+//! level using the ReplicaSet and Job owner references read from the API.
+//! The resolved controller is stored on the pod info when the pod is first
+//! seen, so it survives the ReplicaSet or Job being deleted. Requests read a
+//! cached index, only needed for pods recorded without a controller.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use tracing::debug;
+
+use crate::core::client::jobs::fetch_jobs;
+use crate::core::client::kube_client::build_kube_client;
+use crate::core::client::kube_resources::{Job, ObjectMeta, ReplicaSet};
+use crate::core::client::replicasets::fetch_replicasets;
+use crate::core::persistence::info::k8s::pod::info_pod_entity::InfoPodEntity;
+use crate::domain::metric::k8s::common::dto::MetricScope;
+
+/// Workload controllers that pods can be grouped by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WorkloadKind {
+    Deployment,
+    StatefulSet,
+    DaemonSet,
+    Job,
+    CronJob,
+}
+
+impl WorkloadKind {
+    /// Kubernetes `kind` of the controller.
+    pub fn as_kind(&self) -> &'static str {
+        match self {
+            WorkloadKind::Deployment => "Deployment",
+            WorkloadKind::StatefulSet => "StatefulSet",
+            WorkloadKind::DaemonSet => "DaemonSet",
+            WorkloadKind::Job => "Job",
+            WorkloadKind::CronJob => "CronJob",
+        }
+    }
+
+    /// Scope name used in responses, e.g. `statefulset`.
+    pub fn as_scope_str(&self) -> &'static str {
+        match self {
+            WorkloadKind::Deployment => "deployment",
+            WorkloadKind::StatefulSet => "statefulset",
+            WorkloadKind::DaemonSet => "daemonset",
+            WorkloadKind::Job => "job",
+            WorkloadKind::CronJob => "cronjob",
+        }
+    }
+
+    pub fn scope(&self) -> MetricScope {
+        match self {
+            WorkloadKind::Deployment => MetricScope::Deployment,
+            WorkloadKind::StatefulSet => MetricScope::StatefulSet,
+            WorkloadKind::DaemonSet => MetricScope::DaemonSet,
+            WorkloadKind::Job => MetricScope::Job,
+            WorkloadKind::CronJob => MetricScope::CronJob,
+        }
+    }
+}
+
+/// Maps ReplicaSets to their Deployment and Jobs to their CronJob, keyed by
+/// `(namespace, name)`. Objects without such a controller map to `None`.
+#[derive(Debug, Default)]
+pub struct WorkloadOwnerIndex {
+    replicaset_deployments: HashMap<(String, String), Option<String>>,
+    job_cronjobs: HashMap<(String, String), Option<String>>,
+}
+
+/// How long a loaded index is reused before the API is read again.
+const CACHE_TTL: Duration = Duration::from_secs(300);
+
+static CACHE: Mutex<Option<(Instant, Arc<WorkloadOwnerIndex>)>> = Mutex::new(None);
+
+impl WorkloadOwnerIndex {
+    /// Reads ReplicaSets and Jobs from the API and caches the result. Falls
+    /// back to name-based inference when the cluster is unreachable.
+    pub async fn load() -> Arc<Self> {
+        let index = match Self::fetch().await {
+            Ok(index) => Arc::new(index),
+            Err(e) => {
+                debug!("Workload owners unavailable, inferring from names: {e:?}");
+                return Arc::default();
+            }
+        };
+        *CACHE.lock().unwrap_or_else(|e| e.into_inner()) = Some((Instant::now(), index.clone()));
+        index
+    }
+
+    /// The index loaded within the last [`CACHE_TTL`], loading it otherwise.
+    /// Used on request paths so they do not list every ReplicaSet and Job.
+    pub async fn cached() -> Arc<Self> {
+        let cached = CACHE
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .as_ref()
+            .filter(|(loaded_at, _)| loaded_at.elapsed() < CACHE_TTL)
+            .map(|(_, index)| index.clone());
+        match cached {
+            Some(index) => index,
+            None => Self::load().await,
+        }
+    }
+
+    async fn fetch() -> Result<Self> {
+        let client = build_kube_client().await?;
+        let replicasets = fetch_replicasets(&client).await?;
+        let jobs = fetch_jobs(&client).await?;
+        Ok(Self::from_objects(&replicasets, &jobs))
+    }
+
+    pub fn from_objects(replicasets: &[ReplicaSet], jobs: &[Job]) -> Self {
+        Self {
+            replicaset_deployments: controllers_by_name(replicasets.iter().map(|rs| &rs.metadata), "Deployment"),
+            job_cronjobs: controllers_by_name(jobs.iter().map(|j| &j.metadata), "CronJob"),
+        }
+    }
+
+    /// Name of the `kind` workload that owns `pod`, if any.
+    ///
+    /// The controller stored on the pod wins. Otherwise the ReplicaSet or Job
+    /// is looked up in the index, and names are inferred when it is not there
+    /// (deleted, or the API could not be read).
+    pub fn owner_of(&self, pod: &InfoPodEntity, kind: WorkloadKind) -> Option<String> {
+        let owner_kind = non_empty(&pod.owner_kind)?;
+        let owner_name = non_empty(&pod.owner_name)?;
+        let key = || (pod.namespace.clone().unwrap_or_default(), owner_name.to_string());
+        let stored = non_empty(&pod.controller_kind).zip(non_empty(&pod.controller_name));
+
+        match kind {
+            WorkloadKind::Deployment | WorkloadKind::CronJob if stored.is_some() => stored
+                .filter(|(controller_kind, _)| *controller_kind == kind.as_kind())
+                .map(|(_, name)| name.to_string()),
+            WorkloadKind::Deployment if owner_kind == "ReplicaSet" => {
+                match self.replicaset_deployments.get(&key()) {
+                    Some(deployment) => deployment.clone(),
+                    // "<deployment>-<pod-template-hash>"
+                    None => owner_name.rsplit_once('-').map(|(base, _)| base.to_string()),
+                }
+            }
+            WorkloadKind::CronJob if owner_kind == "Job" => match self.job_cronjobs.get(&key()) {
+                Some(cronjob) => cronjob.clone(),
+                // "<cronjob>-<scheduled time in minutes>"
+                None => owner_name
+                    .rsplit_once('-')
+                    .filter(|(_, suffix)| !suffix.is_empty() && suffix.chars().all(|c| c.is_ascii_digit()))
+                    .map(|(base, _)| base.to_string()),
+            },
+            WorkloadKind::StatefulSet | WorkloadKind::DaemonSet | WorkloadKind::Job
+                if owner_kind == kind.as_kind() =>
+            {
+                Some(owner_name.to_string())
+            }
+            _ => None,
+        }
+    }
+
+    /// Top-level controller of `pod` as `(kind, name)`, e.g. the Deployment
+    /// rather than its ReplicaSet. Falls back to the direct owner.
+    pub fn controller_of(&self, pod: &InfoPodEntity) -> Option<(String, String)> {
+        [WorkloadKind::Deployment, WorkloadKind::CronJob]
+            .into_iter()
+            .find_map(|kind| self.owner_of(pod, kind).map(|name| (kind.as_kind().to_string(), name)))
+            .or_else(|| Some((non_empty(&pod.owner_kind)?.to_string(), non_empty(&pod.owner_name)?.to_string())))
+    }
+}
+
+/// Whether `selector` picks the workload `(namespace, name)`. Workloads are
+/// namespaced, so a selector is either `namespace/name` or a bare name that
+/// matches in every namespace.
+pub fn matches_workload(selector: &str, namespace: &str, name: &str) -> bool {
+    match selector.split_once('/') {
+        Some((ns, n)) => ns == namespace && n == name,
+        None => selector == name,
+    }
+}
+
+/// Info fields are stored as `KEY:` when unset, which reads back as `""`.
+fn non_empty(value: &Option<String>) -> Option<&str> {
+    value.as_deref().filter(|v| !v.is_empty())
+}
+
+/// `(namespace, name)` of each object to the name of its `kind` controller.
+fn controllers_by_name<'a>(
+    metas: impl Iterator<Item = &'a ObjectMeta>,
+    kind: &str,
+) -> HashMap<(String, String), Option<String>> {
+    metas
+        .filter_map(|meta| {
+            let owner = meta
+                .owner_references
+                .iter()
+                .flatten()
+                .find(|o| o.kind == kind && o.controller.unwrap_or(true));
+            Some((
+                (meta.namespace.clone().unwrap_or_default(), meta.name.clone()?),
+                owner.map(|o| o.name.clone()),
+            ))
+        })
+        .collect()
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::client::kube_resources::OwnerReference;
+
+    fn meta(name: &str, owner: Option<(&str, &str)>) -> ObjectMeta {
+        ObjectMeta {
+            name: Some(name.into()),
+            namespace: Some("shop".into()),
+            owner_references: owner.map(|(kind, name)| {
+                vec![OwnerReference {
+                    kind: kind.into(),
+                    name: name.into(),
+                    controller: Some(true),
+                    ..Default::default()
+                }]
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn pod(owner_kind: &str, owner_name: &str) -> InfoPodEntity {
+        InfoPodEntity {
+            namespace: Some("shop".into()),
+            owner_kind: Some(owner_kind.into()),
+            owner_name: Some(owner_name.into()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn resolves_controllers_through_replicasets_and_jobs() {
+        let replicasets = [
+            ReplicaSet { metadata: meta("api-7d9f8c6b5", Some(("Deployment", "api"))), ..Default::default() },
+            ReplicaSet { metadata: meta("bare-rs", None), ..Default::default() },
+        ];
+        let jobs = [
+            Job { metadata: meta("report-29000000", Some(("CronJob", "report"))), ..Default::default() },
+            Job { metadata: meta("migrate", None), ..Default::default() },
+        ];
+        let index = WorkloadOwnerIndex::from_objects(&replicasets, &jobs);
+
+        let api = pod("ReplicaSet", "api-7d9f8c6b5");
+        assert_eq!(index.owner_of(&api, WorkloadKind::Deployment).as_deref(), Some("api"));
+        assert_eq!(index.owner_of(&pod("ReplicaSet", "bare-rs"), WorkloadKind::Deployment), None);
+
+        let report = pod("Job", "report-29000000");
+        assert_eq!(index.owner_of(&report, WorkloadKind::CronJob).as_deref(), Some("report"));
+        assert_eq!(index.owner_of(&report, WorkloadKind::Job).as_deref(), Some("report-29000000"));
+        assert_eq!(index.owner_of(&pod("Job", "migrate"), WorkloadKind::CronJob), None);
+
+        let db = pod("StatefulSet", "db");
+        assert_eq!(index.owner_of(&db, WorkloadKind::StatefulSet).as_deref(), Some("db"));
+        assert_eq!(index.owner_of(&db, WorkloadKind::DaemonSet), None);
+
+        // Owners missing from the index (deleted, or no API access) are inferred
+        let gone = pod("ReplicaSet", "web-5c4b3a291");
+        assert_eq!(index.owner_of(&gone, WorkloadKind::Deployment).as_deref(), Some("web"));
+        let fallback = WorkloadOwnerIndex::default();
+        assert_eq!(fallback.owner_of(&api, WorkloadKind::Deployment).as_deref(), Some("api"));
+        assert_eq!(fallback.owner_of(&report, WorkloadKind::CronJob).as_deref(), Some("report"));
+        assert_eq!(fallback.owner_of(&pod("Job", "migrate"), WorkloadKind::CronJob), None);
+    }
+
+    #[test]
+    fn stored_controller_wins_over_the_index() {
+        let index = WorkloadOwnerIndex::default();
+        let stored = |kind: &str, name: &str| InfoPodEntity {
+            controller_kind: Some(kind.into()),
+            controller_name: Some(name.into()),
+            ..pod("ReplicaSet", "checkout-v2-6f7d")
+        };
+
+        let pod = stored("Deployment", "checkout");
+        assert_eq!(index.owner_of(&pod, WorkloadKind::Deployment).as_deref(), Some("checkout"));
+        assert_eq!(index.controller_of(&pod), Some(("Deployment".into(), "checkout".into())));
+        // A bare ReplicaSet stays unowned even though its name looks derived
+        assert_eq!(index.owner_of(&stored("ReplicaSet", "checkout-v2-6f7d"), WorkloadKind::Deployment), None);
+    }
+
+    #[test]
+    fn selectors_match_namespaced_workloads() {
+        assert!(matches_workload("api", "shop", "api"));
+        assert!(matches_workload("shop/api", "shop", "api"));
+        assert!(!matches_workload("billing/api", "shop", "api"));
+        assert!(!matches_workload("api-v2", "shop", "api"));
+    }
+}
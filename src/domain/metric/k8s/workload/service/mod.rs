@@ -0,0 +1,361 @@
+use anyhow::{anyhow, Result};
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+
+use crate::core::persistence::storage_backend::storage_backend;
+use crate::api::dto::metrics_dto::RangeQuery;
+use crate::core::persistence::info::fixed::unit_price::info_unit_price_entity::InfoUnitPriceEntity;
+use crate::core::persistence::info::{
+    k8s::pod::{info_pod_entity::InfoPodEntity, info_pod_repository::InfoPodRepository},
+    path::info_k8s_pod_dir_path,
+};
+use crate::core::persistence::info::k8s::pod::info_pod_api_repository_trait::InfoPodApiRepository;
+use crate::domain::metric::k8s::common::dto::{
+    MetricGetResponseDto, MetricSeriesDto, UniversalMetricPointDto,
+};
+use crate::domain::metric::k8s::common::service_helpers::{
+    apply_idle_share, build_cost_summary_dto, build_cost_trend_dto, build_raw_summary_value,
+};
+use crate::domain::metric::k8s::namespace::service::aggregate_namespace_points;
+use crate::domain::metric::k8s::node::service::build_cluster_cost_breakdown;
+use crate::domain::metric::k8s::pvc::service::build_pvc_cost_breakdown;
+use crate::domain::metric::k8s::workload::owner::{matches_workload, WorkloadKind, WorkloadOwnerIndex};
+
+use crate::domain::info::service::info_unit_price_service;
+use crate::domain::metric::k8s::pod::service::{
+    build_pod_cost_response_from_infos, build_pod_response_from_infos, filter_pod_infos,
+};
+
+// ------------------------------
+// Helpers
+// ------------------------------
+
+/// `(namespace, name)` of a workload.
+type WorkloadKey = (String, String);
+
+/// Name used for a workload in responses, `namespace/name`.
+fn workload_label((namespace, name): &WorkloadKey) -> String {
+    format!("{namespace}/{name}")
+}
+
+/// Load pods grouped by their `kind` workload, keeping only those that match
+/// the tag and label filters of `q` and one of the `filter` selectors
+/// (`namespace/name` or a bare name).
+async fn load_pods_by_workload(
+    kind: WorkloadKind,
+    q: &RangeQuery,
+    filter: &[String],
+) -> Result<BTreeMap<WorkloadKey, Vec<InfoPodEntity>>> {
+    let mut map: BTreeMap<WorkloadKey, Vec<InfoPodEntity>> = BTreeMap::new();
+    let repo = InfoPodRepository::new();
+
+    let mut pods = Vec::new();
+    for pod_uid in storage_backend().list_keys(&info_k8s_pod_dir_path())? {
+        if let Ok(pod) = repo.read(&pod_uid) {
+            pods.push(pod);
+        }
+    }
+
+    let owners = WorkloadOwnerIndex::cached().await;
+    for pod in filter_pod_infos(q, pods)? {
+        let Some(owner) = owners.owner_of(&pod, kind) else {
+            continue;
+        };
+        let namespace = pod.namespace.clone().unwrap_or_default();
+        if filter.is_empty() || filter.iter().any(|s| matches_workload(s, &namespace, &owner)) {
+            map.entry((namespace, owner)).or_default().push(pod);
+        }
+    }
+
+    Ok(map)
+}
+
+/// Pods of the single workload picked by `selector`. A bare name that exists
+/// in several namespaces is rejected as ambiguous.
+async fn pods_for_workload(
+    kind: WorkloadKind,
+    q: &RangeQuery,
+    selector: &str,
+) -> Result<(String, Vec<InfoPodEntity>)> {
+    let map = load_pods_by_workload(kind, q, &[selector.to_string()]).await?;
+
+    let mut matches = map.into_iter().filter(|(_, pods)| !pods.is_empty());
+    match (matches.next(), matches.next()) {
+        (Some((key, pods)), None) => Ok((workload_label(&key), pods)),
+        (Some(_), Some(_)) => Err(anyhow!(
+            "{} '{}' exists in several namespaces, use namespace/name",
+            kind.as_scope_str(),
+            selector
+        )),
+        (None, _) => Err(anyhow!("{} '{}' has no pods", kind.as_scope_str(), selector)),
+    }
+}
+
+async fn all_pods_for(
+    kind: WorkloadKind,
+    q: &RangeQuery,
+    names: &[String],
+) -> Result<Vec<InfoPodEntity>> {
+    let map = load_pods_by_workload(kind, q, names).await?;
+    Ok(map.into_values().flatten().collect())
+}
+
+fn aggregate_workload_response(
+    kind: WorkloadKind,
+    name: &str,
+    per_pod_response: &MetricGetResponseDto,
+) -> MetricGetResponseDto {
+    let all_points: Vec<UniversalMetricPointDto> =
+        per_pod_response.series.iter().flat_map(|s| s.points.clone()).collect();
+
+    let aggregated_points = aggregate_namespace_points(all_points);
+
+    MetricGetResponseDto {
+        start: per_pod_response.start,
+        end: per_pod_response.end,
+        scope: kind.as_scope_str().to_string(),
+        target: Some(name.to_string()),
+        granularity: per_pod_response.granularity.clone(),
+        series: vec![MetricSeriesDto {
+            key: name.to_string(),
+            name: name.to_string(),
+            scope: kind.scope(),
+            points: aggregated_points,
+            running_hours: None,
+            cost_summary: None,
+        }],
+        total: None,
+        limit: None,
+        offset: None,
+        currency: per_pod_response.currency,
+    }
+}
+
+// ------------------------------
+// RAW (MULTIPLE)
+// ------------------------------
+
+pub async fn get_metric_k8s_workloads_raw(
+    kind: WorkloadKind,
+    q: RangeQuery,
+    names: Vec<String>,
+) -> Result<Value> {
+    let map = load_pods_by_workload(kind, &q, &names).await?;
+
+    let mut series = Vec::new();
+    let mut base = None;
+
+    for (key, pods) in map {
+        if pods.is_empty() {
+            continue;
+        }
+        let name = workload_label(&key);
+        let pod_response = build_pod_response_from_infos(q.clone(), pods, Some(name.clone()))?;
+        let aggregated = aggregate_workload_response(kind, &name, &pod_response);
+
+        if base.is_none() {
+            base = Some(aggregated.clone());
+        }
+        series.push(aggregated.series[0].clone());
+    }
+
+    if let Some(mut final_resp) = base {
+        final_resp.target = None;
+        final_resp.series = series;
+        return Ok(serde_json::to_value(final_resp)?);
+    }
+
+    Ok(json!({ "status": "no data" }))
+}
+
+// ------------------------------
+// RAW (SINGLE)
+// ------------------------------
+
+pub async fn get_metric_k8s_workload_raw(
+    kind: WorkloadKind,
+    name: String,
+    q: RangeQuery,
+) -> Result<Value> {
+    let (name, pods) = pods_for_workload(kind, &q, &name).await?;
+    let pod_response = build_pod_response_from_infos(q, pods, Some(name.clone()))?;
+    let aggregated = aggregate_workload_response(kind, &name, &pod_response);
+
+    Ok(serde_json::to_value(aggregated)?)
+}
+
+// ------------------------------
+// RAW SUMMARY (MULTIPLE)
+// ------------------------------
+
+pub async fn get_metric_k8s_workloads_raw_summary(
+    kind: WorkloadKind,
+    q: RangeQuery,
+    names: Vec<String>,
+) -> Result<Value> {
+    let all_pods = all_pods_for(kind, &q, &names).await?;
+
+    if all_pods.is_empty() {
+        return Ok(json!({ "status": "no data" }));
+    }
+
+    let per_pod = build_pod_response_from_infos(q, all_pods.clone(), None)?;
+    let aggregated = aggregate_workload_response(kind, "all", &per_pod);
+
+    build_raw_summary_value(&aggregated, kind.scope(), all_pods.len())
+}
+
+// ------------------------------
+// RAW SUMMARY (SINGLE)
+// ------------------------------
+
+pub async fn get_metric_k8s_workload_raw_summary(
+    kind: WorkloadKind,
+    name: String,
+    q: RangeQuery,
+) -> Result<Value> {
+    let (name, pods) = pods_for_workload(kind, &q, &name).await?;
+    let per_pod = build_pod_response_from_infos(q, pods.clone(), Some(name.clone()))?;
+    let aggregated = aggregate_workload_response(kind, &name, &per_pod);
+
+    build_raw_summary_value(&aggregated, kind.scope(), pods.len())
+}
+
+// ------------------------------
+// RAW EFFICIENCY (NOT SUPPORTED)
+// ------------------------------
+
+pub async fn get_metric_k8s_workload_raw_efficiency(kind: WorkloadKind) -> Result<Value> {
+    Ok(json!({
+        "status": "not_supported",
+        "message": format!("{} efficiency not supported yet", kind.as_kind())
+    }))
+}
+
+// ------------------------------
+// COST (HELPERS)
+// ------------------------------
+
+async fn build_workload_cost(
+    kind: WorkloadKind,
+    name: Option<String>,
+    q: RangeQuery,
+    filter: &[String],
+    unit_prices: &InfoUnitPriceEntity,
+) -> Result<MetricGetResponseDto> {
+    let (name, pods) = match name {
+        Some(selector) => {
+            let (name, pods) = pods_for_workload(kind, &q, &selector).await?;
+            (Some(name), pods)
+        }
+        None => (None, all_pods_for(kind, &q, filter).await?),
+    };
+
+    if pods.is_empty() {
+        return Err(anyhow!("no pods available for {} cost calculation", kind.as_scope_str()));
+    }
+
+    let share_idle = q.share_idle.unwrap_or(false);
+    let per_pod =
+        build_pod_cost_response_from_infos(q.clone(), pods, name.clone(), unit_prices).await?;
+    let mut response = aggregate_workload_response(
+        kind,
+        name.as_deref().unwrap_or("all"),
+        &per_pod,
+    );
+
+    if share_idle {
+        let cluster = build_cluster_cost_breakdown(q, unit_prices).await?;
+        apply_idle_share(&mut response, &cluster);
+    }
+
+    Ok(response)
+}
+
+// ------------------------------
+// COST (MULTIPLE)
+// ------------------------------
+
+pub async fn get_metric_k8s_workloads_cost(
+    kind: WorkloadKind,
+    q: RangeQuery,
+    names: Vec<String>,
+) -> Result<Value> {
+    let unit_prices = info_unit_price_service::get_info_unit_prices_in(q.currency).await?;
+    let dto = build_workload_cost(kind, None, q, &names, &unit_prices).await?;
+
+    Ok(serde_json::to_value(dto)?)
+}
+
+pub async fn get_metric_k8s_workloads_cost_summary(
+    kind: WorkloadKind,
+    q: RangeQuery,
+    names: Vec<String>,
+) -> Result<Value> {
+    let unit_prices = info_unit_price_service::get_info_unit_prices_in(q.currency).await?;
+    let dto = build_workload_cost(kind, None, q.clone(), &names, &unit_prices).await?;
+
+    // Persistent storage is billed on the capacity of the claims the workloads mount
+    let mut summary = build_cost_summary_dto(&dto, kind.scope(), None, &unit_prices);
+    let pvc = build_pvc_cost_breakdown(&q, &unit_prices).await?;
+    summary.summary.set_persistent_storage_cost(pvc.owner_cost(kind.as_kind(), |ns, name| {
+        names.is_empty() || names.iter().any(|s| matches_workload(s, ns, name))
+    }));
+    Ok(serde_json::to_value(summary)?)
+}
+
+pub async fn get_metric_k8s_workloads_cost_trend(
+    kind: WorkloadKind,
+    q: RangeQuery,
+    names: Vec<String>,
+) -> Result<Value> {
+    let unit_prices = info_unit_price_service::get_info_unit_prices_in(q.currency).await?;
+    let dto = build_workload_cost(kind, None, q, &names, &unit_prices).await?;
+
+    let trend = build_cost_trend_dto(&dto, kind.scope(), None)?;
+    Ok(serde_json::to_value(trend)?)
+}
+
+// ------------------------------
+// COST (SINGLE)
+// ------------------------------
+
+pub async fn get_metric_k8s_workload_cost(
+    kind: WorkloadKind,
+    name: String,
+    q: RangeQuery,
+) -> Result<Value> {
+    let unit_prices = info_unit_price_service::get_info_unit_prices_in(q.currency).await?;
+    let dto = build_workload_cost(kind, Some(name), q, &[], &unit_prices).await?;
+
+    Ok(serde_json::to_value(dto)?)
+}
+
+pub async fn get_metric_k8s_workload_cost_summary(
+    kind: WorkloadKind,
+    name: String,
+    q: RangeQuery,
+) -> Result<Value> {
+    let unit_prices = info_unit_price_service::get_info_unit_prices_in(q.currency).await?;
+    let dto = build_workload_cost(kind, Some(name), q.clone(), &[], &unit_prices).await?;
+
+    let mut summary = build_cost_summary_dto(&dto, kind.scope(), dto.target.clone(), &unit_prices);
+    let target = dto.target.clone().unwrap_or_default();
+    let pvc = build_pvc_cost_breakdown(&q, &unit_prices).await?;
+    summary
+        .summary
+        .set_persistent_storage_cost(pvc.owner_cost(kind.as_kind(), |ns, name| matches_workload(&target, ns, name)));
+    Ok(serde_json::to_value(summary)?)
+}
+
+pub async fn get_metric_k8s_workload_cost_trend(
+    kind: WorkloadKind,
+    name: String,
+    q: RangeQuery,
+) -> Result<Value> {
+    let unit_prices = info_unit_price_service::get_info_unit_prices_in(q.currency).await?;
+    let dto = build_workload_cost(kind, Some(name), q, &[], &unit_prices).await?;
+
+    let trend = build_cost_trend_dto(&dto, kind.scope(), dto.target.clone())?;
+    Ok(serde_json::to_value(trend)?)
+}
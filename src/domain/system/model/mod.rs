@@ -1,2 +1,31 @@
 //! Domain entities for system (SystemStatus, HealthReport, BackupJob, etc.)
 
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::core::persistence::metrics::metric_rollup::RollupTier;
+use crate::scheduler::tasks::processors::backfill::ReaggregateSummary;
+
+/// State of a background re-aggregation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReaggregateJobStatus {
+    Running,
+    Completed,
+    Failed,
+}
+
+/// One forced re-aggregation started through `POST /system/reaggregate`.
+#[derive(Debug, Clone, Serialize)]
+pub struct ReaggregateJob {
+    pub job_id: String,
+    pub status: ReaggregateJobStatus,
+    pub tier: Option<RollupTier>,
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: Option<DateTime<Utc>>,
+    pub summary: Option<ReaggregateSummary>,
+    pub error: Option<String>,
+}
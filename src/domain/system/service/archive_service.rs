@@ -0,0 +1,21 @@
+use anyhow::Result;
+
+use crate::api::dto::system_dto::ArchiveRestoreRequest;
+use crate::core::persistence::info::fixed::setting::info_setting_api_repository_trait::InfoSettingApiRepository;
+use crate::core::persistence::info::fixed::setting::info_setting_repository::InfoSettingRepository;
+use crate::core::persistence::metrics::metric_archive::{
+    self, archive_root, MetricArchiveEntry, MetricArchiveRestoreSummary,
+};
+
+/// Archives written by the `archive` retention policy.
+pub async fn list_archives() -> Result<Vec<MetricArchiveEntry>> {
+    let settings = InfoSettingRepository::new().read()?;
+    metric_archive::list_archives(&archive_root(&settings))
+}
+
+/// Restores one archive into the read-only restored tree so historical
+/// queries can read it again without retention archiving it a second time.
+pub async fn restore_archive(req: ArchiveRestoreRequest) -> Result<MetricArchiveRestoreSummary> {
+    let settings = InfoSettingRepository::new().read()?;
+    metric_archive::restore_archive(&archive_root(&settings), &req.archive)
+}
@@ -1,7 +1,130 @@
 use anyhow::Result;
-use serde_json::{json, Value};
+use chrono::Utc;
+use futures::{Stream, StreamExt};
+use std::io::{self, Read, Write};
+use tokio::sync::mpsc;
+use tokio::task;
+use tracing::error;
 
-pub async fn backup() -> Result<Value> {
-    Ok(json!({"backup": "scheduled"}))
+use crate::api::dto::system_dto::BackupRestoreRequest;
+use crate::core::persistence::data_backup::{
+    self, backup_root, BackupEntry, BackupRestoreSummary, BackupSummary,
+};
+use crate::core::persistence::info::fixed::setting::info_setting_api_repository_trait::InfoSettingApiRepository;
+use crate::core::persistence::info::fixed::setting::info_setting_repository::InfoSettingRepository;
+
+/// Chunk size of a streamed backup.
+const STREAM_CHUNK_BYTES: usize = 64 * 1024;
+
+/// Writes a backup archive into the backup directory and applies
+/// `backup_retention_count`.
+pub async fn backup() -> Result<BackupSummary> {
+    let settings = InfoSettingRepository::new().read()?;
+    let root = backup_root(&settings);
+    let keep = settings.backup_retention_count;
+    task::spawn_blocking(move || data_backup::create_backup(&root, keep, Utc::now())).await?
+}
+
+/// Streams a backup archive as it is written; nothing is stored locally.
+pub fn backup_stream() -> mpsc::Receiver<io::Result<Vec<u8>>> {
+    let (tx, rx) = mpsc::channel(8);
+    task::spawn_blocking(move || {
+        let mut writer = ChannelWriter { tx: tx.clone(), buf: Vec::with_capacity(STREAM_CHUNK_BYTES) };
+        let result = data_backup::write_backup(&mut writer, Utc::now()).and_then(|_| Ok(writer.flush()?));
+        if let Err(e) = result {
+            error!(?e, "Streaming backup failed");
+            // Ends the response with an error so the client sees a broken download
+            let _ = tx.blocking_send(Err(io::Error::other(e.to_string())));
+        }
+    });
+    rx
+}
+
+/// Backup archives in the backup directory, newest first.
+pub async fn list_backups() -> Result<Vec<BackupEntry>> {
+    let settings = InfoSettingRepository::new().read()?;
+    data_backup::list_backups(&backup_root(&settings))
+}
+
+/// Restores one archive from the backup directory after validating its version.
+pub async fn restore_backup(req: BackupRestoreRequest) -> Result<BackupRestoreSummary> {
+    let settings = InfoSettingRepository::new().read()?;
+    let root = backup_root(&settings);
+    task::spawn_blocking(move || data_backup::restore_backup(&root, &req.file)).await?
+}
+
+/// Restores an uploaded archive as it arrives. The upload is only kept in
+/// the staging directory, and nothing is replaced unless all of it is valid.
+pub async fn restore_uploaded_backup<S>(mut body: S) -> Result<BackupRestoreSummary>
+where
+    S: Stream<Item = io::Result<Vec<u8>>> + Unpin,
+{
+    let (tx, rx) = mpsc::channel(8);
+    let restore = task::spawn_blocking(move || {
+        data_backup::restore_backup_from(ChannelReader { rx, chunk: Vec::new(), pos: 0 })
+    });
+
+    while let Some(chunk) = body.next().await {
+        let failed = chunk.is_err();
+        // A closed channel means the restore stopped early; it reports why
+        if tx.send(chunk).await.is_err() || failed {
+            break;
+        }
+    }
+    drop(tx);
+
+    let mut summary = restore.await??;
+    summary.file = "upload".to_string();
+    Ok(summary)
+}
+
+/// `Write` end of a streamed response body.
+struct ChannelWriter {
+    tx: mpsc::Sender<io::Result<Vec<u8>>>,
+    buf: Vec<u8>,
+}
+
+impl Write for ChannelWriter {
+    fn write(&mut self, data: &[u8]) -> io::Result<usize> {
+        self.buf.extend_from_slice(data);
+        if self.buf.len() >= STREAM_CHUNK_BYTES {
+            self.flush()?;
+        }
+        Ok(data.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        if self.buf.is_empty() {
+            return Ok(());
+        }
+        let chunk = std::mem::replace(&mut self.buf, Vec::with_capacity(STREAM_CHUNK_BYTES));
+        self.tx
+            .blocking_send(Ok(chunk))
+            .map_err(|_| io::Error::new(io::ErrorKind::BrokenPipe, "backup download was closed"))
+    }
 }
 
+/// `Read` end of a streamed request body.
+struct ChannelReader {
+    rx: mpsc::Receiver<io::Result<Vec<u8>>>,
+    chunk: Vec<u8>,
+    pos: usize,
+}
+
+impl Read for ChannelReader {
+    fn read(&mut self, out: &mut [u8]) -> io::Result<usize> {
+        while self.pos >= self.chunk.len() {
+            match self.rx.blocking_recv() {
+                Some(chunk) => {
+                    self.chunk = chunk?;
+                    self.pos = 0;
+                }
+                None => return Ok(0),
+            }
+        }
+        let n = (self.chunk.len() - self.pos).min(out.len());
+        out[..n].copy_from_slice(&self.chunk[self.pos..self.pos + n]);
+        self.pos += n;
+        Ok(n)
+    }
+}
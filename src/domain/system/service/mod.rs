@@ -1,8 +1,10 @@
-//! System services: backup, resync, healthcheck logic
+//! System services: backup, archives, resync, healthcheck logic
 
 pub mod status_service;
 pub mod health_service;
 pub mod backup_service;
+pub mod archive_service;
 pub mod resync_service;
+pub mod reaggregate_service;
 pub mod log_service;
 
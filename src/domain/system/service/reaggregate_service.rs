@@ -0,0 +1,83 @@
+use anyhow::{anyhow, bail, Result};
+use chrono::Utc;
+use std::collections::VecDeque;
+use std::sync::{Mutex, MutexGuard};
+use tracing::error;
+
+use crate::api::dto::system_dto::ReaggregateRequest;
+use crate::domain::system::model::{ReaggregateJob, ReaggregateJobStatus};
+use crate::scheduler::tasks::processors::backfill;
+
+/// Finished jobs kept for `GET /system/reaggregate/{job_id}`.
+const KEEP_FINISHED_JOBS: usize = 20;
+
+static JOBS: Mutex<VecDeque<ReaggregateJob>> = Mutex::new(VecDeque::new());
+
+fn jobs() -> MutexGuard<'static, VecDeque<ReaggregateJob>> {
+    JOBS.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Starts rebuilding hour/day buckets in a time range from their source rows
+/// (e.g. after restoring minute data or fixing a collector) and returns the
+/// job right away. Only one re-aggregation runs at a time.
+pub async fn reaggregate(req: ReaggregateRequest) -> Result<ReaggregateJob> {
+    if req.start >= req.end {
+        bail!("start must be before end");
+    }
+
+    let now = Utc::now();
+    let job = {
+        let mut jobs = jobs();
+        if let Some(running) = jobs.iter().find(|j| j.status == ReaggregateJobStatus::Running) {
+            bail!("Re-aggregation {} is still running", running.job_id);
+        }
+        let job = ReaggregateJob {
+            job_id: format!("reaggregate-{}", now.format("%Y%m%dT%H%M%S%.3fZ")),
+            status: ReaggregateJobStatus::Running,
+            tier: req.tier,
+            start: req.start,
+            end: req.end,
+            started_at: now,
+            finished_at: None,
+            summary: None,
+            error: None,
+        };
+        jobs.push_front(job.clone());
+        while jobs.len() > KEEP_FINISHED_JOBS + 1 {
+            jobs.pop_back();
+        }
+        job
+    };
+
+    let job_id = job.job_id.clone();
+    tokio::spawn(async move {
+        let result = backfill::reaggregate(req.tier, req.start, req.end, now).await;
+        let mut jobs = jobs();
+        let Some(job) = jobs.iter_mut().find(|j| j.job_id == job_id) else {
+            return;
+        };
+        job.finished_at = Some(Utc::now());
+        match result {
+            Ok(summary) => {
+                job.status = ReaggregateJobStatus::Completed;
+                job.summary = Some(summary);
+            }
+            Err(e) => {
+                error!(?e, %job_id, "Forced re-aggregation failed");
+                job.status = ReaggregateJobStatus::Failed;
+                job.error = Some(format!("{:#}", e));
+            }
+        }
+    });
+
+    Ok(job)
+}
+
+/// A re-aggregation started by [`reaggregate`], while it is still remembered.
+pub async fn get_reaggregate_job(job_id: &str) -> Result<ReaggregateJob> {
+    jobs()
+        .iter()
+        .find(|j| j.job_id == job_id)
+        .cloned()
+        .ok_or_else(|| anyhow!("Re-aggregation job '{}' not found", job_id))
+}
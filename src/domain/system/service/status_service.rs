@@ -3,6 +3,9 @@
 use std::sync::Arc;
 use serde_json::{json, Value};
 use anyhow::Result;
+use crate::core::persistence::info::fixed::setting::info_setting_api_repository_trait::InfoSettingApiRepository;
+use crate::core::persistence::info::fixed::setting::info_setting_repository::InfoSettingRepository;
+use crate::core::persistence::storage_budget::measure_storage_usage;
 use crate::core::state::runtime::k8s::k8s_runtime_state_manager::K8sRuntimeStateManager;
 use crate::core::state::runtime::k8s::k8s_runtime_state_repository::K8sRuntimeStateRepository;
 use crate::core::state::runtime::k8s::k8s_runtime_state_repository_trait::K8sRuntimeStateRepositoryTrait;
@@ -10,11 +13,14 @@ pub async fn status_internal(
     k8s_state: Arc<K8sRuntimeStateManager<K8sRuntimeStateRepository>>,
 ) -> Result<Value> {
     let st = k8s_state.repo.get().await;
+    let settings = InfoSettingRepository::new().read()?;
+    let storage = measure_storage_usage(settings.max_storage_gb)?;
 
     Ok(json!({
         "last_discovered_at": st.last_discovered_at,
         "last_error_at": st.last_error_at,
         "last_error_message": st.last_error_message,
         "resync_running": k8s_state.is_resyncing(),
+        "storage": storage,
     }))
 }
@@ -36,7 +36,7 @@ pub struct InfoSettingUpsertRequest {
     /// Maximum local storage capacity in gigabytes before cleanup triggers.
     pub max_storage_gb: Option<u32>,
 
-    /// Enables on-disk compression (gzip or zstd).
+    /// Gzip-compresses closed metric partitions.
     pub compression_enabled: Option<bool>,
 
     // ===== Metrics Collection =====
@@ -43,7 +43,7 @@ pub struct InfoSettingEntity {
     /// Maximum local storage capacity in gigabytes before cleanup triggers.
     pub max_storage_gb: u32,
 
-    /// Enables on-disk compression (gzip or zstd).
+    /// Gzip-compresses closed metric partitions.
     pub compression_enabled: bool,
 
     // ===== Metrics Collection =====
@@ -1,6 +1,7 @@
-use super::tasks::{day_task, hour_task, minute_task};
+use super::tasks::{day_task, hour_task, minute_task, rollup_catch_up_task};
 // src/scheduler/schedule.rs
 use crate::app_state::AppState;
+use crate::core::persistence::metrics::metric_write_buffer::flush_metric_writes;
 use anyhow::Result;
 use chrono::Duration as ChronoDuration;
 use chrono::{Timelike, Utc};
@@ -18,7 +19,7 @@ pub async fn scheduler_start_all_tasks(state: AppState, mut shutdown: broadcast:
     let mut s3 = shutdown.resubscribe();
 
     // Minute loop
-    tokio::spawn({
+    let minute = tokio::spawn({
         let state = state.clone(); // ✔ each spawn gets its own clone
         async move {
             run_minute_loop(state, &mut s1).await;
@@ -26,26 +27,38 @@ pub async fn scheduler_start_all_tasks(state: AppState, mut shutdown: broadcast:
     });
 
     // Hour loop
-    tokio::spawn({
+    let hour = tokio::spawn({
         async move {
             run_hour_loop(&mut s2).await;
         }
     });
 
     // Day loop
-    tokio::spawn({
+    let day = tokio::spawn({
         async move {
             run_day_loop(&mut s3).await;
         }
     });
 
-    // Keep function alive until shutdown signal
+    // Keep function alive until shutdown signal, then let running tasks finish
     let _ = shutdown.recv().await;
+    for handle in [minute, hour, day] {
+        if let Err(e) = handle.await {
+            error!(?e, "Scheduler loop panicked");
+        }
+    }
+    info!("Scheduler stopped");
 }
 
 /// Runs every aligned minute (e.g., 12:00:00, 12:01:00 …)
 pub async fn run_minute_loop(state: AppState, shutdown: &mut broadcast::Receiver<()>) {
-    align_to_next_minute().await;
+    tokio::select! {
+        _ = align_to_next_minute() => {}
+        _ = shutdown.recv() => {
+            info!("Minute loop shutting down");
+            return;
+        }
+    }
     let mut ticker = interval(Duration::from_secs(60));
     ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
 
@@ -65,6 +78,9 @@ pub async fn run_minute_loop(state: AppState, shutdown: &mut broadcast::Receiver
             }
             _ = shutdown.recv() => {
                 info!("Minute loop shutting down");
+                if let Err(e) = flush_metric_writes() {
+                    error!(?e, "Flushing buffered metric rows on shutdown failed");
+                }
                 break;
             }
         }
@@ -73,7 +89,18 @@ pub async fn run_minute_loop(state: AppState, shutdown: &mut broadcast::Receiver
 
 /// Runs an hour loop that fires at HH:00:30 each hour (e.g., 01:00:30, 02:00:30 …)
 pub async fn run_hour_loop(shutdown: &mut broadcast::Receiver<()>) {
-    align_to_next_hour_plus_30s().await;
+    // Produce hours and days missed while the process was down
+    if let Err(e) = rollup_catch_up_task(Utc::now()).await {
+        error!(?e, "rollup catch-up failed");
+    }
+
+    tokio::select! {
+        _ = align_to_next_hour_plus_30s() => {}
+        _ = shutdown.recv() => {
+            info!("Hour loop shutting down");
+            return;
+        }
+    }
 
     let mut ticker = interval(Duration::from_secs(3600));
     ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
@@ -95,7 +122,13 @@ pub async fn run_hour_loop(shutdown: &mut broadcast::Receiver<()>) {
 
 /// Runs day at 00:30:30 UTC.
 pub async fn run_day_loop(shutdown: &mut broadcast::Receiver<()>) {
-    align_to_next_midnight_plus_30m30s().await;
+    tokio::select! {
+        _ = align_to_next_midnight_plus_30m30s() => {}
+        _ = shutdown.recv() => {
+            info!("Day loop shutting down");
+            return;
+        }
+    }
 
     let mut ticker = interval(Duration::from_secs(86_400)); // 24h
     ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
@@ -8,9 +8,11 @@ use crate::app_state::AppState;
 use crate::core::persistence::info::fixed::alerts::alert_rule_entity::{
     AlertMetricType, AlertRuleEntity, AlertSeverity,
 };
+use crate::core::persistence::metrics::k8s::node::metric_node_entity::MetricNodeEntity;
 use crate::domain::alert::alert_rule_evaluator::{AlertMetricSnapshot, AlertRuleEvaluator};
 use crate::domain::alert::discord_webhook_sender::DiscordWebhookSender;
 use crate::scheduler::tasks::collectors::k8s::summary_dto::Summary;
+use crate::scheduler::tasks::collectors::rustexporter::gpu_snapshot::{GpuSnapshot, GpuUsage};
 
 static EVALUATOR: OnceLock<Mutex<AlertRuleEvaluator>> = OnceLock::new();
 
@@ -18,16 +20,33 @@ pub async fn handle_alarm(
     state: &AppState,
     summary: &Summary,
     now: DateTime<Utc>,
+    gpu: &GpuSnapshot,
 ) -> Result<()> {
-    let alert_cfg = state.info_service.get_info_alerts().await?;
+    evaluate_alert_rules(state, &build_snapshot(summary, gpu), now).await?;
 
-    let snapshot = build_snapshot(summary);
+    // Legacy heuristic alarms (kept until rules replace them fully)
+    check_node_memory(state, summary, now).await?;
+    check_fs_usage(state, summary, now).await?;
+    check_pod_memory(state, summary, now).await?;
+
+    Ok(())
+}
+
+/// Evaluates the alert rules against one node's snapshot, firing and
+/// resolving alerts. Used directly for nodes collected from cAdvisor, which
+/// have no kubelet summary for the legacy alarms.
+pub async fn evaluate_alert_rules(
+    state: &AppState,
+    snapshot: &AlertMetricSnapshot,
+    now: DateTime<Utc>,
+) -> Result<()> {
+    let alert_cfg = state.info_service.get_info_alerts().await?;
     debug!(?snapshot, "alert_snapshot_built");
 
     let (triggered, active_conditions): (Vec<AlertRuleEntity>, HashSet<String>) = {
         let evaluator = EVALUATOR.get_or_init(|| Mutex::new(AlertRuleEvaluator::default()));
         let mut guard = evaluator.lock().unwrap();
-        let outcome = guard.evaluate(&alert_cfg.rules, &snapshot, now);
+        let outcome = guard.evaluate(&alert_cfg.rules, snapshot, now);
         debug!(
             triggered_ids = ?outcome.triggered.iter().map(|r| &r.id).collect::<Vec<_>>(),
             active_ids = ?outcome.active_conditions,
@@ -37,7 +56,7 @@ pub async fn handle_alarm(
     };
 
     for rule in triggered.iter() {
-        let message = format_rule_message(rule, &snapshot);
+        let message = format_rule_message(rule, snapshot);
         debug!(rule_id = %rule.id, severity = ?rule.severity, "alert_rule_triggered");
         state
             .alerts
@@ -59,15 +78,10 @@ pub async fn handle_alarm(
         }
     }
 
-    // Legacy heuristic alarms (kept until rules replace them fully)
-    check_node_memory(state, summary, now).await?;
-    check_fs_usage(state, summary, now).await?;
-    check_pod_memory(state, summary, now).await?;
-
     Ok(())
 }
 
-fn build_snapshot(summary: &Summary) -> AlertMetricSnapshot {
+fn build_snapshot(summary: &Summary, gpu: &GpuSnapshot) -> AlertMetricSnapshot {
     let mem = &summary.node.memory;
     let working = mem.working_set_bytes.or(mem.usage_bytes);
     let avail = mem.available_bytes;
@@ -91,11 +105,36 @@ fn build_snapshot(summary: &Summary) -> AlertMetricSnapshot {
             _ => None,
         });
 
+    let gpu_pct = gpu
+        .node(&summary.node.node_name)
+        .and_then(|g| g.utilization_percent);
+
     AlertMetricSnapshot {
         cpu_usage_percent: cpu_pct,
         memory_usage_percent: mem_pct,
         disk_usage_percent: disk_pct,
-        gpu_usage_percent: None,
+        gpu_usage_percent: gpu_pct,
+    }
+}
+
+/// Snapshot of a node minute row collected from cAdvisor.
+pub fn build_node_row_snapshot(
+    row: &MetricNodeEntity,
+    memory_capacity_bytes: Option<u64>,
+    gpu: Option<&GpuUsage>,
+) -> AlertMetricSnapshot {
+    let percent = |used: Option<u64>, total: Option<u64>| match (used, total) {
+        (Some(u), Some(t)) if t > 0 => Some(u as f64 / t as f64 * 100.0),
+        _ => None,
+    };
+
+    AlertMetricSnapshot {
+        cpu_usage_percent: row
+            .cpu_usage_nano_cores
+            .map(|nano| (nano as f64 / 1_000_000_000.0) * 100.0),
+        memory_usage_percent: percent(row.memory_working_set_bytes, memory_capacity_bytes),
+        disk_usage_percent: percent(row.fs_used_bytes, row.fs_capacity_bytes),
+        gpu_usage_percent: gpu.and_then(|g| g.utilization_percent),
     }
 }
 
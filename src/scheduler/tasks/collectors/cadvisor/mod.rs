@@ -0,0 +1,7 @@
+/* Entry point */
+mod task;
+pub use task::run;
+
+/* cAdvisor samples → pod/container stats */
+pub mod models;
+mod repository;
@@ -0,0 +1,395 @@
+use std::collections::{BTreeMap, HashMap};
+
+use crate::core::util::prometheus_text::PromSample;
+
+// Kubelet-embedded cAdvisor labels first, then standalone cAdvisor (docker labels).
+const NAMESPACE_LABELS: &[&str] = &["namespace", "container_label_io_kubernetes_pod_namespace"];
+const POD_LABELS: &[&str] = &["pod", "container_label_io_kubernetes_pod_name"];
+const POD_UID_LABELS: &[&str] = &["container_label_io_kubernetes_pod_uid"];
+const CONTAINER_LABELS: &[&str] = &["container", "container_label_io_kubernetes_container_name"];
+const NODE_LABELS: &[&str] = &["node", "kubernetes_io_hostname"];
+
+/// Pause containers only hold the pod sandbox (network namespace).
+const SANDBOX_CONTAINER: &str = "POD";
+
+/// Parent cgroup of every pod (cgroupfs and systemd drivers).
+const KUBEPODS_CGROUPS: &[&str] = &["/kubepods", "/kubepods.slice"];
+
+/// Resource usage of one cgroup (a pod or one of its containers), as reported by cAdvisor.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CadvisorCgroupStats {
+    /// Cumulative CPU time in nanoseconds.
+    pub cpu_usage_core_nano_seconds: Option<u64>,
+    pub memory_usage_bytes: Option<u64>,
+    pub memory_working_set_bytes: Option<u64>,
+    pub memory_rss_bytes: Option<u64>,
+    pub memory_page_faults: Option<u64>,
+    pub fs_used_bytes: Option<u64>,
+    pub fs_capacity_bytes: Option<u64>,
+    pub fs_inodes_used: Option<u64>,
+    pub fs_inodes: Option<u64>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CadvisorContainerStats {
+    pub name: String,
+    pub stats: CadvisorCgroupStats,
+}
+
+/// One pod and its containers from a cAdvisor scrape.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CadvisorPodStats {
+    pub pod_uid: String,
+    pub pod_name: Option<String>,
+    pub namespace: Option<String>,
+    pub node_name: Option<String>,
+
+    /// Pod-level cgroup, when cAdvisor exports it.
+    pub pod_cgroup: Option<CadvisorCgroupStats>,
+    pub containers: Vec<CadvisorContainerStats>,
+
+    // Network counters of the pod sandbox, summed over interfaces
+    pub network_rx_bytes: Option<u64>,
+    pub network_tx_bytes: Option<u64>,
+    pub network_rx_errors: Option<u64>,
+    pub network_tx_errors: Option<u64>,
+}
+
+/// One node's root cgroup (`id="/"`) from a cAdvisor scrape.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CadvisorNodeStats {
+    pub node_name: String,
+    pub stats: CadvisorCgroupStats,
+
+    /// The kubepods cgroup; usage outside it is system overhead.
+    pub pods: Option<CadvisorCgroupStats>,
+    pub memory_capacity_bytes: Option<u64>,
+
+    // Host network counters, summed over interfaces
+    pub network_rx_bytes: Option<u64>,
+    pub network_tx_bytes: Option<u64>,
+    pub network_rx_errors: Option<u64>,
+    pub network_tx_errors: Option<u64>,
+}
+
+impl CadvisorPodStats {
+    /// Pod totals: the pod cgroup when present, otherwise the sum of its containers.
+    pub fn totals(&self) -> CadvisorCgroupStats {
+        let summed = self
+            .containers
+            .iter()
+            .fold(CadvisorCgroupStats::default(), |acc, c| acc.plus(&c.stats));
+
+        match &self.pod_cgroup {
+            // Filesystem usage is only tracked per container
+            Some(pod) => CadvisorCgroupStats {
+                fs_used_bytes: summed.fs_used_bytes,
+                fs_capacity_bytes: summed.fs_capacity_bytes,
+                fs_inodes_used: summed.fs_inodes_used,
+                fs_inodes: summed.fs_inodes,
+                ..pod.clone()
+            },
+            None => summed,
+        }
+    }
+}
+
+impl CadvisorCgroupStats {
+    fn plus(&self, other: &Self) -> Self {
+        let add = |a: Option<u64>, b: Option<u64>| match (a, b) {
+            (Some(a), Some(b)) => Some(a + b),
+            (a, b) => a.or(b),
+        };
+        Self {
+            cpu_usage_core_nano_seconds: add(self.cpu_usage_core_nano_seconds, other.cpu_usage_core_nano_seconds),
+            memory_usage_bytes: add(self.memory_usage_bytes, other.memory_usage_bytes),
+            memory_working_set_bytes: add(self.memory_working_set_bytes, other.memory_working_set_bytes),
+            memory_rss_bytes: add(self.memory_rss_bytes, other.memory_rss_bytes),
+            memory_page_faults: add(self.memory_page_faults, other.memory_page_faults),
+            fs_used_bytes: add(self.fs_used_bytes, other.fs_used_bytes),
+            fs_capacity_bytes: add(self.fs_capacity_bytes, other.fs_capacity_bytes),
+            fs_inodes_used: add(self.fs_inodes_used, other.fs_inodes_used),
+            fs_inodes: add(self.fs_inodes, other.fs_inodes),
+        }
+    }
+}
+
+#[derive(Default)]
+struct CgroupAcc {
+    stats: CadvisorCgroupStats,
+    fs_inodes_free: Option<u64>,
+}
+
+impl CgroupAcc {
+    fn finish(mut self) -> CadvisorCgroupStats {
+        if let (Some(total), Some(free)) = (self.stats.fs_inodes, self.fs_inodes_free) {
+            self.stats.fs_inodes_used = Some(total.saturating_sub(free));
+        }
+        self.stats
+    }
+}
+
+#[derive(Default)]
+struct PodAcc {
+    pod_name: Option<String>,
+    namespace: Option<String>,
+    node_name: Option<String>,
+    pod_cgroup: Option<CgroupAcc>,
+    containers: BTreeMap<String, CgroupAcc>,
+    // (metric, interface) → value; the same sandbox may be reported more than once
+    network: HashMap<(String, String), u64>,
+}
+
+#[derive(Default)]
+struct NodeAcc {
+    root: CgroupAcc,
+    pods: Option<CgroupAcc>,
+    memory_capacity_bytes: Option<u64>,
+    network: HashMap<(String, String), u64>,
+}
+
+/// Sum of one network counter over all interfaces.
+fn network_total(network: &HashMap<(String, String), u64>, metric: &str) -> Option<u64> {
+    network
+        .iter()
+        .filter(|((m, _), _)| m == metric)
+        .map(|(_, v)| *v)
+        .reduce(|a, b| a + b)
+}
+
+/// Groups the node-level samples (root and kubepods cgroups) into per-node stats.
+///
+/// Only samples carrying a node label can be attributed to a node; nodes
+/// without one keep being collected from the kubelet summary.
+pub fn group_cadvisor_nodes(samples: &[PromSample]) -> Vec<CadvisorNodeStats> {
+    let mut nodes: BTreeMap<String, NodeAcc> = BTreeMap::new();
+
+    for sample in samples {
+        if !sample.value.is_finite() || sample.value < 0.0 {
+            continue;
+        }
+        let Some(node_name) = sample.label(NODE_LABELS) else {
+            continue;
+        };
+        let value = sample.value as u64;
+
+        if sample.name == "machine_memory_bytes" {
+            nodes.entry(node_name.to_string()).or_default().memory_capacity_bytes = Some(value);
+            continue;
+        }
+        if !sample.name.starts_with("container_") || sample.label(&["cpu"]).is_some_and(|cpu| cpu != "total") {
+            continue;
+        }
+
+        let id = sample.labels.get("id").map(String::as_str).unwrap_or_default();
+        let node = nodes.entry(node_name.to_string()).or_default();
+        if id == "/" {
+            if let Some(metric) = sample.name.strip_prefix("container_network_") {
+                let iface = sample.label(&["interface"]).unwrap_or_default().to_string();
+                node.network.insert((metric.to_string(), iface), value);
+            } else {
+                record(&mut node.root, sample, value);
+            }
+        } else if KUBEPODS_CGROUPS.contains(&id) {
+            record(node.pods.get_or_insert_with(CgroupAcc::default), sample, value);
+        }
+    }
+
+    nodes
+        .into_iter()
+        .filter(|(_, acc)| acc.root.stats != CadvisorCgroupStats::default())
+        .map(|(node_name, acc)| CadvisorNodeStats {
+            network_rx_bytes: network_total(&acc.network, "receive_bytes_total"),
+            network_tx_bytes: network_total(&acc.network, "transmit_bytes_total"),
+            network_rx_errors: network_total(&acc.network, "receive_errors_total"),
+            network_tx_errors: network_total(&acc.network, "transmit_errors_total"),
+            node_name,
+            stats: acc.root.finish(),
+            pods: acc.pods.map(CgroupAcc::finish),
+            memory_capacity_bytes: acc.memory_capacity_bytes,
+        })
+        .collect()
+}
+
+/// Groups cAdvisor samples into per-pod stats.
+///
+/// Pods are keyed by UID, taken from the pod UID label or the cgroup path
+/// (`/kubepods/.../pod<uid>/...` or `kubepods-...-pod<uid_with_underscores>.slice`).
+/// Samples outside a pod cgroup (system slices, the node root) are ignored.
+pub fn group_cadvisor_samples(samples: &[PromSample]) -> Vec<CadvisorPodStats> {
+    let mut pods: BTreeMap<String, PodAcc> = BTreeMap::new();
+
+    for sample in samples {
+        if !sample.name.starts_with("container_") || !sample.value.is_finite() || sample.value < 0.0 {
+            continue;
+        }
+        // Per-core CPU series duplicate the total
+        if sample.label(&["cpu"]).is_some_and(|cpu| cpu != "total") {
+            continue;
+        }
+
+        let id = sample.labels.get("id").map(String::as_str).unwrap_or_default();
+        let Some(pod_uid) = sample
+            .label(POD_UID_LABELS)
+            .map(str::to_string)
+            .or_else(|| pod_uid_from_cgroup(id))
+        else {
+            continue;
+        };
+
+        let pod = pods.entry(pod_uid).or_default();
+        if pod.pod_name.is_none() {
+            pod.pod_name = sample.label(POD_LABELS).map(str::to_string);
+        }
+        if pod.namespace.is_none() {
+            pod.namespace = sample.label(NAMESPACE_LABELS).map(str::to_string);
+        }
+        if pod.node_name.is_none() {
+            pod.node_name = sample.label(NODE_LABELS).map(str::to_string);
+        }
+
+        let value = sample.value as u64;
+        if let Some(metric) = sample.name.strip_prefix("container_network_") {
+            let iface = sample.label(&["interface"]).unwrap_or_default().to_string();
+            let slot = pod.network.entry((metric.to_string(), iface)).or_default();
+            *slot = (*slot).max(value);
+            continue;
+        }
+
+        let cgroup = match sample.label(CONTAINER_LABELS) {
+            Some(SANDBOX_CONTAINER) => continue,
+            Some(name) => pod.containers.entry(name.to_string()).or_default(),
+            // Only the pod's own cgroup has no container name
+            None if is_pod_cgroup(id) => pod.pod_cgroup.get_or_insert_with(CgroupAcc::default),
+            None => continue,
+        };
+        record(cgroup, sample, value);
+    }
+
+    pods.into_iter()
+        .map(|(pod_uid, acc)| {
+            let net = |metric: &str| network_total(&acc.network, metric);
+            CadvisorPodStats {
+                network_rx_bytes: net("receive_bytes_total"),
+                network_tx_bytes: net("transmit_bytes_total"),
+                network_rx_errors: net("receive_errors_total"),
+                network_tx_errors: net("transmit_errors_total"),
+                pod_uid,
+                pod_name: acc.pod_name,
+                namespace: acc.namespace,
+                node_name: acc.node_name,
+                pod_cgroup: acc.pod_cgroup.map(CgroupAcc::finish),
+                containers: acc
+                    .containers
+                    .into_iter()
+                    .map(|(name, c)| CadvisorContainerStats { name, stats: c.finish() })
+                    .collect(),
+            }
+        })
+        .collect()
+}
+
+fn record(cgroup: &mut CgroupAcc, sample: &PromSample, value: u64) {
+    let stats = &mut cgroup.stats;
+    // Filesystem series are per device, so they are summed
+    let sum = |slot: &mut Option<u64>| *slot = Some(slot.unwrap_or(0) + value);
+
+    match sample.name.as_str() {
+        "container_cpu_usage_seconds_total" => {
+            stats.cpu_usage_core_nano_seconds = Some((sample.value * 1_000_000_000.0) as u64)
+        }
+        "container_memory_usage_bytes" => stats.memory_usage_bytes = Some(value),
+        "container_memory_working_set_bytes" => stats.memory_working_set_bytes = Some(value),
+        "container_memory_rss" => stats.memory_rss_bytes = Some(value),
+        "container_memory_failures_total" => {
+            let labels = (sample.label(&["failure_type"]), sample.label(&["scope"]));
+            if matches!(labels, (Some("pgfault"), Some("container") | None)) {
+                stats.memory_page_faults = Some(value);
+            }
+        }
+        "container_fs_usage_bytes" => sum(&mut stats.fs_used_bytes),
+        "container_fs_limit_bytes" => sum(&mut stats.fs_capacity_bytes),
+        "container_fs_inodes_total" => sum(&mut stats.fs_inodes),
+        "container_fs_inodes_free" => sum(&mut cgroup.fs_inodes_free),
+        _ => {}
+    }
+}
+
+/// Extracts the pod UID from a kubepods cgroup path.
+fn pod_uid_from_cgroup(id: &str) -> Option<String> {
+    id.split('/').find_map(|segment| {
+        let segment = segment.trim_end_matches(".slice");
+        let (_, uid) = segment.rsplit_once("pod")?;
+        let looks_like_uid =
+            !uid.is_empty() && uid.chars().all(|c| c.is_ascii_hexdigit() || c == '-' || c == '_');
+        looks_like_uid.then(|| uid.replace('_', "-"))
+    })
+}
+
+/// True when the cgroup path ends at the pod level (no container below it).
+fn is_pod_cgroup(id: &str) -> bool {
+    id.trim_end_matches('/')
+        .rsplit('/')
+        .next()
+        .is_some_and(|last| pod_uid_from_cgroup(last).is_some())
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::util::prometheus_text::parse_prometheus_text;
+
+    #[test]
+    fn groups_kubelet_cadvisor_samples_by_pod() {
+        let text = r#"
+container_cpu_usage_seconds_total{container="",id="/kubepods.slice/kubepods-burstable.slice/kubepods-burstable-pod1a2b_3c4d.slice",namespace="ml",pod="train-0"} 12.5
+container_memory_working_set_bytes{container="",id="/kubepods.slice/kubepods-burstable.slice/kubepods-burstable-pod1a2b_3c4d.slice",namespace="ml",pod="train-0"} 3000
+container_cpu_usage_seconds_total{container="main",id="/kubepods.slice/kubepods-burstable.slice/kubepods-burstable-pod1a2b_3c4d.slice/cri-containerd-abc.scope",namespace="ml",pod="train-0"} 10
+container_memory_working_set_bytes{container="main",id="/kubepods.slice/kubepods-burstable.slice/kubepods-burstable-pod1a2b_3c4d.slice/cri-containerd-abc.scope",namespace="ml",pod="train-0"} 2000
+container_fs_usage_bytes{container="main",device="/dev/sda1",id="/kubepods.slice/kubepods-burstable.slice/kubepods-burstable-pod1a2b_3c4d.slice/cri-containerd-abc.scope",namespace="ml",pod="train-0"} 100
+container_fs_usage_bytes{container="main",device="/dev/sdb1",id="/kubepods.slice/kubepods-burstable.slice/kubepods-burstable-pod1a2b_3c4d.slice/cri-containerd-abc.scope",namespace="ml",pod="train-0"} 50
+container_network_receive_bytes_total{container="POD",id="/kubepods.slice/kubepods-burstable.slice/kubepods-burstable-pod1a2b_3c4d.slice/cri-containerd-def.scope",interface="eth0",namespace="ml",pod="train-0"} 4096
+container_network_receive_bytes_total{container="",id="/kubepods.slice/kubepods-burstable.slice/kubepods-burstable-pod1a2b_3c4d.slice",interface="eth0",namespace="ml",pod="train-0"} 4096
+container_memory_working_set_bytes{container="",id="/system.slice/kubelet.service"} 999
+"#;
+        let pods = group_cadvisor_samples(&parse_prometheus_text(text));
+        assert_eq!(pods.len(), 1);
+
+        let pod = &pods[0];
+        assert_eq!(pod.pod_uid, "1a2b-3c4d");
+        assert_eq!(pod.pod_name.as_deref(), Some("train-0"));
+        assert_eq!(pod.network_rx_bytes, Some(4096));
+        assert_eq!(pod.containers.len(), 1);
+        assert_eq!(pod.containers[0].stats.fs_used_bytes, Some(150));
+
+        let totals = pod.totals();
+        assert_eq!(totals.cpu_usage_core_nano_seconds, Some(12_500_000_000));
+        assert_eq!(totals.memory_working_set_bytes, Some(3000));
+        assert_eq!(totals.fs_used_bytes, Some(150));
+    }
+
+    #[test]
+    fn groups_labelled_root_cgroups_by_node() {
+        let text = r#"
+machine_memory_bytes{node="node-a"} 8000
+container_cpu_usage_seconds_total{cpu="total",id="/",node="node-a"} 100
+container_memory_working_set_bytes{id="/",node="node-a"} 5000
+container_network_receive_bytes_total{id="/",interface="eth0",node="node-a"} 10
+container_network_receive_bytes_total{id="/",interface="eth1",node="node-a"} 20
+container_memory_working_set_bytes{id="/kubepods.slice",node="node-a"} 3000
+container_memory_working_set_bytes{id="/"} 7000
+"#;
+        let nodes = group_cadvisor_nodes(&parse_prometheus_text(text));
+        assert_eq!(nodes.len(), 1);
+
+        let node = &nodes[0];
+        assert_eq!(node.node_name, "node-a");
+        assert_eq!(node.memory_capacity_bytes, Some(8000));
+        assert_eq!(node.stats.cpu_usage_core_nano_seconds, Some(100_000_000_000));
+        assert_eq!(node.stats.memory_working_set_bytes, Some(5000));
+        assert_eq!(node.network_rx_bytes, Some(30));
+        assert_eq!(node.pods.as_ref().and_then(|p| p.memory_working_set_bytes), Some(3000));
+    }
+}
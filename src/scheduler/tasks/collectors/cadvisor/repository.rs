@@ -0,0 +1,96 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+
+use crate::core::persistence::info::k8s::container::info_container_collector_repository_trait::InfoContainerCollectorRepository;
+use crate::core::persistence::info::k8s::container::info_container_entity::InfoContainerEntity;
+use crate::core::persistence::info::k8s::node::info_node_collector_repository_trait::InfoNodeCollectorRepository;
+use crate::core::persistence::info::k8s::pod::info_pod_collector_repository_trait::InfoPodCollectorRepository;
+use crate::core::persistence::info::k8s::pod::info_pod_entity::InfoPodEntity;
+use crate::core::persistence::metrics::k8s::container::metric_container_entity::MetricContainerEntity;
+use crate::core::persistence::metrics::k8s::container::minute::metric_container_minute_collector_repository_trait::MetricContainerMinuteCollectorRepository;
+use crate::core::persistence::metrics::k8s::container::minute::metric_container_minute_fs_adapter::MetricContainerMinuteFsAdapter;
+use crate::core::persistence::metrics::k8s::node::metric_node_entity::MetricNodeEntity;
+use crate::core::persistence::metrics::k8s::node::minute::metric_node_minute_collector_repository_trait::MetricNodeMinuteCollectorRepository;
+use crate::core::persistence::metrics::k8s::node::minute::metric_node_minute_fs_adapter::MetricNodeMinuteFsAdapter;
+use crate::core::persistence::metrics::k8s::pod::metric_pod_entity::MetricPodEntity;
+use crate::core::persistence::metrics::k8s::pod::minute::metric_pod_minute_collector_repository_trait::MetricPodMinuteCollectorRepository;
+use crate::core::persistence::metrics::k8s::pod::minute::metric_pod_minute_fs_adapter::MetricPodMinuteFsAdapter;
+use crate::scheduler::tasks::collectors::k8s::container::info_container_minute_collector_repository::InfoContainerCollectorRepositoryImpl;
+use crate::scheduler::tasks::collectors::k8s::container::metric_container_minute_collector_repository::MetricContainerMinuteCollectorRepositoryImpl;
+use crate::scheduler::tasks::collectors::k8s::node::info_node_minute_collector_repository::InfoNodeCollectorRepositoryImpl;
+use crate::scheduler::tasks::collectors::k8s::node::metric_node_minute_collector_repository::MetricNodeMinuteCollectorRepositoryImpl;
+use crate::scheduler::tasks::collectors::k8s::pod::info_pod_minute_collector_repository::InfoPodCollectorRepositoryImpl;
+use crate::scheduler::tasks::collectors::k8s::pod::metric_pod_minute_collector_repository::MetricPodMinuteCollectorRepositoryImpl;
+
+/// Persists cAdvisor results into the same info/minute files as the kubelet
+/// summary collector, so both sources are interchangeable downstream.
+pub struct CadvisorCollectorRepository {
+    pod_info: InfoPodCollectorRepositoryImpl,
+    container_info: InfoContainerCollectorRepositoryImpl,
+    node_info: InfoNodeCollectorRepositoryImpl,
+    pod_metrics: MetricPodMinuteCollectorRepositoryImpl,
+    container_metrics: MetricContainerMinuteCollectorRepositoryImpl,
+    node_metrics: MetricNodeMinuteCollectorRepositoryImpl,
+}
+
+impl Default for CadvisorCollectorRepository {
+    fn default() -> Self {
+        Self {
+            pod_info: InfoPodCollectorRepositoryImpl::default(),
+            container_info: InfoContainerCollectorRepositoryImpl::default(),
+            node_info: InfoNodeCollectorRepositoryImpl::default(),
+            pod_metrics: MetricPodMinuteCollectorRepositoryImpl {
+                adapter: MetricPodMinuteFsAdapter,
+            },
+            container_metrics: MetricContainerMinuteCollectorRepositoryImpl {
+                adapter: MetricContainerMinuteFsAdapter,
+            },
+            node_metrics: MetricNodeMinuteCollectorRepositoryImpl {
+                adapter: MetricNodeMinuteFsAdapter,
+            },
+        }
+    }
+}
+
+impl CadvisorCollectorRepository {
+    pub fn create_pod_info_if_missing(&self, pod_uid: &str, info: &InfoPodEntity) -> Result<bool> {
+        self.pod_info.create_if_missing(pod_uid, info)
+    }
+
+    pub fn create_container_info_if_missing(
+        &self,
+        container_key: &str,
+        info: &InfoContainerEntity,
+    ) -> Result<bool> {
+        self.container_info.create_if_missing(container_key, info)
+    }
+
+    pub fn append_pod_row(&self, pod_uid: &str, row: &MetricPodEntity, now: DateTime<Utc>) -> Result<()> {
+        self.pod_metrics.append_row(pod_uid, row, now)
+    }
+
+    pub fn append_container_row(
+        &self,
+        container_key: &str,
+        row: &MetricContainerEntity,
+        now: DateTime<Utc>,
+    ) -> Result<()> {
+        self.container_metrics.append_row(container_key, row, now)
+    }
+
+    pub fn append_node_row(&self, node_name: &str, row: &MetricNodeEntity, now: DateTime<Utc>) -> Result<()> {
+        self.node_metrics.append_row(node_name, row, now)
+    }
+
+    /// Refreshes the system overhead snapshot on existing node info. Missing
+    /// node info is created by the k8s collector from the Node API.
+    pub fn refresh_node_overhead(&self, node_name: &str, row: &MetricNodeEntity) -> Result<()> {
+        if !self.node_info.exists(node_name)? {
+            return Ok(());
+        }
+        let mut existing = self.node_info.fs_adapter().read(node_name)?;
+        existing.system_cpu_usage_nano_cores = row.system_cpu_usage_nano_cores;
+        existing.system_memory_working_set_bytes = row.system_memory_working_set_bytes;
+        self.node_info.update(&existing)
+    }
+}
@@ -0,0 +1,353 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::{Mutex, OnceLock};
+use std::time::Duration;
+
+use anyhow::{anyhow, Context, Result};
+use chrono::{DateTime, Utc};
+use reqwest::Client;
+use tracing::{debug, warn};
+
+use crate::app_state::AppState;
+use crate::core::client::kube_client::load_ca_bytes;
+use crate::core::persistence::info::fixed::setting::info_setting_entity::InfoSettingEntity;
+use crate::core::persistence::info::k8s::container::info_container_entity::InfoContainerEntity;
+use crate::core::persistence::info::k8s::pod::info_pod_entity::InfoPodEntity;
+use crate::core::persistence::metrics::k8s::container::metric_container_entity::MetricContainerEntity;
+use crate::core::persistence::metrics::k8s::node::metric_node_entity::MetricNodeEntity;
+use crate::core::persistence::metrics::k8s::pod::metric_pod_entity::MetricPodEntity;
+use crate::core::util::prometheus_text::parse_prometheus_text;
+use crate::scheduler::tasks::alarm::task::{build_node_row_snapshot, evaluate_alert_rules};
+use crate::scheduler::tasks::collectors::cadvisor::models::{
+    group_cadvisor_nodes, group_cadvisor_samples, CadvisorCgroupStats, CadvisorNodeStats, CadvisorPodStats,
+};
+use crate::scheduler::tasks::collectors::cadvisor::repository::CadvisorCollectorRepository;
+use crate::scheduler::tasks::collectors::netflow::models::{NetworkLocalityBytes, NetworkLocalitySnapshot};
+use crate::scheduler::tasks::collectors::rustexporter::gpu_snapshot::{GpuSnapshot, GpuUsage};
+use crate::scheduler::tasks::info::ownership::task::queue_new_pod;
+
+/// Last `(scrape time, CPU counter)` per cgroup key.
+type CpuCounters = HashMap<String, (DateTime<Utc>, u64)>;
+
+/// Previous CPU counters, used to derive `cpu_usage_nano_cores`
+/// (cAdvisor only exports the cumulative counter).
+static CPU_COUNTERS: OnceLock<Mutex<CpuCounters>> = OnceLock::new();
+
+/// Counters not refreshed for this long are dropped (pod gone or exporter down).
+const CPU_COUNTER_TTL_MINUTES: i64 = 10;
+
+/// Collects pod/container metrics from the cAdvisor endpoints in
+/// `container_exporter_urls`, writing the same minute rows as the kubelet
+/// summary collector.
+///
+/// Node rows are written from the root cgroup of every node-labelled scrape;
+/// the names of those nodes are returned so the kubelet summary is only
+/// fetched for the others.
+pub async fn run(
+    state: &AppState,
+    now: DateTime<Utc>,
+    settings: &InfoSettingEntity,
+    gpu: &GpuSnapshot,
+    network: &NetworkLocalitySnapshot,
+) -> Result<HashSet<String>> {
+    if settings.container_exporter_urls.is_empty() {
+        return Err(anyhow!("cAdvisor source selected but no container exporter URLs are configured"));
+    }
+
+    let client = build_client(settings)?;
+
+    let mut samples = Vec::new();
+    for url in &settings.container_exporter_urls {
+        match scrape(&client, url).await {
+            Ok(text) => samples.extend(parse_prometheus_text(&text)),
+            Err(e) => warn!(?e, %url, "cAdvisor scrape failed"),
+        }
+    }
+
+    let pods = group_cadvisor_samples(&samples);
+    debug!(pods = pods.len(), "cAdvisor scrape finished");
+
+    let repo = CadvisorCollectorRepository::default();
+    for pod in &pods {
+        // Skip pseudo-UIDs (static pod hashes), as the summary collector does
+        if !pod.pod_uid.contains('-') {
+            continue;
+        }
+        handle_pod(&repo, pod, now, gpu, network)?;
+    }
+
+    let mut covered_nodes = HashSet::new();
+    for node in group_cadvisor_nodes(&samples) {
+        let node_gpu = gpu.node(&node.node_name);
+        let row = map_node_metrics(&node, node_gpu, now);
+        repo.append_node_row(&node.node_name, &row, now)?;
+        repo.refresh_node_overhead(&node.node_name, &row)?;
+
+        let snapshot = build_node_row_snapshot(&row, node.memory_capacity_bytes, node_gpu);
+        if let Err(e) = evaluate_alert_rules(state, &snapshot, now).await {
+            warn!(?e, node = %node.node_name, "Evaluating alert rules failed");
+        }
+        covered_nodes.insert(node.node_name);
+    }
+
+    prune_cpu_counters(now);
+    Ok(covered_nodes)
+}
+
+/// HTTP client trusting the cluster CA (kubelet endpoints) and the system
+/// roots. Certificate checks are only skipped when explicitly configured.
+fn build_client(settings: &InfoSettingEntity) -> Result<Client> {
+    let mut builder = Client::builder().timeout(Duration::from_secs(10));
+
+    if let Some(ca) = load_ca_bytes() {
+        match reqwest::Certificate::from_der(&ca) {
+            Ok(cert) => builder = builder.add_root_certificate(cert),
+            Err(e) => warn!(?e, "Cluster CA certificate could not be loaded"),
+        }
+    }
+
+    if settings.container_exporter_insecure_tls {
+        warn!("TLS certificate verification is disabled for container exporter endpoints");
+        builder = builder.danger_accept_invalid_certs(true);
+    }
+
+    builder.build().context("Failed to build HTTP client")
+}
+
+async fn scrape(client: &Client, url: &str) -> Result<String> {
+    client
+        .get(url)
+        .send()
+        .await
+        .context("Failed to request cAdvisor metrics")?
+        .error_for_status()
+        .context("cAdvisor returned an error status")?
+        .text()
+        .await
+        .context("Failed to read cAdvisor response")
+}
+
+fn handle_pod(
+    repo: &CadvisorCollectorRepository,
+    pod: &CadvisorPodStats,
+    now: DateTime<Utc>,
+    gpu: &GpuSnapshot,
+    network: &NetworkLocalitySnapshot,
+) -> Result<()> {
+    let pod_uid = &pod.pod_uid;
+    let namespace = pod.namespace.as_deref().unwrap_or_default();
+    let pod_name = pod.pod_name.as_deref().unwrap_or_default();
+
+    // ---- Info section (only when cAdvisor carries the pod identity) ----
+    if !pod_name.is_empty() && repo.create_pod_info_if_missing(pod_uid, &map_pod_info(pod))? {
+        queue_new_pod(pod_uid, namespace, pod_name);
+    }
+
+    // ---- Metrics section ----
+    let totals = pod.totals();
+    let cpu_usage_nano_cores = cpu_rate(pod_uid, now, &totals);
+    let row = map_pod_metrics(
+        pod,
+        &totals,
+        cpu_usage_nano_cores,
+        gpu.pod(namespace, pod_name),
+        network.pod(pod_uid),
+        now,
+    );
+    repo.append_pod_row(pod_uid, &row, now)?;
+
+    for container in &pod.containers {
+        if container.name == "debug" || container.name.starts_with("debug-") {
+            continue;
+        }
+
+        let container_key = format!("{}-{}", pod_uid, container.name);
+        if !pod_name.is_empty() {
+            let info = InfoContainerEntity {
+                pod_uid: Some(pod_uid.clone()),
+                pod_name: Some(pod_name.to_string()),
+                container_name: Some(container.name.clone()),
+                namespace: Some(namespace.to_string()),
+                node_name: pod.node_name.clone(),
+                deleted: Some(false),
+                last_check_deleted_count: Some(0),
+                ..Default::default()
+            };
+            repo.create_container_info_if_missing(&container_key, &info)?;
+        }
+
+        let stats = &container.stats;
+        let container_gpu = gpu.container(namespace, pod_name, &container.name);
+        let row = MetricContainerEntity {
+            time: now,
+            cpu_usage_nano_cores: cpu_rate(&container_key, now, stats),
+            cpu_usage_core_nano_seconds: stats.cpu_usage_core_nano_seconds,
+            memory_usage_bytes: stats.memory_usage_bytes,
+            memory_working_set_bytes: stats.memory_working_set_bytes,
+            memory_rss_bytes: stats.memory_rss_bytes,
+            memory_page_faults: stats.memory_page_faults,
+            fs_used_bytes: stats.fs_used_bytes,
+            fs_capacity_bytes: stats.fs_capacity_bytes,
+            fs_inodes_used: stats.fs_inodes_used,
+            fs_inodes: stats.fs_inodes,
+            gpu_count: container_gpu.map(|g| g.gpu_count),
+            gpu_utilization_percent: container_gpu
+                .and_then(|g| g.utilization_percent)
+                .map(|v| v.round() as u64),
+            gpu_memory_used_bytes: container_gpu.and_then(|g| g.memory_used_bytes),
+
+            // Peaks are computed when aggregating into the hour/day tiers
+            ..Default::default()
+        };
+        repo.append_container_row(&container_key, &row, now)?;
+    }
+
+    Ok(())
+}
+
+fn map_node_metrics(
+    node: &CadvisorNodeStats,
+    gpu: Option<&GpuUsage>,
+    now: DateTime<Utc>,
+) -> MetricNodeEntity {
+    let stats = &node.stats;
+    let cpu_usage_nano_cores = cpu_rate(&format!("node/{}", node.node_name), now, stats);
+
+    // Usage outside the kubepods cgroup is system overhead, as in the kubelet summary
+    let pods = node.pods.as_ref();
+    let pods_cpu = pods.and_then(|p| cpu_rate(&format!("node/{}/kubepods", node.node_name), now, p));
+    let pods_memory = pods.and_then(|p| p.memory_working_set_bytes);
+
+    MetricNodeEntity {
+        time: now,
+
+        // CPU
+        cpu_usage_nano_cores,
+        cpu_usage_core_nano_seconds: stats.cpu_usage_core_nano_seconds,
+
+        // Memory
+        memory_usage_bytes: stats.memory_usage_bytes,
+        memory_working_set_bytes: stats.memory_working_set_bytes,
+        memory_rss_bytes: stats.memory_rss_bytes,
+        memory_page_faults: stats.memory_page_faults,
+
+        // Network (host interfaces)
+        network_physical_rx_bytes: node.network_rx_bytes,
+        network_physical_tx_bytes: node.network_tx_bytes,
+        network_physical_rx_errors: node.network_rx_errors,
+        network_physical_tx_errors: node.network_tx_errors,
+
+        // Filesystem
+        fs_used_bytes: stats.fs_used_bytes,
+        fs_capacity_bytes: stats.fs_capacity_bytes,
+        fs_inodes_used: stats.fs_inodes_used,
+        fs_inodes: stats.fs_inodes,
+
+        // System overhead
+        system_cpu_usage_nano_cores: cpu_usage_nano_cores
+            .zip(pods_cpu)
+            .map(|(node, pods)| node.saturating_sub(pods)),
+        system_memory_working_set_bytes: stats
+            .memory_working_set_bytes
+            .zip(pods_memory)
+            .map(|(node, pods)| node.saturating_sub(pods)),
+
+        // GPU
+        gpu_count: gpu.map(|g| g.gpu_count),
+        gpu_utilization_percent: gpu.and_then(|g| g.utilization_percent).map(|v| v.round() as u64),
+        gpu_memory_used_bytes: gpu.and_then(|g| g.memory_used_bytes),
+
+        // Peaks are computed when aggregating into the hour/day tiers
+        ..Default::default()
+    }
+}
+
+fn map_pod_info(pod: &CadvisorPodStats) -> InfoPodEntity {
+    InfoPodEntity {
+        pod_name: pod.pod_name.clone(),
+        namespace: pod.namespace.clone(),
+        pod_uid: Some(pod.pod_uid.clone()),
+        node_name: pod.node_name.clone(),
+        container_count: Some(pod.containers.len() as u32),
+        container_names: Some(pod.containers.iter().map(|c| c.name.clone()).collect()),
+        ..Default::default()
+    }
+}
+
+fn map_pod_metrics(
+    pod: &CadvisorPodStats,
+    totals: &CadvisorCgroupStats,
+    cpu_usage_nano_cores: Option<u64>,
+    gpu: Option<&GpuUsage>,
+    locality: Option<&NetworkLocalityBytes>,
+    now: DateTime<Utc>,
+) -> MetricPodEntity {
+    MetricPodEntity {
+        time: now,
+
+        // CPU
+        cpu_usage_nano_cores,
+        cpu_usage_core_nano_seconds: totals.cpu_usage_core_nano_seconds,
+
+        // Memory
+        memory_usage_bytes: totals.memory_usage_bytes,
+        memory_working_set_bytes: totals.memory_working_set_bytes,
+        memory_rss_bytes: totals.memory_rss_bytes,
+        memory_page_faults: totals.memory_page_faults,
+
+        // Network (pod sandbox)
+        network_physical_rx_bytes: pod.network_rx_bytes,
+        network_physical_tx_bytes: pod.network_tx_bytes,
+        network_physical_rx_errors: pod.network_rx_errors,
+        network_physical_tx_errors: pod.network_tx_errors,
+
+        // Ephemeral storage (container filesystems); cAdvisor has no PVC stats
+        es_used_bytes: totals.fs_used_bytes,
+        es_capacity_bytes: totals.fs_capacity_bytes,
+        es_inodes_used: totals.fs_inodes_used,
+        es_inodes: totals.fs_inodes,
+        pv_used_bytes: None,
+        pv_capacity_bytes: None,
+        pv_inodes_used: None,
+        pv_inodes: None,
+
+        // GPU (DCGM exporter)
+        gpu_count: gpu.map(|g| g.gpu_count),
+        gpu_utilization_percent: gpu.and_then(|g| g.utilization_percent).map(|v| v.round() as u64),
+        gpu_memory_used_bytes: gpu.and_then(|g| g.memory_used_bytes),
+
+        // Network by locality (flow exporter)
+        network_local_bytes: locality.map(|l| l.local),
+        network_regional_bytes: locality.map(|l| l.regional),
+        network_external_bytes: locality.map(|l| l.external),
+
+        // Peaks are computed when aggregating into the hour/day tiers
+        ..Default::default()
+    }
+}
+
+/// CPU usage in nano cores since the previous scrape of the same cgroup.
+fn cpu_rate(key: &str, now: DateTime<Utc>, stats: &CadvisorCgroupStats) -> Option<u64> {
+    let counter = stats.cpu_usage_core_nano_seconds?;
+    let counters = CPU_COUNTERS.get_or_init(|| Mutex::new(HashMap::new()));
+    let mut guard = counters.lock().unwrap_or_else(|e| e.into_inner());
+
+    let previous = guard.insert(key.to_string(), (now, counter));
+    let (prev_time, prev_counter) = previous?;
+    let elapsed_secs = (now - prev_time).num_milliseconds() as f64 / 1000.0;
+
+    // A counter going backwards means the container restarted
+    if elapsed_secs <= 0.0 || counter < prev_counter {
+        return None;
+    }
+    Some(((counter - prev_counter) as f64 / elapsed_secs) as u64)
+}
+
+fn prune_cpu_counters(now: DateTime<Utc>) {
+    if let Some(counters) = CPU_COUNTERS.get() {
+        let ttl = chrono::Duration::minutes(CPU_COUNTER_TTL_MINUTES);
+        counters
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .retain(|_, (seen, _)| now - *seen < ttl);
+    }
+}
@@ -1,9 +1,10 @@
 use crate::core::persistence::metrics::k8s::container::metric_container_entity::MetricContainerEntity;
 use crate::scheduler::tasks::collectors::k8s::summary_dto::{ContainerSummary};
+use crate::scheduler::tasks::collectors::rustexporter::gpu_snapshot::GpuUsage;
 use chrono::{DateTime, Utc};
 
 /// Maps a Kubernetes ContainerSummary (from Kubelet /stats/summary) into MetricContainerEntity.
-pub fn map_container_summary_to_metrics(container: &ContainerSummary, now: DateTime<Utc>) -> MetricContainerEntity {
+pub fn map_container_summary_to_metrics(container: &ContainerSummary, gpu: Option<&GpuUsage>, now: DateTime<Utc>) -> MetricContainerEntity {
     // --- Use CPU timestamp as primary metric timestamp ---
     // let time = chrono::DateTime::parse_from_rfc3339(&container.cpu.time)
     //     .map(|t| t.with_timezone(&Utc))
@@ -32,7 +33,13 @@ pub fn map_container_summary_to_metrics(container: &ContainerSummary, now: DateT
         fs_inodes_used: fs_inodes_used,
         fs_inodes: fs_inodes,
 
+        // GPU (DCGM exporter)
+        gpu_count: gpu.map(|g| g.gpu_count),
+        gpu_utilization_percent: gpu.and_then(|g| g.utilization_percent).map(|v| v.round() as u64),
+        gpu_memory_used_bytes: gpu.and_then(|g| g.memory_used_bytes),
 
+        // Peaks are computed when aggregating into the hour/day tiers
+        ..Default::default()
     }
 }
 
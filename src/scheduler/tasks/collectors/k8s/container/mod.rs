@@ -1,5 +1,5 @@
 pub mod task;
 mod info_container_minute_collector_mapper;
-mod info_container_minute_collector_repository;
-mod metric_container_minute_collector_repository;
+pub(crate) mod info_container_minute_collector_repository;
+pub(crate) mod metric_container_minute_collector_repository;
 mod metric_container_minute_collector_mapper;
\ No newline at end of file
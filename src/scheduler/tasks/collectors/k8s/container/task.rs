@@ -3,6 +3,7 @@ use crate::core::persistence::metrics::k8s::container::minute::metric_container_
 use crate::core::persistence::metrics::k8s::container::minute::metric_container_minute_fs_adapter::MetricContainerMinuteFsAdapter;
 use crate::scheduler::tasks::collectors::k8s::container::metric_container_minute_collector_repository::MetricContainerMinuteCollectorRepositoryImpl;
 use crate::scheduler::tasks::collectors::k8s::summary_dto::Summary;
+use crate::scheduler::tasks::collectors::rustexporter::gpu_snapshot::GpuSnapshot;
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 use crate::scheduler::tasks::collectors::k8s::container::info_container_minute_collector_mapper::map_container_summary_to_info;
@@ -10,7 +11,7 @@ use crate::scheduler::tasks::collectors::k8s::container::info_container_minute_c
 use crate::scheduler::tasks::collectors::k8s::container::metric_container_minute_collector_mapper::map_container_summary_to_metrics;
 
 /// Collects container-level info and metrics from the node summary.
-pub async fn handle_container(summary: &Summary, now: DateTime<Utc>) -> Result<bool> {
+pub async fn handle_container(summary: &Summary, now: DateTime<Utc>, gpu: &GpuSnapshot) -> Result<bool> {
     let mut any_created = false;
 
     // Step 1: Return early if no pods
@@ -59,7 +60,8 @@ pub async fn handle_container(summary: &Summary, now: DateTime<Utc>) -> Result<b
             let metric_repo = MetricContainerMinuteCollectorRepositoryImpl {
                 adapter: MetricContainerMinuteFsAdapter,
             };
-            let metrics_dto = map_container_summary_to_metrics(container, now);
+            let container_gpu = gpu.container(namespace, pod_name, &container.name);
+            let metrics_dto = map_container_summary_to_metrics(container, container_gpu, now);
             metric_repo.append_row(&container_key, &metrics_dto, now)?;
         }
     }
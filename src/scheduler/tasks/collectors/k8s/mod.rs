@@ -6,5 +6,5 @@ pub use task::run;
 /* Data structures */
 pub mod summary_dto;
 pub mod node;
-mod pod;
-mod container;
+pub(crate) mod pod;
+pub(crate) mod container;
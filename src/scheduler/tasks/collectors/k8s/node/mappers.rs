@@ -3,24 +3,53 @@
 use crate::core::persistence::info::k8s::node::info_node_entity::InfoNodeEntity;
 use crate::core::persistence::metrics::k8s::node::metric_node_entity::MetricNodeEntity;
 use crate::scheduler::tasks::collectors::k8s::summary_dto::{NetworkStats, Summary};
+use crate::scheduler::tasks::collectors::rustexporter::gpu_snapshot::GpuUsage;
 use chrono::{DateTime, Utc};
 
 pub fn map_summary_to_node_info(summary: &Summary, now: DateTime<Utc>) -> InfoNodeEntity {
+    let (system_cpu, system_memory) = sum_system_containers(summary);
+
     InfoNodeEntity {
         node_name: Some(summary.node.node_name.clone()),
         last_updated_info_at: Some(now),
         ready: Some(true),
+        system_cpu_usage_nano_cores: system_cpu,
+        system_memory_working_set_bytes: system_memory,
         ..Default::default() // leaves all other fields as None
     }
 }
 
-pub fn map_summary_to_metrics(summary: &Summary, now: DateTime<Utc>) -> MetricNodeEntity {
+/// Sums CPU and memory working set of the kubelet `system_containers`.
+///
+/// The `pods` system container is the parent cgroup of every pod on the node,
+/// so it is excluded to avoid counting workload usage as overhead.
+pub fn sum_system_containers(summary: &Summary) -> (Option<u64>, Option<u64>) {
+    let containers = match summary.node.system_containers.as_ref() {
+        Some(c) => c,
+        None => return (None, None),
+    };
+
+    let (cpu, memory) = containers
+        .iter()
+        .filter(|c| c.name != "pods")
+        .fold((0u64, 0u64), |acc, c| {
+            (
+                acc.0 + c.cpu.usage_nano_cores.unwrap_or(0),
+                acc.1 + c.memory.working_set_bytes.unwrap_or(0),
+            )
+        });
+
+    (Some(cpu), Some(memory))
+}
+
+pub fn map_summary_to_metrics(summary: &Summary, gpu: Option<&GpuUsage>, now: DateTime<Utc>) -> MetricNodeEntity {
     let n = &summary.node;
 
     // --- Compute summed physical network stats ---
     let (rx, tx, rx_err, tx_err) = n.network.as_ref()
         .and_then(|net| sum_network_interfaces(net))
         .unwrap_or((None, None, None, None));
+    let (system_cpu, system_memory) = sum_system_containers(summary);
 
     MetricNodeEntity {
         time: now,
@@ -46,6 +75,18 @@ pub fn map_summary_to_metrics(summary: &Summary, now: DateTime<Utc>) -> MetricNo
         fs_capacity_bytes: n.fs.as_ref().and_then(|x| x.capacity_bytes),
         fs_inodes_used: n.fs.as_ref().and_then(|x| x.inodes_used),
         fs_inodes: n.fs.as_ref().and_then(|x| x.inodes),
+
+        // System overhead, kept per row so later changes don't reprice history
+        system_cpu_usage_nano_cores: system_cpu,
+        system_memory_working_set_bytes: system_memory,
+
+        // GPU
+        gpu_count: gpu.map(|g| g.gpu_count),
+        gpu_utilization_percent: gpu.and_then(|g| g.utilization_percent).map(|v| v.round() as u64),
+        gpu_memory_used_bytes: gpu.and_then(|g| g.memory_used_bytes),
+
+        // Peaks are computed when aggregating into the hour/day tiers
+        ..Default::default()
     }
 }
 
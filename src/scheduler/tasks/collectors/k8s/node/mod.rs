@@ -1,6 +1,6 @@
 pub mod task;
 pub mod mappers;
 
-mod info_node_minute_collector_repository;
-mod metric_node_minute_collector_repository;
+pub(crate) mod info_node_minute_collector_repository;
+pub(crate) mod metric_node_minute_collector_repository;
 
@@ -8,8 +8,9 @@ use crate::core::client::mappers::map_node_to_info_entity;
 use crate::scheduler::tasks::collectors::k8s::node::metric_node_minute_collector_repository::MetricNodeMinuteCollectorRepositoryImpl;
 use crate::core::client::kube_resources::Node;
 use crate::scheduler::tasks::collectors::k8s::summary_dto::Summary;
+use crate::scheduler::tasks::collectors::rustexporter::gpu_snapshot::GpuSnapshot;
 
-pub async fn handle_node(summary: &Summary, now: DateTime<Utc>) -> Result<bool, anyhow::Error> {
+pub async fn handle_node(summary: &Summary, now: DateTime<Utc>, gpu: &GpuSnapshot) -> Result<bool, anyhow::Error> {
     let node_name = &summary.node.node_name;
 
     // Step 1: Write info.rci if missing
@@ -17,8 +18,16 @@ pub async fn handle_node(summary: &Summary, now: DateTime<Utc>) -> Result<bool,
     let node_info = map_summary_to_node_info(summary, now);
     let created = info_repo.create_if_missing(node_name, &node_info)?;
 
+    // Step 1-1: Refresh the system overhead snapshot on existing nodes
+    if !created {
+        let mut existing = info_repo.fs_adapter().read(node_name)?;
+        existing.system_cpu_usage_nano_cores = node_info.system_cpu_usage_nano_cores;
+        existing.system_memory_working_set_bytes = node_info.system_memory_working_set_bytes;
+        info_repo.update(&existing)?;
+    }
+
     // Step 2: Append metrics
-    let metrics_dto = map_summary_to_metrics(summary, now);
+    let metrics_dto = map_summary_to_metrics(summary, gpu.node(node_name), now);
     let metric_repo = MetricNodeMinuteCollectorRepositoryImpl {
         adapter: MetricNodeMinuteFsAdapter,
     };
@@ -27,6 +36,20 @@ pub async fn handle_node(summary: &Summary, now: DateTime<Utc>) -> Result<bool,
     Ok(created)
 }
 
+/// Creates node info from the Node API for a node whose metrics come from
+/// another source (cAdvisor), leaving existing info untouched.
+pub async fn ensure_node_info(
+    node: Node,
+    now: DateTime<Utc>,
+    spot_selector: Option<&str>,
+) -> anyhow::Result<()> {
+    let name = node.metadata.name.clone().unwrap_or_default();
+    if InfoNodeCollectorRepositoryImpl::default().exists(&name)? {
+        return Ok(());
+    }
+    update_node_info(node, now, spot_selector).await
+}
+
 /// Checks cluster nodes and updates node info files if any node is new or changed.
 /// Updates local node info for nodes whose names appear in `updated_nodes`.
 ///
@@ -36,11 +59,12 @@ pub async fn handle_node(summary: &Summary, now: DateTime<Utc>) -> Result<bool,
 pub async fn update_node_info(
     node: Node,
     now: DateTime<Utc>,
+    spot_selector: Option<&str>,
 ) -> anyhow::Result<()> {
 
     let repo = InfoNodeCollectorRepositoryImpl::default();
 
-    let node_info = map_node_to_info_entity(&node, now)?;
+    let node_info = map_node_to_info_entity(&node, now, spot_selector)?;
 
     repo.update(&node_info)
         .expect("Failed to update node info in InfoNodeCollectorRepository");
@@ -1,8 +1,15 @@
 use crate::core::persistence::metrics::k8s::pod::metric_pod_entity::MetricPodEntity;
 use crate::scheduler::tasks::collectors::k8s::summary_dto::{NetworkStats, PodSummary, VolumeStats};
+use crate::scheduler::tasks::collectors::netflow::models::NetworkLocalityBytes;
+use crate::scheduler::tasks::collectors::rustexporter::gpu_snapshot::GpuUsage;
 use chrono::{DateTime, Utc};
 
-pub fn map_pod_summary_to_metrics(pod: &PodSummary, now: DateTime<Utc>) -> MetricPodEntity {
+pub fn map_pod_summary_to_metrics(
+    pod: &PodSummary,
+    gpu: Option<&GpuUsage>,
+    locality: Option<&NetworkLocalityBytes>,
+    now: DateTime<Utc>,
+) -> MetricPodEntity {
     // --- Compute summed physical network stats ---
     let (rx, tx, rx_err, tx_err) = pod
         .network
@@ -66,6 +73,18 @@ pub fn map_pod_summary_to_metrics(pod: &PodSummary, now: DateTime<Utc>) -> Metri
         pv_inodes_used: pv_inodes_used,
         pv_inodes: pv_inodes,
 
+        // GPU (DCGM exporter)
+        gpu_count: gpu.map(|g| g.gpu_count),
+        gpu_utilization_percent: gpu.and_then(|g| g.utilization_percent).map(|v| v.round() as u64),
+        gpu_memory_used_bytes: gpu.and_then(|g| g.memory_used_bytes),
+
+        // Network by locality (flow exporter)
+        network_local_bytes: locality.map(|l| l.local),
+        network_regional_bytes: locality.map(|l| l.regional),
+        network_external_bytes: locality.map(|l| l.external),
+
+        // Peaks are computed when aggregating into the hour/day tiers
+        ..Default::default()
     }
 }
 
@@ -6,10 +6,18 @@ use crate::scheduler::tasks::collectors::k8s::pod::info_pod_minute_collector_rep
 use crate::scheduler::tasks::collectors::k8s::pod::metric_pod_minute_collector_mapper::map_pod_summary_to_metrics;
 use crate::scheduler::tasks::collectors::k8s::pod::metric_pod_minute_collector_repository::MetricPodMinuteCollectorRepositoryImpl;
 use crate::scheduler::tasks::collectors::k8s::summary_dto::Summary;
+use crate::scheduler::tasks::collectors::netflow::models::NetworkLocalitySnapshot;
+use crate::scheduler::tasks::collectors::rustexporter::gpu_snapshot::GpuSnapshot;
+use crate::scheduler::tasks::info::ownership::task::queue_new_pod;
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 
-pub async fn handle_pod(summary: &Summary, now: DateTime<Utc>) -> Result<bool> {
+pub async fn handle_pod(
+    summary: &Summary,
+    now: DateTime<Utc>,
+    gpu: &GpuSnapshot,
+    network: &NetworkLocalitySnapshot,
+) -> Result<bool> {
     let mut any_created = false;
 
     // Step 1: If there are no pods, return early
@@ -34,13 +42,15 @@ pub async fn handle_pod(summary: &Summary, now: DateTime<Utc>) -> Result<bool> {
         let created = info_repo.create_if_missing(pod_uid, &pod_info)?;
         if created {
             any_created = true;
+            queue_new_pod(pod_uid, &pod.pod_ref.namespace, &pod.pod_ref.name);
         }
 
         // ---- Metrics section ----
         let metric_repo = MetricPodMinuteCollectorRepositoryImpl {
             adapter: MetricPodMinuteFsAdapter,
         };
-        let metrics_dto = map_pod_summary_to_metrics(pod, now);
+        let pod_gpu = gpu.pod(&pod.pod_ref.namespace, &pod.pod_ref.name);
+        let metrics_dto = map_pod_summary_to_metrics(pod, pod_gpu, network.pod(pod_uid), now);
         metric_repo.append_row(pod_uid, &metrics_dto, now)?;
     }
 
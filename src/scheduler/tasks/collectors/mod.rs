@@ -1,3 +1,4 @@
 pub mod rustexporter;
 pub mod cadvisor;
 pub mod k8s;
+pub mod netflow;
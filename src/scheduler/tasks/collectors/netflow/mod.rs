@@ -0,0 +1,6 @@
+/* Entry point */
+mod task;
+pub use task::run;
+
+/* Flow byte counters → per-pod traffic by locality */
+pub mod models;
@@ -0,0 +1,183 @@
+use std::collections::HashMap;
+use std::net::IpAddr;
+
+use crate::core::util::prometheus_text::PromSample;
+use crate::domain::info::model::network_locality::{NetworkLocality, NetworkTopology};
+
+// Label names used by common flow exporters (Cilium/Hubble, Kube-OVN, custom eBPF exporters).
+const SOURCE_LABELS: &[&str] = &["source_ip", "src_ip", "source_address", "src_addr", "src"];
+const DESTINATION_LABELS: &[&str] = &[
+    "destination_ip",
+    "dst_ip",
+    "destination_address",
+    "dst_addr",
+    "dst",
+];
+
+/// Bytes sent or received by one pod within the collection interval, by locality.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NetworkLocalityBytes {
+    pub local: u64,
+    pub regional: u64,
+    pub external: u64,
+}
+
+impl NetworkLocalityBytes {
+    fn add(&mut self, locality: NetworkLocality, bytes: u64) {
+        let slot = match locality {
+            NetworkLocality::Local => &mut self.local,
+            NetworkLocality::Regional => &mut self.regional,
+            NetworkLocality::External => &mut self.external,
+        };
+        *slot = slot.saturating_add(bytes);
+    }
+}
+
+/// Per-pod traffic by locality for one collection interval, keyed by pod UID.
+#[derive(Debug, Clone, Default)]
+pub struct NetworkLocalitySnapshot {
+    pods: HashMap<String, NetworkLocalityBytes>,
+}
+
+impl NetworkLocalitySnapshot {
+    pub fn pod(&self, pod_uid: &str) -> Option<&NetworkLocalityBytes> {
+        self.pods.get(pod_uid)
+    }
+}
+
+/// One source → destination byte counter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FlowKey {
+    pub source: IpAddr,
+    pub destination: IpAddr,
+}
+
+/// Sums byte counters per (source, destination) pair.
+///
+/// Any counter ending in `_bytes_total` / `_bytes` that carries both a source
+/// and a destination IP label is accepted; exporters splitting one flow over
+/// several series (per port, protocol, …) are summed.
+pub fn group_flow_counters(samples: &[PromSample]) -> HashMap<FlowKey, u64> {
+    let mut flows: HashMap<FlowKey, u64> = HashMap::new();
+
+    for sample in samples {
+        if !(sample.name.ends_with("_bytes_total") || sample.name.ends_with("_bytes"))
+            || !sample.value.is_finite()
+            || sample.value < 0.0
+        {
+            continue;
+        }
+        let parse = |keys: &[&str]| sample.label(keys).and_then(|v| v.parse::<IpAddr>().ok());
+        let (Some(source), Some(destination)) = (parse(SOURCE_LABELS), parse(DESTINATION_LABELS)) else {
+            continue;
+        };
+
+        let total = flows.entry(FlowKey { source, destination }).or_default();
+        *total = total.saturating_add(sample.value as u64);
+    }
+
+    flows
+}
+
+/// Attributes interval bytes per flow to pods and classifies their locality.
+///
+/// Bytes count against both the sending and the receiving pod, matching the
+/// pod's own tx/rx counters, so a pod-to-pod flow is classified on both ends.
+/// Flows between two non-pod addresses are ignored.
+pub fn attribute_flows(
+    deltas: &HashMap<FlowKey, u64>,
+    topology: &NetworkTopology,
+) -> NetworkLocalitySnapshot {
+    let mut pods: HashMap<String, NetworkLocalityBytes> = HashMap::new();
+
+    for (flow, bytes) in deltas {
+        let source = topology.pod_uid(flow.source);
+        let destination = topology.pod_uid(flow.destination).filter(|uid| Some(*uid) != source);
+        if source.is_none() && destination.is_none() {
+            continue;
+        }
+
+        let locality = topology.classify(flow.source, flow.destination);
+        for pod_uid in source.into_iter().chain(destination) {
+            pods.entry(pod_uid.to_string()).or_default().add(locality, *bytes);
+        }
+    }
+
+    NetworkLocalitySnapshot { pods }
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::persistence::info::k8s::node::info_node_entity::InfoNodeEntity;
+    use crate::core::persistence::info::k8s::pod::info_pod_entity::InfoPodEntity;
+    use crate::core::util::prometheus_text::parse_prometheus_text;
+
+    #[test]
+    fn attributes_flow_bytes_to_pods_by_locality() {
+        let node = |name: &str, zone: &str| InfoNodeEntity {
+            node_name: Some(name.to_string()),
+            label: Some(format!(r#"{{"topology.kubernetes.io/zone":"{zone}"}}"#)),
+            ..Default::default()
+        };
+        let pod = |uid: &str, ip: &str, node: &str| InfoPodEntity {
+            pod_uid: Some(uid.to_string()),
+            pod_ip: Some(ip.to_string()),
+            node_name: Some(node.to_string()),
+            ..Default::default()
+        };
+        let topology = NetworkTopology::new(
+            &[pod("a", "10.0.0.1", "n1"), pod("b", "10.0.0.2", "n1"), pod("c", "10.0.1.1", "n2")],
+            &[node("n1", "zone-a"), node("n2", "zone-b")],
+        );
+
+        let text = r#"
+flow_bytes_total{src_ip="10.0.0.1",dst_ip="10.0.0.2",port="80"} 100
+flow_bytes_total{src_ip="10.0.0.1",dst_ip="10.0.0.2",port="443"} 50
+flow_bytes_total{src_ip="10.0.0.1",dst_ip="10.0.1.1"} 200
+flow_bytes_total{src_ip="1.1.1.1",dst_ip="10.0.1.1"} 300
+flow_packets_total{src_ip="10.0.0.1",dst_ip="10.0.0.2"} 9
+"#;
+        let flows = group_flow_counters(&parse_prometheus_text(text));
+        assert_eq!(flows.len(), 3);
+
+        let snapshot = attribute_flows(&flows, &topology);
+        assert_eq!(
+            snapshot.pod("a"),
+            Some(&NetworkLocalityBytes { local: 150, regional: 200, external: 0 })
+        );
+        assert_eq!(
+            snapshot.pod("c"),
+            Some(&NetworkLocalityBytes { local: 0, regional: 200, external: 300 })
+        );
+    }
+
+    #[test]
+    fn pod_to_pod_flow_counts_on_both_ends() {
+        let pod = |uid: &str, ip: &str| InfoPodEntity {
+            pod_uid: Some(uid.to_string()),
+            pod_ip: Some(ip.to_string()),
+            node_name: Some("n1".to_string()),
+            ..Default::default()
+        };
+        let node = InfoNodeEntity {
+            node_name: Some("n1".to_string()),
+            label: Some(r#"{"topology.kubernetes.io/zone":"zone-a"}"#.to_string()),
+            ..Default::default()
+        };
+        let topology = NetworkTopology::new(&[pod("a", "10.0.0.1"), pod("b", "10.0.0.2")], &[node]);
+
+        let flows = group_flow_counters(&parse_prometheus_text(
+            r#"flow_bytes_total{src_ip="10.0.0.1",dst_ip="10.0.0.2"} 120"#,
+        ));
+        let snapshot = attribute_flows(&flows, &topology);
+
+        // The sender's tx and the receiver's rx are both fully classified,
+        // so neither end leaves the bytes to be billed as external.
+        let expected = NetworkLocalityBytes { local: 120, regional: 0, external: 0 };
+        assert_eq!(snapshot.pod("a"), Some(&expected));
+        assert_eq!(snapshot.pod("b"), Some(&expected));
+    }
+}
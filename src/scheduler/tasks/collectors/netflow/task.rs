@@ -0,0 +1,121 @@
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use reqwest::Client;
+use tracing::{debug, warn};
+
+use crate::core::persistence::storage_backend::storage_backend;
+use crate::core::persistence::info::fixed::setting::info_setting_entity::InfoSettingEntity;
+use crate::core::persistence::info::k8s::node::info_node_api_repository_trait::InfoNodeApiRepository;
+use crate::core::persistence::info::k8s::node::info_node_entity::InfoNodeEntity;
+use crate::core::persistence::info::k8s::node::info_node_repository::InfoNodeRepository;
+use crate::core::persistence::info::k8s::pod::info_pod_api_repository_trait::InfoPodApiRepository;
+use crate::core::persistence::info::k8s::pod::info_pod_entity::InfoPodEntity;
+use crate::core::persistence::info::k8s::pod::info_pod_repository::InfoPodRepository;
+use crate::core::persistence::info::path::{info_k8s_node_dir_path, info_k8s_pod_dir_path};
+use crate::core::util::prometheus_text::parse_prometheus_text;
+use crate::domain::info::model::network_locality::NetworkTopology;
+use crate::scheduler::tasks::collectors::netflow::models::{
+    attribute_flows, group_flow_counters, FlowKey, NetworkLocalitySnapshot,
+};
+
+/// Last `(scrape time, byte counter)` per flow.
+type FlowCounters = HashMap<FlowKey, (DateTime<Utc>, u64)>;
+
+/// Previous flow counters, used to turn cumulative bytes into interval deltas.
+static FLOW_COUNTERS: OnceLock<Mutex<FlowCounters>> = OnceLock::new();
+
+/// Counters not refreshed for this long are dropped (flow ended or exporter down).
+const FLOW_COUNTER_TTL_MINUTES: i64 = 10;
+
+/// Scrapes the flow exporters in `network_flow_exporter_urls` and returns the
+/// bytes each pod exchanged since the previous scrape, split by locality.
+///
+/// Returns an empty snapshot when no flow exporter is configured; the first
+/// scrape of a flow only records its counter.
+pub async fn run(settings: &InfoSettingEntity, now: DateTime<Utc>) -> Result<NetworkLocalitySnapshot> {
+    if settings.network_flow_exporter_urls.is_empty() {
+        return Ok(NetworkLocalitySnapshot::default());
+    }
+
+    let client = Client::builder()
+        .timeout(Duration::from_secs(10))
+        .build()
+        .context("Failed to build HTTP client")?;
+
+    let mut samples = Vec::new();
+    for url in &settings.network_flow_exporter_urls {
+        match scrape(&client, url).await {
+            Ok(text) => samples.extend(parse_prometheus_text(&text)),
+            Err(e) => warn!(?e, %url, "Flow exporter scrape failed"),
+        }
+    }
+
+    let deltas = flow_deltas(group_flow_counters(&samples), now);
+    let topology = NetworkTopology::new(&list_stored_pods(), &list_stored_nodes());
+    let snapshot = attribute_flows(&deltas, &topology);
+
+    debug!(flows = deltas.len(), "Flow exporter scrape finished");
+    Ok(snapshot)
+}
+
+async fn scrape(client: &Client, url: &str) -> Result<String> {
+    client
+        .get(url)
+        .send()
+        .await
+        .context("Failed to request flow exporter")?
+        .error_for_status()
+        .context("Flow exporter returned an error status")?
+        .text()
+        .await
+        .context("Failed to read flow exporter response")
+}
+
+/// Bytes per flow since the previous scrape. A counter going backwards means
+/// the exporter restarted, so the whole new value counts.
+fn flow_deltas(current: HashMap<FlowKey, u64>, now: DateTime<Utc>) -> HashMap<FlowKey, u64> {
+    let counters = FLOW_COUNTERS.get_or_init(|| Mutex::new(HashMap::new()));
+    let mut guard = counters.lock().unwrap_or_else(|e| e.into_inner());
+
+    let mut deltas = HashMap::new();
+    for (flow, counter) in current {
+        if let Some((_, previous)) = guard.insert(flow, (now, counter)) {
+            let delta = if counter >= previous { counter - previous } else { counter };
+            if delta > 0 {
+                deltas.insert(flow, delta);
+            }
+        }
+    }
+
+    let ttl = chrono::Duration::minutes(FLOW_COUNTER_TTL_MINUTES);
+    guard.retain(|_, (seen, _)| now - *seen < ttl);
+    deltas
+}
+
+fn list_stored_pods() -> Vec<InfoPodEntity> {
+    let Ok(uids) = storage_backend().list_keys(&info_k8s_pod_dir_path()) else {
+        return Vec::new();
+    };
+
+    let repo = InfoPodRepository::new();
+    uids.iter()
+        .filter_map(|uid| repo.read(uid).ok())
+        .filter(|pod| pod.deleted != Some(true))
+        .collect()
+}
+
+fn list_stored_nodes() -> Vec<InfoNodeEntity> {
+    let Ok(names) = storage_backend().list_keys(&info_k8s_node_dir_path()) else {
+        return Vec::new();
+    };
+
+    let repo = InfoNodeRepository::new();
+    names.iter()
+        .filter_map(|name| repo.read(name).ok())
+        .filter(|node| node.deleted != Some(true))
+        .collect()
+}
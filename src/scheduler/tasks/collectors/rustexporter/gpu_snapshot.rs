@@ -0,0 +1,172 @@
+use std::collections::HashMap;
+
+use crate::core::util::prometheus_text::PromSample;
+
+const GPU_UTIL: &str = "DCGM_FI_DEV_GPU_UTIL";
+const GPU_ENGINE_ACTIVE: &str = "DCGM_FI_PROF_GR_ENGINE_ACTIVE";
+const GPU_FB_USED: &str = "DCGM_FI_DEV_FB_USED";
+
+const BYTES_PER_MIB: f64 = 1024.0 * 1024.0;
+
+// Prometheus re-labels target labels to `exported_*` when scraping through a server.
+const NAMESPACE_LABELS: &[&str] = &["exported_namespace", "namespace"];
+const POD_LABELS: &[&str] = &["exported_pod", "pod"];
+const CONTAINER_LABELS: &[&str] = &["exported_container", "container"];
+const NODE_LABELS: &[&str] = &["kubernetes_node", "node", "Hostname"];
+const DEVICE_LABELS: &[&str] = &["UUID", "gpu", "device"];
+
+/// Aggregated GPU usage for one pod, container or node.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GpuUsage {
+    pub gpu_count: u64,
+    /// Average utilization across the attached devices (0–100).
+    pub utilization_percent: Option<f64>,
+    pub memory_used_bytes: Option<u64>,
+}
+
+/// GPU usage from one DCGM exporter scrape, keyed by the workload it is attached to.
+#[derive(Debug, Clone, Default)]
+pub struct GpuSnapshot {
+    pods: HashMap<(String, String), GpuUsage>,
+    containers: HashMap<(String, String, String), GpuUsage>,
+    nodes: HashMap<String, GpuUsage>,
+}
+
+#[derive(Debug, Default)]
+struct Device {
+    node: Option<String>,
+    namespace: Option<String>,
+    pod: Option<String>,
+    container: Option<String>,
+    utilization: Option<f64>,
+    engine_active: Option<f64>,
+    memory_used_bytes: Option<u64>,
+}
+
+impl Device {
+    fn utilization_percent(&self) -> Option<f64> {
+        self.utilization.or(self.engine_active.map(|ratio| ratio * 100.0))
+    }
+}
+
+impl GpuSnapshot {
+    /// Builds the snapshot from DCGM exporter samples.
+    ///
+    /// Devices are identified by node + GPU UUID (or index) and attributed to the
+    /// pod/container labels the exporter attaches to allocated GPUs.
+    pub fn from_samples(samples: &[PromSample]) -> Self {
+        let mut devices: HashMap<(String, String), Device> = HashMap::new();
+
+        for sample in samples {
+            if !matches!(sample.name.as_str(), GPU_UTIL | GPU_ENGINE_ACTIVE | GPU_FB_USED)
+                || !sample.value.is_finite()
+            {
+                continue;
+            }
+            let Some(device_id) = sample.label(DEVICE_LABELS) else {
+                continue;
+            };
+            let node = sample.label(NODE_LABELS);
+            let key = (node.unwrap_or_default().to_string(), device_id.to_string());
+
+            let device = devices.entry(key).or_default();
+            let owned = |keys: &[&str]| sample.label(keys).map(str::to_string);
+            device.node = device.node.take().or(node.map(str::to_string));
+            device.namespace = device.namespace.take().or(owned(NAMESPACE_LABELS));
+            device.pod = device.pod.take().or(owned(POD_LABELS));
+            device.container = device.container.take().or(owned(CONTAINER_LABELS));
+
+            match sample.name.as_str() {
+                GPU_UTIL => device.utilization = Some(sample.value),
+                GPU_ENGINE_ACTIVE => device.engine_active = Some(sample.value),
+                _ => device.memory_used_bytes = Some((sample.value * BYTES_PER_MIB) as u64),
+            }
+        }
+
+        let mut pods: HashMap<_, Vec<&Device>> = HashMap::new();
+        let mut containers: HashMap<_, Vec<&Device>> = HashMap::new();
+        let mut nodes: HashMap<_, Vec<&Device>> = HashMap::new();
+
+        for device in devices.values() {
+            if let Some(node) = &device.node {
+                nodes.entry(node.clone()).or_default().push(device);
+            }
+            let (Some(ns), Some(pod)) = (&device.namespace, &device.pod) else {
+                continue;
+            };
+            pods.entry((ns.clone(), pod.clone())).or_default().push(device);
+            if let Some(container) = &device.container {
+                containers
+                    .entry((ns.clone(), pod.clone(), container.clone()))
+                    .or_default()
+                    .push(device);
+            }
+        }
+
+        Self {
+            pods: pods.into_iter().map(|(k, v)| (k, aggregate(&v))).collect(),
+            containers: containers.into_iter().map(|(k, v)| (k, aggregate(&v))).collect(),
+            nodes: nodes.into_iter().map(|(k, v)| (k, aggregate(&v))).collect(),
+        }
+    }
+
+    pub fn pod(&self, namespace: &str, pod: &str) -> Option<&GpuUsage> {
+        self.pods.get(&(namespace.to_string(), pod.to_string()))
+    }
+
+    pub fn container(&self, namespace: &str, pod: &str, container: &str) -> Option<&GpuUsage> {
+        self.containers
+            .get(&(namespace.to_string(), pod.to_string(), container.to_string()))
+    }
+
+    pub fn node(&self, node: &str) -> Option<&GpuUsage> {
+        self.nodes.get(node)
+    }
+}
+
+fn aggregate(devices: &[&Device]) -> GpuUsage {
+    let utils: Vec<f64> = devices.iter().filter_map(|d| d.utilization_percent()).collect();
+    let utilization_percent =
+        (!utils.is_empty()).then(|| utils.iter().sum::<f64>() / utils.len() as f64);
+
+    let memory_used_bytes = devices
+        .iter()
+        .filter_map(|d| d.memory_used_bytes)
+        .reduce(|a, b| a + b);
+
+    GpuUsage {
+        gpu_count: devices.len() as u64,
+        utilization_percent,
+        memory_used_bytes,
+    }
+}
+
+/* ---------------- Tests ---------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::util::prometheus_text::parse_prometheus_text;
+
+    #[test]
+    fn attributes_devices_to_pods_containers_and_nodes() {
+        let text = r#"
+DCGM_FI_DEV_GPU_UTIL{gpu="0",UUID="GPU-a",Hostname="node-1",namespace="ml",pod="train-0",container="main"} 80
+DCGM_FI_DEV_FB_USED{gpu="0",UUID="GPU-a",Hostname="node-1",namespace="ml",pod="train-0",container="main"} 1024
+DCGM_FI_DEV_GPU_UTIL{gpu="1",UUID="GPU-b",Hostname="node-1",namespace="ml",pod="train-0",container="main"} 40
+DCGM_FI_DEV_FB_USED{gpu="1",UUID="GPU-b",Hostname="node-1",namespace="ml",pod="train-0",container="main"} 1024
+DCGM_FI_PROF_GR_ENGINE_ACTIVE{gpu="2",UUID="GPU-c",Hostname="node-1",pod="",namespace="",container=""} 0.5
+"#;
+        let snapshot = GpuSnapshot::from_samples(&parse_prometheus_text(text));
+
+        let pod = snapshot.pod("ml", "train-0").unwrap();
+        assert_eq!(pod.gpu_count, 2);
+        assert_eq!(pod.utilization_percent, Some(60.0));
+        assert_eq!(pod.memory_used_bytes, Some(2 * 1024 * 1024 * 1024));
+        assert_eq!(snapshot.container("ml", "train-0", "main"), Some(pod));
+
+        let node = snapshot.node("node-1").unwrap();
+        assert_eq!(node.gpu_count, 3);
+        assert_eq!(node.utilization_percent, Some(170.0 / 3.0));
+    }
+}
@@ -1,2 +1,3 @@
 mod task;
+pub mod gpu_snapshot;
 pub use task::run;
@@ -1,7 +1,48 @@
-use anyhow::Result;
-use chrono::{DateTime, Utc};
+use std::time::Duration;
 
-pub async fn run(_now: DateTime<Utc>) -> Result<()> {
-    // placeholder implementation
-    Ok(())
+use anyhow::{Context, Result};
+use reqwest::Client;
+use tracing::{debug, warn};
+
+use crate::core::persistence::info::fixed::setting::info_setting_entity::InfoSettingEntity;
+use crate::core::util::prometheus_text::parse_prometheus_text;
+use crate::scheduler::tasks::collectors::rustexporter::gpu_snapshot::GpuSnapshot;
+
+/// Scrapes the configured DCGM exporters and returns the current GPU usage.
+///
+/// Returns an empty snapshot when the GPU exporter is disabled; an unreachable
+/// exporter is logged and skipped so the remaining URLs are still collected.
+pub async fn run(settings: &InfoSettingEntity) -> Result<GpuSnapshot> {
+    if !settings.enable_gpu_exporter || settings.gpu_exporter_urls.is_empty() {
+        return Ok(GpuSnapshot::default());
+    }
+
+    let client = Client::builder()
+        .timeout(Duration::from_secs(10))
+        .build()
+        .context("Failed to build HTTP client")?;
+
+    let mut samples = Vec::new();
+    for url in &settings.gpu_exporter_urls {
+        match scrape(&client, url).await {
+            Ok(text) => samples.extend(parse_prometheus_text(&text)),
+            Err(e) => warn!(?e, %url, "GPU exporter scrape failed"),
+        }
+    }
+
+    debug!(samples = samples.len(), "GPU exporter scrape finished");
+    Ok(GpuSnapshot::from_samples(&samples))
+}
+
+async fn scrape(client: &Client, url: &str) -> Result<String> {
+    client
+        .get(url)
+        .send()
+        .await
+        .context("Failed to request GPU exporter")?
+        .error_for_status()
+        .context("GPU exporter returned an error status")?
+        .text()
+        .await
+        .context("Failed to read GPU exporter response")
 }
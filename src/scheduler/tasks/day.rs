@@ -2,11 +2,12 @@ use anyhow::Result;
 use chrono::Utc;
 use tracing::{debug, error};
 use crate::core::persistence::info::fixed::setting::info_setting_repository::InfoSettingRepository;
+use crate::core::persistence::info::fixed::setting::info_setting_retention_repository_trait::InfoSettingRetentionRepository;
 use crate::scheduler::tasks::processors::retention::task::RetentionTask;
 
 pub async fn run() -> Result<()> {
     let now = Utc::now();
-    debug!("Running day task (aggregation + retention)...");
+    debug!("Running day task (aggregation + retention + compression)...");
 
     if let Err(e) = super::processors::day::run(now).await {
         error!(?e, "Daily aggregator failed");
@@ -14,11 +15,24 @@ pub async fn run() -> Result<()> {
 
     // Create settings repository DI
     let settings_repo = InfoSettingRepository::new();
+    let (compression_enabled, index_enabled) = settings_repo
+        .read()
+        .map(|s| (s.compression_enabled, s.enable_index_file))
+        .unwrap_or((false, false));
     let retention_task = RetentionTask::new(settings_repo);
 
     if let Err(e) = retention_task.run(now).await {
         error!(?e, "Retention cleanup failed");
     }
 
+    // Compress/index after retention so expired partitions are not processed first
+    if compression_enabled || index_enabled {
+        if let Err(e) =
+            super::processors::compression::run(now, compression_enabled, index_enabled).await
+        {
+            error!(?e, "Metric compression failed");
+        }
+    }
+
     Ok(())
 }
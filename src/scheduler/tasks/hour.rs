@@ -1,6 +1,11 @@
 use anyhow::Result;
-use chrono::Utc;
-use tracing::{debug, error};
+use chrono::{DateTime, Duration, Utc};
+use tracing::{debug, error, info};
+use crate::core::persistence::data_backup::{backup_root, create_backup, latest_backup_time};
+use crate::core::persistence::info::fixed::setting::info_setting_repository::InfoSettingRepository;
+use crate::core::persistence::info::fixed::setting::info_setting_retention_repository_trait::InfoSettingRetentionRepository;
+use crate::core::persistence::storage_backend::uses_fs_backend;
+use crate::core::persistence::storage_budget::enforce_storage_budget;
 
 pub async fn run() -> Result<()> {
     let now = Utc::now();
@@ -10,5 +15,52 @@ pub async fn run() -> Result<()> {
         error!(?e, "hour aggregator failed");
     }
 
+    if let Err(e) = super::info::unit_price::task::import_price_catalog().await {
+        error!(?e, "price catalog import failed");
+    }
+
+    if let Err(e) = run_scheduled_backup(now).await {
+        error!(?e, "scheduled backup failed");
+    }
+
+    // Keep metric and info data within max_storage_gb (partition files only)
+    if !uses_fs_backend() {
+        return Ok(());
+    }
+    let eviction = InfoSettingRepository::new()
+        .read()
+        .and_then(|settings| enforce_storage_budget(settings.max_storage_gb, now));
+    match eviction {
+        Ok(eviction) if eviction.evicted_partitions > 0 => {
+            info!(?eviction, "Evicted old metric partitions to stay within the storage budget")
+        }
+        Ok(_) => {}
+        Err(e) => error!(?e, "storage budget enforcement failed"),
+    }
+
+    Ok(())
+}
+
+/// Writes a backup once `backup_interval_hours` have passed since the newest one.
+async fn run_scheduled_backup(now: DateTime<Utc>) -> Result<()> {
+    let settings = InfoSettingRepository::new().read()?;
+    if settings.backup_interval_hours == 0 {
+        return Ok(());
+    }
+
+    let root = backup_root(&settings);
+    let interval = Duration::hours(settings.backup_interval_hours as i64);
+    // The hour tick drifts by a few seconds; don't skip a slot because of it
+    let due = match latest_backup_time(&root)? {
+        Some(last) => now - last >= interval - Duration::minutes(5),
+        None => true,
+    };
+    if !due {
+        return Ok(());
+    }
+
+    let keep = settings.backup_retention_count;
+    let summary = tokio::task::spawn_blocking(move || create_backup(&root, keep, now)).await??;
+    info!(?summary, "Scheduled backup written");
     Ok(())
 }
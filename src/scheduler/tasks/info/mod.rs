@@ -3,6 +3,9 @@ pub mod version;
 pub mod settings;
 pub mod unit_price;
 pub mod k8s_refresh;
+pub mod ownership;
+pub mod service;
+pub mod volume;
 
 use crate::core::persistence::info::fixed::setting::info_setting_entity::InfoSettingEntity;
 use crate::core::persistence::info::fixed::version::info_version_entity::InfoVersionEntity;
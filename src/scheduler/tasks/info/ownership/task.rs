@@ -0,0 +1,85 @@
+use std::sync::{Mutex, MutexGuard};
+
+use anyhow::Result;
+use tracing::debug;
+
+use crate::core::client::kube_client::build_kube_client;
+use crate::core::client::pods::fetch_pod_by_name_and_namespace;
+use crate::domain::info::service::info_k8s_container_service::record_container_resources;
+use crate::domain::info::service::info_k8s_pod_service::record_pod_controller;
+use crate::domain::info::service::info_ownership_service::{tag_stored_pod, OwnershipResolver};
+use crate::domain::metric::k8s::workload::owner::WorkloadOwnerIndex;
+
+/// Pods whose info record was created since the last run.
+#[derive(Debug, Clone)]
+struct NewPod {
+    uid: String,
+    namespace: String,
+    name: String,
+}
+
+static NEW_PODS: Mutex<Vec<NewPod>> = Mutex::new(Vec::new());
+
+fn new_pods() -> MutexGuard<'static, Vec<NewPod>> {
+    NEW_PODS.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Called by the collectors when they create a pod info record, which
+/// carries no labels or annotations yet.
+pub fn queue_new_pod(uid: &str, namespace: &str, name: &str) {
+    new_pods().push(NewPod {
+        uid: uid.to_string(),
+        namespace: namespace.to_string(),
+        name: name.to_string(),
+    });
+}
+
+/// Completes the info of pods created during this tick from the live pod:
+/// container requests and the workload controller are stored for chargeback,
+/// then the ownership rules are applied. Manual tags are kept.
+pub async fn run() -> Result<()> {
+    let pending = std::mem::take(&mut *new_pods());
+    if pending.is_empty() {
+        return Ok(());
+    }
+
+    let mut resolver = OwnershipResolver::load()?;
+
+    let client = match build_kube_client().await {
+        Ok(c) => c,
+        Err(e) => {
+            // Retry on the next tick
+            new_pods().extend(pending);
+            return Err(e);
+        }
+    };
+    let owners = WorkloadOwnerIndex::load().await;
+
+    for pod in pending {
+        let live = match fetch_pod_by_name_and_namespace(&client, &pod.namespace, &pod.name).await {
+            Ok(p) => p,
+            Err(e) => {
+                debug!("Skipping new pod {}/{}: {:?}", pod.namespace, pod.name, e);
+                continue;
+            }
+        };
+
+        if let Err(e) = record_container_resources(&live) {
+            debug!("Storing requests of {}/{} failed: {:?}", pod.namespace, pod.name, e);
+        }
+        if let Err(e) = record_pod_controller(&live, &owners) {
+            debug!("Storing controller of {}/{} failed: {:?}", pod.namespace, pod.name, e);
+        }
+
+        let containers: Vec<String> = live
+            .spec
+            .as_ref()
+            .map(|s| s.containers.iter().map(|c| c.name.clone()).collect())
+            .unwrap_or_default();
+
+        let tags = resolver.tags_for(&client, &live).await;
+        tag_stored_pod(&pod.uid, &containers, &tags)?;
+    }
+
+    Ok(())
+}
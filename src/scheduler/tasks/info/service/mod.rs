@@ -0,0 +1,2 @@
+/* Entry point */
+pub mod task;
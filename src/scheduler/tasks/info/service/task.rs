@@ -0,0 +1,88 @@
+use std::collections::HashSet;
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use tracing::debug;
+
+use crate::core::client::ingresses::fetch_ingresses;
+use crate::core::client::kube_client::build_kube_client;
+use crate::core::client::mappers::{map_ingress_to_info_entity, map_service_to_info_entity};
+use crate::core::client::services::fetch_services;
+use crate::core::persistence::info::k8s::service::info_service_collector_repository_trait::InfoServiceCollectorRepository;
+use crate::core::persistence::info::k8s::service::info_service_entity::InfoServiceEntity;
+use crate::core::persistence::info::k8s::service::info_service_repository::InfoServiceRepository;
+
+/// Records every Service and Ingress, and marks the ones no longer returned
+/// by the API as deleted. Records are never removed, so past windows keep
+/// their LoadBalancer / Ingress cost.
+pub async fn run(now: DateTime<Utc>) -> Result<()> {
+    let client = build_kube_client().await?;
+
+    // Both lists must succeed, otherwise live objects would be marked deleted
+    let services = fetch_services(&client).await?;
+    let ingresses = fetch_ingresses(&client).await?;
+
+    let live = services
+        .iter()
+        .map(|s| map_service_to_info_entity(s, now))
+        .chain(ingresses.iter().map(|i| map_ingress_to_info_entity(i, now)))
+        .collect();
+
+    sync_service_infos(&InfoServiceRepository::new(), live, now)
+}
+
+fn sync_service_infos<R: InfoServiceCollectorRepository>(
+    repo: &R,
+    live: Vec<InfoServiceEntity>,
+    now: DateTime<Utc>,
+) -> Result<()> {
+    let mut seen = HashSet::new();
+
+    for mut info in live {
+        let Some(uid) = info.uid.clone() else {
+            continue;
+        };
+        seen.insert(uid.clone());
+
+        if !repo.exists(&uid)? {
+            info.record_service_type(now);
+            repo.create(&info)?;
+            continue;
+        }
+
+        // Only rewrite the record when the object changed
+        let mut stored = repo.read(&uid)?;
+        if stored.deleted != Some(true) && stored.resource_version == info.resource_version {
+            continue;
+        }
+        stored.merge_from(info);
+        stored.record_service_type(now);
+        stored.deleted = Some(false);
+        stored.deleted_at = None;
+        repo.update(&stored)?;
+    }
+
+    for uid in repo.list_uids()? {
+        if seen.contains(&uid) {
+            continue;
+        }
+        let mut stored = match repo.read(&uid) {
+            Ok(v) => v,
+            Err(e) => {
+                debug!("Skipping unreadable service info '{}': {:?}", uid, e);
+                continue;
+            }
+        };
+        if stored.deleted == Some(true) {
+            continue;
+        }
+
+        stored.deleted = Some(true);
+        stored.deleted_at = Some(now);
+        stored.last_check_deleted_count = Some(stored.last_check_deleted_count.unwrap_or(0) + 1);
+        stored.last_updated_info_at = Some(now);
+        repo.update(&stored)?;
+    }
+
+    Ok(())
+}
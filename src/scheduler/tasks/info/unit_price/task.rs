@@ -1,6 +1,8 @@
 use crate::core::persistence::info::fixed::unit_price::info_unit_price_collector_repository_trait::InfoUnitPriceCollectorRepository;
 use crate::core::persistence::info::fixed::unit_price::info_unit_price_entity::InfoUnitPriceEntity;
 use anyhow::Result;
+use tracing::warn;
+use crate::domain::info::service::info_price_catalog_service;
 use crate::core::persistence::storage_path::info_unit_price_path;
 use crate::scheduler::tasks::info::unit_price::info_unit_price_collector_repository::InfoUnitPriceCollectorRepositoryImpl;
 
@@ -18,3 +20,18 @@ pub fn load_or_init_unit_price() -> Result<InfoUnitPriceEntity> {
 
     repo.read()
 }
+
+/// Re-import node prices from the mounted provider price catalog, if one is configured.
+pub async fn import_price_catalog() -> Result<()> {
+    let settings = crate::scheduler::tasks::info::settings::task::load_or_init_settings()?;
+    if settings.price_catalog_path.is_none() {
+        return Ok(());
+    }
+
+    let report = info_price_catalog_service::import_price_catalog(true).await?;
+    for node in &report.unpriced {
+        warn!(node = %node.node_name, reason = ?node.reason, "Node not priced from catalog");
+    }
+
+    Ok(())
+}
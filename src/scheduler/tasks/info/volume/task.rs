@@ -0,0 +1,98 @@
+use std::collections::{HashMap, HashSet};
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use tracing::debug;
+
+use crate::core::client::kube_client::build_kube_client;
+use crate::core::client::mappers::{map_pv_to_info_entity, map_pvc_to_info_entity};
+use crate::core::client::other_resources::{fetch_persistent_volume_claims, fetch_persistent_volumes};
+use crate::core::persistence::info::k8s::volume::info_volume_collector_repository_trait::InfoVolumeCollectorRepository;
+use crate::core::persistence::info::k8s::volume::info_volume_entity::InfoVolumeEntity;
+use crate::core::persistence::info::k8s::volume::info_volume_repository::InfoVolumeRepository;
+
+/// Records every PersistentVolumeClaim and PersistentVolume with its phase
+/// history, and marks the ones no longer returned by the API as deleted.
+/// Records are never removed, so past windows keep their capacity cost.
+pub async fn run(now: DateTime<Utc>) -> Result<()> {
+    let client = build_kube_client().await?;
+
+    // Both lists must succeed, otherwise live objects would be marked deleted
+    let claims = fetch_persistent_volume_claims(&client).await?;
+    let volumes = fetch_persistent_volumes(&client).await?;
+
+    let by_name: HashMap<&str, _> = volumes
+        .iter()
+        .filter_map(|pv| Some((pv.metadata.name.as_deref()?, pv)))
+        .collect();
+    let live = claims
+        .iter()
+        .map(|claim| {
+            let volume = claim
+                .spec
+                .as_ref()
+                .and_then(|s| s.volume_name.as_deref())
+                .and_then(|name| by_name.get(name).copied());
+            map_pvc_to_info_entity(claim, volume, now)
+        })
+        .chain(volumes.iter().map(|pv| map_pv_to_info_entity(pv, now)))
+        .collect();
+
+    sync_volume_infos(&InfoVolumeRepository::new(), live, now)
+}
+
+fn sync_volume_infos<R: InfoVolumeCollectorRepository>(
+    repo: &R,
+    live: Vec<InfoVolumeEntity>,
+    now: DateTime<Utc>,
+) -> Result<()> {
+    let mut seen = HashSet::new();
+
+    for mut info in live {
+        let Some(uid) = info.uid.clone() else {
+            continue;
+        };
+        seen.insert(uid.clone());
+
+        if !repo.exists(&uid)? {
+            info.record_phase(now);
+            repo.create(&info)?;
+            continue;
+        }
+
+        // Only rewrite the record when the object changed
+        let mut stored = repo.read(&uid)?;
+        if stored.deleted != Some(true) && stored.resource_version == info.resource_version {
+            continue;
+        }
+        stored.merge_from(info);
+        stored.record_phase(now);
+        stored.deleted = Some(false);
+        stored.deleted_at = None;
+        repo.update(&stored)?;
+    }
+
+    for uid in repo.list_uids()? {
+        if seen.contains(&uid) {
+            continue;
+        }
+        let mut stored = match repo.read(&uid) {
+            Ok(v) => v,
+            Err(e) => {
+                debug!("Skipping unreadable volume info '{}': {:?}", uid, e);
+                continue;
+            }
+        };
+        if stored.deleted == Some(true) {
+            continue;
+        }
+
+        stored.deleted = Some(true);
+        stored.deleted_at = Some(now);
+        stored.last_check_deleted_count = Some(stored.last_check_deleted_count.unwrap_or(0) + 1);
+        stored.last_updated_info_at = Some(now);
+        repo.update(&stored)?;
+    }
+
+    Ok(())
+}
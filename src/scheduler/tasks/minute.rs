@@ -1,7 +1,10 @@
 use anyhow::Result;
+use std::collections::HashSet;
 use chrono::Utc;
 use tracing::{debug, error};
 use crate::app_state::AppState;
+use crate::core::persistence::info::fixed::setting::info_setting_entity::ContainerMetricsSource;
+use crate::core::persistence::metrics::metric_write_buffer::{flush_metric_writes, set_metrics_batch_size};
 
 pub async fn run(state: AppState) -> Result<()> {
     let now = Utc::now();
@@ -11,15 +14,69 @@ pub async fn run(state: AppState) -> Result<()> {
     let info = super::info::load_info_state().await?;
     debug!("Version: {}", info.version.git_version);
     debug!("Settings: {:?}", info.settings);
-
+    set_metrics_batch_size(info.settings.metrics_batch_size);
 
     // --- Collectors ---
-    if let Err(e) = super::collectors::k8s::run(state, now).await {
+    // GPU usage is scraped first so the K8s collector can attach it to pod/container rows
+    let gpu = super::collectors::rustexporter::run(&info.settings)
+        .await
+        .unwrap_or_else(|e| {
+            error!(?e, "RustExporter collector failed");
+            Default::default()
+        });
+
+    // Flow counters are scraped before the pod rows so each row carries its traffic locality
+    let network = super::collectors::netflow::run(&info.settings, now)
+        .await
+        .unwrap_or_else(|e| {
+            error!(?e, "Network flow collector failed");
+            Default::default()
+        });
+
+    // Pods/containers come from the selected source. Nodes come from cAdvisor
+    // when it labels its root cgroups, otherwise from the kubelet summary.
+    let source = info.settings.container_metrics_source;
+    let mut covered_nodes = HashSet::new();
+    if source == ContainerMetricsSource::Cadvisor {
+        match super::collectors::cadvisor::run(&state, now, &info.settings, &gpu, &network).await {
+            Ok(nodes) => covered_nodes = nodes,
+            Err(e) => error!(?e, "cAdvisor collector failed"),
+        }
+    }
+
+    let spot_selector = info.settings.spot_node_label_selector.as_deref();
+    if let Err(e) = super::collectors::k8s::run(
+        state,
+        now,
+        &gpu,
+        &network,
+        source,
+        spot_selector,
+        &covered_nodes,
+    )
+    .await
+    {
         error!(?e, "K8s collector failed");
     }
 
-    if let Err(e) = super::collectors::rustexporter::run(now).await {
-        error!(?e, "RustExporter collector failed");
+    // New pods get their container requests and team/service/env from the live pod
+    if let Err(e) = super::info::ownership::task::run().await {
+        error!(?e, "Completing new pod info failed");
+    }
+
+    // Service / Ingress lifecycle for LoadBalancer and Ingress costs
+    if let Err(e) = super::info::service::task::run(now).await {
+        error!(?e, "Service lifecycle collector failed");
+    }
+
+    // Claim / volume lifecycle for persistent volume capacity costs
+    if let Err(e) = super::info::volume::task::run(now).await {
+        error!(?e, "Volume lifecycle collector failed");
+    }
+
+    // Minute boundary: make this tick's rows visible to readers
+    if let Err(e) = flush_metric_writes() {
+        error!(?e, "Flushing buffered metric rows failed");
     }
 
     Ok(())
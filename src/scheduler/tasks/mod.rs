@@ -8,6 +8,7 @@ mod utils;
 mod alarm;
 
 pub use day::run as day_task;
+pub use processors::backfill::catch_up as rollup_catch_up_task;
 pub use hour::run as hour_task;
 pub use minute::run as minute_task;
 
@@ -0,0 +1,2 @@
+mod task;
+pub use task::{catch_up, reaggregate, ReaggregateSummary};
@@ -0,0 +1,80 @@
+use anyhow::Result;
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+use tracing::info;
+
+use crate::core::persistence::info::fixed::setting::info_setting_repository::InfoSettingRepository;
+use crate::core::persistence::info::fixed::setting::info_setting_retention_repository_trait::InfoSettingRetentionRepository;
+use crate::core::persistence::metrics::metric_rollup::{RollupReport, RollupTier, RollupWindow};
+use crate::scheduler::tasks::processors::{day, hour};
+use crate::scheduler::tasks::utils::time_util::TimeUtils;
+
+/// Outcome of a forced re-aggregation, per tier (absent when not requested).
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ReaggregateSummary {
+    pub hour: Option<RollupReport>,
+    pub day: Option<RollupReport>,
+}
+
+/// Re-checks every hour and day bucket still backed by source data.
+///
+/// Runs once at startup so buckets missed while the process was down (or
+/// written by a crashed tick) are produced before the first regular tick.
+pub async fn catch_up(now: DateTime<Utc>) -> Result<()> {
+    let settings = InfoSettingRepository::new().read()?;
+
+    let (_, hour_end) = TimeUtils::previous_hour_window(now)?;
+    let hours = RollupWindow {
+        from: hour_end - Duration::days(settings.minute_retention_days.max(1) as i64),
+        to: hour_end,
+        force: false,
+    };
+    let (_, day_end) = TimeUtils::previous_day_window(now);
+    let days = RollupWindow {
+        from: day_end - Duration::days(31 * settings.hour_retention_months.max(1) as i64),
+        to: day_end,
+        force: false,
+    };
+
+    let hour = hour::run_window(&hours, now).await?;
+    let day = day::run_window(&days, now).await?;
+    info!(?hour, ?day, "Rollup catch-up finished");
+    Ok(())
+}
+
+/// Re-aggregates every closed bucket overlapping `[start, end]`, even if up
+/// to date. Without a tier both run, hours first so days are built from them.
+///
+/// The range is capped to the retention of each tier's source (minute data
+/// for hours, hour data for days); older buckets have nothing to rebuild from.
+pub async fn reaggregate(
+    tier: Option<RollupTier>,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    now: DateTime<Utc>,
+) -> Result<ReaggregateSummary> {
+    let settings = InfoSettingRepository::new().read()?;
+    let window_for = |tier: RollupTier| {
+        let len = tier.bucket_len();
+        let retained_from = match tier {
+            RollupTier::Hour => now - Duration::days(settings.minute_retention_days.into()),
+            RollupTier::Day => now - Duration::days((settings.hour_retention_months * 30).into()),
+        };
+        RollupWindow {
+            from: tier.bucket_start(start.max(retained_from)),
+            // Round the end up to a bucket boundary, but never past the open bucket
+            to: tier.bucket_start(end + len - Duration::seconds(1)).min(tier.bucket_start(now)),
+            force: true,
+        }
+    };
+
+    let mut summary = ReaggregateSummary::default();
+    if tier.is_none_or(|t| t == RollupTier::Hour) {
+        summary.hour = Some(hour::run_window(&window_for(RollupTier::Hour), now).await?);
+    }
+    if tier.is_none_or(|t| t == RollupTier::Day) {
+        summary.day = Some(day::run_window(&window_for(RollupTier::Day), now).await?);
+    }
+    info!(?summary, %start, %end, "Forced re-aggregation finished");
+    Ok(summary)
+}
@@ -0,0 +1,69 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use tracing::{debug, error};
+
+use crate::core::persistence::metrics::k8s::path::{
+    metric_k8s_container_dir_path, metric_k8s_node_dir_path, metric_k8s_pod_dir_path,
+};
+use crate::core::persistence::metrics::metric_index::index_closed_segments;
+use crate::core::persistence::metrics::metric_segment::compress_closed_segments;
+
+/// Compresses (`*.rcd` → `*.rcd.gz`) and/or indexes (`*.rcd.idx`) closed
+/// metric partitions for every node, pod and container.
+///
+/// Only the open partition of each tier — today's minute file, this month's
+/// hour file and this year's day file — stays plain, appendable and unindexed.
+pub async fn run(now: DateTime<Utc>, compress: bool, index: bool) -> Result<()> {
+    debug!("Running metric compression task...");
+
+    // Tier directory name → name of its open partition
+    let open_partitions = [
+        ("m", now.format("%Y-%m-%d").to_string()),
+        ("h", now.format("%Y-%m").to_string()),
+        ("d", now.format("%Y").to_string()),
+    ];
+
+    let (mut compressed, mut indexed) = (0, 0);
+    for base_dir in [metric_k8s_node_dir_path(), metric_k8s_pod_dir_path(), metric_k8s_container_dir_path()] {
+        for key_dir in collect_key_dirs(&base_dir)? {
+            for (tier, open_stem) in &open_partitions {
+                let dir = key_dir.join(tier);
+                // Compress first: compression makes an existing index stale
+                if compress {
+                    match compress_closed_segments(&dir, open_stem) {
+                        Ok(n) => compressed += n,
+                        Err(e) => error!("⚠️ Compression failed for {:?}/{}: {}", key_dir, tier, e),
+                    }
+                }
+                if index {
+                    match index_closed_segments(&dir, open_stem) {
+                        Ok(n) => indexed += n,
+                        Err(e) => error!("⚠️ Indexing failed for {:?}/{}: {}", key_dir, tier, e),
+                    }
+                }
+            }
+        }
+    }
+
+    debug!("✅ Compressed {} and indexed {} closed metric partitions", compressed, indexed);
+    Ok(())
+}
+
+/// Per-object metric directories (node name, pod UID, container key).
+fn collect_key_dirs(base_dir: &Path) -> Result<Vec<PathBuf>> {
+    if !base_dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut dirs = Vec::new();
+    for entry in fs::read_dir(base_dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            dirs.push(path);
+        }
+    }
+    Ok(dirs)
+}
@@ -1,83 +1,75 @@
-use std::fs;
-use std::path::{PathBuf};
+use std::path::Path;
 
 use anyhow::{ Result};
 use chrono::{DateTime, Utc};
 
+use crate::core::persistence::storage_backend::storage_backend;
 use crate::core::persistence::metrics::k8s::pod::day::{
     metric_pod_day_fs_adapter::MetricPodDayFsAdapter,
     metric_pod_day_processor_repository_trait::MetricPodDayProcessorRepository,
 };
 use tracing::{debug, error};
-use crate::core::persistence::metrics::k8s::path::metric_k8s_pod_dir_path;
+use crate::core::persistence::metrics::k8s::path::{metric_k8s_pod_dir_path, metric_k8s_pod_key_dir_path};
+use crate::core::persistence::metrics::metric_rollup::{rollup_object, RollupReport, RollupTier, RollupWindow};
 use crate::core::persistence::metrics::k8s::pod::day::metric_pod_day_processor_repository::MetricPodDayProcessorRepositoryImpl;
-use crate::scheduler::tasks::utils::time_util::TimeUtils;
 
 /// Aggregates all pods’ minute-level metrics into dayly metrics.
 ///
 /// This scans `data/metric/pod/{pod_uid}/` and calls `append_row_aggregated()`
-/// for each pod directory, generating an dayly summary.
-pub async fn process_pod_hour_to_day(now: DateTime<Utc>) -> Result<()> {
-    let (start, end) = TimeUtils::previous_day_window(now);
+/// for each pod directory, generating an dayly summary
+/// for every bucket in `window` that is missing or stale (see `metric_rollup`).
+pub async fn process_pod_hour_to_day(window: &RollupWindow, now: DateTime<Utc>) -> Result<RollupReport> {
     let base_dir = metric_k8s_pod_dir_path();
 
-    if !base_dir.exists() {
-        debug!("No pods directory found at {:?}", base_dir);
-        return Ok(());
-    }
-
     let pod_uids = collect_pod_uids(&base_dir)?;
     if pod_uids.is_empty() {
         debug!("No pod metric directories found under {:?}", base_dir);
-        return Ok(());
+        return Ok(RollupReport::default());
     }
 
     let repo = MetricPodDayProcessorRepositoryImpl {
         adapter: MetricPodDayFsAdapter,
     };
 
-    process_all_pods(&repo, &pod_uids, start, end, now);
-    Ok(())
+    Ok(process_all_pods(&repo, &pod_uids, window, now))
 }
 
 
 
 /// Collects all pod UIDs (directory names) under the given base directory.
-fn collect_pod_uids(base_dir: &PathBuf) -> Result<Vec<String>> {
-    let mut pod_uids = Vec::new();
-
-    for entry in fs::read_dir(base_dir)? {
-        let entry = entry?;
-        let path = entry.path();
-        if path.is_dir() {
-            if let Some(pod_uid) = entry.file_name().to_str() {
-                pod_uids.push(pod_uid.to_string());
-            }
-        }
-    }
-
-    Ok(pod_uids)
+fn collect_pod_uids(base_dir: &Path) -> Result<Vec<String>> {
+    storage_backend().list_keys(base_dir)
 }
 
 /// Aggregates minute-level data into dayly data for all given pods.
 fn process_all_pods<R: MetricPodDayProcessorRepository>(
     repo: &R,
     pod_uids: &[String],
-    start: DateTime<Utc>,
-    end: DateTime<Utc>,
+    window: &RollupWindow,
     now: DateTime<Utc>
-) {
+) -> RollupReport {
+    let mut report = RollupReport::default();
     for pod_uid in pod_uids {
-        match repo.append_row_aggregated(pod_uid, start, end, now) {
-            Ok(_) => debug!(
-                "✅ Aggregated pod '{}' minute metrics from {} → {}",
-                pod_uid, start, end
-            ),
+        let result = rollup_object(
+            &metric_k8s_pod_key_dir_path(pod_uid),
+            RollupTier::Day,
+            window,
+            |start, end| repo.append_row_aggregated(pod_uid, start, end, now),
+        );
+        match result {
+            Ok(rolled) => {
+                debug!(
+                    "✅ Aggregated pod '{}' minute metrics: {} buckets in {} → {}",
+                    pod_uid, rolled.materialized, window.from, window.to
+                );
+                report.merge(rolled);
+            }
             Err(err) => error!(
                 "⚠️ Failed to aggregate pod '{}' metrics: {}",
                 pod_uid, err
             ),
         }
     }
+    report
 }
 
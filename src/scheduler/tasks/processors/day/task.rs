@@ -1,22 +1,48 @@
-use anyhow::Result;
-use chrono::{DateTime, Utc};
+use anyhow::{Context, Result};
+use chrono::{DateTime, Duration, Utc};
 use tracing::{debug};
+use crate::core::persistence::metrics::metric_rollup::{RollupReport, RollupWindow};
 use crate::scheduler::tasks::processors::day::pod::task::process_pod_hour_to_day;
 use crate::scheduler::tasks::processors::day::node::task::process_node_hour_to_day;
 use crate::scheduler::tasks::processors::day::container::task::process_container_hour_to_day;
+use crate::scheduler::tasks::utils::time_util::TimeUtils;
+
+/// Closed days re-checked on every tick (hours re-aggregated since, missed ticks).
+const TICK_LOOKBACK_DAYS: i64 = 3;
 
 pub async fn run(now: DateTime<Utc>) -> Result<()> {
     debug!("Running day aggregation task...");
 
-    process_pod_hour_to_day(now)
-        .await
-        .expect("Failed to process pod hour-to-day aggregation");
-    process_container_hour_to_day(now)
-        .await
-        .expect("Failed to process container hour-to-day aggregation");
-    process_node_hour_to_day(now)
-        .await
-        .expect("Failed to process node hour-to-day aggregation");
+    let (_, end) = TimeUtils::previous_day_window(now);
+    let window = RollupWindow {
+        from: end - Duration::days(TICK_LOOKBACK_DAYS),
+        to: end,
+        force: false,
+    };
+    let report = run_window(&window, now).await?;
+    debug!(?report, "Day aggregation finished");
 
     Ok(())
 }
+
+/// Rolls hour rows up into the day buckets of `window` for every node, pod and container.
+pub async fn run_window(window: &RollupWindow, now: DateTime<Utc>) -> Result<RollupReport> {
+    let mut report = RollupReport::default();
+    report.merge(
+        process_pod_hour_to_day(window, now)
+            .await
+            .context("Failed to process pod hour-to-day aggregation")?,
+    );
+    report.merge(
+        process_container_hour_to_day(window, now)
+            .await
+            .context("Failed to process container hour-to-day aggregation")?,
+    );
+    report.merge(
+        process_node_hour_to_day(window, now)
+            .await
+            .context("Failed to process node hour-to-day aggregation")?,
+    );
+
+    Ok(report)
+}
@@ -1,5 +1,5 @@
 mod task;
-pub use task::run;
+pub use task::{run, run_window};
 
 pub mod container;
 pub mod node;
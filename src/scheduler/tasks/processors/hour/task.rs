@@ -1,23 +1,49 @@
-use anyhow::Result;
-use chrono::{DateTime, Utc};
+use anyhow::{Context, Result};
+use chrono::{DateTime, Duration, Utc};
 use tracing::{debug};
+use crate::core::persistence::metrics::metric_rollup::{RollupReport, RollupWindow};
 use crate::scheduler::tasks::processors::hour::pod::task::process_pod_minute_to_hour;
 use crate::scheduler::tasks::processors::hour::node::task::process_node_minute_to_hour;
 use crate::scheduler::tasks::processors::hour::container::task::process_container_minute_to_hour;
+use crate::scheduler::tasks::utils::time_util::TimeUtils;
+
+/// Closed hours re-checked on every tick, so a missed tick or late minute
+/// samples are picked up without waiting for a restart.
+const TICK_LOOKBACK_HOURS: i64 = 6;
 
 pub async fn run(now: DateTime<Utc>) -> Result<()> {
     debug!("Running hour aggregation task...");
 
-    process_node_minute_to_hour(now)
-        .await
-        .expect("Failed to process node minute-to-hour aggregation");
-    process_pod_minute_to_hour(now)
-        .await
-        .expect("Failed to process pod minute-to-hour aggregation");
-    process_container_minute_to_hour(now)
-        .await
-        .expect("Failed to process container minute-to-hour aggregation");
-
+    let (_, end) = TimeUtils::previous_hour_window(now)?;
+    let window = RollupWindow {
+        from: end - Duration::hours(TICK_LOOKBACK_HOURS),
+        to: end,
+        force: false,
+    };
+    let report = run_window(&window, now).await?;
+    debug!(?report, "Hour aggregation finished");
 
     Ok(())
 }
+
+/// Rolls minute rows up into the hour buckets of `window` for every node, pod and container.
+pub async fn run_window(window: &RollupWindow, now: DateTime<Utc>) -> Result<RollupReport> {
+    let mut report = RollupReport::default();
+    report.merge(
+        process_node_minute_to_hour(window, now)
+            .await
+            .context("Failed to process node minute-to-hour aggregation")?,
+    );
+    report.merge(
+        process_pod_minute_to_hour(window, now)
+            .await
+            .context("Failed to process pod minute-to-hour aggregation")?,
+    );
+    report.merge(
+        process_container_minute_to_hour(window, now)
+            .await
+            .context("Failed to process container minute-to-hour aggregation")?,
+    );
+
+    Ok(report)
+}
@@ -1,3 +1,5 @@
 pub mod retention;
 pub mod hour;
 pub mod day;
+pub mod compression;
+pub mod backfill;
@@ -0,0 +1,52 @@
+use std::fs;
+use std::path::Path;
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use tracing::{debug, error};
+
+use crate::core::persistence::metrics::metric_archive::{
+    archive_expired_segments, kind_dir_path, ARCHIVE_KINDS, ARCHIVE_TIERS,
+};
+
+/// Archives expired partitions of every node, pod and container instead of
+/// deleting them. Replaces the delete cleanup: partitions of a period that
+/// has not fully expired stay live until it has (see `metric_archive`).
+pub async fn run(
+    archive_root: &Path,
+    minute_before: DateTime<Utc>,
+    hour_before: DateTime<Utc>,
+    day_before: DateTime<Utc>,
+) -> Result<()> {
+    // Partition names sorting before these are expired (same cutoffs as `cleanup_old`)
+    let cutoffs = [
+        minute_before.format("%Y-%m-%d").to_string(),
+        hour_before.format("%Y-%m").to_string(),
+        day_before.format("%Y").to_string(),
+    ];
+
+    let mut archived = 0;
+    for kind in ARCHIVE_KINDS {
+        let Some(base_dir) = kind_dir_path(kind).filter(|d| d.exists()) else {
+            continue;
+        };
+
+        for entry in fs::read_dir(&base_dir)? {
+            let key_dir = entry?.path();
+            if !key_dir.is_dir() {
+                continue;
+            }
+            let key = key_dir.file_name().unwrap_or_default().to_string_lossy().into_owned();
+
+            for ((tier, dir), cutoff) in ARCHIVE_TIERS.iter().zip(&cutoffs) {
+                match archive_expired_segments(archive_root, kind, &key, tier, &key_dir.join(dir), cutoff) {
+                    Ok(n) => archived += n,
+                    Err(e) => error!("⚠️ Archiving {} {} ({}) failed: {}", kind, key, tier, e),
+                }
+            }
+        }
+    }
+
+    debug!("✅ Archived {} expired metric partitions into {:?}", archived, archive_root);
+    Ok(())
+}
@@ -1,10 +1,10 @@
-use std::fs;
-use std::path::{PathBuf};
+use std::path::Path;
 
 use anyhow::{ Result};
 use chrono::{DateTime, Utc};
 use tracing::{debug, error};
 
+use crate::core::persistence::storage_backend::storage_backend;
 use crate::core::persistence::metrics::k8s::container::day::metric_container_day_repository::MetricContainerDayRepository;
 use crate::core::persistence::metrics::k8s::container::day::metric_container_day_retention_repository_traits::MetricContainerDayRetentionRepository;
 use crate::core::persistence::metrics::k8s::container::hour::metric_container_hour_fs_adapter::MetricContainerHourFsAdapter;
@@ -19,11 +19,6 @@ use crate::core::persistence::metrics::k8s::path::metric_k8s_container_dir_path;
 pub async fn run(minute_before: DateTime<Utc>, hour_before: DateTime<Utc>, day_before: DateTime<Utc>) -> Result<()> {
     let base_dir = metric_k8s_container_dir_path();
 
-    if !base_dir.exists() {
-        debug!("No containers directory found at {:?}", base_dir);
-        return Ok(());
-    }
-
     let container_uids = collect_container_uids(&base_dir)?;
     if container_uids.is_empty() {
         debug!("No container metric directories found under {:?}", base_dir);
@@ -59,18 +54,6 @@ pub async fn run(minute_before: DateTime<Utc>, hour_before: DateTime<Utc>, day_b
 }
 
 /// Collects all container UIDs (directory names) under the given base directory.
-fn collect_container_uids(base_dir: &PathBuf) -> Result<Vec<String>> {
-    let mut container_uids = Vec::new();
-
-    for entry in fs::read_dir(base_dir)? {
-        let entry = entry?;
-        let path = entry.path();
-        if path.is_dir() {
-            if let Some(container_uid) = entry.file_name().to_str() {
-                container_uids.push(container_uid.to_string());
-            }
-        }
-    }
-
-    Ok(container_uids)
+fn collect_container_uids(base_dir: &Path) -> Result<Vec<String>> {
+    storage_backend().list_keys(base_dir)
 }
@@ -1,5 +1,5 @@
 pub mod task;
+pub mod archive;
 pub mod container;
 pub mod node;
 pub mod pod;
-
@@ -1,10 +1,10 @@
-use std::fs;
-use std::path::{PathBuf};
+use std::path::Path;
 
 use anyhow::{Result};
 use chrono::{DateTime, Utc};
 use tracing::{debug, error};
 
+use crate::core::persistence::storage_backend::storage_backend;
 use crate::core::persistence::metrics::k8s::node::day::metric_node_day_repository::MetricNodeDayRepository;
 use crate::core::persistence::metrics::k8s::node::day::metric_node_day_retention_repository_traits::MetricNodeDayRetentionRepository;
 use crate::core::persistence::metrics::k8s::node::hour::metric_node_hour_fs_adapter::MetricNodeHourFsAdapter;
@@ -19,11 +19,6 @@ use crate::core::persistence::metrics::k8s::node::minute::metric_processor_reten
 pub async fn run(minute_before: DateTime<Utc>, hour_before: DateTime<Utc>, day_before: DateTime<Utc>) -> Result<()> {
     let base_dir = metric_k8s_node_dir_path();
 
-    if !base_dir.exists() {
-        debug!("No nodes directory found at {:?}", base_dir);
-        return Ok(());
-    }
-
     let node_uids = collect_node_uids(&base_dir)?;
     if node_uids.is_empty() {
         debug!("No node metric directories found under {:?}", base_dir);
@@ -59,18 +54,6 @@ pub async fn run(minute_before: DateTime<Utc>, hour_before: DateTime<Utc>, day_b
 }
 
 /// Collects all node UIDs (directory names) under the given base directory.
-fn collect_node_uids(base_dir: &PathBuf) -> Result<Vec<String>> {
-    let mut node_uids = Vec::new();
-
-    for entry in fs::read_dir(base_dir)? {
-        let entry = entry?;
-        let path = entry.path();
-        if path.is_dir() {
-            if let Some(node_uid) = entry.file_name().to_str() {
-                node_uids.push(node_uid.to_string());
-            }
-        }
-    }
-
-    Ok(node_uids)
+fn collect_node_uids(base_dir: &Path) -> Result<Vec<String>> {
+    storage_backend().list_keys(base_dir)
 }
@@ -1,10 +1,10 @@
-use std::fs;
-use std::path::PathBuf;
+use std::path::Path;
 
 use anyhow::{ Result};
 use chrono::{DateTime, Utc};
 use tracing::{debug, error};
 
+use crate::core::persistence::storage_backend::storage_backend;
 use crate::core::persistence::metrics::k8s::pod::day::metric_pod_day_fs_adapter::MetricPodDayFsAdapter;
 use crate::core::persistence::metrics::k8s::pod::day::metric_pod_day_retention_repository_traits::MetricPodDayRetentionRepository;
 use crate::core::persistence::metrics::k8s::pod::hour::metric_pod_hour_fs_adapter::MetricPodHourFsAdapter;
@@ -21,11 +21,6 @@ pub async fn run(minute_before: DateTime<Utc>, hour_before: DateTime<Utc>, day_b
 
     let base_dir = metric_k8s_pod_dir_path();
 
-    if !base_dir.exists() {
-        debug!("No pods directory found at {:?}", base_dir);
-        return Ok(());
-    }
-
     let pod_uids = collect_pod_uids(&base_dir)?;
     if pod_uids.is_empty() {
         debug!("No pod metric directories found under {:?}", base_dir);
@@ -62,18 +57,6 @@ pub async fn run(minute_before: DateTime<Utc>, hour_before: DateTime<Utc>, day_b
 }
 
 /// Collects all pod UIDs (directory names) under the given base directory.
-fn collect_pod_uids(base_dir: &PathBuf) -> Result<Vec<String>> {
-    let mut pod_uids = Vec::new();
-
-    for entry in fs::read_dir(base_dir)? {
-        let entry = entry?;
-        let path = entry.path();
-        if path.is_dir() {
-            if let Some(pod_uid) = entry.file_name().to_str() {
-                pod_uids.push(pod_uid.to_string());
-            }
-        }
-    }
-
-    Ok(pod_uids)
+fn collect_pod_uids(base_dir: &Path) -> Result<Vec<String>> {
+    storage_backend().list_keys(base_dir)
 }
@@ -1,7 +1,10 @@
 use anyhow::Result;
 use chrono::{DateTime, Duration, Utc};
+use tracing::warn;
 use crate::scheduler::tasks::processors::retention;
 use crate::core::persistence::info::fixed::setting::info_setting_retention_repository_trait::InfoSettingRetentionRepository;
+use crate::core::persistence::metrics::metric_archive::archive_root;
+use crate::core::persistence::storage_backend::uses_fs_backend;
 
 pub struct RetentionTask<R: InfoSettingRetentionRepository> {
     pub settings_repo: R,
@@ -19,6 +22,17 @@ impl<R: InfoSettingRetentionRepository> RetentionTask<R> {
         let hour_before   = now - Duration::days((settings.hour_retention_months * 30).into());
         let day_before    = now - Duration::days((settings.day_retention_years * 365).into());
 
+        if settings.retention_policy.eq_ignore_ascii_case("archive") {
+            if uses_fs_backend() {
+                let root = archive_root(&settings);
+                retention::archive::task::run(&root, minute_before, hour_before, day_before).await?;
+                return Ok(());
+            }
+            // Deleting would lose data the operator asked to keep
+            warn!("Archive retention needs the fs storage backend; expired data is kept");
+            return Ok(());
+        }
+
         retention::pod::task::run(minute_before, hour_before, day_before).await?;
         retention::node::task::run(minute_before, hour_before, day_before).await?;
         retention::container::task::run(minute_before, hour_before, day_before).await?;